@@ -0,0 +1,30 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use libfuzzer_sys::fuzz_target;
+use unreal_asset::{engine_version::EngineVersion, Asset};
+
+const ENGINE_VERSIONS: [EngineVersion; 6] = [
+    EngineVersion::VER_UE4_18,
+    EngineVersion::VER_UE4_23,
+    EngineVersion::VER_UE4_25,
+    EngineVersion::VER_UE4_27,
+    EngineVersion::VER_UE5_1,
+    EngineVersion::VER_UE5_2,
+];
+
+// the first byte selects the engine version and the second one where the data is split into
+// the .uasset and .uexp file, 0 meaning no .uexp
+fuzz_target!(|data: &[u8]| {
+    let [version, split, data @ ..] = data else {
+        return;
+    };
+    let engine_version = ENGINE_VERSIONS[*version as usize % ENGINE_VERSIONS.len()];
+
+    let (asset, bulk) = match *split as usize * data.len() / 256 {
+        0 => (data, None),
+        split => (&data[..split], Some(Cursor::new(data[split..].to_vec()))),
+    };
+    let _ = Asset::new(Cursor::new(asset.to_vec()), bulk, engine_version, None);
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use libfuzzer_sys::fuzz_target;
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    engine_version::{get_object_versions, EngineVersion},
+    reader::RawReader,
+    registry::AssetRegistryState,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_25);
+    let mut reader = RawReader::new(
+        Chain::new(Cursor::new(data.to_vec()), None),
+        object_version,
+        object_version_ue5,
+        false,
+        NameMap::new(),
+    );
+    let _ = AssetRegistryState::new(&mut reader);
+});
@@ -0,0 +1,32 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use libfuzzer_sys::fuzz_target;
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    engine_version::{get_object_versions, EngineVersion},
+    reader::{ArchiveTrait, RawReader},
+    types::PackageIndex,
+    KismetExpression,
+};
+
+// kismet is also reached through blueprint exports in the `asset` target,
+// this target reaches more expressions by reading bytecode directly
+fuzz_target!(|data: &[u8]| {
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_25);
+    let name_map = NameMap::from_name_batch(&["None".to_string(), "Value".to_string()]);
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(Cursor::new(data.to_vec()), None),
+        object_version,
+        object_version_ue5,
+        false,
+        name_map,
+    );
+
+    while reader.position() < data.len() as u64 {
+        if KismetExpression::new(&mut reader).is_err() {
+            break;
+        }
+    }
+});
@@ -0,0 +1,22 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use libfuzzer_sys::fuzz_target;
+use unreal_pak::PakReader;
+
+fuzz_target!(|data: &[u8]| {
+    let mut pak = PakReader::new(Cursor::new(data));
+    if pak.load_index().is_err() {
+        return;
+    }
+
+    let names = pak
+        .get_entry_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    for name in &names {
+        let _ = pak.read_entry(name);
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use libfuzzer_sys::fuzz_target;
+use unreal_asset::unversioned::Usmap;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Usmap::new(Cursor::new(data.to_vec()));
+});
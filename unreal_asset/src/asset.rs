@@ -1,8 +1,11 @@
 //! Main [`Asset`] type
 
+use std::any::Any;
+use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::io::{Read, Seek, SeekFrom, Write};
 use std::mem::size_of;
+use std::sync::Arc;
 
 use byteorder::{ReadBytesExt, WriteBytesExt, BE, LE};
 
@@ -19,13 +22,20 @@ use unreal_asset_base::{
     error::Error,
     flags::EPackageFlags,
     object_version::{ObjectVersion, ObjectVersionUE5},
-    reader::{ArchiveReader, ArchiveTrait, ArchiveType, ArchiveWriter, RawReader, RawWriter},
+    reader::{
+        capped_capacity, ArchiveReader, ArchiveTrait, ArchiveType, ArchiveWriter, RawReader,
+        RawWriter,
+    },
     types::{fname::FNameContainer, FName, GenerationInfo, PackageIndex},
     unversioned::Usmap,
-    FNameContainer, Guid, Import,
+    FNameContainer, Guid, Import, PackageIndexContainer,
+};
+use unreal_asset_exports::{
+    raw_export::RawExport, BaseExport, Export, ExportBaseTrait, ExportNormalTrait, ExportTrait,
+};
+use unreal_asset_properties::{
+    custom_property::CustomSerializers, world_tile_property::FWorldTileInfo,
 };
-use unreal_asset_exports::{BaseExport, Export, ExportBaseTrait, ExportNormalTrait, ExportTrait};
-use unreal_asset_properties::world_tile_property::FWorldTileInfo;
 
 use crate::asset_archive_writer::AssetArchiveWriter;
 use crate::asset_data::{AssetData, AssetTrait, ExportReaderTrait};
@@ -33,7 +43,7 @@ use crate::fengineversion::FEngineVersion;
 use crate::UE4_ASSET_MAGIC;
 
 /// Parent Class Info
-#[derive(FNameContainer, Debug, Clone, Eq, PartialEq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Eq, PartialEq)]
 pub struct ParentClassInfo {
     /// Parent classpath
     pub parent_class_path: FName,
@@ -150,13 +160,13 @@ impl UAssetExportMapEntry {
         {
             entry.first_export_dependency_offset = archive.read_i32::<LE>()?;
             entry.serialization_before_serialization_dependencies =
-                Vec::with_capacity(archive.read_i32::<LE>()? as usize);
+                Vec::with_capacity(capped_capacity(archive.read_i32::<LE>()?));
             entry.create_before_serialization_dependencies =
-                Vec::with_capacity(archive.read_i32::<LE>()? as usize);
+                Vec::with_capacity(capped_capacity(archive.read_i32::<LE>()?));
             entry.serialization_before_create_dependencies =
-                Vec::with_capacity(archive.read_i32::<LE>()? as usize);
+                Vec::with_capacity(capped_capacity(archive.read_i32::<LE>()?));
             entry.create_before_create_dependencies =
-                Vec::with_capacity(archive.read_i32::<LE>()? as usize);
+                Vec::with_capacity(capped_capacity(archive.read_i32::<LE>()?));
         }
 
         Ok(entry)
@@ -344,7 +354,7 @@ struct AssetHeader {
 
 //#[derive(Debug)]
 /// Unreal Engine uasset
-#[derive(FNameContainer)]
+#[derive(FNameContainer, PackageIndexContainer)]
 pub struct Asset<C: Read + Seek> {
     /// Raw reader
     #[container_ignore]
@@ -442,7 +452,7 @@ pub struct Asset<C: Read + Seek> {
     pub imports: Vec<Import>,
     /// Depends map
     #[container_ignore]
-    depends_map: Option<Vec<Vec<i32>>>,
+    pub(crate) depends_map: Option<Vec<Vec<i32>>>,
     /// Soft package reference list
     #[container_ignore]
     soft_package_reference_list: Option<Vec<String>>,
@@ -458,6 +468,56 @@ impl<'a, C: Read + Seek> Asset<C> {
         bulk_data: Option<C>,
         engine_version: EngineVersion,
         mappings: Option<Usmap>,
+    ) -> Result<Self, Error> {
+        Self::read(asset_data, bulk_data, engine_version, mappings, None, false)
+    }
+
+    /// Create an asset from a binary file without decoding its exports
+    ///
+    /// The header, name map, imports and export table are parsed up front,
+    /// every export is kept as a pending [`RawExport`] without data until it is decoded with [`Asset::load_export`].
+    /// Export data isn't copied, exports that are never loaded are written back byte for byte
+    /// from `asset_data` and `bulk_data` by [`Asset::write_lazy_data`].
+    ///
+    /// Class exports are always decoded, because they register map and array struct type overrides
+    /// needed to decode other exports.
+    pub fn new_lazy(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+    ) -> Result<Self, Error> {
+        Self::read(asset_data, bulk_data, engine_version, mappings, None, true)
+    }
+
+    /// Create an asset from a binary file using custom struct and property serializers
+    ///
+    /// The serializers are kept with the asset and used again when writing it.
+    pub fn new_with_custom_serializers(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+        custom_serializers: Arc<CustomSerializers>,
+    ) -> Result<Self, Error> {
+        Self::read(
+            asset_data,
+            bulk_data,
+            engine_version,
+            mappings,
+            Some(custom_serializers),
+            false,
+        )
+    }
+
+    /// Read an asset from a binary file
+    fn read(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+        custom_serializers: Option<Arc<CustomSerializers>>,
+        lazy: bool,
     ) -> Result<Self, Error> {
         let use_event_driven_loader = bulk_data.is_some();
 
@@ -519,7 +579,8 @@ impl<'a, C: Read + Seek> Asset<C> {
         };
         asset.set_engine_version(engine_version);
         asset.asset_data.mappings = mappings;
-        asset.parse_data()?;
+        asset.asset_data.custom_serializers = custom_serializers;
+        asset.parse_data(lazy)?;
         Ok(asset)
     }
 
@@ -712,8 +773,7 @@ impl<'a, C: Read + Seek> Asset<C> {
         } else if self.asset_data.object_version
             >= ObjectVersion::VER_UE4_ADDED_CHUNKID_TO_ASSETDATA_AND_UPACKAGE
         {
-            self.chunk_ids = vec![];
-            self.chunk_ids[0] = self.read_i32::<LE>()?;
+            self.chunk_ids = vec![self.read_i32::<LE>()?];
         }
 
         if self.asset_data.object_version
@@ -826,8 +886,8 @@ impl<'a, C: Read + Seek> Asset<C> {
             return None;
         }
 
-        let index = -index.index - 1;
-        if index < 0 || index > self.imports.len() as i32 {
+        let index = -(index.index + 1);
+        if index < 0 || index >= self.imports.len() as i32 {
             return None;
         }
 
@@ -847,6 +907,75 @@ impl<'a, C: Read + Seek> Asset<C> {
         self.asset_data.get_export_mut(index)
     }
 
+    /// Check if an export has been decoded
+    ///
+    /// Always true for exports of assets that weren't read with [`Asset::new_lazy`].
+    pub fn is_export_loaded(&self, index: PackageIndex) -> bool {
+        !matches!(
+            self.asset_data.get_export(index),
+            Some(Export::RawExport(raw)) if raw.pending
+        )
+    }
+
+    /// Get a mutable export reference, decoding the export first if it hasn't been loaded yet
+    ///
+    /// Exports that fail to decode are loaded as a [`RawExport`] holding their data.
+    /// If reading the export fails, it stays pending and can be loaded again.
+    pub fn load_export(
+        &mut self,
+        index: PackageIndex,
+    ) -> Result<Option<&mut Export<PackageIndex>>, Error> {
+        if self.asset_data.get_export(index).is_none() {
+            return Ok(None);
+        }
+
+        let export_index = (index.index - 1) as usize;
+        if let Export::RawExport(raw) = &self.asset_data.exports[export_index] {
+            if raw.pending {
+                let base_export = raw.base_export.clone();
+                let next_starting = (base_export.serial_offset + base_export.serial_size) as u64;
+
+                let export = self.read_export(base_export, next_starting)?;
+                self.asset_data.exports[export_index] = export;
+            }
+        }
+
+        Ok(self.asset_data.get_export_mut(index))
+    }
+
+    /// Decode all exports that haven't been loaded yet
+    pub fn load_all_exports(&mut self) -> Result<(), Error> {
+        for export_index in 0..self.asset_data.exports.len() {
+            self.load_export(PackageIndex::from_export(export_index as i32)?)?;
+        }
+
+        Ok(())
+    }
+
+    /// Check if instances of a class are classes themselves
+    ///
+    /// The class is resolved through its import, with mappings native classes are matched
+    /// by walking their super classes up to `Class`.
+    fn is_class_type(&self, class_index: PackageIndex) -> bool {
+        let Some(import) = self.get_import(class_index) else {
+            return false;
+        };
+        let mappings = self.asset_data.mappings.as_ref();
+        let mut name = import.object_name.get_owned_content();
+
+        // guard against cyclic super chains
+        for _ in 0..=mappings.map_or(0, |e| e.schemas.len()) {
+            if name == "Class" || name.ends_with("GeneratedClass") {
+                return true;
+            }
+            match mappings.and_then(|e| e.schemas.get_by_key(&name)) {
+                Some(schema) if !schema.super_type.is_empty() => name = schema.super_type.clone(),
+                _ => return false,
+            }
+        }
+        false
+    }
+
     /// Get custom version serialization format
     pub fn get_custom_version_serialization_format(&self) -> ECustomVersionSerializationFormat {
         if self.legacy_file_version > 3 {
@@ -859,7 +988,9 @@ impl<'a, C: Read + Seek> Asset<C> {
     }
 
     /// Parse asset data
-    fn parse_data(&mut self) -> Result<(), Error> {
+    ///
+    /// If `lazy` is set, exports other than class exports are kept as raw data
+    fn parse_data(&mut self, lazy: bool) -> Result<(), Error> {
         self.parse_header()?;
 
         self.seek(SeekFrom::Start(self.name_offset as u64))?;
@@ -897,7 +1028,8 @@ impl<'a, C: Read + Seek> Asset<C> {
             }
         }
 
-        let mut export_map = Vec::with_capacity(self.asset_data.summary.export_count as usize);
+        let mut export_map =
+            Vec::with_capacity(capped_capacity(self.asset_data.summary.export_count));
         if self.export_offset > 0 {
             self.seek(SeekFrom::Start(self.export_offset as u64))?;
             for _i in 0..self.asset_data.summary.export_count {
@@ -911,7 +1043,8 @@ impl<'a, C: Read + Seek> Asset<C> {
         if self.depends_offset > 0
             || depends_offset_zero_version_range.contains(&self.get_object_version())
         {
-            let mut depends_map = Vec::with_capacity(self.asset_data.summary.export_count as usize);
+            let mut depends_map =
+                Vec::with_capacity(capped_capacity(self.asset_data.summary.export_count));
 
             // 4.14-4.15 the depends offset wasnt updated so always serialized as 0
             if self.depends_offset > 0 {
@@ -931,7 +1064,7 @@ impl<'a, C: Read + Seek> Asset<C> {
 
         if self.soft_package_reference_offset > 0 {
             let mut soft_package_reference_list =
-                Vec::with_capacity(self.soft_package_reference_count as usize);
+                Vec::with_capacity(capped_capacity(self.soft_package_reference_count));
 
             self.seek(SeekFrom::Start(self.soft_package_reference_offset as u64))?;
 
@@ -981,6 +1114,13 @@ impl<'a, C: Read + Seek> Asset<C> {
                 .map(|e| e.serial_offset as u64)
                 .collect::<Vec<_>>();
 
+            // exports of this asset that other exports are instances of are classes
+            let instanced = export_map
+                .iter()
+                .map(|e| e.class_index)
+                .filter(|e| e.is_export())
+                .collect::<HashSet<_>>();
+
             for (i, entry) in export_map.into_iter().enumerate() {
                 let base_export = entry.to_base_export();
 
@@ -989,7 +1129,13 @@ impl<'a, C: Read + Seek> Asset<C> {
                     false => self.data_length()? - 4,
                 };
 
-                let export = self.read_export(base_export, next_starting)?;
+                let is_class = instanced.contains(&PackageIndex::new(i as i32 + 1))
+                    || self.is_class_type(base_export.class_index);
+
+                let export = match lazy && !is_class {
+                    true => RawExport::pending(base_export).into(),
+                    false => self.read_export(base_export, next_starting)?,
+                };
                 self.asset_data.exports.push(export);
             }
         }
@@ -1119,7 +1265,10 @@ impl<'a, C: Read + Seek> Asset<C> {
         } else if self.asset_data.object_version
             >= ObjectVersion::VER_UE4_ADDED_CHUNKID_TO_ASSETDATA_AND_UPACKAGE
         {
-            cursor.write_i32::<LE>(self.chunk_ids[0])?;
+            let chunk_id = self.chunk_ids.first().ok_or_else(|| {
+                Error::invalid_file("Chunk id is required for this asset version".to_string())
+            })?;
+            cursor.write_i32::<LE>(*chunk_id)?;
         }
 
         if self.asset_data.object_version
@@ -1171,11 +1320,26 @@ impl<'a, C: Read + Seek> Asset<C> {
     }
 
     /// Write asset data
+    ///
+    /// Fails if the asset was read with [`Asset::new_lazy`] and has exports that haven't been loaded,
+    /// use [`Asset::write_lazy_data`] for those.
     pub fn write_data<W: Read + Seek + Write>(
         &self,
         cursor: &mut W,
         uexp_cursor: Option<&mut W>,
     ) -> Result<(), Error> {
+        if let Some(i) = self
+            .asset_data
+            .exports
+            .iter()
+            .position(|e| matches!(e, Export::RawExport(raw) if raw.pending))
+        {
+            return Err(Error::no_data(format!(
+                "Export {} hasn't been loaded, use write_lazy_data to write it from the source",
+                i + 1
+            )));
+        }
+
         if self.asset_data.use_event_driven_loader != uexp_cursor.is_some() {
             return Err(Error::no_data(format!(
                 "use_separate_bulk_data_files is {} but uexp_cursor is {}",
@@ -1455,6 +1619,52 @@ impl<'a, C: Read + Seek> Asset<C> {
 
         Ok(())
     }
+
+    /// Write asset data, copying exports that haven't been loaded from the source
+    ///
+    /// The data of unloaded exports is only read for writing,
+    /// afterwards the exports are still pending and can be loaded.
+    pub fn write_lazy_data<W: Read + Seek + Write>(
+        &mut self,
+        cursor: &mut W,
+        uexp_cursor: Option<&mut W>,
+    ) -> Result<(), Error> {
+        let mut read_exports = Vec::new();
+        let result = self
+            .read_pending_data(&mut read_exports)
+            .and_then(|_| self.write_data(cursor, uexp_cursor));
+
+        for export_index in read_exports {
+            if let Export::RawExport(raw) = &mut self.asset_data.exports[export_index] {
+                raw.data = Vec::new();
+                raw.pending = true;
+            }
+        }
+
+        result
+    }
+
+    /// Read the data of pending exports from the source, the indices of the read exports are added to `read_exports`
+    fn read_pending_data(&mut self, read_exports: &mut Vec<usize>) -> Result<(), Error> {
+        for (i, export) in self.asset_data.exports.iter_mut().enumerate() {
+            let Export::RawExport(raw) = export else {
+                continue;
+            };
+            if !raw.pending {
+                continue;
+            }
+
+            self.raw_reader
+                .seek(SeekFrom::Start(raw.base_export.serial_offset as u64))?;
+            raw.data = self
+                .raw_reader
+                .read_bytes(raw.base_export.serial_size as usize)?;
+            raw.pending = false;
+            read_exports.push(i);
+        }
+
+        Ok(())
+    }
 }
 
 impl<C: Read + Seek> AssetTrait<PackageIndex> for Asset<C> {
@@ -1545,6 +1755,13 @@ impl<C: Read + Seek> ArchiveTrait<PackageIndex> for Asset<C> {
         self.asset_data.mappings.as_ref()
     }
 
+    fn get_custom_serializers(&self) -> Option<&(dyn Any + Send + Sync)> {
+        self.asset_data
+            .custom_serializers
+            .as_deref()
+            .map(|e| e as &(dyn Any + Send + Sync))
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         self.asset_data
             .exports
@@ -1,5 +1,6 @@
 //! Archive that can be used to write an asset
 
+use std::any::Any;
 use std::io::{Seek, Write};
 
 use unreal_asset_base::{
@@ -55,8 +56,8 @@ impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter<PackageIndex>>
             return None;
         }
 
-        let index = -index.index - 1;
-        if index < 0 || index > self.imports.len() as i32 {
+        let index = -(index.index + 1);
+        if index < 0 || index >= self.imports.len() as i32 {
             return None;
         }
 
@@ -130,6 +131,13 @@ impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter<PackageIndex>> ArchiveT
         self.asset_data.mappings.as_ref()
     }
 
+    fn get_custom_serializers(&self) -> Option<&(dyn Any + Send + Sync)> {
+        self.asset_data
+            .custom_serializers
+            .as_deref()
+            .map(|e| e as &(dyn Any + Send + Sync))
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         self.asset_data
             .exports
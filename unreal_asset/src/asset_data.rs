@@ -2,6 +2,7 @@
 //! Must be implemented for all unreal assets
 
 use std::io::SeekFrom;
+use std::sync::Arc;
 
 use unreal_asset_base::{
     cast,
@@ -14,7 +15,7 @@ use unreal_asset_base::{
     reader::ArchiveReader,
     types::{FName, PackageIndex, PackageIndexTrait},
     unversioned::Usmap,
-    FNameContainer,
+    FNameContainer, PackageIndexContainer,
 };
 use unreal_asset_exports::{
     base_export::BaseExport, class_export::ClassExport, data_table_export::DataTableExport,
@@ -24,12 +25,14 @@ use unreal_asset_exports::{
     user_defined_struct_export::UserDefinedStructExport, world_export::WorldExport, Export,
     ExportNormalTrait,
 };
-use unreal_asset_properties::world_tile_property::FWorldTileInfo;
+use unreal_asset_properties::{
+    custom_property::CustomSerializers, world_tile_property::FWorldTileInfo,
+};
 
 use crate::package_file_summary::PackageFileSummary;
 
 /// Unreal asset data, this is relevant for all assets
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq)]
 pub struct AssetData<Index: PackageIndexTrait> {
     /// Does asset use the event driven loader
     pub use_event_driven_loader: bool,
@@ -51,6 +54,10 @@ pub struct AssetData<Index: PackageIndexTrait> {
     #[container_ignore]
     pub mappings: Option<Usmap>,
 
+    /// Custom struct and property serializers
+    #[container_ignore]
+    pub custom_serializers: Option<Arc<CustomSerializers>>,
+
     /// Object exports
     pub exports: Vec<Export<Index>>,
 
@@ -233,6 +240,7 @@ impl<Index: PackageIndexTrait> Default for AssetData<Index> {
             object_version: ObjectVersion::UNKNOWN,
             object_version_ue5: ObjectVersionUE5::UNKNOWN,
             mappings: None,
+            custom_serializers: None,
             exports: Vec::new(),
             world_tile_info: None,
             map_key_override: IndexedMap::from([
@@ -423,8 +431,7 @@ pub trait ExportReaderTrait<Index: PackageIndexTrait>:
                 new_array_overrides,
             ));
         } else if let Some(normal_export) = export.get_normal_export_mut() {
-            let mut extras = vec![0u8; extras_len as usize];
-            self.read_exact(&mut extras)?;
+            let extras = self.read_bytes(extras_len as usize)?;
             normal_export.extras = extras;
         }
 
@@ -0,0 +1,385 @@
+//! Parallel batch asset parsing
+//!
+//! Parses many assets from disk, pak files or memory at once on a pool of worker threads,
+//! each asset is handed to a callback on the thread that parsed it, so assets never have to be sent between threads.
+//!
+//! # Examples
+//!
+//! Finding every DataTable inside of a cooked content directory:
+//!
+//! ```no_run
+//! use unreal_asset::{
+//!     batch::{self, BatchOptions},
+//!     engine_version::EngineVersion,
+//!     Export,
+//! };
+//!
+//! let inputs = batch::collect_directory("Content").unwrap();
+//! let options = BatchOptions::new(EngineVersion::VER_UE4_27).with_class_filter(["DataTable"]);
+//!
+//! let results = batch::parse_batch(inputs, &options, |_, asset| {
+//!     Ok(asset
+//!         .asset_data
+//!         .exports
+//!         .iter()
+//!         .filter_map(|e| match e {
+//!             Export::DataTableExport(e) => e.table.data.first(),
+//!             _ => None,
+//!         })
+//!         .filter_map(|row| row.struct_type.as_ref())
+//!         .map(|e| e.get_owned_content())
+//!         .collect::<Vec<_>>())
+//! });
+//!
+//! for result in results {
+//!     match result.result {
+//!         Ok(row_structs) => println!("{}: {:?}", result.path, row_structs),
+//!         Err(e) => eprintln!("{}: {}", result.path, e),
+//!     }
+//! }
+//! ```
+
+use std::collections::{hash_map::Entry, HashMap};
+use std::fs::{self, File};
+use std::io::{BufReader, Cursor};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::asset::Asset;
+use unreal_asset_base::{
+    engine_version::EngineVersion, error::Error, types::PackageIndex, unversioned::Usmap,
+};
+use unreal_pak::{PakError, PakReader};
+
+/// Asset type used by batch parsing
+pub type BatchAsset = Asset<Cursor<Vec<u8>>>;
+
+/// Pak files opened by a worker thread, each pak index is only read once per thread
+type OpenPaks = HashMap<PathBuf, PakReader<BufReader<File>>>;
+
+/// Asset to parse in a batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchInput {
+    /// Asset file on disk, a `.uexp` file next to it is used as bulk data if it exists
+    File(PathBuf),
+    /// Entry of a pak file on disk, a `.uexp` entry next to it is used as bulk data if it exists
+    Pak {
+        /// Pak file path
+        pak: PathBuf,
+        /// Entry name inside of the pak file
+        entry: String,
+    },
+    /// Asset that is already in memory, for example an entry read from a pak file
+    Memory {
+        /// Path used to identify the asset in results
+        path: String,
+        /// Asset data
+        asset: Vec<u8>,
+        /// Bulk data
+        bulk: Option<Vec<u8>>,
+    },
+}
+
+impl BatchInput {
+    /// Get the path used to identify this input in results
+    pub fn path(&self) -> String {
+        match self {
+            BatchInput::File(path) => path.to_string_lossy().into_owned(),
+            BatchInput::Pak { pak, entry } => pak.join(entry).to_string_lossy().into_owned(),
+            BatchInput::Memory { path, .. } => path.clone(),
+        }
+    }
+
+    /// Read asset and bulk data
+    fn read(self, paks: &mut OpenPaks) -> Result<(Vec<u8>, Option<Vec<u8>>), Error> {
+        match self {
+            BatchInput::File(path) => {
+                let asset = fs::read(&path)?;
+
+                let bulk_path = path.with_extension("uexp");
+                let bulk = match bulk_path.is_file() {
+                    true => Some(fs::read(bulk_path)?),
+                    false => None,
+                };
+
+                Ok((asset, bulk))
+            }
+            BatchInput::Pak { pak, entry } => {
+                let pak_error = |e: PakError| {
+                    Error::invalid_file(format!("Failed to read {}: {e}", pak.display()))
+                };
+                let reader = match paks.entry(pak.clone()) {
+                    Entry::Occupied(e) => e.into_mut(),
+                    Entry::Vacant(e) => e.insert(open_pak(&pak).map_err(pak_error)?),
+                };
+
+                let asset = reader.read_entry(&entry).map_err(pak_error)?;
+
+                let bulk_entry = Path::new(&entry)
+                    .with_extension("uexp")
+                    .to_string_lossy()
+                    .into_owned();
+                let bulk = match reader.contains_entry(&bulk_entry) {
+                    true => Some(reader.read_entry(&bulk_entry).map_err(pak_error)?),
+                    false => None,
+                };
+
+                Ok((asset, bulk))
+            }
+            BatchInput::Memory { asset, bulk, .. } => Ok((asset, bulk)),
+        }
+    }
+}
+
+/// Batch parsing options
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    /// Engine version of all assets
+    pub engine_version: EngineVersion,
+    /// `.usmap` mappings file data
+    ///
+    /// [`Usmap`] can't be shared between threads, so every worker thread parses its own copy.
+    pub mappings: Option<Vec<u8>>,
+    /// Amount of worker threads, uses the available parallelism if `None`
+    pub threads: Option<NonZeroUsize>,
+    /// Export class types to look for
+    ///
+    /// If not empty, assets without an export of one of these classes are skipped
+    /// and only exports of these classes are decoded, other exports can be decoded with [`Asset::load_export`].
+    /// Assets with exports that weren't decoded are written with [`Asset::write_lazy_data`].
+    pub class_filter: Vec<String>,
+}
+
+impl BatchOptions {
+    /// Create new `BatchOptions` for assets of an engine version
+    pub fn new(engine_version: EngineVersion) -> Self {
+        BatchOptions {
+            engine_version,
+            mappings: None,
+            threads: None,
+            class_filter: Vec::new(),
+        }
+    }
+
+    /// Set `.usmap` mappings file data used for unversioned assets
+    pub fn with_mappings(mut self, mappings: Vec<u8>) -> Self {
+        self.mappings = Some(mappings);
+        self
+    }
+
+    /// Set amount of worker threads
+    pub fn with_threads(mut self, threads: NonZeroUsize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Set export class types to look for
+    pub fn with_class_filter<T: Into<String>>(
+        mut self,
+        class_filter: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.class_filter = class_filter.into_iter().map(|e| e.into()).collect();
+        self
+    }
+
+    /// Get amount of worker threads to use
+    fn thread_count(&self) -> usize {
+        self.threads
+            .or_else(|| thread::available_parallelism().ok())
+            .map(|e| e.get())
+            .unwrap_or(1)
+    }
+}
+
+/// Result of processing a single asset in a batch
+#[derive(Debug)]
+pub struct BatchResult<T> {
+    /// Asset path
+    pub path: String,
+    /// Value returned by the callback, or the error that occured while reading or parsing the asset
+    pub result: Result<T, Error>,
+}
+
+/// Recursively collect all `.uasset` and `.umap` files inside of a directory
+///
+/// Files are sorted by path.
+pub fn collect_directory(path: impl AsRef<Path>) -> Result<Vec<BatchInput>, Error> {
+    let mut files = Vec::new();
+    let mut directories = vec![path.as_ref().to_path_buf()];
+
+    while let Some(directory) = directories.pop() {
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                directories.push(path);
+                continue;
+            }
+
+            let is_asset = path
+                .extension()
+                .is_some_and(|e| e == "uasset" || e == "umap");
+            if is_asset {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files.into_iter().map(BatchInput::File).collect())
+}
+
+/// Open a pak file and read its index
+fn open_pak(path: &Path) -> Result<PakReader<BufReader<File>>, PakError> {
+    let mut reader = PakReader::new(BufReader::new(File::open(path)?));
+    reader.load_index()?;
+    Ok(reader)
+}
+
+/// Collect all `.uasset` and `.umap` entries of a pak file
+///
+/// Entries are sorted by name, they are read from the pak file while the batch is parsed.
+pub fn collect_pak(path: impl AsRef<Path>) -> Result<Vec<BatchInput>, Error> {
+    let path = path.as_ref();
+    let reader = open_pak(path)
+        .map_err(|e| Error::invalid_file(format!("Failed to read {}: {e}", path.display())))?;
+
+    Ok(reader
+        .get_entry_names()
+        .into_iter()
+        .filter(|e| e.ends_with(".uasset") || e.ends_with(".umap"))
+        .map(|entry| BatchInput::Pak {
+            pak: path.to_path_buf(),
+            entry: entry.clone(),
+        })
+        .collect())
+}
+
+/// Parse a single batch input
+///
+/// Returns `None` if the asset was skipped by the class filter
+fn parse_input(
+    input: BatchInput,
+    paks: &mut OpenPaks,
+    options: &BatchOptions,
+    mappings: Option<Usmap>,
+) -> Result<Option<BatchAsset>, Error> {
+    let (asset, bulk) = input.read(paks)?;
+    let asset = Cursor::new(asset);
+    let bulk = bulk.map(Cursor::new);
+
+    if options.class_filter.is_empty() {
+        return Asset::new(asset, bulk, options.engine_version, mappings).map(Some);
+    }
+
+    let mut asset = Asset::new_lazy(asset, bulk, options.engine_version, mappings)?;
+
+    let matching = asset
+        .asset_data
+        .exports
+        .iter()
+        .enumerate()
+        .filter(|(_, export)| {
+            asset
+                .get_export_class_name(export)
+                .is_some_and(|class| options.class_filter.contains(&class))
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    if matching.is_empty() {
+        return Ok(None);
+    }
+
+    for export_index in matching {
+        asset.load_export(PackageIndex::from_export(export_index as i32)?)?;
+    }
+
+    Ok(Some(asset))
+}
+
+/// Parse assets on multiple threads
+///
+/// `process` is called on the worker thread that parsed the asset,
+/// results are returned in the same order as the inputs.
+/// Assets that were skipped by [`BatchOptions::class_filter`] are not included in the results.
+pub fn parse_batch<I, F, T>(inputs: I, options: &BatchOptions, process: F) -> Vec<BatchResult<T>>
+where
+    I: IntoIterator<Item = BatchInput>,
+    I::IntoIter: Send,
+    F: Fn(&str, &mut BatchAsset) -> Result<T, Error> + Sync,
+    T: Send,
+{
+    let inputs = Mutex::new(inputs.into_iter().enumerate());
+    let results = Mutex::new(Vec::new());
+
+    thread::scope(|scope| {
+        for _ in 0..options.thread_count() {
+            scope.spawn(|| {
+                let mappings = options
+                    .mappings
+                    .as_ref()
+                    .map(|e| Usmap::new(Cursor::new(e.clone())))
+                    .transpose();
+                let mut paks = OpenPaks::new();
+
+                loop {
+                    let Some((index, input)) = inputs.lock().unwrap().next() else {
+                        break;
+                    };
+
+                    let path = input.path();
+                    let parsed = match &mappings {
+                        Ok(mappings) => parse_input(input, &mut paks, options, mappings.clone()),
+                        Err(e) => Err(Error::invalid_file(format!("Failed to read mappings: {e}"))),
+                    };
+                    let result = match parsed {
+                        Ok(Some(mut asset)) => process(&path, &mut asset),
+                        Ok(None) => continue,
+                        Err(e) => Err(e),
+                    };
+
+                    results
+                        .lock()
+                        .unwrap()
+                        .push((index, BatchResult { path, result }));
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Parse all assets inside of a directory on multiple threads
+///
+/// See [`parse_batch`] and [`collect_directory`]
+pub fn parse_directory<F, T>(
+    path: impl AsRef<Path>,
+    options: &BatchOptions,
+    process: F,
+) -> Result<Vec<BatchResult<T>>, Error>
+where
+    F: Fn(&str, &mut BatchAsset) -> Result<T, Error> + Sync,
+    T: Send,
+{
+    Ok(parse_batch(collect_directory(path)?, options, process))
+}
+
+/// Parse all assets inside of a pak file on multiple threads
+///
+/// See [`parse_batch`] and [`collect_pak`]
+pub fn parse_pak<F, T>(
+    path: impl AsRef<Path>,
+    options: &BatchOptions,
+    process: F,
+) -> Result<Vec<BatchResult<T>>, Error>
+where
+    F: Fn(&str, &mut BatchAsset) -> Result<T, Error> + Sync,
+    T: Send,
+{
+    Ok(parse_batch(collect_pak(path)?, options, process))
+}
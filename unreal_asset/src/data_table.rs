@@ -0,0 +1,1355 @@
+//! Data table CSV and JSON conversion
+//!
+//! Tables are converted the way the editor exports them.
+//! CSV files start with a `---` column holding the row names, followed by one column per row struct property.
+//! Structs, arrays and maps in CSV cells are written as `(X=1,Y=2)`, `(1,2)` and `((Key,Value))`,
+//! strings inside them are quoted.
+//! JSON files are an array of row objects with the row name stored under `Name`.
+//!
+//! Importing replaces all rows of a table. Rows that already exist are updated in place,
+//! new rows only contain the imported columns, and properties missing from the input keep their values.
+//! Property types are taken from the existing rows, and from the row struct schema when it can be resolved,
+//! see [`Asset::get_schema`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::fs::{self, File};
+//!
+//! use unreal_asset::{engine_version::EngineVersion, types::PackageIndex, Asset};
+//!
+//! let file = File::open("DT_Items.uasset").unwrap();
+//! let mut asset = Asset::new(file, None, EngineVersion::VER_UE4_23, None).unwrap();
+//! let table = PackageIndex::new(1);
+//!
+//! fs::write("DT_Items.csv", asset.data_table_to_csv(table).unwrap()).unwrap();
+//! // edit the table in a spreadsheet
+//! let csv = fs::read_to_string("DT_Items.csv").unwrap();
+//! asset.import_data_table_csv(table, &csv).unwrap();
+//! ```
+
+use std::collections::HashSet;
+use std::io::{Read, Seek};
+
+use serde_json::{json, Map, Number, Value};
+
+use unreal_asset_base::{
+    containers::IndexedMap,
+    error::{DataTableError, Error},
+    types::{fname::ToSerializedName, FName, PackageIndex},
+    unversioned::Ancestry,
+};
+use unreal_asset_exports::{data_table_export::DataTableExport, Export};
+use unreal_asset_properties::{
+    array_property::ArrayProperty,
+    int_property::BytePropertyValue,
+    map_property::MapProperty,
+    object_property::SoftObjectPath,
+    property_path::{FromProperty, IntoProperty},
+    str_property::TextHistoryType,
+    struct_property::StructProperty,
+    Property, PropertyDataTrait,
+};
+
+use crate::asset::Asset;
+use crate::object_path::{fname_to_string, split_fname_number};
+use crate::schema::{PropertySchema, PropertyType};
+
+/// Header of the row name column in CSV files
+const CSV_ROW_NAME: &str = "---";
+/// Key of the row name in JSON rows
+const JSON_ROW_NAME: &str = "Name";
+
+/// Imported cell value
+enum Cell {
+    /// CSV cell text
+    Text(String),
+    /// JSON value
+    Json(Value),
+}
+
+/// Imported row
+struct ImportRow {
+    /// Row name
+    name: String,
+    /// Column names and values
+    cells: Vec<(String, Cell)>,
+}
+
+/// Get the column name of a property, static array elements get their index appended
+fn column_name(property: &Property) -> String {
+    let name = fname_to_string(&property.get_name());
+    match property.get_duplication_index() {
+        0 => name,
+        index => format!("{name}[{index}]"),
+    }
+}
+
+/// Split a column name into a property name and a duplication index
+fn parse_column(column: &str) -> (&str, i32) {
+    column
+        .strip_suffix(']')
+        .and_then(|e| e.rsplit_once('['))
+        .and_then(|(name, index)| Some((name, index.parse().ok()?)))
+        .unwrap_or((column, 0))
+}
+
+/// Get the columns of a data table, row struct properties come first
+fn data_table_columns(
+    table: &DataTableExport<PackageIndex>,
+    schema: Option<&PropertySchema>,
+) -> Vec<String> {
+    let mut columns = Vec::new();
+    let mut known = HashSet::new();
+
+    let schema_columns = schema.into_iter().flat_map(|schema| {
+        schema.properties.iter().flat_map(|property| {
+            (0..property.array_size).map(|index| match index {
+                0 => property.name.clone(),
+                index => format!("{}[{index}]", property.name),
+            })
+        })
+    });
+    let row_columns = table
+        .table
+        .data
+        .iter()
+        .flat_map(|row| row.value.iter().map(column_name));
+
+    for column in schema_columns.chain(row_columns) {
+        if known.insert(column.clone()) {
+            columns.push(column);
+        }
+    }
+    columns
+}
+
+/// Check if a struct has custom serialization, its value is then stored as the struct's only member
+fn is_custom_struct(struct_property: &StructProperty) -> bool {
+    struct_property.value.len() == 1
+        && struct_property
+            .struct_type
+            .as_ref()
+            .is_some_and(|e| e.get_content(Property::has_custom_serialization))
+}
+
+/// Check if a property is written in parentheses in CSV cells
+fn is_compound(property: &Property) -> bool {
+    match property {
+        Property::StructProperty(_)
+        | Property::ArrayProperty(_)
+        | Property::SetProperty(_)
+        | Property::MapProperty(_)
+        | Property::VectorProperty(_)
+        | Property::Vector2DProperty(_)
+        | Property::RotatorProperty(_)
+        | Property::IntPointProperty(_)
+        | Property::ColorProperty(_)
+        | Property::LinearColorProperty(_) => true,
+        Property::EmptyProperty(e) => e.type_name.get_content(|e| {
+            matches!(
+                e,
+                "StructProperty" | "ArrayProperty" | "SetProperty" | "MapProperty"
+            )
+        }),
+        _ => false,
+    }
+}
+
+/// Check if a property holds a string, empty CSV cells are only imported into these
+fn is_string(property: &Property) -> bool {
+    matches!(
+        property,
+        Property::StrProperty(_)
+            | Property::Utf8StrProperty(_)
+            | Property::AnsiStrProperty(_)
+            | Property::TextProperty(_)
+            | Property::NameProperty(_)
+    )
+}
+
+/// Quote a string inside a CSV cell
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Format a JSON value as CSV cell text, strings are only quoted when nested
+fn format_text(value: &Value, nested: bool) -> String {
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::Number(e) => e.to_string(),
+        Value::String(e) if nested => quote(e),
+        Value::String(e) => e.clone(),
+        Value::Array(e) => {
+            let values = e.iter().map(|e| format_text(e, true)).collect::<Vec<_>>();
+            format!("({})", values.join(","))
+        }
+        Value::Object(e) => {
+            let members = e
+                .iter()
+                .map(|(key, value)| format!("{key}={}", format_text(value, true)))
+                .collect::<Vec<_>>();
+            format!("({})", members.join(","))
+        }
+    }
+}
+
+/// Parser for parenthesized CSV cell values
+///
+/// `(A=1,B="x")` is parsed into an object, `(1,2)` into an array,
+/// unquoted values are kept as strings and converted once the property type is known.
+struct TextParser<'a> {
+    /// Cell text
+    text: &'a str,
+    /// Current byte position
+    position: usize,
+}
+
+impl<'a> TextParser<'a> {
+    /// Parse a whole cell
+    fn parse(text: &'a str) -> Result<Value, String> {
+        let mut parser = TextParser { text, position: 0 };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            Some(c) => Err(format!("unexpected {c:?} at {}", parser.position)),
+            None => Ok(value),
+        }
+    }
+
+    /// Get the next character
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    /// Consume the next character
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    /// Skip whitespace
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Parse a value
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('(') => self.compound(),
+            Some('"') => self.quoted().map(Value::String),
+            _ => {
+                let start = self.position;
+                while self.peek().is_some_and(|c| c != ',' && c != ')') {
+                    self.bump();
+                }
+                match self.text[start..self.position].trim() {
+                    "None" => Ok(Value::Null),
+                    atom => Ok(Value::String(atom.to_string())),
+                }
+            }
+        }
+    }
+
+    /// Parse a member name followed by `=`, the position is left unchanged if there is none
+    fn key(&mut self) -> Option<String> {
+        let rest = &self.text[self.position..];
+        let end = rest.find(['=', ',', '(', ')', '"'])?;
+        let key = rest[..end].trim();
+        if !rest[end..].starts_with('=') || key.is_empty() {
+            return None;
+        }
+
+        self.position += end + 1;
+        Some(key.to_string())
+    }
+
+    /// Parse a parenthesized struct or array
+    fn compound(&mut self) -> Result<Value, String> {
+        self.bump();
+        let mut values = Vec::new();
+        let mut members = Map::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(')') {
+            self.bump();
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            self.skip_whitespace();
+            match self.key() {
+                Some(key) => {
+                    let value = self.value()?;
+                    members.insert(key, value);
+                }
+                None => values.push(self.value()?),
+            }
+
+            self.skip_whitespace();
+            match self.bump() {
+                Some(',') => continue,
+                Some(')') => break,
+                Some(c) => return Err(format!("unexpected {c:?} at {}", self.position)),
+                None => return Err("missing closing parenthesis".to_string()),
+            }
+        }
+
+        match (values.is_empty(), members.is_empty()) {
+            (_, true) => Ok(Value::Array(values)),
+            (true, false) => Ok(Value::Object(members)),
+            (false, false) => Err("named and unnamed values can't be mixed".to_string()),
+        }
+    }
+
+    /// Parse a quoted string
+    fn quoted(&mut self) -> Result<String, String> {
+        self.bump();
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.bump() {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some(c) => value.push(c),
+                    None => break,
+                },
+                Some(c) => value.push(c),
+                None => break,
+            }
+        }
+        Err("unterminated string".to_string())
+    }
+}
+
+/// Get a string from a JSON value, other scalars are formatted like CSV cells
+fn json_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(e) => Some(e.clone()),
+        value => Some(format_text(value, false)),
+    }
+}
+
+/// Get a float from a JSON number or string
+fn json_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(e) => e.as_f64(),
+        Value::String(e) => e.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Get an integer from a JSON number or string
+fn json_i64(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(e) => e.as_i64(),
+        Value::String(e) => e.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Get a bool from a JSON bool or a `True`/`False` string
+fn json_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(e) => Some(*e),
+        Value::String(e) if e.trim().eq_ignore_ascii_case("true") => Some(true),
+        Value::String(e) if e.trim().eq_ignore_ascii_case("false") => Some(false),
+        _ => None,
+    }
+}
+
+/// Convert a float to JSON, non-finite values become `null`
+fn json_float(value: f64) -> Value {
+    Number::from_f64(value).map_or(Value::Null, Value::Number)
+}
+
+/// Convert an `f32` to JSON through its shortest representation, so `0.1` doesn't become `0.10000000149011612`
+fn json_float32(value: f32) -> Value {
+    json_float(value.to_string().parse().unwrap_or(value as f64))
+}
+
+/// Store an integer in an integer property, `false` if it doesn't fit
+fn set_integer(property: &mut Property, value: &Value) -> bool {
+    let text = match value {
+        Value::Number(e) => e.to_string(),
+        Value::String(e) => e.trim().to_string(),
+        _ => return false,
+    };
+
+    match text.parse::<i64>() {
+        Ok(value) => value.set_property(property),
+        Err(_) => text
+            .parse::<u64>()
+            .is_ok_and(|value| value.set_property(property)),
+    }
+}
+
+/// Store JSON object members in fields, missing members are left unchanged
+///
+/// Returns `false` if the value isn't an object, has unknown members or a member can't be parsed.
+fn set_fields<T>(
+    value: &Value,
+    fields: &mut [(&str, &mut T)],
+    parse: impl Fn(&Value) -> Option<T>,
+) -> bool {
+    let Value::Object(members) = value else {
+        return false;
+    };
+    if !members
+        .keys()
+        .all(|key| fields.iter().any(|(name, _)| name == key))
+    {
+        return false;
+    }
+
+    for (name, field) in fields.iter_mut() {
+        if let Some(member) = members.get(*name) {
+            match parse(member) {
+                Some(member) => **field = member,
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+/// Format a soft object path, `None` for a null path
+fn soft_object_path_to_string(path: &SoftObjectPath) -> Option<String> {
+    let asset_name = fname_to_string(&path.asset_path.asset_name);
+    let mut text = match &path.asset_path.package_name {
+        Some(package_name) if package_name != "None" => {
+            format!("{}.{asset_name}", fname_to_string(package_name))
+        }
+        _ if asset_name == "None" => return None,
+        _ => asset_name,
+    };
+
+    if let Some(sub_path) = path.sub_path_string.as_ref().filter(|e| !e.is_empty()) {
+        text.push(':');
+        text.push_str(sub_path);
+    }
+    Some(text)
+}
+
+/// Convert a CSV error
+fn csv_error(error: csv::Error) -> Error {
+    DataTableError::format(error.to_string()).into()
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Add a name formatted by [`fname_to_string`] to the name map
+    fn new_fname(&self, name: &str) -> FName {
+        let (content, number) = split_fname_number(name);
+        self.get_name_map()
+            .get_mut()
+            .add_fname_with_number(content, number)
+    }
+
+    /// Get a data table export
+    fn get_data_table(
+        &self,
+        export_index: PackageIndex,
+    ) -> Result<&DataTableExport<PackageIndex>, Error> {
+        match self.get_export(export_index) {
+            Some(Export::DataTableExport(e)) => Ok(e),
+            _ => Err(DataTableError::not_a_data_table(export_index.index).into()),
+        }
+    }
+
+    /// Get the row struct of a data table
+    fn get_row_struct(&self, table: &DataTableExport<PackageIndex>) -> Option<PackageIndex> {
+        table
+            .normal_export
+            .properties
+            .iter()
+            .find_map(|e| match e {
+                Property::ObjectProperty(e) if e.name == "RowStruct" => Some(e.value),
+                _ => None,
+            })
+            .filter(|e| e.index != 0)
+    }
+
+    /// Convert a property to JSON
+    fn property_to_json(&self, property: &Property, path: &str) -> Result<Value, Error> {
+        Ok(match property {
+            Property::BoolProperty(e) => Value::Bool(e.value),
+            Property::Int8Property(e) => e.value.into(),
+            Property::Int16Property(e) => e.value.into(),
+            Property::IntProperty(e) => e.value.into(),
+            Property::Int64Property(e) => e.value.into(),
+            Property::UInt16Property(e) => e.value.into(),
+            Property::UInt32Property(e) => e.value.into(),
+            Property::UInt64Property(e) => e.value.into(),
+            Property::FloatProperty(e) => json_float32(e.value.0),
+            Property::DoubleProperty(e) => json_float(e.value.0),
+            Property::ByteProperty(e) => match &e.value {
+                BytePropertyValue::Byte(value) => (*value).into(),
+                BytePropertyValue::FName(value) => fname_to_string(value).into(),
+            },
+            Property::EnumProperty(e) => e.value.as_ref().map(fname_to_string).into(),
+            Property::NameProperty(e) => fname_to_string(&e.value).into(),
+            Property::StrProperty(e) => e.value.clone().into(),
+            Property::Utf8StrProperty(e) => e.value.clone().into(),
+            Property::AnsiStrProperty(e) => e.value.clone().into(),
+            Property::TextProperty(e) => match e.history_type {
+                TextHistoryType::None | TextHistoryType::Base => {
+                    e.culture_invariant_string.clone().into()
+                }
+                _ => e.value.clone().into(),
+            },
+            Property::ObjectProperty(e) => self.get_object_path(e.value).into(),
+            Property::InterfaceProperty(e) => self.get_object_path(e.value).into(),
+            Property::SoftObjectProperty(e) => soft_object_path_to_string(&e.value).into(),
+            Property::VectorProperty(e) => json!({
+                "X": json_float(e.value.x.0),
+                "Y": json_float(e.value.y.0),
+                "Z": json_float(e.value.z.0),
+            }),
+            Property::Vector2DProperty(e) => json!({
+                "X": json_float(e.value.x.0),
+                "Y": json_float(e.value.y.0),
+            }),
+            Property::RotatorProperty(e) => json!({
+                "Pitch": json_float(e.value.x.0),
+                "Yaw": json_float(e.value.y.0),
+                "Roll": json_float(e.value.z.0),
+            }),
+            Property::IntPointProperty(e) => json!({ "X": e.value.x, "Y": e.value.y }),
+            Property::ColorProperty(e) => json!({
+                "R": e.color.r,
+                "G": e.color.g,
+                "B": e.color.b,
+                "A": e.color.a,
+            }),
+            Property::LinearColorProperty(e) => json!({
+                "R": json_float32(e.color.r.0),
+                "G": json_float32(e.color.g.0),
+                "B": json_float32(e.color.b.0),
+                "A": json_float32(e.color.a.0),
+            }),
+            Property::StructProperty(e) if is_custom_struct(e) => {
+                self.property_to_json(&e.value[0], path)?
+            }
+            Property::StructProperty(e) => {
+                let mut members = Map::new();
+                for member in &e.value {
+                    let column = column_name(member);
+                    let value = self.property_to_json(member, &format!("{path}.{column}"))?;
+                    members.insert(column, value);
+                }
+                Value::Object(members)
+            }
+            Property::ArrayProperty(e) => self.elements_to_json(&e.value, path)?,
+            Property::SetProperty(e) => self.elements_to_json(&e.value.value, path)?,
+            Property::MapProperty(e) => {
+                let mut entries = Vec::with_capacity(e.value.len());
+                for (_, key, value) in e.value.iter() {
+                    let key = self.property_to_json(key, path)?;
+                    let path = format!("{path}[{}]", format_text(&key, true));
+                    entries.push((key, self.property_to_json(value, &path)?));
+                }
+
+                // maps with scalar keys are objects like in the editor's export, others are key and value pairs
+                let scalar_keys = entries.iter().all(|(key, _)| {
+                    matches!(key, Value::String(_) | Value::Number(_) | Value::Bool(_))
+                });
+                match scalar_keys {
+                    true => Value::Object(
+                        entries
+                            .into_iter()
+                            .map(|(key, value)| (format_text(&key, false), value))
+                            .collect(),
+                    ),
+                    false => Value::Array(
+                        entries
+                            .into_iter()
+                            .map(|(key, value)| Value::Array(vec![key, value]))
+                            .collect(),
+                    ),
+                }
+            }
+            // zero properties of unversioned assets hold the default value of their type
+            Property::EmptyProperty(e) => {
+                let property_type =
+                    PropertyType::from_serialized_name(&fname_to_string(&e.type_name));
+                let name = FName::new_dummy(fname_to_string(&e.name), 0);
+                match property_type.new_property(name, Ancestry::default(), 0) {
+                    Some(property) => self.property_to_json(&property, path)?,
+                    None => Value::Null,
+                }
+            }
+            property => {
+                return Err(DataTableError::unsupported_property(
+                    path,
+                    property.to_serialized_name(),
+                )
+                .into())
+            }
+        })
+    }
+
+    /// Convert array elements to JSON
+    fn elements_to_json(&self, elements: &[Property], path: &str) -> Result<Value, Error> {
+        elements
+            .iter()
+            .enumerate()
+            .map(|(i, e)| self.property_to_json(e, &format!("{path}[{i}]")))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array)
+    }
+
+    /// Convert a property to CSV cell text
+    fn property_to_text(
+        &self,
+        property: &Property,
+        path: &str,
+        nested: bool,
+    ) -> Result<String, Error> {
+        let join = |values: Vec<String>| format!("({})", values.join(","));
+
+        Ok(match property {
+            Property::StructProperty(e) if is_custom_struct(e) => {
+                self.property_to_text(&e.value[0], path, nested)?
+            }
+            Property::StructProperty(e) => join(
+                e.value
+                    .iter()
+                    .map(|member| {
+                        let column = column_name(member);
+                        let path = format!("{path}.{column}");
+                        Ok(format!(
+                            "{column}={}",
+                            self.property_to_text(member, &path, true)?
+                        ))
+                    })
+                    .collect::<Result<_, Error>>()?,
+            ),
+            Property::ArrayProperty(e) => join(self.elements_to_text(&e.value, path)?),
+            Property::SetProperty(e) => join(self.elements_to_text(&e.value.value, path)?),
+            Property::MapProperty(e) => join(
+                e.value
+                    .iter()
+                    .map(|(_, key, value)| {
+                        Ok(format!(
+                            "({},{})",
+                            self.property_to_text(key, path, true)?,
+                            self.property_to_text(value, path, true)?
+                        ))
+                    })
+                    .collect::<Result<_, Error>>()?,
+            ),
+            property => format_text(&self.property_to_json(property, path)?, nested),
+        })
+    }
+
+    /// Convert array elements to CSV cell text
+    fn elements_to_text(&self, elements: &[Property], path: &str) -> Result<Vec<String>, Error> {
+        elements
+            .iter()
+            .enumerate()
+            .map(|(i, e)| self.property_to_text(e, &format!("{path}[{i}]"), true))
+            .collect()
+    }
+
+    /// Store a JSON value in a property
+    fn set_property_json(
+        &self,
+        property: &mut Property,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), Error> {
+        let type_name = property.to_serialized_name();
+        let invalid = || -> Error {
+            DataTableError::invalid_value(path, format!("{value} is not a valid {type_name}"))
+                .into()
+        };
+
+        if i64::from_property(property).is_some() || u64::from_property(property).is_some() {
+            return match set_integer(property, value) {
+                true => Ok(()),
+                false => Err(invalid()),
+            };
+        }
+        if f64::from_property(property).is_some() {
+            return match json_f64(value).is_some_and(|e| e.set_property(property)) {
+                true => Ok(()),
+                false => Err(invalid()),
+            };
+        }
+
+        let stored = match property {
+            Property::BoolProperty(e) => json_bool(value).map(|value| e.value = value).is_some(),
+            // byte properties holding a number are handled as integers
+            Property::ByteProperty(e) => match json_string(value) {
+                Some(value) => {
+                    e.value = BytePropertyValue::FName(self.new_fname(&value));
+                    true
+                }
+                None => false,
+            },
+            Property::EnumProperty(e) => {
+                e.value = json_string(value).map(|e| self.new_fname(&e));
+                true
+            }
+            Property::NameProperty(e) => {
+                let value = json_string(value).filter(|e| !e.is_empty());
+                e.value = self.new_fname(value.as_deref().unwrap_or("None"));
+                true
+            }
+            Property::StrProperty(e) => {
+                e.value = json_string(value);
+                true
+            }
+            Property::Utf8StrProperty(e) => {
+                e.value = json_string(value);
+                true
+            }
+            Property::AnsiStrProperty(e) => {
+                e.value = json_string(value);
+                true
+            }
+            Property::TextProperty(e) => {
+                match e.history_type {
+                    TextHistoryType::None | TextHistoryType::Base => {
+                        e.culture_invariant_string = json_string(value)
+                    }
+                    _ => e.value = json_string(value),
+                }
+                true
+            }
+            Property::ObjectProperty(e) => {
+                e.value = self.json_object_index(value, path)?;
+                true
+            }
+            Property::InterfaceProperty(e) => {
+                e.value = self.json_object_index(value, path)?;
+                true
+            }
+            Property::SoftObjectProperty(e) => {
+                self.set_soft_object_path(&mut e.value, json_string(value));
+                true
+            }
+            Property::VectorProperty(e) => {
+                let v = &mut e.value;
+                set_fields(
+                    value,
+                    &mut [("X", &mut v.x), ("Y", &mut v.y), ("Z", &mut v.z)],
+                    |e| json_f64(e).map(Into::into),
+                )
+            }
+            Property::Vector2DProperty(e) => {
+                let v = &mut e.value;
+                set_fields(value, &mut [("X", &mut v.x), ("Y", &mut v.y)], |e| {
+                    json_f64(e).map(Into::into)
+                })
+            }
+            Property::RotatorProperty(e) => {
+                let v = &mut e.value;
+                set_fields(
+                    value,
+                    &mut [("Pitch", &mut v.x), ("Yaw", &mut v.y), ("Roll", &mut v.z)],
+                    |e| json_f64(e).map(Into::into),
+                )
+            }
+            Property::IntPointProperty(e) => {
+                let v = &mut e.value;
+                set_fields(value, &mut [("X", &mut v.x), ("Y", &mut v.y)], |e| {
+                    json_i64(e).and_then(|e| e.try_into().ok())
+                })
+            }
+            Property::ColorProperty(e) => {
+                let c = &mut e.color;
+                set_fields(
+                    value,
+                    &mut [
+                        ("R", &mut c.r),
+                        ("G", &mut c.g),
+                        ("B", &mut c.b),
+                        ("A", &mut c.a),
+                    ],
+                    |e| json_i64(e).and_then(|e| e.try_into().ok()),
+                )
+            }
+            Property::LinearColorProperty(e) => {
+                let c = &mut e.color;
+                set_fields(
+                    value,
+                    &mut [
+                        ("R", &mut c.r),
+                        ("G", &mut c.g),
+                        ("B", &mut c.b),
+                        ("A", &mut c.a),
+                    ],
+                    |e| json_f64(e).map(|e| (e as f32).into()),
+                )
+            }
+            Property::StructProperty(e) if is_custom_struct(e) => {
+                self.set_property_json(&mut e.value[0], value, path)?;
+                true
+            }
+            Property::StructProperty(e) => match value {
+                Value::Object(members) => {
+                    for (column, member) in members {
+                        self.set_struct_member(e, column, member, path)?;
+                    }
+                    true
+                }
+                // `()` is parsed as an empty array
+                Value::Array(values) => values.is_empty(),
+                _ => false,
+            },
+            Property::ArrayProperty(e) => {
+                self.set_array_json(e, value, path)?;
+                true
+            }
+            Property::SetProperty(e) => {
+                self.set_array_json(&mut e.value, value, path)?;
+                true
+            }
+            Property::MapProperty(e) => {
+                self.set_map_json(e, value, path)?;
+                true
+            }
+            Property::EmptyProperty(e) => {
+                let property_type =
+                    PropertyType::from_serialized_name(&fname_to_string(&e.type_name));
+                let mut new_property = property_type
+                    .new_property(e.name.clone(), e.ancestry.clone(), 0)
+                    .ok_or_else(|| DataTableError::unsupported_property(path, type_name.clone()))?;
+                self.set_property_json(&mut new_property, value, path)?;
+                *property = new_property;
+                true
+            }
+            _ => return Err(DataTableError::unsupported_property(path, type_name.clone()).into()),
+        };
+
+        match stored {
+            true => Ok(()),
+            false => Err(invalid()),
+        }
+    }
+
+    /// Resolve an object path from JSON, `None` and empty paths are null
+    fn json_object_index(&self, value: &Value, path: &str) -> Result<PackageIndex, Error> {
+        let Some(object_path) = json_string(value).filter(|e| !e.is_empty() && e != "None") else {
+            return Ok(PackageIndex::new(0));
+        };
+
+        // the editor wraps object paths in their class, `/Script/Engine.Texture2D'/Game/Foo.Foo'`
+        let object_path = match object_path.split_once('\'') {
+            Some((_, rest)) => rest.trim_end_matches('\''),
+            None => &object_path,
+        };
+
+        self.find_object_by_path(object_path).ok_or_else(|| {
+            DataTableError::invalid_value(
+                path,
+                format!("{object_path} is not imported or exported by this asset"),
+            )
+            .into()
+        })
+    }
+
+    /// Store a JSON value in a struct member, the member is created from the struct schema if it doesn't exist
+    fn set_struct_member(
+        &self,
+        struct_property: &mut StructProperty,
+        column: &str,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), Error> {
+        let path = format!("{path}.{column}");
+        if let Some(member) = struct_property
+            .value
+            .iter_mut()
+            .find(|e| column_name(e) == column)
+        {
+            return self.set_property_json(member, value, &path);
+        }
+
+        let mut member = self
+            .new_struct_member(struct_property, column)
+            .ok_or_else(|| DataTableError::unknown_column(&path))?;
+        self.set_property_json(&mut member, value, &path)?;
+        struct_property.value.push(member);
+        Ok(())
+    }
+
+    /// Create a struct member from the struct schema
+    fn new_struct_member(
+        &self,
+        struct_property: &StructProperty,
+        column: &str,
+    ) -> Option<Property> {
+        let struct_type = fname_to_string(struct_property.struct_type.as_ref()?);
+        let schema = self.get_schema_by_name(&struct_type)?;
+        let (name, duplication_index) = parse_column(column);
+        schema.get_property(name)?;
+
+        schema.new_property(
+            self.new_fname(name),
+            struct_property
+                .ancestry
+                .with_parent(struct_property.name.clone()),
+            duplication_index,
+        )
+    }
+
+    /// Store a JSON array in an array property
+    ///
+    /// New elements are copied from the last element, or created from the array type if the array is empty.
+    fn set_array_json(
+        &self,
+        array: &mut ArrayProperty,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), Error> {
+        let Value::Array(values) = value else {
+            return Err(
+                DataTableError::invalid_value(path, format!("{value} is not an array")).into(),
+            );
+        };
+
+        array.value.truncate(values.len());
+        for (i, value) in values.iter().enumerate() {
+            let path = format!("{path}[{i}]");
+            if i == array.value.len() {
+                let element = self.new_array_element(array).ok_or_else(|| {
+                    DataTableError::invalid_value(
+                        &path,
+                        "elements of an unknown type can't be created".to_string(),
+                    )
+                })?;
+                array.value.push(element);
+            }
+            self.set_property_json(&mut array.value[i], value, &path)?;
+        }
+        Ok(())
+    }
+
+    /// Create a new array element
+    fn new_array_element(&self, array: &ArrayProperty) -> Option<Property> {
+        if let Some(last) = array.value.last() {
+            return Some(last.clone());
+        }
+        if let Some(dummy) = &array.dummy_property {
+            return Some(dummy.clone().into());
+        }
+
+        let array_type = fname_to_string(array.array_type.as_ref()?);
+        PropertyType::from_serialized_name(&array_type).new_property(
+            array.name.clone(),
+            array.ancestry.with_parent(array.name.clone()),
+            0,
+        )
+    }
+
+    /// Store a JSON object or an array of key and value pairs in a map property
+    fn set_map_json(&self, map: &mut MapProperty, value: &Value, path: &str) -> Result<(), Error> {
+        let entries = match value {
+            Value::Object(members) => members
+                .iter()
+                .map(|(key, value)| (Value::String(key.clone()), value))
+                .collect::<Vec<_>>(),
+            Value::Array(pairs) => pairs
+                .iter()
+                .map(|pair| match pair {
+                    Value::Array(pair) if pair.len() == 2 => Ok((pair[0].clone(), &pair[1])),
+                    pair => Err(DataTableError::invalid_value(
+                        path,
+                        format!("{pair} is not a key and value pair"),
+                    )),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            value => {
+                return Err(
+                    DataTableError::invalid_value(path, format!("{value} is not a map")).into(),
+                )
+            }
+        };
+
+        let (key_template, value_template) = match map.value.iter().next() {
+            Some((_, key, value)) => (Some(key.clone()), Some(value.clone())),
+            None => (
+                self.new_map_element(map, &map.key_type),
+                self.new_map_element(map, &map.value_type),
+            ),
+        };
+
+        let mut new_map = IndexedMap::with_capacity(entries.len());
+        for (key, value) in entries {
+            let path = format!("{path}[{}]", format_text(&key, true));
+            let unknown_type = || {
+                DataTableError::invalid_value(
+                    &path,
+                    "entries of an unknown type can't be created".to_string(),
+                )
+            };
+
+            let mut key_property = key_template.clone().ok_or_else(unknown_type)?;
+            self.set_property_json(&mut key_property, &key, &path)?;
+            let mut value_property = value_template.clone().ok_or_else(unknown_type)?;
+            self.set_property_json(&mut value_property, value, &path)?;
+            new_map.insert(key_property, value_property);
+        }
+        map.value = new_map;
+
+        Ok(())
+    }
+
+    /// Create a new map key or value from its type
+    fn new_map_element(&self, map: &MapProperty, element_type: &FName) -> Option<Property> {
+        PropertyType::from_serialized_name(&fname_to_string(element_type)).new_property(
+            map.name.clone(),
+            map.ancestry.with_parent(map.name.clone()),
+            0,
+        )
+    }
+
+    /// Convert the columns of a row, `None` for columns without a value
+    ///
+    /// Columns the row doesn't have are filled with default values from the row struct schema.
+    fn row_cells<T>(
+        &self,
+        row: &StructProperty,
+        columns: &[String],
+        schema: Option<&PropertySchema>,
+        convert: impl Fn(&Property, &str) -> Result<T, Error>,
+    ) -> Result<Vec<Option<T>>, Error> {
+        let row_name = fname_to_string(&row.name);
+
+        columns
+            .iter()
+            .map(|column| {
+                let path = format!("{row_name}.{column}");
+                if let Some(property) = row.value.iter().find(|e| column_name(e) == *column) {
+                    return convert(property, &path).map(Some);
+                }
+
+                let (name, duplication_index) = parse_column(column);
+                let default = schema.and_then(|e| {
+                    e.new_property(
+                        FName::new_dummy(name.to_string(), 0),
+                        Ancestry::default(),
+                        duplication_index,
+                    )
+                });
+                default.map(|e| convert(&e, &path)).transpose()
+            })
+            .collect()
+    }
+
+    /// Convert a data table to CSV
+    ///
+    /// The first column holds the row names, followed by one column per row struct property.
+    pub fn data_table_to_csv(&self, export_index: PackageIndex) -> Result<String, Error> {
+        let table = self.get_data_table(export_index)?;
+        let schema = self.get_row_struct(table).and_then(|e| self.get_schema(e));
+        let columns = data_table_columns(table, schema.as_ref());
+
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer
+            .write_record(std::iter::once(CSV_ROW_NAME).chain(columns.iter().map(String::as_str)))
+            .map_err(csv_error)?;
+
+        for row in &table.table.data {
+            let cells = self.row_cells(row, &columns, schema.as_ref(), |property, path| {
+                self.property_to_text(property, path, false)
+            })?;
+            writer
+                .write_record(
+                    std::iter::once(fname_to_string(&row.name))
+                        .chain(cells.into_iter().map(Option::unwrap_or_default)),
+                )
+                .map_err(csv_error)?;
+        }
+
+        let data = writer
+            .into_inner()
+            .map_err(|e| Error::from(e.into_error()))?;
+        Ok(String::from_utf8(data)?)
+    }
+
+    /// Convert a data table to JSON
+    ///
+    /// Every row is an object with the row name under `Name`, followed by the row struct properties.
+    pub fn data_table_to_json(&self, export_index: PackageIndex) -> Result<Value, Error> {
+        let table = self.get_data_table(export_index)?;
+        let schema = self.get_row_struct(table).and_then(|e| self.get_schema(e));
+        let columns = data_table_columns(table, schema.as_ref());
+
+        let mut rows = Vec::with_capacity(table.table.data.len());
+        for row in &table.table.data {
+            let cells = self.row_cells(row, &columns, schema.as_ref(), |property, path| {
+                self.property_to_json(property, path)
+            })?;
+
+            let mut object = Map::new();
+            object.insert(JSON_ROW_NAME.to_string(), fname_to_string(&row.name).into());
+            for (column, cell) in columns.iter().zip(cells) {
+                if let Some(cell) = cell {
+                    object.insert(column.clone(), cell);
+                }
+            }
+            rows.push(Value::Object(object));
+        }
+
+        Ok(Value::Array(rows))
+    }
+
+    /// Replace the rows of a data table with rows read from CSV
+    ///
+    /// The first column holds the row names, the other column headers are property names.
+    /// Empty cells leave properties that don't hold a string unchanged.
+    pub fn import_data_table_csv(
+        &mut self,
+        export_index: PackageIndex,
+        csv: &str,
+    ) -> Result<(), Error> {
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        let columns = reader
+            .headers()
+            .map_err(csv_error)?
+            .iter()
+            .skip(1)
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+
+        let mut rows = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(csv_error)?;
+            let mut cells = record.iter();
+            let name = cells.next().unwrap_or_default().to_string();
+            let cells = columns
+                .iter()
+                .cloned()
+                .zip(cells.map(|e| Cell::Text(e.to_string())))
+                .collect();
+            rows.push(ImportRow { name, cells });
+        }
+
+        self.import_data_table(export_index, rows)
+    }
+
+    /// Replace the rows of a data table with rows read from JSON
+    ///
+    /// `json` must be an array of objects with the row name under `Name`,
+    /// the other keys are property names.
+    pub fn import_data_table_json(
+        &mut self,
+        export_index: PackageIndex,
+        json: &Value,
+    ) -> Result<(), Error> {
+        let Value::Array(values) = json else {
+            return Err(
+                DataTableError::format("Data table JSON is not an array".to_string()).into(),
+            );
+        };
+
+        let rows = values
+            .iter()
+            .map(|row| {
+                let Value::Object(row) = row else {
+                    return Err(DataTableError::format(format!(
+                        "Row {row} is not an object"
+                    )));
+                };
+                let Some(Value::String(name)) = row.get(JSON_ROW_NAME) else {
+                    return Err(DataTableError::format(format!(
+                        "Row {} has no {JSON_ROW_NAME}",
+                        Value::Object(row.clone())
+                    )));
+                };
+
+                let cells = row
+                    .iter()
+                    .filter(|(key, _)| *key != JSON_ROW_NAME)
+                    .map(|(key, value)| (key.clone(), Cell::Json(value.clone())))
+                    .collect();
+                Ok(ImportRow {
+                    name: name.clone(),
+                    cells,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.import_data_table(export_index, rows)
+    }
+
+    /// Replace the rows of a data table
+    fn import_data_table(
+        &mut self,
+        export_index: PackageIndex,
+        rows: Vec<ImportRow>,
+    ) -> Result<(), Error> {
+        let table = self.get_data_table(export_index)?;
+        let schema = self.get_row_struct(table).and_then(|e| self.get_schema(e));
+        let columns = data_table_columns(table, schema.as_ref());
+
+        let mut row_names = HashSet::new();
+        let mut new_rows = Vec::with_capacity(rows.len());
+        for row in rows {
+            if !row_names.insert(row.name.clone()) {
+                return Err(DataTableError::duplicate_row(&row.name).into());
+            }
+
+            let mut struct_property = match table
+                .table
+                .data
+                .iter()
+                .find(|e| fname_to_string(&e.name) == row.name)
+            {
+                Some(existing) => existing.clone(),
+                None => self.new_row(table, &row.name),
+            };
+
+            let mut inserted = false;
+            for (column, cell) in row.cells {
+                let path = format!("{}.{column}", row.name);
+                match struct_property
+                    .value
+                    .iter_mut()
+                    .find(|e| column_name(e) == column)
+                {
+                    Some(property) => self.set_cell(property, cell, &path)?,
+                    // empty cells of new properties are left out, the engine uses default values for them
+                    None if matches!(&cell, Cell::Text(text) if text.is_empty()) => {}
+                    None => {
+                        let mut property = self
+                            .new_column_property(table, schema.as_ref(), &struct_property, &column)
+                            .ok_or_else(|| DataTableError::unknown_column(&column))?;
+                        self.set_cell(&mut property, cell, &path)?;
+                        struct_property.value.push(property);
+                        inserted = true;
+                    }
+                }
+            }
+
+            if inserted {
+                struct_property.value.sort_by_cached_key(|e| {
+                    let column = column_name(e);
+                    columns.iter().position(|e| *e == column)
+                });
+            }
+            new_rows.push(struct_property);
+        }
+
+        let Some(Export::DataTableExport(table)) = self.get_export_mut(export_index) else {
+            return Err(DataTableError::not_a_data_table(export_index.index).into());
+        };
+        table.table.data = new_rows;
+        Ok(())
+    }
+
+    /// Create an empty row with the same struct header as the other rows of the table
+    fn new_row(&self, table: &DataTableExport<PackageIndex>, name: &str) -> StructProperty {
+        let name = self.new_fname(name);
+        match table.table.data.first() {
+            Some(first) => StructProperty {
+                name,
+                value: Vec::new(),
+                ..first.clone()
+            },
+            None => StructProperty {
+                name,
+                struct_type: self
+                    .get_row_struct(table)
+                    .and_then(|e| self.get_import(e))
+                    .map(|e| e.object_name),
+                serialize_none: true,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Create a property for a column that a row doesn't have yet
+    ///
+    /// The property is copied from another row, or created from the row struct schema.
+    fn new_column_property(
+        &self,
+        table: &DataTableExport<PackageIndex>,
+        schema: Option<&PropertySchema>,
+        row: &StructProperty,
+        column: &str,
+    ) -> Option<Property> {
+        let existing = table
+            .table
+            .data
+            .iter()
+            .flat_map(|e| &e.value)
+            .find(|e| column_name(e) == column);
+        if let Some(existing) = existing {
+            return Some(existing.clone());
+        }
+
+        let schema = schema?;
+        let (name, duplication_index) = parse_column(column);
+        schema.get_property(name)?;
+        schema.new_property(
+            self.new_fname(name),
+            row.ancestry.with_parent(row.name.clone()),
+            duplication_index,
+        )
+    }
+
+    /// Store an imported cell in a property
+    fn set_cell(&self, property: &mut Property, cell: Cell, path: &str) -> Result<(), Error> {
+        let value = match cell {
+            Cell::Json(value) => value,
+            Cell::Text(text) if text.is_empty() && !is_string(property) => return Ok(()),
+            Cell::Text(text) if is_compound(property) => {
+                TextParser::parse(&text).map_err(|e| DataTableError::invalid_value(path, e))?
+            }
+            Cell::Text(text) => Value::String(text),
+        };
+
+        self.set_property_json(property, &value, path)
+    }
+
+    /// Store a path in a soft object path, `None` clears it
+    fn set_soft_object_path(&self, path: &mut SoftObjectPath, value: Option<String>) {
+        let value = value
+            .filter(|e| !e.is_empty())
+            .unwrap_or_else(|| "None".to_string());
+        let (asset_path, sub_path) = match value.split_once(':') {
+            Some((asset_path, sub_path)) => (asset_path, Some(sub_path.to_string())),
+            None => (value.as_str(), None),
+        };
+
+        match path.asset_path.package_name {
+            // since UE5.1 the package name and the asset name are stored separately
+            Some(_) => {
+                let (package_name, asset_name) =
+                    asset_path.rsplit_once('.').unwrap_or((asset_path, "None"));
+                path.asset_path.package_name = Some(self.new_fname(package_name));
+                path.asset_path.asset_name = self.new_fname(asset_name);
+            }
+            None => path.asset_path.asset_name = self.new_fname(asset_path),
+        }
+        path.sub_path_string = sub_path;
+    }
+}
@@ -0,0 +1,537 @@
+//! Asset diffing and merging
+//!
+//! Exports are aligned by their object path and properties by their name and duplication index,
+//! so two versions of the same asset can be compared even if their import, export and name map
+//! order differs.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek};
+
+use unreal_asset_base::{
+    containers::IndexedMap,
+    types::{fname::FNameContainer, FName, PackageIndex, PackageIndexContainer, PackageIndexTrait},
+};
+use unreal_asset_exports::ExportNormalTrait;
+use unreal_asset_properties::{Property, PropertyDataTrait};
+
+use crate::asset::Asset;
+
+/// Property change
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertyChange {
+    /// Property only exists in the new asset
+    Added(Property),
+    /// Property only exists in the old asset
+    Removed(Property),
+    /// Property exists in both assets but has a different value
+    Changed {
+        /// Old property
+        old: Property,
+        /// New property
+        new: Property,
+    },
+}
+
+/// Difference of a single property
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyDiff {
+    /// Property name
+    pub name: String,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Change
+    pub change: PropertyChange,
+}
+
+/// Difference of a single export
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportDiff {
+    /// Export only exists in the new asset
+    Added {
+        /// Export object path
+        path: String,
+        /// Export index in the new asset
+        index: PackageIndex,
+    },
+    /// Export only exists in the old asset
+    Removed {
+        /// Export object path
+        path: String,
+        /// Export index in the old asset
+        index: PackageIndex,
+    },
+    /// Export exists in both assets but its properties differ
+    Changed {
+        /// Export object path
+        path: String,
+        /// Export index in the old asset
+        old_index: PackageIndex,
+        /// Export index in the new asset
+        new_index: PackageIndex,
+        /// Property differences
+        properties: Vec<PropertyDiff>,
+    },
+}
+
+impl ExportDiff {
+    /// Get the object path of the export this diff refers to
+    pub fn get_path(&self) -> &str {
+        match self {
+            ExportDiff::Added { path, .. } => path,
+            ExportDiff::Removed { path, .. } => path,
+            ExportDiff::Changed { path, .. } => path,
+        }
+    }
+}
+
+/// Difference between two assets
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetDiff {
+    /// Export differences
+    pub exports: Vec<ExportDiff>,
+}
+
+impl AssetDiff {
+    /// Check if the assets were equal
+    pub fn is_empty(&self) -> bool {
+        self.exports.is_empty()
+    }
+
+    /// Get property differences of an export by object path
+    pub fn get_property_diffs(&self, path: &str) -> Option<&[PropertyDiff]> {
+        self.exports.iter().find_map(|e| match e {
+            ExportDiff::Changed {
+                path: export_path,
+                properties,
+                ..
+            } if export_path == path => Some(properties.as_slice()),
+            _ => None,
+        })
+    }
+}
+
+/// Three-way merge conflict
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeConflict {
+    /// Both sides changed the same property in a different way
+    Property {
+        /// Export object path
+        path: String,
+        /// Property name
+        name: String,
+        /// Property duplication index
+        duplication_index: i32,
+        /// Our change
+        ours: PropertyChange,
+        /// Their change
+        theirs: PropertyChange,
+    },
+    /// An export can't be merged
+    Export {
+        /// Export object path
+        path: String,
+        /// Why the export can't be merged
+        reason: &'static str,
+    },
+    /// A property references an object that doesn't exist in our asset
+    UnresolvedReference {
+        /// Export object path
+        path: String,
+        /// Property name
+        name: String,
+        /// Object path of the referenced object
+        reference: String,
+    },
+}
+
+/// Property key used for aligning properties
+type PropertyKey = (String, i32);
+
+/// Get a property key
+fn property_key(property: &Property) -> PropertyKey {
+    (
+        property.get_name().get_owned_content(),
+        property.get_duplication_index(),
+    )
+}
+
+/// Get an object path
+///
+/// Export paths are relative to the asset's package so that they don't depend on package name detection
+fn object_path<C: Read + Seek>(asset: &Asset<C>, index: PackageIndex) -> Option<String> {
+    asset.get_object_path_with_package(index, None)
+}
+
+/// Normalize a property so that it can be compared with a property from another asset
+///
+/// Names are replaced with their content and object references are replaced with sequential indices,
+/// the object paths of the references are returned alongside the property.
+fn normalize_property<C: Read + Seek>(
+    asset: &Asset<C>,
+    property: &Property,
+) -> (Property, Vec<Option<String>>) {
+    let mut property = property.clone();
+
+    property.traverse_fnames(&mut |name| {
+        *name = FName::new_dummy(name.get_owned_content(), name.get_number());
+    });
+
+    let mut references = Vec::new();
+    property.traverse_package_indices(&mut |index| {
+        references.push(match index.index {
+            0 => None,
+            _ => Some(object_path(asset, *index).unwrap_or_default()),
+        });
+        *index = PackageIndex::new(references.len() as i32);
+    });
+
+    (property, references)
+}
+
+/// Check if two properties from different assets are equal
+fn properties_equal<C: Read + Seek, D: Read + Seek>(
+    a_asset: &Asset<C>,
+    a: &Property,
+    b_asset: &Asset<D>,
+    b: &Property,
+) -> bool {
+    normalize_property(a_asset, a) == normalize_property(b_asset, b)
+}
+
+/// Check if two property changes have the same result
+fn changes_equal<C: Read + Seek, D: Read + Seek>(
+    a_asset: &Asset<C>,
+    a: &PropertyChange,
+    b_asset: &Asset<D>,
+    b: &PropertyChange,
+) -> bool {
+    match (a, b) {
+        (PropertyChange::Removed(_), PropertyChange::Removed(_)) => true,
+        (
+            PropertyChange::Added(a) | PropertyChange::Changed { new: a, .. },
+            PropertyChange::Added(b) | PropertyChange::Changed { new: b, .. },
+        ) => properties_equal(a_asset, a, b_asset, b),
+        _ => false,
+    }
+}
+
+/// Get a map of export object paths to export indices
+fn export_paths<C: Read + Seek>(asset: &Asset<C>) -> IndexedMap<String, PackageIndex> {
+    (0..asset.asset_data.exports.len())
+        .map(|i| PackageIndex::new(i as i32 + 1))
+        .filter_map(|index| object_path(asset, index).map(|path| (path, index)))
+        .collect()
+}
+
+/// Diff two property lists
+fn diff_properties<C: Read + Seek, D: Read + Seek>(
+    old_asset: &Asset<C>,
+    old: &[Property],
+    new_asset: &Asset<D>,
+    new: &[Property],
+) -> Vec<PropertyDiff> {
+    let old_properties = old
+        .iter()
+        .map(|e| (property_key(e), e))
+        .collect::<HashMap<_, _>>();
+    let new_keys = new.iter().map(property_key).collect::<Vec<_>>();
+
+    let mut diffs = Vec::new();
+    for ((name, duplication_index), new_property) in new_keys.iter().cloned().zip(new) {
+        let change = match old_properties.get(&(name.clone(), duplication_index)) {
+            Some(old_property) => {
+                match properties_equal(old_asset, old_property, new_asset, new_property) {
+                    true => continue,
+                    false => PropertyChange::Changed {
+                        old: (*old_property).clone(),
+                        new: new_property.clone(),
+                    },
+                }
+            }
+            None => PropertyChange::Added(new_property.clone()),
+        };
+
+        diffs.push(PropertyDiff {
+            name,
+            duplication_index,
+            change,
+        });
+    }
+
+    for old_property in old {
+        let (name, duplication_index) = property_key(old_property);
+        if !new_keys
+            .iter()
+            .any(|(new_name, new_index)| *new_name == name && *new_index == duplication_index)
+        {
+            diffs.push(PropertyDiff {
+                name,
+                duplication_index,
+                change: PropertyChange::Removed(old_property.clone()),
+            });
+        }
+    }
+
+    diffs
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Diff this asset against a newer version of it
+    ///
+    /// Only properties of exports that contain a `NormalExport` are compared.
+    pub fn diff<D: Read + Seek>(&self, new: &Asset<D>) -> AssetDiff {
+        let old_paths = export_paths(self);
+        let new_paths = export_paths(new);
+
+        let mut exports = Vec::new();
+
+        for (_, path, new_index) in new_paths.iter() {
+            let Some(old_index) = old_paths.get_by_key(path) else {
+                exports.push(ExportDiff::Added {
+                    path: path.clone(),
+                    index: *new_index,
+                });
+                continue;
+            };
+
+            let old_properties = self
+                .get_export(*old_index)
+                .and_then(|e| e.get_normal_export())
+                .map(|e| e.properties.as_slice())
+                .unwrap_or_default();
+            let new_properties = new
+                .get_export(*new_index)
+                .and_then(|e| e.get_normal_export())
+                .map(|e| e.properties.as_slice())
+                .unwrap_or_default();
+
+            let properties = diff_properties(self, old_properties, new, new_properties);
+            if !properties.is_empty() {
+                exports.push(ExportDiff::Changed {
+                    path: path.clone(),
+                    old_index: *old_index,
+                    new_index: *new_index,
+                    properties,
+                });
+            }
+        }
+
+        for (_, path, old_index) in old_paths.iter() {
+            if !new_paths.contains_key(path) {
+                exports.push(ExportDiff::Removed {
+                    path: path.clone(),
+                    index: *old_index,
+                });
+            }
+        }
+
+        AssetDiff { exports }
+    }
+
+    /// Three-way merge this asset with another modified version of the same base asset
+    ///
+    /// Property changes from `theirs` are applied on top of this asset,
+    /// if both sides changed the same property differently a conflict is reported.
+    /// Exports that were only added or removed by `theirs` can't be merged and are reported as conflicts.
+    pub fn merge<B: Read + Seek, T: Read + Seek>(
+        mut self,
+        base: &Asset<B>,
+        theirs: &Asset<T>,
+    ) -> Result<Self, Vec<MergeConflict>> {
+        let our_diff = base.diff(&self);
+        let their_diff = base.diff(theirs);
+
+        let our_paths = export_paths(&self);
+        let our_objects = (1..=self.imports.len() as i32)
+            .map(|i| PackageIndex::new(-i))
+            .chain(our_paths.values().copied())
+            .filter_map(|index| {
+                object_path(&self, index).map(|path| ((index.is_import(), path), index))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut conflicts = Vec::new();
+
+        for export_diff in &their_diff.exports {
+            let path = export_diff.get_path();
+            let our_change = our_diff.exports.iter().find(|e| e.get_path() == path);
+
+            let (new_index, their_properties) = match export_diff {
+                ExportDiff::Changed {
+                    new_index,
+                    properties,
+                    ..
+                } => (*new_index, properties),
+                ExportDiff::Added { .. } | ExportDiff::Removed { .. } => {
+                    // both sides agreeing on removal doesn't need any changes
+                    let same_removal = matches!(export_diff, ExportDiff::Removed { .. })
+                        && matches!(our_change, Some(ExportDiff::Removed { .. }));
+                    if !same_removal {
+                        conflicts.push(MergeConflict::Export {
+                            path: path.to_string(),
+                            reason: match export_diff {
+                                ExportDiff::Added { .. } => "export was added",
+                                _ => "export was removed",
+                            },
+                        });
+                    }
+                    continue;
+                }
+            };
+
+            let Some(our_index) = our_paths.get_by_key(path).copied() else {
+                conflicts.push(MergeConflict::Export {
+                    path: path.to_string(),
+                    reason: "export was changed but we removed it",
+                });
+                continue;
+            };
+
+            let our_properties = match our_change {
+                Some(ExportDiff::Changed { properties, .. }) => properties.as_slice(),
+                _ => &[],
+            };
+
+            let their_export_properties = theirs
+                .get_export(new_index)
+                .and_then(|e| e.get_normal_export())
+                .map(|e| e.properties.as_slice())
+                .unwrap_or_default();
+
+            for their_property in their_properties {
+                let key = (
+                    their_property.name.clone(),
+                    their_property.duplication_index,
+                );
+
+                if let Some(our_property) = our_properties
+                    .iter()
+                    .find(|e| e.name == key.0 && e.duplication_index == key.1)
+                {
+                    if !changes_equal(&self, &our_property.change, theirs, &their_property.change) {
+                        conflicts.push(MergeConflict::Property {
+                            path: path.to_string(),
+                            name: key.0,
+                            duplication_index: key.1,
+                            ours: our_property.change.clone(),
+                            theirs: their_property.change.clone(),
+                        });
+                    }
+                    continue;
+                }
+
+                let new_property = match &their_property.change {
+                    PropertyChange::Added(e) | PropertyChange::Changed { new: e, .. } => {
+                        match self.import_property(theirs, e, &our_objects) {
+                            Ok(e) => Some(e),
+                            Err(reference) => {
+                                conflicts.push(MergeConflict::UnresolvedReference {
+                                    path: path.to_string(),
+                                    name: key.0,
+                                    reference,
+                                });
+                                continue;
+                            }
+                        }
+                    }
+                    PropertyChange::Removed(_) => None,
+                };
+
+                let Some(our_export) = self
+                    .asset_data
+                    .get_export_mut(our_index)
+                    .and_then(|e| e.get_normal_export_mut())
+                else {
+                    conflicts.push(MergeConflict::Export {
+                        path: path.to_string(),
+                        reason: "export has no properties",
+                    });
+                    continue;
+                };
+
+                let position = our_export
+                    .properties
+                    .iter()
+                    .position(|e| property_key(e) == key);
+
+                match (position, new_property) {
+                    (Some(position), Some(new_property)) => {
+                        our_export.properties[position] = new_property
+                    }
+                    (Some(position), None) => {
+                        our_export.properties.remove(position);
+                    }
+                    (None, Some(new_property)) => {
+                        // keep their property order by inserting after the closest preceding property we have
+                        let their_position = their_export_properties
+                            .iter()
+                            .position(|e| property_key(e) == key)
+                            .unwrap_or_default();
+                        let insert_position = their_export_properties[..their_position]
+                            .iter()
+                            .rev()
+                            .find_map(|e| {
+                                let preceding_key = property_key(e);
+                                our_export
+                                    .properties
+                                    .iter()
+                                    .position(|e| property_key(e) == preceding_key)
+                            })
+                            .map(|e| e + 1)
+                            .unwrap_or_default();
+                        our_export.properties.insert(insert_position, new_property);
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+
+        match conflicts.is_empty() {
+            true => Ok(self),
+            false => Err(conflicts),
+        }
+    }
+
+    /// Import a property from another asset into this asset
+    ///
+    /// Names are added to this asset's name map and object references are remapped by object path,
+    /// if a referenced object doesn't exist in this asset its path is returned as an error.
+    fn import_property<D: Read + Seek>(
+        &mut self,
+        other: &Asset<D>,
+        property: &Property,
+        objects: &HashMap<(bool, String), PackageIndex>,
+    ) -> Result<Property, String> {
+        let mut property = property.clone();
+
+        let mut name_map = self.get_name_map();
+        property.traverse_fnames(&mut |name| {
+            // dummy names are never serialized and can be kept as is
+            if let FName::Backed { number, .. } = name {
+                let number = *number;
+                let content = name.get_owned_content();
+                *name = name_map.get_mut().add_fname_with_number(&content, number);
+            }
+        });
+
+        let mut unresolved = None;
+        property.traverse_package_indices(&mut |index| {
+            if index.index == 0 || unresolved.is_some() {
+                return;
+            }
+
+            let path = object_path(other, *index).unwrap_or_default();
+            match objects.get(&(index.is_import(), path.clone())) {
+                Some(our_index) => *index = *our_index,
+                None => unresolved = Some(path),
+            }
+        });
+
+        match unresolved {
+            Some(path) => Err(path),
+            None => Ok(property),
+        }
+    }
+}
@@ -87,8 +87,18 @@ pub mod ac7;
 pub mod asset;
 pub mod asset_archive_writer;
 pub mod asset_data;
+pub mod batch;
+pub mod data_table;
+pub mod diff;
 pub mod fengineversion;
+pub mod localization;
+pub mod object_path;
 pub mod package_file_summary;
+pub mod query;
+pub mod removal;
+pub mod schema;
+pub mod transplant;
+pub mod validation;
 
 pub use asset::Asset;
 
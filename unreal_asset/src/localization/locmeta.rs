@@ -0,0 +1,100 @@
+//! Localization metadata (`.locmeta`) files
+
+use std::io::{Read, Seek, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::error::{Error, LocalizationError};
+use unreal_helpers::read_ext::capped_capacity;
+
+use super::{read_string, write_string};
+
+/// Magic at the start of all `.locmeta` files
+const LOCMETA_MAGIC: [u8; 16] = [
+    0x4f, 0xee, 0x4c, 0xa1, 0x68, 0x48, 0x55, 0x83, 0x6c, 0x4c, 0x46, 0xbd, 0x70, 0xda, 0x50, 0x7c,
+];
+
+/// `.locmeta` file version
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum LocMetaVersion {
+    /// Initial version
+    Initial = 0,
+    /// Added the list of compiled cultures
+    #[default]
+    AddedCompiledCultures = 1,
+}
+
+impl TryFrom<u8> for LocMetaVersion {
+    type Error = LocalizationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(LocMetaVersion::Initial),
+            1 => Ok(LocMetaVersion::AddedCompiledCultures),
+            _ => Err(LocalizationError::unknown_version("locmeta", value)),
+        }
+    }
+}
+
+/// Localization metadata, describes the cultures of a localization target
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocMeta {
+    /// File version
+    pub version: LocMetaVersion,
+    /// Culture the source strings are written in, e.g. `en`
+    pub native_culture: String,
+    /// Path of the native culture `.locres`, relative to the localization target directory
+    pub native_locres: String,
+    /// Cultures that have a compiled `.locres`
+    pub compiled_cultures: Vec<String>,
+}
+
+impl LocMeta {
+    /// Read a `LocMeta`
+    pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, Error> {
+        let mut magic = [0u8; 16];
+        reader.read_exact(&mut magic)?;
+        if magic != LOCMETA_MAGIC {
+            return Err(Error::invalid_file(format!(
+                "Invalid .locmeta magic, got: {magic:?}"
+            )));
+        }
+
+        let version = LocMetaVersion::try_from(reader.read_u8()?)?;
+        let native_culture = read_string(reader)?;
+        let native_locres = read_string(reader)?;
+
+        let mut compiled_cultures = Vec::new();
+        if version >= LocMetaVersion::AddedCompiledCultures {
+            let count = reader.read_i32::<LE>()?;
+            compiled_cultures.reserve(capped_capacity(count));
+            for _ in 0..count {
+                compiled_cultures.push(read_string(reader)?);
+            }
+        }
+
+        Ok(LocMeta {
+            version,
+            native_culture,
+            native_locres,
+            compiled_cultures,
+        })
+    }
+
+    /// Write this `LocMeta` in its version
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_all(&LOCMETA_MAGIC)?;
+        writer.write_u8(self.version as u8)?;
+        write_string(writer, &self.native_culture)?;
+        write_string(writer, &self.native_locres)?;
+
+        if self.version >= LocMetaVersion::AddedCompiledCultures {
+            writer.write_i32::<LE>(self.compiled_cultures.len() as i32)?;
+            for culture in &self.compiled_cultures {
+                write_string(writer, culture)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,271 @@
+//! Localization resource (`.locres`) files
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::{
+    containers::IndexedMap,
+    crc,
+    error::{Error, LocalizationError},
+};
+use unreal_helpers::read_ext::capped_capacity;
+
+use super::{read_string, write_string};
+
+/// Magic at the start of all `.locres` files newer than [`LocResVersion::Legacy`]
+const LOCRES_MAGIC: [u8; 16] = [
+    0x0e, 0x14, 0x74, 0x75, 0x67, 0x4a, 0x03, 0xfc, 0x4a, 0x15, 0x90, 0x9d, 0xc3, 0x37, 0x7f, 0x1b,
+];
+
+/// `.locres` file version
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum LocResVersion {
+    /// No magic, localized strings are stored inline
+    Legacy = 0,
+    /// Localized strings are deduplicated into an array at the end of the file
+    Compact = 1,
+    /// Namespaces and keys are stored with a CRC32 hash
+    OptimizedCrc32 = 2,
+    /// Namespaces and keys are stored with a CityHash64 hash of their UTF-16 representation
+    #[default]
+    OptimizedCityHash64Utf16 = 3,
+}
+
+impl TryFrom<u8> for LocResVersion {
+    type Error = LocalizationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(LocResVersion::Legacy),
+            1 => Ok(LocResVersion::Compact),
+            2 => Ok(LocResVersion::OptimizedCrc32),
+            3 => Ok(LocResVersion::OptimizedCityHash64Utf16),
+            _ => Err(LocalizationError::unknown_version("locres", value)),
+        }
+    }
+}
+
+impl LocResVersion {
+    /// Hash a namespace or a key the way this version stores it
+    fn hash(self, string: &str) -> u32 {
+        match self {
+            LocResVersion::OptimizedCityHash64Utf16 => crc::cityhash64_to_u32(string),
+            _ => crc::str_crc32(string),
+        }
+    }
+}
+
+/// Localized string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocResEntry {
+    /// Hash of the source string this entry translates
+    ///
+    /// The engine ignores entries whose hash doesn't match the source string in the game.
+    pub source_hash: u32,
+    /// Localized string
+    pub value: String,
+}
+
+impl LocResEntry {
+    /// Create a new `LocResEntry` translating `source`
+    pub fn new(source: &str, value: String) -> Self {
+        LocResEntry {
+            source_hash: crc::str_crc32(source),
+            value,
+        }
+    }
+}
+
+/// Localization resource, holds the localized strings of one culture
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocRes {
+    /// File version
+    pub version: LocResVersion,
+    /// Entries by namespace and key
+    pub namespaces: IndexedMap<String, IndexedMap<String, LocResEntry>>,
+}
+
+impl LocRes {
+    /// Create a new empty `LocRes`
+    pub fn new(version: LocResVersion) -> Self {
+        LocRes {
+            version,
+            namespaces: IndexedMap::new(),
+        }
+    }
+
+    /// Read a `LocRes` of any version
+    pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, Error> {
+        let start = reader.stream_position()?;
+        let mut magic = [0u8; 16];
+        let version = match reader.read_exact(&mut magic).is_ok() && magic == LOCRES_MAGIC {
+            true => LocResVersion::try_from(reader.read_u8()?)?,
+            false => {
+                reader.seek(SeekFrom::Start(start))?;
+                LocResVersion::Legacy
+            }
+        };
+
+        let mut strings = Vec::new();
+        if version >= LocResVersion::Compact {
+            let strings_offset = reader.read_i64::<LE>()?;
+            if strings_offset != -1 {
+                let position = reader.stream_position()?;
+                reader.seek(SeekFrom::Start(strings_offset as u64))?;
+
+                let count = reader.read_i32::<LE>()?;
+                strings.reserve(capped_capacity(count));
+                for _ in 0..count {
+                    strings.push(read_string(reader)?);
+                    if version >= LocResVersion::OptimizedCrc32 {
+                        // reference count
+                        reader.read_i32::<LE>()?;
+                    }
+                }
+
+                reader.seek(SeekFrom::Start(position))?;
+            }
+        }
+
+        if version >= LocResVersion::OptimizedCrc32 {
+            // total entry count
+            reader.read_u32::<LE>()?;
+        }
+
+        let mut namespaces = IndexedMap::new();
+        let namespace_count = reader.read_u32::<LE>()?;
+        for _ in 0..namespace_count {
+            if version >= LocResVersion::OptimizedCrc32 {
+                reader.read_u32::<LE>()?;
+            }
+            let namespace = read_string(reader)?;
+
+            let mut entries = IndexedMap::new();
+            let key_count = reader.read_u32::<LE>()?;
+            for _ in 0..key_count {
+                if version >= LocResVersion::OptimizedCrc32 {
+                    reader.read_u32::<LE>()?;
+                }
+                let key = read_string(reader)?;
+                let source_hash = reader.read_u32::<LE>()?;
+
+                let value = match version {
+                    LocResVersion::Legacy => read_string(reader)?,
+                    _ => {
+                        let index = reader.read_i32::<LE>()?;
+                        usize::try_from(index)
+                            .ok()
+                            .and_then(|e| strings.get(e))
+                            .cloned()
+                            .ok_or_else(|| {
+                                LocalizationError::invalid_string_index(index, strings.len())
+                            })?
+                    }
+                };
+
+                entries.insert(key, LocResEntry { source_hash, value });
+            }
+
+            namespaces.insert(namespace, entries);
+        }
+
+        Ok(LocRes {
+            version,
+            namespaces,
+        })
+    }
+
+    /// Write this `LocRes` in its version
+    pub fn write<W: Write + Seek>(&self, writer: &mut W) -> Result<(), Error> {
+        let version = self.version;
+
+        let mut strings_offset_position = 0;
+        if version >= LocResVersion::Compact {
+            writer.write_all(&LOCRES_MAGIC)?;
+            writer.write_u8(version as u8)?;
+            strings_offset_position = writer.stream_position()?;
+            writer.write_i64::<LE>(-1)?;
+        }
+
+        if version >= LocResVersion::OptimizedCrc32 {
+            let entry_count = self.namespaces.values().map(IndexedMap::len).sum::<usize>();
+            writer.write_u32::<LE>(entry_count as u32)?;
+        }
+
+        // localized strings and their reference counts
+        let mut strings: Vec<(&str, i32)> = Vec::new();
+        let mut string_indices = HashMap::new();
+
+        writer.write_u32::<LE>(self.namespaces.len() as u32)?;
+        for (_, namespace, entries) in &self.namespaces {
+            if version >= LocResVersion::OptimizedCrc32 {
+                writer.write_u32::<LE>(version.hash(namespace))?;
+            }
+            write_string(writer, namespace)?;
+
+            writer.write_u32::<LE>(entries.len() as u32)?;
+            for (_, key, entry) in entries {
+                if version >= LocResVersion::OptimizedCrc32 {
+                    writer.write_u32::<LE>(version.hash(key))?;
+                }
+                write_string(writer, key)?;
+                writer.write_u32::<LE>(entry.source_hash)?;
+
+                match version {
+                    LocResVersion::Legacy => write_string(writer, &entry.value)?,
+                    _ => {
+                        let index =
+                            *string_indices
+                                .entry(entry.value.as_str())
+                                .or_insert_with(|| {
+                                    strings.push((entry.value.as_str(), 0));
+                                    strings.len() - 1
+                                });
+                        strings[index].1 += 1;
+                        writer.write_i32::<LE>(index as i32)?;
+                    }
+                }
+            }
+        }
+
+        if version >= LocResVersion::Compact {
+            let strings_offset = writer.stream_position()?;
+            writer.write_i32::<LE>(strings.len() as i32)?;
+            for (string, reference_count) in strings {
+                write_string(writer, string)?;
+                if version >= LocResVersion::OptimizedCrc32 {
+                    writer.write_i32::<LE>(reference_count)?;
+                }
+            }
+
+            let end = writer.stream_position()?;
+            writer.seek(SeekFrom::Start(strings_offset_position))?;
+            writer.write_i64::<LE>(strings_offset as i64)?;
+            writer.seek(SeekFrom::Start(end))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get an entry
+    pub fn get(&self, namespace: &str, key: &str) -> Option<&LocResEntry> {
+        self.namespaces
+            .get_by_key(namespace)
+            .and_then(|e| e.get_by_key(key))
+    }
+
+    /// Insert an entry, replacing an existing entry with the same namespace and key
+    pub fn insert(&mut self, namespace: &str, key: &str, entry: LocResEntry) {
+        match self.namespaces.get_by_key_mut(namespace) {
+            Some(entries) => entries.insert(key.to_string(), entry),
+            None => {
+                let mut entries = IndexedMap::new();
+                entries.insert(key.to_string(), entry);
+                self.namespaces.insert(namespace.to_string(), entries);
+            }
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! Localization files and translation formats
+//!
+//! Most translated text of a game lives in `.locres` files next to a `.locmeta` file,
+//! see [`LocRes`] and [`LocMeta`]. String table exports hold the source strings of text referenced by key,
+//! see [`StringTableExport`].
+//!
+//! Both can be converted to CSV and to gettext PO files for translation tools.
+//! Entries are identified by namespace and key, which PO files store in `msgctxt` as `Namespace,Key`.
+//!
+//! The engine only uses a localized string when the hash of its source string matches the text in the game,
+//! so importing a translation keeps the source hash of existing entries
+//! and computes it from the source string for new ones.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::fs::{self, File};
+//!
+//! use unreal_asset::localization::LocRes;
+//!
+//! let native = LocRes::read(&mut File::open("en/Game.locres").unwrap()).unwrap();
+//! let mut locres = LocRes::read(&mut File::open("de/Game.locres").unwrap()).unwrap();
+//!
+//! fs::write("Game.po", locres.to_po(Some(&native))).unwrap();
+//! // translate
+//! locres.import_po(&fs::read_to_string("Game.po").unwrap()).unwrap();
+//! locres.write(&mut File::create("de/Game.locres").unwrap()).unwrap();
+//! ```
+
+use std::collections::HashSet;
+use std::io::{Read, Seek, Write};
+
+use unreal_asset_base::{
+    error::{Error, LocalizationError},
+    types::PackageIndex,
+};
+use unreal_asset_exports::{string_table_export::StringTableExport, Export};
+use unreal_helpers::{UnrealReadExt, UnrealWriteExt};
+
+use crate::asset::Asset;
+
+pub mod locmeta;
+pub mod locres;
+mod po;
+
+pub use locmeta::{LocMeta, LocMetaVersion};
+pub use locres::{LocRes, LocResEntry, LocResVersion};
+
+/// Header of the namespace column in CSV files
+const CSV_NAMESPACE: &str = "Namespace";
+/// Header of the key column in CSV files
+const CSV_KEY: &str = "Key";
+/// Header of the source string column in CSV files
+const CSV_SOURCE: &str = "SourceString";
+/// Header of the localized string column in CSV files
+const CSV_TRANSLATION: &str = "LocalizedString";
+
+/// Translatable text
+struct TextEntry {
+    /// Namespace
+    namespace: String,
+    /// Key
+    key: String,
+    /// Source string
+    source: String,
+    /// Localized string, empty when untranslated
+    translation: String,
+}
+
+/// Read a string that may be empty
+fn read_string<R: Read + Seek>(reader: &mut R) -> Result<String, Error> {
+    Ok(reader.read_fstring()?.unwrap_or_default())
+}
+
+/// Write a string, empty strings are written without a terminator like the engine does
+fn write_string<W: Write>(writer: &mut W, string: &str) -> Result<(), Error> {
+    writer.write_fstring((!string.is_empty()).then_some(string))?;
+    Ok(())
+}
+
+/// Convert a CSV error
+fn csv_error(error: csv::Error) -> Error {
+    LocalizationError::format(error.to_string()).into()
+}
+
+/// Write CSV with the given columns
+fn write_csv<'a>(
+    columns: &[&str],
+    rows: impl IntoIterator<Item = Vec<&'a str>>,
+) -> Result<String, Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(columns).map_err(csv_error)?;
+    for row in rows {
+        writer.write_record(row).map_err(csv_error)?;
+    }
+
+    let data = writer
+        .into_inner()
+        .map_err(|e| Error::from(e.into_error()))?;
+    Ok(String::from_utf8(data)?)
+}
+
+/// Read the given columns from CSV, other columns are ignored
+fn read_csv<const N: usize>(csv: &str, columns: [&str; N]) -> Result<Vec<[String; N]>, Error> {
+    let mut reader = csv::Reader::from_reader(csv.as_bytes());
+    let headers = reader.headers().map_err(csv_error)?;
+
+    let mut indices = [0; N];
+    for (index, column) in indices.iter_mut().zip(columns) {
+        *index = headers
+            .iter()
+            .position(|e| e == column)
+            .ok_or_else(|| LocalizationError::format(format!("CSV has no {column} column")))?;
+    }
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(csv_error)?;
+            Ok(indices.map(|e| record.get(e).unwrap_or_default().to_string()))
+        })
+        .collect()
+}
+
+impl LocRes {
+    /// Get the entries of this `LocRes` as translations of `native`
+    ///
+    /// Without a native culture `LocRes` the entries are their own source strings.
+    fn text_entries(&self, native: Option<&LocRes>) -> Vec<TextEntry> {
+        let source = native.unwrap_or(self);
+        let mut entries = Vec::new();
+        let mut seen = HashSet::new();
+
+        for (_, namespace, source_entries) in &source.namespaces {
+            for (_, key, source_entry) in source_entries {
+                seen.insert((namespace.as_str(), key.as_str()));
+                entries.push(TextEntry {
+                    namespace: namespace.clone(),
+                    key: key.clone(),
+                    source: source_entry.value.clone(),
+                    translation: self
+                        .get(namespace, key)
+                        .map(|e| e.value.clone())
+                        .unwrap_or_default(),
+                });
+            }
+        }
+
+        // entries that only exist in this culture
+        for (_, namespace, entries_in_namespace) in &self.namespaces {
+            for (_, key, entry) in entries_in_namespace {
+                if !seen.contains(&(namespace.as_str(), key.as_str())) {
+                    entries.push(TextEntry {
+                        namespace: namespace.clone(),
+                        key: key.clone(),
+                        source: String::new(),
+                        translation: entry.value.clone(),
+                    });
+                }
+            }
+        }
+
+        entries
+    }
+
+    /// Store translated entries, untranslated entries are skipped
+    fn import_text_entries(&mut self, entries: Vec<TextEntry>) {
+        for entry in entries {
+            if entry.translation.is_empty() {
+                continue;
+            }
+
+            let source_hash = self
+                .get(&entry.namespace, &entry.key)
+                .map(|e| e.source_hash);
+            let mut new_entry = LocResEntry::new(&entry.source, entry.translation);
+            if let Some(source_hash) = source_hash {
+                new_entry.source_hash = source_hash;
+            }
+            self.insert(&entry.namespace, &entry.key, new_entry);
+        }
+    }
+
+    /// Convert to CSV with `Namespace`, `Key`, `SourceString` and `LocalizedString` columns
+    ///
+    /// Source strings are taken from the `native` culture `LocRes` when one is given,
+    /// otherwise from this `LocRes`.
+    pub fn to_csv(&self, native: Option<&LocRes>) -> Result<String, Error> {
+        let entries = self.text_entries(native);
+        write_csv(
+            &[CSV_NAMESPACE, CSV_KEY, CSV_SOURCE, CSV_TRANSLATION],
+            entries.iter().map(|e| {
+                vec![
+                    e.namespace.as_str(),
+                    e.key.as_str(),
+                    e.source.as_str(),
+                    e.translation.as_str(),
+                ]
+            }),
+        )
+    }
+
+    /// Convert to a PO file
+    ///
+    /// Source strings are taken from the `native` culture `LocRes` when one is given,
+    /// otherwise from this `LocRes`.
+    pub fn to_po(&self, native: Option<&LocRes>) -> String {
+        po::write_po(&self.text_entries(native))
+    }
+
+    /// Import translations from CSV with `Namespace`, `Key`, `SourceString` and `LocalizedString` columns
+    ///
+    /// Rows with an empty localized string are skipped, entries missing from the CSV are kept.
+    pub fn import_csv(&mut self, csv: &str) -> Result<(), Error> {
+        let entries = read_csv(csv, [CSV_NAMESPACE, CSV_KEY, CSV_SOURCE, CSV_TRANSLATION])?
+            .into_iter()
+            .map(|[namespace, key, source, translation]| TextEntry {
+                namespace,
+                key,
+                source,
+                translation,
+            })
+            .collect();
+
+        self.import_text_entries(entries);
+        Ok(())
+    }
+
+    /// Import translations from a PO file
+    ///
+    /// Entries with an empty `msgstr` are skipped, entries missing from the PO file are kept.
+    pub fn import_po(&mut self, po: &str) -> Result<(), Error> {
+        let entries = po::read_po(po)?;
+        self.import_text_entries(entries);
+        Ok(())
+    }
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Get a string table export
+    fn get_string_table(
+        &self,
+        export_index: PackageIndex,
+    ) -> Result<&StringTableExport<PackageIndex>, Error> {
+        match self.get_export(export_index) {
+            Some(Export::StringTableExport(e)) => Ok(e),
+            _ => Err(LocalizationError::not_a_string_table(export_index.index).into()),
+        }
+    }
+
+    /// Get a mutable string table export
+    fn get_string_table_mut(
+        &mut self,
+        export_index: PackageIndex,
+    ) -> Result<&mut StringTableExport<PackageIndex>, Error> {
+        match self.get_export_mut(export_index) {
+            Some(Export::StringTableExport(e)) => Ok(e),
+            _ => Err(LocalizationError::not_a_string_table(export_index.index).into()),
+        }
+    }
+
+    /// Convert a string table to CSV with `Key` and `SourceString` columns, like the editor exports it
+    pub fn string_table_to_csv(&self, export_index: PackageIndex) -> Result<String, Error> {
+        let table = self.get_string_table(export_index)?;
+        write_csv(
+            &[CSV_KEY, CSV_SOURCE],
+            table
+                .table
+                .iter()
+                .map(|(_, key, value)| vec![key.as_str(), value.as_str()]),
+        )
+    }
+
+    /// Convert a string table to a PO file, with the table entries as source strings and translations
+    pub fn string_table_to_po(&self, export_index: PackageIndex) -> Result<String, Error> {
+        let table = self.get_string_table(export_index)?;
+        let namespace = table.namespace.clone().unwrap_or_default();
+        let entries = table
+            .table
+            .iter()
+            .map(|(_, key, value)| TextEntry {
+                namespace: namespace.clone(),
+                key: key.clone(),
+                source: value.clone(),
+                translation: value.clone(),
+            })
+            .collect::<Vec<_>>();
+        Ok(po::write_po(&entries))
+    }
+
+    /// Replace the entries of a string table with entries read from CSV
+    ///
+    /// The CSV needs `Key` and `SourceString` columns, other columns are ignored.
+    pub fn import_string_table_csv(
+        &mut self,
+        export_index: PackageIndex,
+        csv: &str,
+    ) -> Result<(), Error> {
+        self.get_string_table(export_index)?;
+        let rows = read_csv(csv, [CSV_KEY, CSV_SOURCE])?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        let mut seen = HashSet::new();
+        for [key, value] in rows {
+            if !seen.insert(key.clone()) {
+                return Err(LocalizationError::format(format!("Duplicate key {key}")).into());
+            }
+            entries.push((key, value));
+        }
+
+        let table = self.get_string_table_mut(export_index)?;
+        table.table = entries.into_iter().collect();
+        Ok(())
+    }
+
+    /// Replace string table entries with translations read from a PO file
+    ///
+    /// Only entries in the namespace of the table are imported, entries with an empty `msgstr` are skipped.
+    /// Keys that don't exist in the table yet are added.
+    pub fn import_string_table_po(
+        &mut self,
+        export_index: PackageIndex,
+        po: &str,
+    ) -> Result<(), Error> {
+        self.get_string_table(export_index)?;
+        let entries = po::read_po(po)?;
+
+        let table = self.get_string_table_mut(export_index)?;
+        let namespace = table.namespace.clone().unwrap_or_default();
+        for entry in entries {
+            if entry.namespace == namespace && !entry.translation.is_empty() {
+                table.table.insert(entry.key, entry.translation);
+            }
+        }
+        Ok(())
+    }
+}
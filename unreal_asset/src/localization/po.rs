@@ -0,0 +1,195 @@
+//! Gettext PO files, in the layout the editor exports them
+
+use std::fmt::Write;
+
+use unreal_asset_base::error::{Error, LocalizationError};
+
+use super::TextEntry;
+
+/// Escape a string for a quoted PO value
+fn escape(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+    for character in string.chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+/// Parse a quoted PO value
+fn unquote(value: &str) -> Option<String> {
+    let value = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut string = String::with_capacity(value.len());
+    let mut characters = value.chars();
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => string.push(match characters.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                e @ ('\\' | '"') => e,
+                _ => return None,
+            }),
+            '"' => return None,
+            _ => string.push(character),
+        }
+    }
+    Some(string)
+}
+
+/// Escape a namespace or a key for `msgctxt`, they are separated by a comma
+fn escape_context(string: &str) -> String {
+    string.replace(',', "\\,")
+}
+
+/// Split `msgctxt` into a namespace and a key
+fn split_context(context: &str) -> Option<(String, String)> {
+    let mut namespace = String::new();
+    let mut characters = context.chars();
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => match characters.next()? {
+                ',' => namespace.push(','),
+                e => {
+                    namespace.push('\\');
+                    namespace.push(e);
+                }
+            },
+            ',' => return Some((namespace, characters.as_str().replace("\\,", ","))),
+            _ => namespace.push(character),
+        }
+    }
+    None
+}
+
+/// Write entries as a PO file
+pub(super) fn write_po(entries: &[TextEntry]) -> String {
+    let mut po = String::from(concat!(
+        "msgid \"\"\n",
+        "msgstr \"\"\n",
+        "\"Content-Type: text/plain; charset=UTF-8\\n\"\n",
+        "\"Content-Transfer-Encoding: 8bit\\n\"\n",
+    ));
+
+    for entry in entries {
+        let _ = write!(
+            po,
+            "\n#. Key:\t{}\nmsgctxt \"{},{}\"\nmsgid \"{}\"\nmsgstr \"{}\"\n",
+            escape(&entry.key),
+            escape(&escape_context(&entry.namespace)),
+            escape(&escape_context(&entry.key)),
+            escape(&entry.source),
+            escape(&entry.translation),
+        );
+    }
+    po
+}
+
+/// Field of a PO entry that continuation lines are appended to
+#[derive(Clone, Copy)]
+enum Field {
+    Context,
+    Source,
+    Translation,
+    /// Plural forms, which the engine doesn't use
+    Ignored,
+}
+
+/// PO entry being parsed
+#[derive(Default)]
+struct PoEntry {
+    context: Option<String>,
+    source: Option<String>,
+    translation: Option<String>,
+}
+
+impl PoEntry {
+    /// Finish parsing, the header is skipped
+    ///
+    /// Entries that only exist in the translated culture have an empty source string.
+    fn finish(self, entries: &mut Vec<TextEntry>) -> Result<(), Error> {
+        let source = self.source.unwrap_or_default();
+        if self.context.is_none() && source.is_empty() {
+            return Ok(());
+        }
+
+        let Some((namespace, key)) = self.context.as_deref().and_then(split_context) else {
+            return Err(LocalizationError::format(format!(
+                "PO entry {source:?} has no namespace and key in msgctxt"
+            ))
+            .into());
+        };
+        entries.push(TextEntry {
+            namespace,
+            key,
+            source,
+            translation: self.translation.unwrap_or_default(),
+        });
+        Ok(())
+    }
+}
+
+/// Read entries from a PO file
+pub(super) fn read_po(po: &str) -> Result<Vec<TextEntry>, Error> {
+    let mut entries = Vec::new();
+    let mut entry = PoEntry::default();
+    let mut field = None;
+
+    for (line_number, line) in po.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid_line =
+            || LocalizationError::format(format!("Invalid PO line {}: {line}", line_number + 1));
+
+        let (keyword, value) = match line.starts_with('"') {
+            true => ("", line),
+            false => line
+                .split_once(char::is_whitespace)
+                .ok_or_else(invalid_line)?,
+        };
+        let value = unquote(value).ok_or_else(invalid_line)?;
+
+        let next_field = match keyword {
+            "" => field.ok_or_else(invalid_line)?,
+            "msgctxt" | "msgid" if entry.translation.is_some() => {
+                std::mem::take(&mut entry).finish(&mut entries)?;
+                match keyword {
+                    "msgctxt" => Field::Context,
+                    _ => Field::Source,
+                }
+            }
+            "msgctxt" => Field::Context,
+            "msgid" => Field::Source,
+            "msgstr" | "msgstr[0]" => Field::Translation,
+            "msgid_plural" => Field::Ignored,
+            e if e.starts_with("msgstr[") => Field::Ignored,
+            _ => return Err(invalid_line().into()),
+        };
+
+        let mut ignored = None;
+        let target = match next_field {
+            Field::Context => &mut entry.context,
+            Field::Source => &mut entry.source,
+            Field::Translation => &mut entry.translation,
+            Field::Ignored => &mut ignored,
+        };
+        match (keyword, target) {
+            ("", Some(existing)) => existing.push_str(&value),
+            (_, target) => *target = Some(value),
+        }
+        field = Some(next_field);
+    }
+
+    entry.finish(&mut entries)?;
+    Ok(entries)
+}
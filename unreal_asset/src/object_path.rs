@@ -0,0 +1,294 @@
+//! Object path helpers
+//!
+//! Object paths are formatted the same way the engine formats them,
+//! `/Game/Foo/Bar.Bar_C:Component.SubObject`.
+
+use std::io::{Read, Seek};
+
+use unreal_asset_base::{
+    error::Error,
+    types::{FName, PackageIndex, PackageIndexTrait},
+    Import,
+};
+use unreal_asset_exports::ExportBaseTrait;
+
+use crate::asset::Asset;
+
+/// Format an `FName` the way the engine prints it, including its instance number
+pub(crate) fn fname_to_string(name: &FName) -> String {
+    match name.get_number() {
+        0 => name.get_owned_content(),
+        number => format!("{}_{}", name.get_owned_content(), number - 1),
+    }
+}
+
+/// Split a name into its content and instance number, `Component_2` becomes `("Component", 3)`
+pub(crate) fn split_fname_number(name: &str) -> (&str, i32) {
+    let Some((content, number)) = name.rsplit_once('_') else {
+        return (name, 0);
+    };
+
+    // numbers with leading zeros are a part of the name
+    if number.is_empty() || (number.len() > 1 && number.starts_with('0')) {
+        return (name, 0);
+    }
+
+    match number.parse::<i32>() {
+        Ok(number) if number < i32::MAX => (content, number + 1),
+        _ => (name, 0),
+    }
+}
+
+/// Join object names into an object path, the first name is the package name
+fn join_object_path<'a>(names: impl IntoIterator<Item = &'a str>) -> String {
+    let mut path = String::new();
+    for (i, name) in names.into_iter().enumerate() {
+        match i {
+            0 => {}
+            2 => path.push(':'),
+            _ => path.push('.'),
+        }
+        path.push_str(name);
+    }
+    path
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Get this asset's package name, e.g. `/Game/Foo/Bar`
+    ///
+    /// Cooked assets don't store their package name,
+    /// so it is looked up in the name map by matching it against top-level export names.
+    pub fn get_package_name(&self) -> Option<String> {
+        if self.folder_name.starts_with('/') {
+            return Some(self.folder_name.clone());
+        }
+
+        let top_level_exports = self
+            .asset_data
+            .exports
+            .iter()
+            .map(|e| e.get_base_export())
+            .filter(|e| e.outer_index.index == 0)
+            .map(|e| {
+                e.object_name.get_content(|e| {
+                    let e = e.strip_prefix("Default__").unwrap_or(e);
+                    e.strip_suffix("_C").unwrap_or(e).to_string()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let name_map = self.get_name_map();
+        let name_map = name_map.get_ref();
+        name_map
+            .get_name_map_index_list()
+            .iter()
+            .filter(|name| name.starts_with('/'))
+            .filter(|name| {
+                // imported packages are named the same way as ours
+                !self
+                    .imports
+                    .iter()
+                    .any(|e| e.outer_index.index == 0 && e.object_name == name.as_str())
+            })
+            .find(|name| {
+                name.rsplit_once('/')
+                    .is_some_and(|(_, stem)| top_level_exports.iter().any(|e| e == stem))
+            })
+            .cloned()
+    }
+
+    /// Get the full object path of an import or an export, e.g. `/Game/Foo/Bar.Bar_C:Component`
+    ///
+    /// If this asset's package name can't be determined, export paths start with the top-level object name.
+    /// Returns `None` if the index is null, invalid or has a cyclic outer chain.
+    pub fn get_object_path(&self, index: PackageIndex) -> Option<String> {
+        let package_name = match index.is_export() {
+            true => self.get_package_name(),
+            false => None,
+        };
+        self.get_object_path_with_package(index, package_name.as_deref())
+    }
+
+    /// Get the full object path of an import or an export with a known package name for exports
+    ///
+    /// If `package_name` is `None`, export paths start with the top-level object name.
+    pub(crate) fn get_object_path_with_package(
+        &self,
+        index: PackageIndex,
+        package_name: Option<&str>,
+    ) -> Option<String> {
+        if index.index == 0 {
+            return None;
+        }
+
+        let mut names = Vec::new();
+        let mut current = index;
+        let mut is_import = false;
+
+        while current.index != 0 {
+            // guard against cyclic outer chains
+            if names.len() > self.imports.len() + self.asset_data.exports.len() {
+                return None;
+            }
+
+            let (name, outer) = match current.is_import() {
+                true => {
+                    let import = self.imports.get(-(current.index + 1) as usize)?;
+                    is_import = true;
+                    (&import.object_name, import.outer_index)
+                }
+                false => {
+                    let export = self
+                        .asset_data
+                        .exports
+                        .get((current.index - 1) as usize)?
+                        .get_base_export();
+                    (&export.object_name, export.outer_index)
+                }
+            };
+            names.push(fname_to_string(name));
+            current = outer;
+        }
+
+        let package_name = match is_import {
+            true => None,
+            false => Some(package_name.unwrap_or_default()),
+        };
+
+        let path = join_object_path(
+            package_name
+                .into_iter()
+                .chain(names.iter().rev().map(String::as_str)),
+        );
+
+        Some(match package_name {
+            Some("") => path.trim_start_matches('.').to_string(),
+            _ => path,
+        })
+    }
+
+    /// Find an import or an export by its full object path
+    pub fn find_object_by_path(&self, path: &str) -> Option<PackageIndex> {
+        self.find_import_by_path(path).or_else(|| {
+            let package_name = self.get_package_name();
+            (1..=self.asset_data.exports.len() as i32)
+                .map(PackageIndex::new)
+                .find(|e| {
+                    self.get_object_path_with_package(*e, package_name.as_deref())
+                        .is_some_and(|e| e == path)
+                })
+        })
+    }
+
+    /// Find an import by its full object path
+    pub fn find_import_by_path(&self, path: &str) -> Option<PackageIndex> {
+        (1..=self.imports.len() as i32)
+            .map(|e| PackageIndex::new(-e))
+            .find(|e| {
+                self.get_object_path_with_package(*e, None)
+                    .is_some_and(|e| e == path)
+            })
+    }
+
+    /// Add an import by its full object path, e.g. `/Game/Foo/Bar.Bar_C:Component`,
+    /// together with its outer objects
+    ///
+    /// `classes` are the class package and class name of the last objects in the path,
+    /// the last one is the class of the imported object.
+    /// Leading classes can be left out for outer objects that are already imported,
+    /// a missing package without a class is imported as `/Script/CoreUObject.Package`.
+    /// If the import already exists its index is returned.
+    pub fn add_import_by_path(
+        &mut self,
+        path: &str,
+        classes: &[(&str, &str)],
+    ) -> Result<PackageIndex, Error> {
+        if let Some(existing) = self.find_import_by_path(path) {
+            return Ok(existing);
+        }
+
+        let (package_name, object_names) = match path.split_once('.') {
+            Some((package_name, object_names)) => (
+                package_name,
+                object_names.split([':', '.']).collect::<Vec<_>>(),
+            ),
+            None => (path, Vec::new()),
+        };
+
+        if object_names.iter().any(|e| e.is_empty()) || package_name.is_empty() {
+            return Err(Error::invalid_package_index(format!(
+                "Invalid object path {path}"
+            )));
+        }
+
+        let mut names = vec![package_name];
+        names.extend(object_names);
+
+        // index of the first object that has a class
+        let Some(first_class) = names.len().checked_sub(classes.len()) else {
+            return Err(Error::no_data(format!(
+                "Got {} classes for the {} objects of {path}",
+                classes.len(),
+                names.len()
+            )));
+        };
+
+        let existing = (1..=names.len())
+            .map(|e| {
+                let object_path = join_object_path(names[..e].iter().copied());
+                let import = self.find_import_by_path(&object_path);
+                (object_path, import)
+            })
+            .collect::<Vec<_>>();
+
+        // nothing is added unless every missing object has a class
+        let mut missing_class = existing.iter().take(first_class).skip(1);
+        if let Some((object_path, _)) = missing_class.find(|e| e.1.is_none()) {
+            return Err(Error::no_data(format!(
+                "{object_path} is not imported and has no class"
+            )));
+        }
+
+        let mut outer_index = PackageIndex::new(0);
+        for (i, (object_name, (_, import))) in names.into_iter().zip(existing).enumerate() {
+            outer_index = match import {
+                Some(e) => e,
+                None => {
+                    let (class_package, class_name) = match i.checked_sub(first_class) {
+                        Some(class) => classes[class],
+                        None => ("/Script/CoreUObject", "Package"),
+                    };
+                    let import =
+                        self.create_import(class_package, class_name, outer_index, object_name);
+                    self.add_import(import)
+                }
+            };
+        }
+
+        Ok(outer_index)
+    }
+
+    /// Create an import with its names added to the name map
+    fn create_import(
+        &mut self,
+        class_package: &str,
+        class_name: &str,
+        outer_index: PackageIndex,
+        object_name: &str,
+    ) -> Import {
+        let mut name_map = self.get_name_map();
+        let mut name_map = name_map.get_mut();
+        let mut add_fname = |name: &str| {
+            let (content, number) = split_fname_number(name);
+            name_map.add_fname_with_number(content, number)
+        };
+
+        Import::new(
+            add_fname(class_package),
+            add_fname(class_name),
+            outer_index,
+            add_fname(object_name),
+            false,
+        )
+    }
+}
@@ -0,0 +1,257 @@
+//! Export and property queries
+//!
+//! A [`Query`] matches exports by class, object name and property values.
+//! Every match is returned with the export index and the concrete path of the matched property,
+//! so it can be passed straight to [`PropertyAccess`] to edit the property.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::fs::File;
+//!
+//! use unreal_asset::{engine_version::EngineVersion, query::Query, Asset};
+//!
+//! let file = File::open("asset.uasset").unwrap();
+//! let asset = Asset::new(file, None, EngineVersion::VER_UE4_23, None).unwrap();
+//!
+//! let query = Query::new()
+//!     .with_class("StaticMeshActor")
+//!     .with_object_name("Rock_*")
+//!     .with_value("Tags[*]", "Destructible".to_string())
+//!     .unwrap();
+//!
+//! for found in asset.query(&query) {
+//!     println!("{:?} {:?}", found.export, found.property_path.map(|e| e.to_string()));
+//! }
+//! ```
+
+use std::io::{Read, Seek};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use unreal_asset_base::{
+    error::Error,
+    reader::ArchiveTrait,
+    types::{PackageIndex, PackageIndexTrait},
+};
+use unreal_asset_exports::{Export, ExportBaseTrait, ExportNormalTrait};
+use unreal_asset_properties::{
+    property_path::{FromProperty, PropertyAccess, PropertyPath},
+    Property,
+};
+
+use crate::asset::Asset;
+use crate::batch::{self, BatchInput, BatchOptions, BatchResult};
+use crate::object_path::fname_to_string;
+
+/// Property value predicate
+type Predicate = Arc<dyn Fn(&Property) -> bool + Send + Sync>;
+
+/// Property condition of a [`Query`]
+#[derive(Clone)]
+struct PropertyFilter {
+    /// Property path, may contain `[*]` wildcards
+    path: PropertyPath,
+    /// Predicate the property has to match
+    predicate: Predicate,
+}
+
+/// Export query
+///
+/// All conditions have to match, a query without conditions matches every export.
+#[derive(Clone, Default)]
+pub struct Query {
+    /// Export class name
+    pub class: Option<String>,
+    /// Export object name pattern, `*` matches any amount of characters and `?` a single character
+    ///
+    /// Like all names in Unreal, matching is case insensitive.
+    pub object_name: Option<String>,
+    /// Property condition
+    property: Option<PropertyFilter>,
+}
+
+impl Query {
+    /// Create a new `Query` that matches every export
+    pub fn new() -> Self {
+        Query::default()
+    }
+
+    /// Only match exports of a class
+    pub fn with_class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Only match exports with an object name matching a pattern
+    ///
+    /// Object names include their instance number, e.g. `Rock_5`.
+    pub fn with_object_name(mut self, pattern: impl Into<String>) -> Self {
+        self.object_name = Some(pattern.into());
+        self
+    }
+
+    /// Only match exports that have a property at a path
+    pub fn with_property(self, path: &str) -> Result<Self, Error> {
+        self.with_predicate(path, |_| true)
+    }
+
+    /// Only match exports with a property at a path that has a value
+    pub fn with_value<T>(self, path: &str, value: T) -> Result<Self, Error>
+    where
+        T: FromProperty + PartialEq + Send + Sync + 'static,
+    {
+        self.with_predicate(path, move |property| {
+            T::from_property(property).is_some_and(|e| e == value)
+        })
+    }
+
+    /// Only match exports with a property at a path that satisfies a predicate
+    pub fn with_predicate(
+        mut self,
+        path: &str,
+        predicate: impl Fn(&Property) -> bool + Send + Sync + 'static,
+    ) -> Result<Self, Error> {
+        self.property = Some(PropertyFilter {
+            path: PropertyPath::from_str(path)?,
+            predicate: Arc::new(predicate),
+        });
+        Ok(self)
+    }
+
+    /// Get the property path of this query
+    pub fn property_path(&self) -> Option<&PropertyPath> {
+        self.property.as_ref().map(|e| &e.path)
+    }
+}
+
+/// Location of an export or property matched by a [`Query`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueryMatch {
+    /// Matched export
+    pub export: PackageIndex,
+    /// Concrete path of the matched property, `None` if the query had no property condition
+    pub property_path: Option<PropertyPath>,
+}
+
+/// Check if a name matches a pattern with `*` and `?` wildcards, ignoring ASCII case
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+
+    let (mut p, mut n) = (0, 0);
+    // position of the last `*` and the name position it was tried at
+    let mut backtrack = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || c.eq_ignore_ascii_case(&name[n]) => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, star_n)) => {
+                    p = star + 1;
+                    n = star_n + 1;
+                    backtrack = Some((star, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|e| *e == '*')
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Get the class name of an export
+    pub fn get_export_class_name(&self, export: &Export<PackageIndex>) -> Option<String> {
+        let class_index = export.get_base_export().class_index;
+        match class_index.is_export() {
+            true => self
+                .get_export(class_index)
+                .map(|e| e.get_base_export().object_name.get_owned_content()),
+            false => self
+                .get_export_class_type(class_index)
+                .map(|e| e.get_owned_content()),
+        }
+    }
+
+    /// Find all exports and properties matching a query
+    ///
+    /// Exports of a lazily loaded asset that weren't loaded yet have no properties
+    /// and never match a query with a property condition.
+    pub fn query(&self, query: &Query) -> Vec<QueryMatch> {
+        let mut matches = Vec::new();
+
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let base_export = export.get_base_export();
+
+            if let Some(class) = &query.class {
+                if self.get_export_class_name(export).as_ref() != Some(class) {
+                    continue;
+                }
+            }
+
+            if let Some(pattern) = &query.object_name {
+                if !matches_pattern(pattern, &fname_to_string(&base_export.object_name)) {
+                    continue;
+                }
+            }
+
+            let export_index = PackageIndex::new(i as i32 + 1);
+            let Some(filter) = &query.property else {
+                matches.push(QueryMatch {
+                    export: export_index,
+                    property_path: None,
+                });
+                continue;
+            };
+
+            let Some(normal_export) = export.get_normal_export() else {
+                continue;
+            };
+
+            for (path, property) in normal_export.find_properties(&filter.path) {
+                if (filter.predicate)(property) {
+                    matches.push(QueryMatch {
+                        export: export_index,
+                        property_path: Some(path),
+                    });
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+/// Run a query over many assets on multiple threads
+///
+/// If the query has a class and `options` doesn't have a class filter,
+/// the query class is used as the class filter so only matching exports are decoded.
+///
+/// Assets without matches are not included in the results, assets that failed to parse are.
+pub fn query_batch<I>(
+    inputs: I,
+    options: &BatchOptions,
+    query: &Query,
+) -> Vec<BatchResult<Vec<QueryMatch>>>
+where
+    I: IntoIterator<Item = BatchInput>,
+    I::IntoIter: Send,
+{
+    let mut options = options.clone();
+    if let (true, Some(class)) = (options.class_filter.is_empty(), &query.class) {
+        options.class_filter = vec![class.clone()];
+    }
+
+    batch::parse_batch(inputs, &options, |_, asset| Ok(asset.query(query)))
+        .into_iter()
+        .filter(|e| !matches!(&e.result, Ok(matches) if matches.is_empty()))
+        .collect()
+}
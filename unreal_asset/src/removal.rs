@@ -0,0 +1,247 @@
+//! Export and import removal
+//!
+//! Removing objects renumbers every `PackageIndex` in the asset,
+//! references to the removed objects are nulled or dropped from reference lists.
+//! Objects that are still the class, super, template or outer of a kept object can't be removed.
+
+use std::collections::HashSet;
+use std::io::{Read, Seek};
+
+use unreal_asset_base::{
+    error::Error,
+    types::{PackageIndex, PackageIndexContainer, PackageIndexTrait},
+};
+use unreal_asset_exports::{struct_export::StructExport, Export, ExportBaseTrait};
+
+use crate::asset::Asset;
+use crate::transplant::collect_exports;
+
+/// Check if an export contains raw data which may reference other objects
+pub(crate) fn has_raw_data(export: &Export<PackageIndex>) -> bool {
+    let has_raw_bytecode = |e: &StructExport<PackageIndex>| {
+        e.script_bytecode.is_none() && e.script_bytecode_raw.is_some()
+    };
+
+    match export {
+        Export::RawExport(_) => true,
+        Export::StructExport(e) => has_raw_bytecode(e),
+        Export::ClassExport(e) => has_raw_bytecode(&e.struct_export),
+        Export::FunctionExport(e) => has_raw_bytecode(&e.struct_export),
+        Export::UserDefinedStructExport(e) => has_raw_bytecode(&e.struct_export),
+        _ => false,
+    }
+}
+
+/// Get the new index of an object after removing objects
+fn remap_index(
+    index: PackageIndex,
+    removed_imports: &[i32],
+    removed_exports: &[i32],
+) -> PackageIndex {
+    let removed = match index.is_import() {
+        true => removed_imports,
+        false => removed_exports,
+    };
+
+    let magnitude = index.index.abs();
+    if removed.binary_search(&magnitude).is_ok() {
+        return PackageIndex::new(0);
+    }
+
+    let shift = removed.partition_point(|e| *e < magnitude) as i32;
+    PackageIndex::new(index.index.signum() * (magnitude - shift))
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Remove an export together with its subobjects
+    ///
+    /// All following exports are renumbered, references to the removed exports are nulled
+    /// and dropped from preload dependencies, the depends map and level and struct reference lists.
+    ///
+    /// Fails if the asset contains raw export data or raw bytecode, those can't be renumbered,
+    /// or if a removed export is the class, super, template or outer of an export that is kept.
+    pub fn remove_export(&mut self, index: PackageIndex) -> Result<(), Error> {
+        let removed = collect_exports(self, index, true)?
+            .into_iter()
+            .map(|e| PackageIndex::new(e as i32 + 1))
+            .collect::<HashSet<_>>();
+
+        self.remove_objects(&removed)
+    }
+
+    /// Remove an import together with imports nested inside of it
+    ///
+    /// All following imports are renumbered, references to the removed imports are nulled
+    /// and dropped from preload dependencies, the depends map and level and struct reference lists.
+    ///
+    /// Fails if the asset contains raw export data or raw bytecode, those can't be renumbered,
+    /// or if a removed import is the class, super, template or outer of an export that is kept.
+    pub fn remove_import(&mut self, index: PackageIndex) -> Result<(), Error> {
+        if self.get_import(index).is_none() {
+            return Err(Error::invalid_package_index(format!(
+                "Import {} doesn't exist",
+                index.index
+            )));
+        }
+
+        let is_nested = |mut current: PackageIndex| {
+            // guard against cyclic outer chains
+            for _ in 0..=self.imports.len() {
+                if current == index {
+                    return true;
+                }
+                match self.get_import(current) {
+                    Some(import) => current = import.outer_index,
+                    None => return false,
+                }
+            }
+            false
+        };
+
+        let removed = (1..=self.imports.len() as i32)
+            .map(|e| PackageIndex::new(-e))
+            .filter(|e| is_nested(*e))
+            .collect::<HashSet<_>>();
+
+        self.remove_objects(&removed)
+    }
+
+    /// Remove imports and exports and renumber all references
+    fn remove_objects(&mut self, removed: &HashSet<PackageIndex>) -> Result<(), Error> {
+        if let Some(export) = self.asset_data.exports.iter().find(|e| has_raw_data(e)) {
+            return Err(Error::unimplemented(format!(
+                "Can't renumber {}, it contains raw data which may reference other objects",
+                export.get_base_export().object_name.get_owned_content()
+            )));
+        }
+        self.check_dangling_references(removed)?;
+
+        let mut removed_imports = Vec::new();
+        let mut removed_exports = Vec::new();
+        for index in removed {
+            match index.is_import() {
+                true => removed_imports.push(-index.index),
+                false => removed_exports.push(index.index),
+            }
+        }
+        removed_imports.sort_unstable();
+        removed_exports.sort_unstable();
+
+        let is_kept =
+            |index: usize, removed: &[i32]| removed.binary_search(&(index as i32)).is_err();
+
+        let mut i = 0;
+        self.imports.retain(|_| {
+            i += 1;
+            is_kept(i, &removed_imports)
+        });
+        let mut i = 0;
+        self.asset_data.exports.retain(|_| {
+            i += 1;
+            is_kept(i, &removed_exports)
+        });
+        if let Some(depends_map) = self.depends_map.as_mut() {
+            let mut i = 0;
+            depends_map.retain(|_| {
+                i += 1;
+                is_kept(i, &removed_exports)
+            });
+        }
+
+        let retain_kept = |list: &mut Vec<PackageIndex>| list.retain(|e| !removed.contains(e));
+        for export in &mut self.asset_data.exports {
+            let base_export = export.get_base_export_mut();
+            retain_kept(&mut base_export.serialization_before_serialization_dependencies);
+            retain_kept(&mut base_export.create_before_serialization_dependencies);
+            retain_kept(&mut base_export.serialization_before_create_dependencies);
+            retain_kept(&mut base_export.create_before_create_dependencies);
+
+            match export {
+                Export::LevelExport(level) => {
+                    retain_kept(&mut level.actors);
+                    retain_kept(&mut level.model_components);
+                }
+                Export::WorldExport(world) => {
+                    retain_kept(&mut world.extra_objects);
+                    retain_kept(&mut world.streaming_levels);
+                }
+                Export::StructExport(e) => retain_kept(&mut e.children),
+                Export::ClassExport(e) => retain_kept(&mut e.struct_export.children),
+                Export::FunctionExport(e) => retain_kept(&mut e.struct_export.children),
+                Export::UserDefinedStructExport(e) => retain_kept(&mut e.struct_export.children),
+                _ => {}
+            }
+        }
+
+        if let Some(depends_map) = self.depends_map.as_mut() {
+            for depends in depends_map {
+                depends.retain(|e| !removed.contains(&PackageIndex::new(*e)));
+                for index in depends.iter_mut() {
+                    *index = remap_index(
+                        PackageIndex::new(*index),
+                        &removed_imports,
+                        &removed_exports,
+                    )
+                    .index;
+                }
+            }
+        }
+
+        self.traverse_package_indices(&mut |index| {
+            *index = remap_index(*index, &removed_imports, &removed_exports);
+        });
+
+        Ok(())
+    }
+
+    /// Fail if removed objects are the class, super, template or outer of an object that is kept
+    fn check_dangling_references(&self, removed: &HashSet<PackageIndex>) -> Result<(), Error> {
+        let path = |index: PackageIndex| {
+            self.get_object_path(index)
+                .unwrap_or_else(|| index.index.to_string())
+        };
+
+        let mut dangling = Vec::new();
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let index = PackageIndex::new(i as i32 + 1);
+            if removed.contains(&index) {
+                continue;
+            }
+
+            let base_export = export.get_base_export();
+            for (reference, kind) in [
+                (base_export.class_index, "class"),
+                (base_export.super_index, "super"),
+                (base_export.template_index, "template"),
+                (base_export.outer_index, "outer"),
+            ] {
+                if removed.contains(&reference) {
+                    dangling.push(format!(
+                        "{} is the {kind} of {}",
+                        path(reference),
+                        path(index)
+                    ));
+                }
+            }
+        }
+
+        for (i, import) in self.imports.iter().enumerate() {
+            let index = PackageIndex::new(-(i as i32) - 1);
+            if !removed.contains(&index) && removed.contains(&import.outer_index) {
+                dangling.push(format!(
+                    "{} is the outer of {}",
+                    path(import.outer_index),
+                    path(index)
+                ));
+            }
+        }
+
+        match dangling.is_empty() {
+            true => Ok(()),
+            false => Err(Error::invalid_package_index(format!(
+                "Can't remove objects that are still referenced: {}",
+                dangling.join(", ")
+            ))),
+        }
+    }
+}
@@ -0,0 +1,844 @@
+//! Property schemas
+//!
+//! Resolves the property layout of a class or a struct from its
+//! [`StructExport`] and from loaded [`Usmap`] mappings, so that new properties
+//! can be created with their exact serialized type and existing ones can be checked.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Seek};
+
+use unreal_asset_base::{
+    error::Error,
+    types::{fname::ToSerializedName, FName, PackageIndex, PackageIndexTrait},
+    unversioned::{
+        properties::{EPropertyType, UsmapPropertyData},
+        Ancestry, Usmap,
+    },
+    Guid,
+};
+use unreal_asset_exports::{
+    base_export::BaseExport,
+    properties::{fproperty::FProperty, uproperty::UProperty},
+    struct_export::StructExport,
+    Export, ExportBaseTrait, ExportNormalTrait,
+};
+use unreal_asset_properties::{
+    array_property::ArrayProperty,
+    enum_property::EnumProperty,
+    int_property::{
+        BoolProperty, ByteProperty, BytePropertyValue, DoubleProperty, FloatProperty,
+        Int16Property, Int64Property, Int8Property, IntProperty, UInt16Property, UInt32Property,
+        UInt64Property,
+    },
+    map_property::MapProperty,
+    object_property::{ObjectProperty, SoftObjectProperty},
+    property_path::{IntoProperty, PropertyAccess},
+    set_property::SetProperty,
+    str_property::{NameProperty, StrProperty},
+    struct_property::StructProperty,
+    Property, PropertyDataTrait,
+};
+
+use crate::asset::Asset;
+use crate::object_path::fname_to_string;
+
+/// Maximum amount of super structs or nested property exports followed while resolving a schema
+const MAX_DEPTH: usize = 64;
+
+/// Property type
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PropertyType {
+    /// Property without type parameters, e.g. `IntProperty` or `StrProperty`
+    Simple(String),
+    /// Byte property, holds an enum value if the enum is known
+    Byte {
+        /// Enum type
+        enum_type: Option<String>,
+    },
+    /// Enum property
+    Enum {
+        /// Enum type
+        enum_type: Option<String>,
+        /// Underlying property type
+        underlying: Box<PropertyType>,
+    },
+    /// Struct property
+    Struct {
+        /// Struct type
+        struct_type: Option<String>,
+    },
+    /// Array property
+    Array(Box<PropertyType>),
+    /// Set property
+    Set(Box<PropertyType>),
+    /// Map property
+    Map {
+        /// Key type
+        key: Box<PropertyType>,
+        /// Value type
+        value: Box<PropertyType>,
+    },
+}
+
+impl PropertyType {
+    /// Create a `PropertyType` from a serialized type name without any type parameters
+    pub(crate) fn from_serialized_name(name: &str) -> Self {
+        match name {
+            "ByteProperty" => PropertyType::Byte { enum_type: None },
+            "StructProperty" => PropertyType::Struct { struct_type: None },
+            // class properties are tagged as object properties
+            "ClassProperty" => PropertyType::Simple("ObjectProperty".to_string()),
+            "SoftClassProperty" => PropertyType::Simple("SoftObjectProperty".to_string()),
+            _ => PropertyType::Simple(name.to_string()),
+        }
+    }
+
+    /// Get the serialized type name of this property type
+    pub fn serialized_name(&self) -> &str {
+        match self {
+            PropertyType::Simple(name) => name,
+            PropertyType::Byte { .. } => "ByteProperty",
+            PropertyType::Enum { .. } => "EnumProperty",
+            PropertyType::Struct { .. } => "StructProperty",
+            PropertyType::Array(_) => "ArrayProperty",
+            PropertyType::Set(_) => "SetProperty",
+            PropertyType::Map { .. } => "MapProperty",
+        }
+    }
+
+    /// Check if a property matches this type
+    ///
+    /// Container elements are checked too, struct members are not.
+    pub fn matches(&self, property: &Property) -> bool {
+        let known_matches =
+            |expected: &Option<String>, found: Option<&FName>| match (expected, found) {
+                (Some(expected), Some(found)) => found == expected.as_str() || found == "None",
+                _ => true,
+            };
+
+        match (self, property) {
+            (PropertyType::Byte { enum_type }, Property::ByteProperty(e)) => {
+                known_matches(enum_type, e.enum_type.as_ref())
+            }
+            (PropertyType::Enum { enum_type, .. }, Property::EnumProperty(e)) => {
+                known_matches(enum_type, e.enum_type.as_ref())
+            }
+            (PropertyType::Struct { struct_type }, Property::StructProperty(e)) => {
+                known_matches(struct_type, e.struct_type.as_ref())
+            }
+            // structs with custom serialization are parsed into their own property types
+            (PropertyType::Struct { struct_type }, property) => {
+                let serialized_name = property.to_serialized_name();
+                match struct_type {
+                    Some(struct_type) => &serialized_name == struct_type,
+                    None => Property::has_custom_serialization(&serialized_name),
+                }
+            }
+            (PropertyType::Array(inner), Property::ArrayProperty(e)) => {
+                Self::array_matches(inner, e)
+            }
+            (PropertyType::Set(inner), Property::SetProperty(e)) => {
+                Self::array_matches(inner, &e.value)
+            }
+            (PropertyType::Map { key, value }, Property::MapProperty(e)) => {
+                e.key_type == key.serialized_name()
+                    && e.value_type == value.serialized_name()
+                    && e.value
+                        .iter()
+                        .all(|(_, k, v)| key.matches(k) && value.matches(v))
+            }
+            // zero properties in unversioned assets only know their type name
+            (_, Property::EmptyProperty(e)) => e.type_name == self.serialized_name(),
+            (PropertyType::Simple(name), property) => &property.to_serialized_name() == name,
+            _ => false,
+        }
+    }
+
+    /// Check if an array matches an inner type
+    fn array_matches(inner: &PropertyType, array: &ArrayProperty) -> bool {
+        let type_matches = match (&array.array_type, array.value.is_empty()) {
+            (Some(array_type), true) => array_type == inner.serialized_name(),
+            _ => true,
+        };
+        type_matches && array.value.iter().all(|e| inner.matches(e))
+    }
+
+    /// Create a new property with a default value
+    ///
+    /// Type names are added to the name map `name` belongs to.
+    /// Returns `None` for types that can't be created without knowing their binary layout,
+    /// like structs with custom serialization or delegates.
+    pub fn new_property(
+        &self,
+        name: FName,
+        ancestry: Ancestry,
+        duplication_index: i32,
+    ) -> Option<Property> {
+        macro_rules! simple {
+            ($property:ident) => {
+                Some(
+                    $property {
+                        name,
+                        ancestry,
+                        duplication_index,
+                        ..Default::default()
+                    }
+                    .into(),
+                )
+            };
+        }
+
+        match self {
+            PropertyType::Simple(ty) => match ty.as_str() {
+                "BoolProperty" => simple!(BoolProperty),
+                "Int8Property" => simple!(Int8Property),
+                "Int16Property" => simple!(Int16Property),
+                "IntProperty" => simple!(IntProperty),
+                "Int64Property" => simple!(Int64Property),
+                "UInt16Property" => simple!(UInt16Property),
+                "UInt32Property" => simple!(UInt32Property),
+                "UInt64Property" => simple!(UInt64Property),
+                "FloatProperty" => simple!(FloatProperty),
+                "DoubleProperty" => simple!(DoubleProperty),
+                "ObjectProperty" => simple!(ObjectProperty),
+                "SoftObjectProperty" => simple!(SoftObjectProperty),
+                "StrProperty" => Some(
+                    StrProperty {
+                        name,
+                        ancestry,
+                        property_guid: None,
+                        duplication_index,
+                        value: None,
+                    }
+                    .into(),
+                ),
+                "NameProperty" => Some(
+                    NameProperty {
+                        value: name.new_sibling("None"),
+                        name,
+                        ancestry,
+                        property_guid: None,
+                        duplication_index,
+                    }
+                    .into(),
+                ),
+                _ => None,
+            },
+            PropertyType::Byte { enum_type } => Some(
+                ByteProperty {
+                    enum_type: Some(name.new_sibling(enum_type.as_deref().unwrap_or("None"))),
+                    value: match enum_type {
+                        Some(_) => BytePropertyValue::FName(name.new_sibling("None")),
+                        None => BytePropertyValue::Byte(0),
+                    },
+                    name,
+                    ancestry,
+                    property_guid: None,
+                    duplication_index,
+                }
+                .into(),
+            ),
+            PropertyType::Enum {
+                enum_type,
+                underlying,
+            } => Some(
+                EnumProperty {
+                    enum_type: enum_type.as_deref().map(|e| name.new_sibling(e)),
+                    inner_type: Some(name.new_sibling(underlying.serialized_name())),
+                    value: None,
+                    name,
+                    ancestry,
+                    property_guid: None,
+                    duplication_index,
+                }
+                .into(),
+            ),
+            PropertyType::Struct { struct_type } => {
+                let struct_type = struct_type.as_deref()?;
+                if Property::has_custom_serialization(struct_type) {
+                    return None;
+                }
+                Some(
+                    StructProperty {
+                        struct_type: Some(name.new_sibling(struct_type)),
+                        name,
+                        ancestry,
+                        duplication_index,
+                        struct_guid: Some(Guid::default()),
+                        serialize_none: true,
+                        ..Default::default()
+                    }
+                    .into(),
+                )
+            }
+            PropertyType::Array(inner) => {
+                Some(Self::new_array(inner, name, ancestry, duplication_index).into())
+            }
+            PropertyType::Set(inner) => Some(
+                SetProperty {
+                    array_type: Some(name.new_sibling(inner.serialized_name())),
+                    value: Self::new_array(inner, name.clone(), ancestry.clone(), 0),
+                    removed_items: Self::new_array(inner, name.clone(), ancestry.clone(), 0),
+                    name,
+                    ancestry,
+                    property_guid: None,
+                    duplication_index,
+                }
+                .into(),
+            ),
+            PropertyType::Map { key, value } => Some(
+                MapProperty {
+                    key_type: name.new_sibling(key.serialized_name()),
+                    value_type: name.new_sibling(value.serialized_name()),
+                    name,
+                    ancestry,
+                    duplication_index,
+                    ..Default::default()
+                }
+                .into(),
+            ),
+        }
+    }
+
+    /// Create an empty array property
+    fn new_array(
+        inner: &PropertyType,
+        name: FName,
+        ancestry: Ancestry,
+        duplication_index: i32,
+    ) -> ArrayProperty {
+        // empty struct arrays need a dummy struct to write the inner tag
+        let dummy_property = match inner {
+            PropertyType::Struct {
+                struct_type: Some(struct_type),
+            } => Some(StructProperty {
+                name: name.clone(),
+                ancestry: ancestry.with_parent(name.clone()),
+                struct_type: Some(name.new_sibling(struct_type)),
+                struct_guid: Some(Guid::default()),
+                serialize_none: true,
+                ..Default::default()
+            }),
+            _ => None,
+        };
+
+        ArrayProperty {
+            array_type: Some(name.new_sibling(inner.serialized_name())),
+            name,
+            ancestry,
+            property_guid: None,
+            duplication_index,
+            value: Vec::new(),
+            dummy_property,
+        }
+    }
+}
+
+impl Display for PropertyType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyType::Simple(name) => f.write_str(name),
+            PropertyType::Byte { enum_type: None } => f.write_str("ByteProperty"),
+            PropertyType::Byte {
+                enum_type: Some(enum_type),
+            } => write!(f, "ByteProperty<{enum_type}>"),
+            PropertyType::Enum {
+                enum_type,
+                underlying,
+            } => write!(
+                f,
+                "EnumProperty<{}, {underlying}>",
+                enum_type.as_deref().unwrap_or("?")
+            ),
+            PropertyType::Struct { struct_type } => write!(
+                f,
+                "StructProperty<{}>",
+                struct_type.as_deref().unwrap_or("?")
+            ),
+            PropertyType::Array(inner) => write!(f, "ArrayProperty<{inner}>"),
+            PropertyType::Set(inner) => write!(f, "SetProperty<{inner}>"),
+            PropertyType::Map { key, value } => write!(f, "MapProperty<{key}, {value}>"),
+        }
+    }
+}
+
+/// Property in a schema
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SchemaProperty {
+    /// Property name
+    pub name: String,
+    /// Property type
+    pub property_type: PropertyType,
+    /// Static array size, 1 if the property isn't a static array
+    pub array_size: i32,
+}
+
+/// Problem found while validating properties against a schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaMismatch {
+    /// Property doesn't exist in the schema
+    UnknownProperty {
+        /// Property name
+        name: String,
+    },
+    /// Property type doesn't match the schema
+    TypeMismatch {
+        /// Property name
+        name: String,
+        /// Type from the schema
+        expected: PropertyType,
+        /// Serialized type of the property
+        found: String,
+    },
+    /// Duplication index is outside of the static array
+    InvalidArrayIndex {
+        /// Property name
+        name: String,
+        /// Duplication index
+        duplication_index: i32,
+        /// Static array size
+        array_size: i32,
+    },
+}
+
+impl Display for SchemaMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaMismatch::UnknownProperty { name } => {
+                write!(f, "{name} doesn't exist in the schema")
+            }
+            SchemaMismatch::TypeMismatch {
+                name,
+                expected,
+                found,
+            } => write!(f, "{name} should be {expected}, found {found}"),
+            SchemaMismatch::InvalidArrayIndex {
+                name,
+                duplication_index,
+                array_size,
+            } => write!(
+                f,
+                "{name}[{duplication_index}] is outside of a static array of size {array_size}"
+            ),
+        }
+    }
+}
+
+/// Property layout of a class or a struct
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertySchema {
+    /// Class or struct name
+    pub name: String,
+    /// Properties, including the ones from super structs, super struct properties come first
+    pub properties: Vec<SchemaProperty>,
+    /// First super struct that couldn't be resolved
+    ///
+    /// If this is set, the schema is missing all properties of that struct and its supers.
+    pub unresolved_super: Option<String>,
+}
+
+impl PropertySchema {
+    /// Check if all properties of the class or struct are known
+    pub fn is_complete(&self) -> bool {
+        self.unresolved_super.is_none()
+    }
+
+    /// Get a schema property by name
+    pub fn get_property(&self, name: &str) -> Option<&SchemaProperty> {
+        // properties of child structs shadow super struct properties
+        self.properties.iter().rev().find(|e| e.name == name)
+    }
+
+    /// Create a new property with a default value
+    ///
+    /// See [`PropertyType::new_property`]
+    pub fn new_property(
+        &self,
+        name: FName,
+        ancestry: Ancestry,
+        duplication_index: i32,
+    ) -> Option<Property> {
+        let schema_property = self.get_property(&fname_to_string(&name))?;
+        if duplication_index < 0 || duplication_index >= schema_property.array_size {
+            return None;
+        }
+        schema_property
+            .property_type
+            .new_property(name, ancestry, duplication_index)
+    }
+
+    /// Validate properties against this schema
+    ///
+    /// Unknown properties are only reported for complete schemas.
+    pub fn validate(&self, properties: &[Property]) -> Vec<SchemaMismatch> {
+        let mut mismatches = Vec::new();
+
+        for property in properties {
+            let name = fname_to_string(&property.get_name());
+            let Some(schema_property) = self.get_property(&name) else {
+                if self.is_complete() {
+                    mismatches.push(SchemaMismatch::UnknownProperty { name });
+                }
+                continue;
+            };
+
+            let duplication_index = property.get_duplication_index();
+            if duplication_index < 0 || duplication_index >= schema_property.array_size {
+                mismatches.push(SchemaMismatch::InvalidArrayIndex {
+                    name: name.clone(),
+                    duplication_index,
+                    array_size: schema_property.array_size,
+                });
+            }
+
+            if !schema_property.property_type.matches(property) {
+                mismatches.push(SchemaMismatch::TypeMismatch {
+                    name,
+                    expected: schema_property.property_type.clone(),
+                    found: property.to_serialized_name(),
+                });
+            }
+        }
+
+        mismatches
+    }
+}
+
+/// Get the struct export of an export that has one
+fn get_struct_export(export: &Export<PackageIndex>) -> Option<&StructExport<PackageIndex>> {
+    match export {
+        Export::StructExport(e) => Some(e),
+        Export::ClassExport(e) => Some(&e.struct_export),
+        Export::FunctionExport(e) => Some(&e.struct_export),
+        Export::UserDefinedStructExport(e) => Some(&e.struct_export),
+        _ => None,
+    }
+}
+
+/// Convert usmap property data to a `PropertyType`
+fn usmap_property_type(data: &UsmapPropertyData) -> PropertyType {
+    match data {
+        UsmapPropertyData::UsmapEnumPropertyData(e) => PropertyType::Enum {
+            enum_type: Some(e.name.clone()),
+            underlying: Box::new(usmap_property_type(&e.inner_property)),
+        },
+        UsmapPropertyData::UsmapStructPropertyData(e) => PropertyType::Struct {
+            struct_type: Some(e.struct_type.clone()),
+        },
+        UsmapPropertyData::UsmapSetPropertyData(e) => {
+            PropertyType::Set(Box::new(usmap_property_type(&e.inner_type)))
+        }
+        UsmapPropertyData::UsmapArrayPropertyData(e) => {
+            PropertyType::Array(Box::new(usmap_property_type(&e.inner_type)))
+        }
+        UsmapPropertyData::UsmapMapPropertyData(e) => PropertyType::Map {
+            key: Box::new(usmap_property_type(&e.inner_type)),
+            value: Box::new(usmap_property_type(&e.value_type)),
+        },
+        UsmapPropertyData::UsmapShallowPropertyData(e) => match e.property_type {
+            EPropertyType::ByteProperty => PropertyType::Byte { enum_type: None },
+            EPropertyType::StructProperty => PropertyType::Struct { struct_type: None },
+            property_type => PropertyType::Simple(property_type.to_string()),
+        },
+    }
+}
+
+/// Resolve a schema from usmap mappings
+fn usmap_schema(usmap: &Usmap, name: &str) -> Option<PropertySchema> {
+    let mut schema = usmap.schemas.get_by_key(name)?;
+    let mut chain = vec![schema];
+    let mut unresolved_super = None;
+
+    while !schema.super_type.is_empty() && chain.len() < MAX_DEPTH {
+        match usmap.schemas.get_by_key(&schema.super_type) {
+            Some(super_schema) => {
+                schema = super_schema;
+                chain.push(schema);
+            }
+            None => {
+                unresolved_super = Some(schema.super_type.clone());
+                break;
+            }
+        }
+    }
+
+    let properties = chain
+        .iter()
+        .rev()
+        .flat_map(|e| e.properties.values())
+        // static arrays are stored as one usmap property per element
+        .filter(|e| e.array_index == 0)
+        .map(|e| SchemaProperty {
+            name: e.name.clone(),
+            property_type: usmap_property_type(&e.property_data),
+            array_size: (e.array_size as i32).max(1),
+        })
+        .collect();
+
+    Some(PropertySchema {
+        name: name.to_string(),
+        properties,
+        unresolved_super,
+    })
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Get the name of an import or an export
+    fn get_object_name_string(&self, index: PackageIndex) -> Option<String> {
+        match index.is_import() {
+            true => self
+                .get_import(index)
+                .map(|e| fname_to_string(&e.object_name)),
+            false => self
+                .get_export(index)
+                .map(|e| fname_to_string(&e.get_base_export().object_name)),
+        }
+    }
+
+    /// Get the type of an `FProperty`
+    fn fproperty_type(&self, property: &FProperty) -> PropertyType {
+        let name_of = |index: PackageIndex| match index.index {
+            0 => None,
+            _ => self.get_object_name_string(index),
+        };
+
+        match property {
+            FProperty::FEnumProperty(e) => PropertyType::Enum {
+                enum_type: name_of(e.enum_value),
+                underlying: Box::new(self.fproperty_type(&e.underlying_prop)),
+            },
+            FProperty::FArrayProperty(e) => {
+                PropertyType::Array(Box::new(self.fproperty_type(&e.inner)))
+            }
+            FProperty::FSetProperty(e) => {
+                PropertyType::Set(Box::new(self.fproperty_type(&e.element_prop)))
+            }
+            FProperty::FMapProperty(e) => PropertyType::Map {
+                key: Box::new(self.fproperty_type(&e.key_prop)),
+                value: Box::new(self.fproperty_type(&e.value_prop)),
+            },
+            FProperty::FByteProperty(e) => PropertyType::Byte {
+                enum_type: name_of(e.enum_value),
+            },
+            FProperty::FStructProperty(e) => PropertyType::Struct {
+                struct_type: name_of(e.struct_value),
+            },
+            _ => PropertyType::from_serialized_name(&property.to_serialized_name()),
+        }
+    }
+
+    /// Get the type of a `UProperty` export
+    fn uproperty_type(&self, index: PackageIndex, depth: usize) -> Option<PropertyType> {
+        let Some(Export::PropertyExport(export)) = self.get_export(index) else {
+            return None;
+        };
+        if depth >= MAX_DEPTH {
+            return None;
+        }
+
+        let name_of = |index: PackageIndex| match index.index {
+            0 => None,
+            _ => self.get_object_name_string(index),
+        };
+        let inner = |index: PackageIndex| {
+            self.uproperty_type(index, depth + 1)
+                .map(Box::new)
+                .unwrap_or_else(|| Box::new(PropertyType::Simple("Unknown".to_string())))
+        };
+
+        Some(match &export.property {
+            UProperty::UEnumProperty(e) => PropertyType::Enum {
+                enum_type: name_of(e.value),
+                underlying: inner(e.underlying_prop),
+            },
+            UProperty::UArrayProperty(e) => PropertyType::Array(inner(e.inner)),
+            UProperty::USetProperty(e) => PropertyType::Set(inner(e.element_prop)),
+            UProperty::UMapProperty(e) => PropertyType::Map {
+                key: inner(e.key_prop),
+                value: inner(e.value_prop),
+            },
+            UProperty::UByteProperty(e) => PropertyType::Byte {
+                enum_type: name_of(e.enum_value),
+            },
+            UProperty::UStructProperty(e) => PropertyType::Struct {
+                struct_type: name_of(e.struct_value),
+            },
+            // the serialized type of a property export is its class name
+            _ => PropertyType::from_serialized_name(&name_of(
+                export.normal_export.base_export.class_index,
+            )?),
+        })
+    }
+
+    /// Get the properties declared directly in a struct export
+    fn own_schema_properties(
+        &self,
+        struct_export: &StructExport<PackageIndex>,
+    ) -> Vec<SchemaProperty> {
+        let loaded_properties = struct_export.loaded_properties.iter().map(|e| {
+            let generic_property = e.get_generic_property();
+            SchemaProperty {
+                name: fname_to_string(&generic_property.name),
+                property_type: self.fproperty_type(e),
+                array_size: i32::from(generic_property.array_dim).max(1),
+            }
+        });
+
+        // before FProperties, properties were stored as exports listed in children
+        let property_exports = struct_export.children.iter().filter_map(|index| {
+            let Some(Export::PropertyExport(export)) = self.get_export(*index) else {
+                return None;
+            };
+            Some(SchemaProperty {
+                name: fname_to_string(&export.normal_export.base_export.object_name),
+                property_type: self.uproperty_type(*index, 0)?,
+                array_size: i32::from(export.property.get_generic_property().array_dim).max(1),
+            })
+        });
+
+        loaded_properties.chain(property_exports).collect()
+    }
+
+    /// Get the property schema of a class or a struct
+    ///
+    /// Exports are resolved from their [`StructExport`], following super structs,
+    /// imports and super structs that are imports are resolved from the loaded mappings.
+    /// Returns `None` if the index doesn't point to a struct export or to an import known by the mappings.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use unreal_asset::{Asset, engine_version::EngineVersion, types::PackageIndex};
+    /// # let mut asset = Asset::new(File::open("BP_Example.uasset").unwrap(), None, EngineVersion::VER_UE4_27, None).unwrap();
+    /// let schema = asset.get_schema(PackageIndex::new(1)).unwrap();
+    /// let speed = asset.add_fname("Speed");
+    /// let property = schema.new_property(speed, Default::default(), 0);
+    /// ```
+    pub fn get_schema(&self, struct_index: PackageIndex) -> Option<PropertySchema> {
+        if struct_index.is_import() {
+            let import = self.get_import(struct_index)?;
+            let usmap = self.asset_data.mappings.as_ref()?;
+            return import
+                .object_name
+                .get_content(|name| usmap_schema(usmap, name));
+        }
+
+        let export = self.get_export(struct_index)?;
+        let name = fname_to_string(&export.get_base_export().object_name);
+        let mut struct_export = get_struct_export(export)?;
+
+        let mut chain = vec![self.own_schema_properties(struct_export)];
+        let mut unresolved_super = None;
+
+        while struct_export.super_struct.index != 0 {
+            let super_struct = struct_export.super_struct;
+            if chain.len() >= MAX_DEPTH {
+                unresolved_super = self.get_object_name_string(super_struct);
+                break;
+            }
+
+            if super_struct.is_export() {
+                match self.get_export(super_struct).and_then(get_struct_export) {
+                    Some(e) => {
+                        struct_export = e;
+                        chain.push(self.own_schema_properties(struct_export));
+                        continue;
+                    }
+                    None => {
+                        unresolved_super = self.get_object_name_string(super_struct);
+                        break;
+                    }
+                }
+            }
+
+            match self.get_schema(super_struct) {
+                Some(super_schema) => {
+                    chain.push(super_schema.properties);
+                    unresolved_super = super_schema.unresolved_super;
+                }
+                None => unresolved_super = self.get_object_name_string(super_struct),
+            }
+            break;
+        }
+
+        Some(PropertySchema {
+            name,
+            properties: chain.into_iter().rev().flatten().collect(),
+            unresolved_super,
+        })
+    }
+
+    /// Get the property schema of a class or a struct by name
+    ///
+    /// Struct exports with this name are preferred over the loaded mappings.
+    pub fn get_schema_by_name(&self, name: &str) -> Option<PropertySchema> {
+        let export_index = self.asset_data.exports.iter().position(|e| {
+            get_struct_export(e).is_some()
+                && fname_to_string(&e.get_base_export().object_name) == name
+        });
+
+        match export_index {
+            Some(index) => self.get_schema(PackageIndex::from_export(index as i32).ok()?),
+            None => usmap_schema(self.asset_data.mappings.as_ref()?, name),
+        }
+    }
+
+    /// Get the property schema of an export's class
+    pub fn get_export_schema(&self, export_index: PackageIndex) -> Option<PropertySchema> {
+        let class_index = self.get_export(export_index)?.get_base_export().class_index;
+        self.get_schema(class_index)
+    }
+
+    /// Set a property value of an export by path
+    ///
+    /// Missing properties are created with the type declared in the schema of the export's class,
+    /// or in the schema of the struct they are missing from. See [`PropertyAccess::set_or_create`].
+    pub fn set_export_property(
+        &mut self,
+        export_index: PackageIndex,
+        path: &str,
+        value: impl IntoProperty,
+    ) -> Result<(), Error> {
+        if !export_index.is_export() || self.get_export(export_index).is_none() {
+            return Err(Error::invalid_package_index(format!(
+                "Export {} doesn't exist",
+                export_index.index
+            )));
+        }
+        let class_schema = self.get_export_schema(export_index);
+
+        // the export is taken out so that struct schemas can be resolved while it's edited
+        let index = export_index.index as usize - 1;
+        let mut export = std::mem::replace(
+            &mut self.asset_data.exports[index],
+            Export::BaseExport(BaseExport::default()),
+        );
+
+        let mut struct_schemas = HashMap::new();
+        let result = match export.get_normal_export_mut() {
+            Some(normal_export) => normal_export.set_or_create(
+                path,
+                value,
+                |struct_type, name, ancestry, duplication_index| {
+                    let schema = match struct_type {
+                        Some(struct_type) => struct_schemas
+                            .entry(fname_to_string(struct_type))
+                            .or_insert_with_key(|e| self.get_schema_by_name(e))
+                            .as_ref(),
+                        None => class_schema.as_ref(),
+                    };
+                    schema?.new_property(name, ancestry, duplication_index)
+                },
+            ),
+            None => Err(Error::invalid_package_index(format!(
+                "Export {} isn't a normal export",
+                export_index.index
+            ))),
+        };
+
+        self.asset_data.exports[index] = export;
+        result
+    }
+}
@@ -0,0 +1,399 @@
+//! Export cloning and transplanting
+//!
+//! Exports can be copied within an asset or from another asset together with their subobjects,
+//! names are moved into the target name map and object references are remapped.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{Read, Seek};
+
+use unreal_asset_base::{
+    error::Error,
+    types::{fname::FNameContainer, FName, PackageIndex, PackageIndexContainer, PackageIndexTrait},
+};
+use unreal_asset_exports::{Export, ExportBaseTrait};
+
+use crate::asset::Asset;
+use crate::removal::has_raw_data;
+
+/// Collect an export and optionally all of its subobjects, the root export comes first
+pub(crate) fn collect_exports<C: Read + Seek>(
+    asset: &Asset<C>,
+    index: PackageIndex,
+    include_subobjects: bool,
+) -> Result<Vec<usize>, Error> {
+    if !index.is_export() || index.index as usize > asset.asset_data.exports.len() {
+        return Err(Error::invalid_package_index(format!(
+            "Export {} doesn't exist",
+            index.index
+        )));
+    }
+
+    if !include_subobjects {
+        return Ok(vec![index.index as usize - 1]);
+    }
+
+    let is_subobject = |mut current: PackageIndex| {
+        // guard against cyclic outer chains
+        for _ in 0..=asset.asset_data.exports.len() {
+            if current == index {
+                return true;
+            }
+            match asset.asset_data.get_export(current) {
+                Some(export) => current = export.get_base_export().outer_index,
+                None => return false,
+            }
+        }
+        false
+    };
+
+    // the root goes first even if its subobjects come before it
+    let root = index.index as usize - 1;
+    Ok(std::iter::once(root)
+        .chain(
+            (0..asset.asset_data.exports.len())
+                .filter(|e| *e != root && is_subobject(PackageIndex::new(*e as i32 + 1))),
+        )
+        .collect())
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Copy an export from another asset into this asset
+    ///
+    /// The copy is placed under `outer_index` and all of its names are added to this asset's name map.
+    /// If `include_subobjects` is set, every export nested inside of the copied export is copied with it.
+    ///
+    /// References to the source's imports are imported into this asset,
+    /// references to exports of the source that weren't copied are imported from the source package,
+    /// and references to the original outer are pointed at `outer_index`.
+    /// If the copied export was an actor of the source level and `outer_index` is a level,
+    /// the copy is added to that level's actors.
+    ///
+    /// Returns the index of the copied export, its subobjects are placed right after it.
+    pub fn transplant_export<D: Read + Seek>(
+        &mut self,
+        source: &Asset<D>,
+        index: PackageIndex,
+        outer_index: PackageIndex,
+        include_subobjects: bool,
+    ) -> Result<PackageIndex, Error> {
+        let source_indices = collect_exports(source, index, include_subobjects)?;
+        let source_package = source.get_package_name();
+        let same_package = source_package.is_some() && source_package == self.get_package_name();
+
+        let mut name_map = self.get_name_map();
+        let exports = source_indices
+            .iter()
+            .map(|e| {
+                let mut export = source.asset_data.exports[*e].clone();
+                export.traverse_fnames(&mut |name| {
+                    // dummy names are never serialized and can be kept as is
+                    if let FName::Backed { number, .. } = name {
+                        let number = *number;
+                        let content = name.get_owned_content();
+                        *name = name_map.get_mut().add_fname_with_number(&content, number);
+                    }
+                });
+                export
+            })
+            .collect::<Vec<_>>();
+
+        let is_level_actor = source.is_level_actor(index);
+
+        self.insert_exports(
+            exports,
+            source.depends_for(&source_indices),
+            &source_indices,
+            is_level_actor,
+            outer_index,
+            |asset, reference| match same_package {
+                true => asset.find_own_reference(source, source_package.as_deref(), reference),
+                false => asset.import_reference(source, source_package.as_deref(), reference),
+            },
+        )
+    }
+
+    /// Clone an export of this asset
+    ///
+    /// Works the same way as [`Asset::transplant_export`] with this asset as the source,
+    /// references to exports that weren't cloned are kept as is.
+    pub fn clone_export(
+        &mut self,
+        index: PackageIndex,
+        outer_index: PackageIndex,
+        include_subobjects: bool,
+    ) -> Result<PackageIndex, Error> {
+        let source_indices = collect_exports(self, index, include_subobjects)?;
+        let exports = source_indices
+            .iter()
+            .map(|e| self.asset_data.exports[*e].clone())
+            .collect::<Vec<_>>();
+        let is_level_actor = self.is_level_actor(index);
+
+        self.insert_exports(
+            exports,
+            self.depends_for(&source_indices),
+            &source_indices,
+            is_level_actor,
+            outer_index,
+            |_, reference| Ok(reference),
+        )
+    }
+
+    /// Insert copied exports, remapping their object references
+    ///
+    /// `exports` must start with the root export, references to objects that weren't copied are resolved with `resolve`.
+    /// Imports added while resolving references are removed again if this fails.
+    fn insert_exports(
+        &mut self,
+        mut exports: Vec<Export<PackageIndex>>,
+        mut depends: Vec<Vec<i32>>,
+        source_indices: &[usize],
+        is_level_actor: bool,
+        outer_index: PackageIndex,
+        mut resolve: impl FnMut(&mut Self, PackageIndex) -> Result<PackageIndex, Error>,
+    ) -> Result<PackageIndex, Error> {
+        if outer_index.index != 0
+            && self
+                .get_object_path_with_package(outer_index, None)
+                .is_none()
+        {
+            return Err(Error::invalid_package_index(format!(
+                "Outer {} doesn't exist",
+                outer_index.index
+            )));
+        }
+
+        let import_count = self.imports.len();
+        let first_index = self.asset_data.exports.len() as i32 + 1;
+        let copied = source_indices
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (*e as i32 + 1, PackageIndex::new(first_index + i as i32)))
+            .collect::<HashMap<_, _>>();
+        let source_outer = exports[0].get_base_export().outer_index;
+
+        let mut remap = |asset: &mut Self, index: PackageIndex| {
+            if index.index == 0 {
+                return Ok(index);
+            }
+            if let Some(copied) = copied.get(&index.index) {
+                return Ok(*copied);
+            }
+            if index == source_outer {
+                return Ok(outer_index);
+            }
+            resolve(asset, index)
+        };
+
+        if let Some(export) = exports.iter().find(|e| has_raw_data(e)) {
+            return Err(Error::unimplemented(format!(
+                "Can't copy {}, it contains raw data which may reference other objects",
+                export.get_base_export().object_name.get_owned_content()
+            )));
+        }
+
+        for (export, depends) in exports.iter_mut().zip(depends.iter_mut()) {
+            let mut error = None;
+            export.traverse_package_indices(&mut |index| {
+                if error.is_some() {
+                    return;
+                }
+                match remap(self, *index) {
+                    Ok(remapped) => *index = remapped,
+                    Err(e) => error = Some(e),
+                }
+            });
+
+            for index in depends.iter_mut() {
+                if error.is_some() {
+                    break;
+                }
+                match remap(self, PackageIndex::new(*index)) {
+                    Ok(remapped) => *index = remapped.index,
+                    Err(e) => error = Some(e),
+                }
+            }
+
+            if let Some(error) = error {
+                self.imports.truncate(import_count);
+                return Err(error);
+            }
+
+            let base_export = export.get_base_export_mut();
+            for dependencies in [
+                &mut base_export.serialization_before_serialization_dependencies,
+                &mut base_export.create_before_serialization_dependencies,
+                &mut base_export.serialization_before_create_dependencies,
+                &mut base_export.create_before_create_dependencies,
+            ] {
+                let mut seen = HashSet::new();
+                dependencies.retain(|e| e.index != 0 && seen.insert(*e));
+            }
+        }
+
+        let root = PackageIndex::new(first_index);
+        let root_export = exports[0].get_base_export_mut();
+        root_export.outer_index = outer_index;
+        root_export.object_name = self.unique_object_name(&root_export.object_name, outer_index);
+
+        if let Some(depends_map) = self.depends_map.as_mut() {
+            depends_map.resize(first_index as usize - 1, Vec::new());
+            depends_map.append(&mut depends);
+        }
+        self.asset_data.exports.append(&mut exports);
+
+        if is_level_actor {
+            if let Some(Export::LevelExport(level)) = self.asset_data.get_export_mut(outer_index) {
+                level.actors.push(root);
+                level
+                    .get_base_export_mut()
+                    .create_before_serialization_dependencies
+                    .push(root);
+            }
+        }
+
+        Ok(root)
+    }
+
+    /// Get the dependencies of exports, empty if this asset has no depends map
+    fn depends_for(&self, indices: &[usize]) -> Vec<Vec<i32>> {
+        indices
+            .iter()
+            .map(|e| {
+                self.depends_map
+                    .as_ref()
+                    .and_then(|depends_map| depends_map.get(*e))
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Check if an export is an actor of the level it's in
+    fn is_level_actor(&self, index: PackageIndex) -> bool {
+        let Some(export) = self.asset_data.get_export(index) else {
+            return false;
+        };
+
+        match self
+            .asset_data
+            .get_export(export.get_base_export().outer_index)
+        {
+            Some(Export::LevelExport(level)) => level.actors.contains(&index),
+            _ => false,
+        }
+    }
+
+    /// Get an object name that is unique within an outer
+    fn unique_object_name(&mut self, name: &FName, outer_index: PackageIndex) -> FName {
+        let content = name.get_owned_content();
+        let mut number = name.get_number();
+
+        let is_taken = |number: i32| {
+            self.asset_data.exports.iter().any(|e| {
+                let base_export = e.get_base_export();
+                base_export.outer_index == outer_index
+                    && base_export.object_name.get_number() == number
+                    && base_export.object_name == content.as_str()
+            })
+        };
+
+        if !is_taken(number) {
+            return name.clone();
+        }
+        while is_taken(number) {
+            number += 1;
+        }
+        self.get_name_map()
+            .get_mut()
+            .add_fname_with_number(&content, number)
+    }
+
+    /// Resolve a reference of an asset with the same package as this one
+    fn find_own_reference<D: Read + Seek>(
+        &mut self,
+        source: &Asset<D>,
+        source_package: Option<&str>,
+        reference: PackageIndex,
+    ) -> Result<PackageIndex, Error> {
+        if reference.is_import() {
+            return self.import_reference(source, source_package, reference);
+        }
+
+        let path = source
+            .get_object_path_with_package(reference, source_package)
+            .ok_or_else(|| {
+                Error::invalid_package_index(format!("Invalid reference {}", reference.index))
+            })?;
+        self.find_object_by_path(&path)
+            .filter(|e| e.is_export())
+            .ok_or_else(|| Error::no_data(format!("Export {path} doesn't exist")))
+    }
+
+    /// Import an object referenced by another asset
+    fn import_reference<D: Read + Seek>(
+        &mut self,
+        source: &Asset<D>,
+        source_package: Option<&str>,
+        reference: PackageIndex,
+    ) -> Result<PackageIndex, Error> {
+        if reference.is_export() && source_package.is_none() {
+            return Err(Error::no_data(format!(
+                "Can't import export {} without knowing the source package name",
+                reference.index
+            )));
+        }
+
+        let path = source
+            .get_object_path_with_package(reference, source_package)
+            .ok_or_else(|| {
+                Error::invalid_package_index(format!("Invalid reference {}", reference.index))
+            })?;
+        if let Some(existing) = self.find_import_by_path(&path) {
+            return Ok(existing);
+        }
+
+        let (outer_index, class_package, class_name) = match reference.is_import() {
+            true => {
+                let import = source.get_import(reference).ok_or_else(|| {
+                    Error::invalid_package_index(format!("Invalid import {}", reference.index))
+                })?;
+                (
+                    import.outer_index,
+                    import.class_package.get_owned_content(),
+                    import.class_name.get_owned_content(),
+                )
+            }
+            false => {
+                let export = source
+                    .asset_data
+                    .get_export(reference)
+                    .ok_or_else(|| {
+                        Error::invalid_package_index(format!("Invalid export {}", reference.index))
+                    })?
+                    .get_base_export();
+
+                let class_path = source
+                    .get_object_path_with_package(export.class_index, source_package)
+                    .ok_or_else(|| Error::no_data(format!("Class of {path} doesn't exist")))?;
+                let (class_package, _) = class_path
+                    .split_once('.')
+                    .ok_or_else(|| Error::no_data(format!("Invalid class path {class_path}")))?;
+                let class_name = class_path.rsplit(['.', ':']).next().unwrap_or_default();
+
+                (
+                    export.outer_index,
+                    class_package.to_string(),
+                    class_name.to_string(),
+                )
+            }
+        };
+
+        // outers other than the package have to be imported first
+        if outer_index.index != 0 {
+            self.import_reference(source, source_package, outer_index)?;
+        }
+
+        self.add_import_by_path(&path, &[(&class_package, &class_name)])
+    }
+}
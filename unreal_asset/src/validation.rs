@@ -0,0 +1,577 @@
+//! Asset validation
+//!
+//! Checks an [`Asset`] for structural problems that would otherwise only show up
+//! as a crash when the game tries to load it.
+
+use std::fmt::{Display, Formatter};
+use std::io::{Cursor, Read, Seek};
+
+use unreal_asset_base::{
+    cast,
+    containers::SharedResource,
+    flags::EObjectFlags,
+    reader::RawWriter,
+    types::{fname::FNameContainer, FName, PackageIndex, PackageIndexContainer, PackageIndexTrait},
+};
+use unreal_asset_exports::{BaseExport, Export, ExportBaseTrait, ExportNormalTrait, ExportTrait};
+use unreal_asset_properties::PropertyDataTrait;
+
+use crate::asset::Asset;
+use crate::asset_archive_writer::AssetArchiveWriter;
+
+/// Diagnostic severity
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The asset can be written, but the result is suspicious
+    Warning,
+    /// The asset is broken and will most likely fail to load
+    Error,
+}
+
+/// Object a diagnostic refers to
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticLocation {
+    /// Import at an index inside of the import table
+    Import(usize),
+    /// Export at an index inside of the export table
+    Export(usize),
+}
+
+/// Problem found while validating an asset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A `PackageIndex` doesn't point to an existing import or export
+    InvalidPackageIndex {
+        /// Field that contains the index
+        field: String,
+        /// Invalid index
+        index: PackageIndex,
+    },
+    /// Following outer indices leads back to the same object
+    OuterCycle {
+        /// Objects that form the cycle, in the order they were visited
+        chain: Vec<PackageIndex>,
+    },
+    /// A backed `FName` points outside of its name map
+    FNameOutOfRange {
+        /// Name map index
+        index: i32,
+        /// Instance number
+        number: i32,
+    },
+    /// A backed `FName` belongs to another name map
+    ///
+    /// [`Asset::rebuild_name_map`] fixes this
+    ForeignFName {
+        /// Name content
+        value: String,
+    },
+    /// A dummy `FName` that can't be serialized
+    DummyFName {
+        /// Name content
+        value: String,
+    },
+    /// A preload dependency doesn't point to an existing import or export
+    InvalidPreloadDependency {
+        /// Dependency list name
+        list: &'static str,
+        /// Invalid index
+        index: PackageIndex,
+    },
+    /// Serialized export size is different from the recorded serial size
+    SerialSizeMismatch {
+        /// Serial size recorded in the export map
+        serial_size: i64,
+        /// Size of the export when written
+        written_size: i64,
+    },
+    /// Export failed to serialize
+    SerializationFailed {
+        /// Error message
+        message: String,
+    },
+    /// Class index doesn't point to a class
+    NotAClass {
+        /// Class index
+        class_index: PackageIndex,
+    },
+    /// Template index points to something that can't be a template for this export
+    InvalidTemplate {
+        /// Template index
+        template_index: PackageIndex,
+        /// Why the template is invalid
+        reason: &'static str,
+    },
+}
+
+impl Display for DiagnosticKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiagnosticKind::InvalidPackageIndex { field, index } => {
+                write!(f, "{field} points to a non-existent object {index}")
+            }
+            DiagnosticKind::OuterCycle { chain } => {
+                let chain = chain
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                write!(f, "outer chain is cyclic: {chain}")
+            }
+            DiagnosticKind::FNameOutOfRange { index, number } => {
+                write!(f, "name {index}_{number} is not in the name map")
+            }
+            DiagnosticKind::ForeignFName { value } => {
+                write!(f, "name {value} belongs to another name map")
+            }
+            DiagnosticKind::DummyFName { value } => {
+                write!(f, "name {value} is a dummy name and can't be serialized")
+            }
+            DiagnosticKind::InvalidPreloadDependency { list, index } => {
+                write!(f, "{list} contains a non-existent object {index}")
+            }
+            DiagnosticKind::SerialSizeMismatch {
+                serial_size,
+                written_size,
+            } => write!(
+                f,
+                "serial size is {serial_size} but the export is {written_size} bytes when written"
+            ),
+            DiagnosticKind::SerializationFailed { message } => {
+                write!(f, "failed to serialize: {message}")
+            }
+            DiagnosticKind::NotAClass { class_index } => {
+                write!(f, "class index {class_index} doesn't point to a class")
+            }
+            DiagnosticKind::InvalidTemplate {
+                template_index,
+                reason,
+            } => write!(f, "template {template_index} is invalid: {reason}"),
+        }
+    }
+}
+
+/// Validation diagnostic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Severity
+    pub severity: Severity,
+    /// Object this diagnostic refers to
+    pub location: DiagnosticLocation,
+    /// Problem
+    pub kind: DiagnosticKind,
+}
+
+impl Diagnostic {
+    /// Create a new error `Diagnostic`
+    pub fn error(location: DiagnosticLocation, kind: DiagnosticKind) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            location,
+            kind,
+        }
+    }
+
+    /// Create a new warning `Diagnostic`
+    pub fn warning(location: DiagnosticLocation, kind: DiagnosticKind) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            location,
+            kind,
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        match self.location {
+            DiagnosticLocation::Import(i) => write!(f, "{severity}: import {i}: {}", self.kind),
+            DiagnosticLocation::Export(i) => write!(f, "{severity}: export {i}: {}", self.kind),
+        }
+    }
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Validate this asset
+    ///
+    /// Returns every problem that was found, an empty `Vec` means the asset is valid.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        self.validate_package_indices(&mut diagnostics);
+        self.validate_outer_chains(&mut diagnostics);
+        self.validate_fnames(&mut diagnostics);
+        self.validate_serial_sizes(&mut diagnostics);
+        self.validate_class_and_template(&mut diagnostics);
+
+        diagnostics
+    }
+
+    /// Check if a `PackageIndex` points to an existing object, null indices are valid
+    fn is_valid_index(&self, index: PackageIndex) -> bool {
+        match index.index {
+            0 => true,
+            i if i < 0 => (-(i + 1) as usize) < self.imports.len(),
+            i => ((i - 1) as usize) < self.asset_data.exports.len(),
+        }
+    }
+
+    /// Check if a `PackageIndex` points to a class, returns `None` if the index is null or invalid
+    fn is_class(&self, index: PackageIndex) -> Option<bool> {
+        if index.is_import() {
+            let import = self.imports.get(-(index.index + 1) as usize)?;
+            Some(import.class_name.get_content(|e| e.ends_with("Class")))
+        } else if index.is_export() {
+            let export = self.asset_data.exports.get((index.index - 1) as usize)?;
+            Some(cast!(Export, ClassExport, export).is_some())
+        } else {
+            None
+        }
+    }
+
+    /// Get an object name by `PackageIndex`
+    fn get_object_name(&self, index: PackageIndex) -> Option<&FName> {
+        if index.is_import() {
+            self.imports
+                .get(-(index.index + 1) as usize)
+                .map(|e| &e.object_name)
+        } else if index.is_export() {
+            self.asset_data
+                .exports
+                .get((index.index - 1) as usize)
+                .map(|e| &e.get_base_export().object_name)
+        } else {
+            None
+        }
+    }
+
+    /// Get an outer index by `PackageIndex`
+    fn get_outer_index(&self, index: PackageIndex) -> Option<PackageIndex> {
+        if index.is_import() {
+            self.imports
+                .get(-(index.index + 1) as usize)
+                .map(|e| e.outer_index)
+        } else if index.is_export() {
+            self.asset_data
+                .exports
+                .get((index.index - 1) as usize)
+                .map(|e| e.get_base_export().outer_index)
+        } else {
+            None
+        }
+    }
+
+    /// Check export table, property, depends map and preload dependency indices
+    fn validate_package_indices(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for (i, import) in self.imports.iter().enumerate() {
+            if !self.is_valid_index(import.outer_index) {
+                diagnostics.push(Diagnostic::error(
+                    DiagnosticLocation::Import(i),
+                    DiagnosticKind::InvalidPackageIndex {
+                        field: "outer_index".to_string(),
+                        index: import.outer_index,
+                    },
+                ));
+            }
+        }
+
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let location = DiagnosticLocation::Export(i);
+            let base_export = export.get_base_export();
+
+            let mut indices = vec![
+                ("class_index".to_string(), base_export.class_index),
+                ("super_index".to_string(), base_export.super_index),
+                ("template_index".to_string(), base_export.template_index),
+                ("outer_index".to_string(), base_export.outer_index),
+            ];
+            collect_export_data_indices(export, &mut indices);
+            if let Some(depends) = self
+                .depends_map
+                .as_ref()
+                .and_then(|depends_map| depends_map.get(i))
+            {
+                for (j, index) in depends.iter().enumerate() {
+                    indices.push((format!("depends_map[{j}]"), PackageIndex::new(*index)));
+                }
+            }
+
+            for (field, index) in indices {
+                if !self.is_valid_index(index) {
+                    diagnostics.push(Diagnostic::error(
+                        location,
+                        DiagnosticKind::InvalidPackageIndex { field, index },
+                    ));
+                }
+            }
+
+            let preload_dependencies = [
+                (
+                    "serialization_before_serialization_dependencies",
+                    &base_export.serialization_before_serialization_dependencies,
+                ),
+                (
+                    "create_before_serialization_dependencies",
+                    &base_export.create_before_serialization_dependencies,
+                ),
+                (
+                    "serialization_before_create_dependencies",
+                    &base_export.serialization_before_create_dependencies,
+                ),
+                (
+                    "create_before_create_dependencies",
+                    &base_export.create_before_create_dependencies,
+                ),
+            ];
+            for (list, dependencies) in preload_dependencies {
+                for index in dependencies {
+                    if index.index == 0 || !self.is_valid_index(*index) {
+                        diagnostics.push(Diagnostic::error(
+                            location,
+                            DiagnosticKind::InvalidPreloadDependency {
+                                list,
+                                index: *index,
+                            },
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Check that no outer chain loops back on itself
+    fn validate_outer_chains(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let object_count = self.imports.len() + self.asset_data.exports.len();
+
+        let starts = (0..self.imports.len())
+            .map(|i| {
+                (
+                    DiagnosticLocation::Import(i),
+                    PackageIndex::new(-(i as i32) - 1),
+                )
+            })
+            .chain((0..self.asset_data.exports.len()).map(|i| {
+                (
+                    DiagnosticLocation::Export(i),
+                    PackageIndex::new(i as i32 + 1),
+                )
+            }));
+
+        for (location, start) in starts {
+            let mut chain = vec![start];
+            let mut current = start;
+            while let Some(outer) = self.get_outer_index(current) {
+                if outer == start {
+                    // only report the cycle once, from its member with the smallest index
+                    let smallest = chain.iter().map(|e| e.index).min();
+                    if smallest == Some(start.index) {
+                        chain.push(outer);
+                        diagnostics.push(Diagnostic::error(
+                            location,
+                            DiagnosticKind::OuterCycle { chain },
+                        ));
+                    }
+                    break;
+                }
+
+                // a cycle that doesn't include the starting object gets reported by its members
+                if chain.contains(&outer) || chain.len() > object_count {
+                    break;
+                }
+
+                chain.push(outer);
+                current = outer;
+            }
+        }
+    }
+
+    /// Check that every import and export `FName` can be serialized with this asset's name map
+    ///
+    /// Exports keep dummy names in places that never get serialized, so dummy names inside of
+    /// export data are left to [`Asset::validate_serial_sizes`] which reports them as serialization failures.
+    fn validate_fnames(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let name_map = self.get_name_map();
+
+        let mut check_fname =
+            |location: DiagnosticLocation, name: &FName, serialized: bool| match name {
+                FName::Backed {
+                    index,
+                    number,
+                    name_map: fname_name_map,
+                    ..
+                } => {
+                    if !SharedResource::ptr_eq(fname_name_map, &name_map) {
+                        diagnostics.push(Diagnostic::error(
+                            location,
+                            DiagnosticKind::ForeignFName {
+                                value: name.get_owned_content(),
+                            },
+                        ));
+                    } else if *index < 0
+                        || *index as usize >= name_map.get_ref().get_name_map_index_list().len()
+                    {
+                        diagnostics.push(Diagnostic::error(
+                            location,
+                            DiagnosticKind::FNameOutOfRange {
+                                index: *index,
+                                number: *number,
+                            },
+                        ));
+                    }
+                }
+                FName::Dummy { value, .. } if serialized => {
+                    diagnostics.push(Diagnostic::error(
+                        location,
+                        DiagnosticKind::DummyFName {
+                            value: value.clone(),
+                        },
+                    ));
+                }
+                FName::Dummy { .. } => {}
+            };
+
+        for (i, import) in self.imports.iter().enumerate() {
+            let location = DiagnosticLocation::Import(i);
+            let mut import = import.clone();
+            import.traverse_fnames(&mut |name| check_fname(location, name, true));
+        }
+
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let location = DiagnosticLocation::Export(i);
+            // object names are always written to the export map
+            let object_name = &export.get_base_export().object_name;
+            if matches!(object_name, FName::Dummy { .. }) {
+                check_fname(location, object_name, true);
+            }
+            let mut export = export.clone();
+            export.traverse_fnames(&mut |name| check_fname(location, name, false));
+        }
+    }
+
+    /// Check that every export serializes to its recorded serial size
+    ///
+    /// [`Asset::write_data`] recomputes serial sizes, so on a modified asset a mismatch is expected,
+    /// on a freshly parsed asset it means the export doesn't survive a roundtrip.
+    fn validate_serial_sizes(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let location = DiagnosticLocation::Export(i);
+
+            let mut cursor = Cursor::new(Vec::new());
+            let mut raw_writer = RawWriter::new(
+                &mut cursor,
+                self.asset_data.object_version,
+                self.asset_data.object_version_ue5,
+                self.asset_data.use_event_driven_loader,
+                self.get_name_map(),
+            );
+            let mut writer = AssetArchiveWriter::new(
+                &mut raw_writer,
+                &self.asset_data,
+                &self.imports,
+                self.get_name_map(),
+            );
+
+            if let Err(err) = export.write(&mut writer) {
+                diagnostics.push(Diagnostic::error(
+                    location,
+                    DiagnosticKind::SerializationFailed {
+                        message: err.to_string(),
+                    },
+                ));
+                continue;
+            }
+
+            let extras_len = export
+                .get_normal_export()
+                .map(|e| e.extras.len())
+                .unwrap_or_default();
+            let written_size = (cursor.get_ref().len() + extras_len) as i64;
+
+            let serial_size = export.get_base_export().serial_size;
+            if serial_size != written_size {
+                diagnostics.push(Diagnostic::warning(
+                    location,
+                    DiagnosticKind::SerialSizeMismatch {
+                        serial_size,
+                        written_size,
+                    },
+                ));
+            }
+        }
+    }
+
+    /// Check that class indices point to classes and template indices point to instances
+    fn validate_class_and_template(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let location = DiagnosticLocation::Export(i);
+            let base_export = export.get_base_export();
+
+            if self.is_class(base_export.class_index) == Some(false) {
+                diagnostics.push(Diagnostic::error(
+                    location,
+                    DiagnosticKind::NotAClass {
+                        class_index: base_export.class_index,
+                    },
+                ));
+            }
+
+            let template_index = base_export.template_index;
+            if template_index.index == 0 || !self.is_valid_index(template_index) {
+                continue;
+            }
+
+            // class default objects of class types are classes themselves
+            let is_default_object = self
+                .get_object_name(template_index)
+                .is_some_and(|e| e.get_content(|e| e.starts_with("Default__")));
+
+            let reason = if template_index == PackageIndex::new(i as i32 + 1) {
+                Some("export is its own template")
+            } else if !is_default_object && self.is_class(template_index) == Some(true) {
+                Some("template is a class")
+            } else if base_export
+                .object_flags
+                .contains(EObjectFlags::RF_CLASS_DEFAULT_OBJECT)
+                && !is_default_object
+            {
+                Some("class default object template is not a class default object")
+            } else {
+                None
+            };
+
+            if let Some(reason) = reason {
+                diagnostics.push(Diagnostic::warning(
+                    location,
+                    DiagnosticKind::InvalidTemplate {
+                        template_index,
+                        reason,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+/// Collect every `PackageIndex` referenced by an export's data
+///
+/// Export table fields and preload dependencies are checked separately, so they are skipped.
+fn collect_export_data_indices(
+    export: &Export<PackageIndex>,
+    indices: &mut Vec<(String, PackageIndex)>,
+) {
+    let mut export = export.clone();
+
+    if let Some(normal_export) = export.get_normal_export_mut() {
+        for mut property in std::mem::take(&mut normal_export.properties) {
+            let name = property.get_name().get_owned_content();
+            property.traverse_package_indices(&mut |index| indices.push((name.clone(), *index)));
+        }
+    }
+
+    *export.get_base_export_mut() = BaseExport::default();
+    export.traverse_package_indices(&mut |index| indices.push(("export_data".to_string(), *index)));
+}
@@ -0,0 +1,265 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::{Chain, IndexedMap, NameMap},
+    custom_version::FAssetRegistryVersionType,
+    engine_version::{get_object_versions, EngineVersion},
+    flags::EPackageFlags,
+    reader::RawReader,
+    registry::{
+        objects::{
+            asset_bundle_data::AssetBundleData,
+            asset_data::AssetData,
+            depends_node::{AssetIdentifier, DependencyType, DependsNode},
+        },
+        AssetRegistryState,
+    },
+    types::FName,
+    Error,
+};
+
+const VERSION: FAssetRegistryVersionType = FAssetRegistryVersionType::AddedCookedMD5Hash;
+
+fn new_registry() -> AssetRegistryState {
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_25);
+    AssetRegistryState::new_empty(VERSION, object_version, object_version_ue5)
+}
+
+fn new_asset(package_name: &str, class: &str, tags: &[(&str, &str)]) -> AssetData {
+    let (package_path, asset_name) = package_name.rsplit_once('/').unwrap();
+    let tags_and_values = tags
+        .iter()
+        .map(|(key, value)| (FName::from_slice(key), Some(value.to_string())))
+        .collect::<IndexedMap<_, _>>();
+
+    AssetData::from_data(
+        FName::from_slice(&format!("{package_name}.{asset_name}")),
+        FName::from_slice(package_name),
+        FName::from_slice(package_path),
+        FName::from_slice(asset_name),
+        Some(FName::from_slice(class)),
+        None,
+        tags_and_values,
+        AssetBundleData::default(),
+        Vec::new(),
+        EPackageFlags::PKG_NONE,
+        VERSION,
+    )
+}
+
+/// Create a package `DependsNode` with hard dependencies and referencers
+fn new_node(
+    registry: &mut AssetRegistryState,
+    index: i32,
+    package_name: &str,
+    dependencies: &[i32],
+    referencers: &[i32],
+) -> DependsNode {
+    let mut identifier = AssetIdentifier {
+        package_name: Some(FName::from_slice(package_name)),
+        ..Default::default()
+    };
+    registry.import_fnames(&mut identifier);
+
+    let mut node = DependsNode::new(index, VERSION);
+    node.identifier = identifier;
+    node.hard_dependencies = dependencies
+        .iter()
+        .map(|e| DependsNode::new(*e, VERSION))
+        .collect();
+    node.referencers = referencers
+        .iter()
+        .map(|e| DependsNode::new(*e, VERSION))
+        .collect();
+    node
+}
+
+fn reread(registry: &AssetRegistryState) -> Result<AssetRegistryState, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    registry.write(&mut cursor)?;
+    cursor.set_position(0);
+
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_25);
+    let mut reader = RawReader::new(
+        Chain::new(cursor, None),
+        object_version,
+        object_version_ue5,
+        false,
+        NameMap::new(),
+    );
+    AssetRegistryState::new(&mut reader)
+}
+
+fn object_paths(assets: &[&AssetData]) -> Vec<String> {
+    assets
+        .iter()
+        .map(|e| e.object_path.get_owned_content())
+        .collect()
+}
+
+fn package_names(nodes: &[&DependsNode]) -> Vec<String> {
+    nodes
+        .iter()
+        .filter_map(|e| e.identifier.package_name.as_ref())
+        .map(|e| e.get_owned_content())
+        .collect()
+}
+
+/// Game registry with a level that hard references a rock and a tree
+fn base_registry() -> AssetRegistryState {
+    let mut registry = new_registry();
+    registry.add_asset_data(new_asset("/Game/Maps/Level", "World", &[]));
+    registry.add_asset_data(new_asset(
+        "/Game/Props/Rock",
+        "StaticMesh",
+        &[("Tier", "1"), ("Biome", "Desert")],
+    ));
+    registry.add_asset_data(new_asset(
+        "/Game/Props/Tree",
+        "StaticMesh",
+        &[("Tier", "2")],
+    ));
+
+    let nodes = [
+        new_node(&mut registry, 0, "/Game/Maps/Level", &[1, 2], &[]),
+        new_node(&mut registry, 1, "/Game/Props/Rock", &[], &[0]),
+        new_node(&mut registry, 2, "/Game/Props/Tree", &[], &[0]),
+    ];
+    registry.depends_nodes.extend(nodes);
+
+    registry
+}
+
+#[test]
+fn queries() -> Result<(), Error> {
+    let registry = reread(&base_registry())?;
+    assert_eq!(registry.package_data.len(), 3);
+
+    let index = registry.index();
+    assert_eq!(
+        object_paths(&index.find_by_package("/game/props/rock")),
+        vec!["/Game/Props/Rock.Rock"]
+    );
+    assert!(index.find_by_object_path("/Game/Props/Tree.Tree").is_some());
+    assert!(index.find_by_object_path("/Game/Props/Tree").is_none());
+    assert_eq!(
+        object_paths(&index.find_by_class("StaticMesh")),
+        vec!["/Game/Props/Rock.Rock", "/Game/Props/Tree.Tree"]
+    );
+    assert_eq!(index.find_by_tag("tier", None).len(), 2);
+    assert_eq!(
+        object_paths(&index.find_by_tag("Tier", Some("2"))),
+        vec!["/Game/Props/Tree.Tree"]
+    );
+    assert!(index.find_by_tag("Biome", Some("Arctic")).is_empty());
+
+    assert_eq!(
+        package_names(&index.get_dependencies("/Game/Maps/Level", DependencyType::Hard)),
+        vec!["/Game/Props/Rock", "/Game/Props/Tree"]
+    );
+    assert!(index
+        .get_dependencies("/Game/Maps/Level", DependencyType::Soft)
+        .is_empty());
+    assert_eq!(
+        package_names(&index.get_referencers("/Game/Props/Rock")),
+        vec!["/Game/Maps/Level"]
+    );
+    assert!(index.get_depends_node("/Game/Props/Missing").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn add_and_remove() -> Result<(), Error> {
+    let mut registry = base_registry();
+
+    let replaced = registry.add_asset_data(new_asset(
+        "/Game/Props/Rock",
+        "StaticMesh",
+        &[("Tier", "3")],
+    ));
+    assert!(replaced.is_some());
+    assert_eq!(registry.assets_data.len(), 3);
+    assert_eq!(registry.index().find_by_tag("Tier", Some("3")).len(), 1);
+
+    assert!(registry
+        .remove_asset_data("/Game/Props/Missing.Missing")
+        .is_none());
+    assert!(registry
+        .remove_asset_data("/Game/Maps/Level.Level")
+        .is_some());
+    assert!(registry
+        .index()
+        .get_package_data("/Game/Maps/Level")
+        .is_some());
+
+    let removed = registry.remove_package("/Game/Props/Rock");
+    assert_eq!(removed.len(), 1);
+
+    let registry = reread(&registry)?;
+    let index = registry.index();
+    assert!(index.get_package_data("/Game/Props/Rock").is_none());
+    assert!(index.get_depends_node("/Game/Props/Rock").is_none());
+    assert_eq!(registry.depends_nodes.len(), 2);
+    assert_eq!(
+        package_names(&index.get_dependencies("/Game/Maps/Level", DependencyType::Hard)),
+        vec!["/Game/Props/Tree"]
+    );
+    assert_eq!(
+        package_names(&index.get_referencers("/Game/Props/Tree")),
+        vec!["/Game/Maps/Level"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn merge() -> Result<(), Error> {
+    let mut registry = reread(&base_registry())?;
+
+    // a mod that replaces the rock and adds a crate referencing the tree
+    let mut patch = new_registry();
+    patch.add_asset_data(new_asset(
+        "/Game/Props/Rock",
+        "StaticMesh",
+        &[("Tier", "5")],
+    ));
+    patch.add_asset_data(new_asset("/Game/Mods/Crate", "StaticMesh", &[]));
+    let nodes = [
+        new_node(&mut patch, 0, "/Game/Mods/Crate", &[1], &[]),
+        new_node(&mut patch, 1, "/Game/Props/Tree", &[], &[0]),
+    ];
+    patch.depends_nodes.extend(nodes);
+    let patch = reread(&patch)?;
+
+    registry.merge(patch)?;
+    let registry = reread(&registry)?;
+    let index = registry.index();
+
+    assert_eq!(registry.assets_data.len(), 4);
+    assert_eq!(registry.package_data.len(), 4);
+    assert_eq!(
+        object_paths(&index.find_by_tag("Tier", Some("5"))),
+        vec!["/Game/Props/Rock.Rock"]
+    );
+
+    assert_eq!(registry.depends_nodes.len(), 4);
+    assert_eq!(
+        package_names(&index.get_dependencies("/Game/Mods/Crate", DependencyType::Hard)),
+        vec!["/Game/Props/Tree"]
+    );
+    assert_eq!(
+        package_names(&index.get_referencers("/Game/Props/Tree")),
+        vec!["/Game/Maps/Level", "/Game/Mods/Crate"]
+    );
+
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_25);
+    let other_version = AssetRegistryState::new_empty(
+        FAssetRegistryVersionType::AddedHardManage,
+        object_version,
+        object_version_ue5,
+    );
+    assert!(base_registry().merge(other_version).is_err());
+
+    Ok(())
+}
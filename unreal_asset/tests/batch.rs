@@ -0,0 +1,279 @@
+use std::fs;
+use std::io::Cursor;
+use std::num::NonZeroUsize;
+
+use unreal_asset::{
+    batch::{self, BatchInput, BatchOptions},
+    engine_version::EngineVersion,
+    exports::Export,
+    types::PackageIndex,
+    Asset, Error,
+};
+use unreal_pak::{pakversion::PakVersion, PakReader, PakWriter};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const ASTRONEER_ASSETS: [(&str, &[u8]); 5] = [
+    (
+        "Augment_BroadBrush.uasset",
+        include_bytes!(concat!(
+            assets_folder!(),
+            "Astroneer_prebulk/Augment_BroadBrush.uasset"
+        )),
+    ),
+    (
+        "DebugMenu.uasset",
+        include_bytes!(concat!(
+            assets_folder!(),
+            "Astroneer_prebulk/DebugMenu.uasset"
+        )),
+    ),
+    (
+        "LargeResourceCanister_IT.uasset",
+        include_bytes!(concat!(
+            assets_folder!(),
+            "Astroneer_prebulk/LargeResourceCanister_IT.uasset"
+        )),
+    ),
+    (
+        "ResourceProgressCurve.uasset",
+        include_bytes!(concat!(
+            assets_folder!(),
+            "Astroneer_prebulk/ResourceProgressCurve.uasset"
+        )),
+    ),
+    (
+        "Staging_T2.umap",
+        include_bytes!(concat!(
+            assets_folder!(),
+            "Astroneer_prebulk/Staging_T2.umap"
+        )),
+    ),
+];
+
+const PSEUDOREGALIA_ASSETS: [(&str, &[u8]); 4] = [
+    (
+        "Game/Maps/Zone_Caves.umap",
+        include_bytes!(concat!(assets_folder!(), "pseudoregalia/Zone_Caves.umap")),
+    ),
+    (
+        "Game/Maps/Zone_Caves.uexp",
+        include_bytes!(concat!(assets_folder!(), "pseudoregalia/Zone_Caves.uexp")),
+    ),
+    (
+        "Game/BP_looseWeapon.uasset",
+        include_bytes!(concat!(
+            assets_folder!(),
+            "pseudoregalia/BP_looseWeapon.uasset"
+        )),
+    ),
+    (
+        "Game/BP_looseWeapon.uexp",
+        include_bytes!(concat!(
+            assets_folder!(),
+            "pseudoregalia/BP_looseWeapon.uexp"
+        )),
+    ),
+];
+
+fn export_count(data: &[u8], bulk: Option<&[u8]>, engine_version: EngineVersion) -> usize {
+    Asset::new(
+        Cursor::new(data),
+        bulk.map(Cursor::new),
+        engine_version,
+        None,
+    )
+    .expect("asset parses")
+    .asset_data
+    .exports
+    .len()
+}
+
+#[test]
+fn parse_directory() -> Result<(), Error> {
+    let directory = std::env::temp_dir().join(format!("unreal_asset_batch_{}", std::process::id()));
+    let nested = directory.join("Nested");
+    fs::create_dir_all(&nested)?;
+
+    for (name, data) in ASTRONEER_ASSETS {
+        fs::write(nested.join(name), data)?;
+    }
+    fs::write(directory.join("Broken.uasset"), [0u8; 16])?;
+    fs::write(directory.join("Readme.txt"), "not an asset")?;
+
+    let options =
+        BatchOptions::new(EngineVersion::VER_UE4_23).with_threads(NonZeroUsize::new(3).unwrap());
+    let results = batch::parse_directory(&directory, &options, |_, asset| {
+        Ok(asset.asset_data.exports.len())
+    });
+    fs::remove_dir_all(&directory)?;
+    let results = results?;
+
+    // results are sorted by path, so the broken asset comes first
+    assert_eq!(results.len(), ASTRONEER_ASSETS.len() + 1);
+    assert!(results[0].path.ends_with("Broken.uasset"));
+    assert!(results[0].result.is_err());
+
+    for ((name, data), result) in ASTRONEER_ASSETS.into_iter().zip(&results[1..]) {
+        assert!(result.path.ends_with(name));
+        assert_eq!(
+            *result.result.as_ref().unwrap(),
+            export_count(data, None, EngineVersion::VER_UE4_23)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_pak() -> Result<(), Error> {
+    let mut pak = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut pak, PakVersion::FnameBasedCompressionMethod);
+    for (name, data) in PSEUDOREGALIA_ASSETS {
+        writer
+            .write_entry(&name.to_string(), &data.to_vec(), true)
+            .unwrap();
+    }
+    writer.finish_write().unwrap();
+
+    pak.set_position(0);
+    let mut reader = PakReader::new(pak);
+    reader.load_index().unwrap();
+
+    let names = reader
+        .get_entry_names()
+        .into_iter()
+        .filter(|e| !e.ends_with(".uexp"))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    // entries are read from the pak while the batch is running
+    let inputs = names.into_iter().map(move |path| {
+        let asset = reader.read_entry(&path).unwrap();
+        let bulk_name = format!("{}.uexp", path.rsplit_once('.').unwrap().0);
+        let bulk = reader.read_entry(&bulk_name).ok();
+        BatchInput::Memory { path, asset, bulk }
+    });
+
+    let options = BatchOptions::new(EngineVersion::VER_UE5_1);
+    let results = batch::parse_batch(inputs, &options, |path, asset| {
+        Ok((path.to_string(), asset.asset_data.exports.len()))
+    });
+
+    assert_eq!(results.len(), 2);
+    for result in results {
+        let (path, count) = result.result?;
+        assert_eq!(path, result.path);
+
+        let (_, data) = PSEUDOREGALIA_ASSETS
+            .into_iter()
+            .find(|(name, _)| *name == path)
+            .unwrap();
+        let bulk_path = format!("{}.uexp", path.rsplit_once('.').unwrap().0);
+        let (_, bulk) = PSEUDOREGALIA_ASSETS
+            .into_iter()
+            .find(|(name, _)| *name == bulk_path)
+            .unwrap();
+        assert_eq!(
+            count,
+            export_count(data, Some(bulk), EngineVersion::VER_UE5_1)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_pak_file() -> Result<(), Error> {
+    let path = std::env::temp_dir().join(format!("unreal_asset_batch_{}.pak", std::process::id()));
+    let mut writer = PakWriter::new(
+        fs::File::create(&path)?,
+        PakVersion::FnameBasedCompressionMethod,
+    );
+    for (name, data) in PSEUDOREGALIA_ASSETS {
+        writer
+            .write_entry(&name.to_string(), &data.to_vec(), true)
+            .unwrap();
+    }
+    writer.finish_write().unwrap();
+
+    let options =
+        BatchOptions::new(EngineVersion::VER_UE5_1).with_threads(NonZeroUsize::new(2).unwrap());
+    let results = batch::parse_pak(&path, &options, |_, asset| {
+        Ok(asset.asset_data.exports.len())
+    });
+    fs::remove_file(&path)?;
+    let results = results?;
+
+    // bulk data entries are picked up next to their assets
+    assert_eq!(results.len(), 2);
+    for (result, name) in results
+        .iter()
+        .zip(["Game/BP_looseWeapon.uasset", "Game/Maps/Zone_Caves.umap"])
+    {
+        assert_eq!(result.path, path.join(name).to_string_lossy());
+
+        let bulk_name = format!("{}.uexp", name.rsplit_once('.').unwrap().0);
+        let find = |name: &str| {
+            PSEUDOREGALIA_ASSETS
+                .into_iter()
+                .find(|(e, _)| *e == name)
+                .unwrap()
+                .1
+        };
+        assert_eq!(
+            *result.result.as_ref().unwrap(),
+            export_count(find(name), Some(find(&bulk_name)), EngineVersion::VER_UE5_1)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn class_filter() -> Result<(), Error> {
+    let inputs = ASTRONEER_ASSETS
+        .into_iter()
+        .map(|(name, data)| BatchInput::Memory {
+            path: name.to_string(),
+            asset: data.to_vec(),
+            bulk: None,
+        })
+        .chain([BatchInput::Memory {
+            path: "Broken.uasset".to_string(),
+            asset: vec![0u8; 16],
+            bulk: None,
+        }]);
+
+    let options = BatchOptions::new(EngineVersion::VER_UE4_23).with_class_filter(["Level"]);
+    let results = batch::parse_batch(inputs, &options, |_, asset| {
+        let level = asset
+            .asset_data
+            .exports
+            .iter()
+            .position(|e| matches!(e, Export::LevelExport(_)))
+            .expect("level is decoded");
+        let level = PackageIndex::from_export(level as i32)?;
+
+        // only the level is decoded
+        let loaded = (1..=asset.asset_data.exports.len() as i32)
+            .map(PackageIndex::new)
+            .filter(|e| *e != level && asset.is_export_loaded(*e))
+            .filter(|e| !matches!(asset.get_export(*e), Some(Export::ClassExport(_))))
+            .count();
+        Ok(loaded)
+    });
+
+    // assets without a level are skipped, broken assets are still reported
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].path, "Staging_T2.umap");
+    assert_eq!(*results[0].result.as_ref().unwrap(), 0);
+    assert_eq!(results[1].path, "Broken.uasset");
+    assert!(results[1].result.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,256 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        custom_property::{CustomReader, CustomSerializer, CustomSerializers, CustomWriter},
+        int_property::Int8Property,
+        Ancestry, Property,
+    },
+    types::FName,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/")
+    };
+}
+
+const MAP_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "general/Astroneer_prebulk/Staging_T2.umap"
+));
+const UNKNOWN_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "unknown_properties/BP_DetPack_Charge.uasset"
+));
+const UNKNOWN_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+/// Reads a vector as three float properties
+#[derive(Debug)]
+struct VectorSerializer;
+
+impl CustomSerializer for VectorSerializer {
+    fn read(
+        &self,
+        reader: &mut dyn CustomReader,
+        ancestry: &Ancestry,
+        _length: i64,
+    ) -> Result<Vec<Property>, Error> {
+        ["X", "Y", "Z"]
+            .into_iter()
+            .map(|e| {
+                reader.read_property(
+                    "FloatProperty",
+                    FName::new_dummy(e.to_string(), 0),
+                    ancestry.clone(),
+                )
+            })
+            .collect()
+    }
+
+    fn write(&self, writer: &mut dyn CustomWriter, value: &[Property]) -> Result<(), Error> {
+        for property in value {
+            writer.write_property(property)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a value as a list of bytes
+#[derive(Debug)]
+struct BytesSerializer;
+
+impl CustomSerializer for BytesSerializer {
+    fn read(
+        &self,
+        reader: &mut dyn CustomReader,
+        ancestry: &Ancestry,
+        length: i64,
+    ) -> Result<Vec<Property>, Error> {
+        let mut value = vec![0u8; length as usize];
+        reader.read_exact(&mut value)?;
+
+        Ok(value
+            .into_iter()
+            .enumerate()
+            .map(|(i, e)| {
+                Int8Property {
+                    name: FName::new_dummy(i.to_string(), 0),
+                    ancestry: ancestry.clone(),
+                    value: e as i8,
+                    ..Default::default()
+                }
+                .into()
+            })
+            .collect())
+    }
+
+    fn write(&self, writer: &mut dyn CustomWriter, value: &[Property]) -> Result<(), Error> {
+        for property in value {
+            writer.write_property(property)?;
+        }
+        Ok(())
+    }
+}
+
+fn custom_properties<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> Vec<&Property> {
+    let mut properties = Vec::new();
+    for export in &asset.asset_data.exports {
+        let Some(normal_export) = export.get_normal_export() else {
+            continue;
+        };
+        for property in &normal_export.properties {
+            match property {
+                Property::CustomProperty(_) => properties.push(property),
+                Property::StructProperty(struct_property) => properties.extend(
+                    struct_property
+                        .value
+                        .iter()
+                        .filter(|e| matches!(e, Property::CustomProperty(_))),
+                ),
+                _ => {}
+            }
+        }
+    }
+    properties
+}
+
+#[test]
+fn asset_serializers() -> Result<(), Error> {
+    let mut custom_serializers = CustomSerializers::new();
+    custom_serializers.register("GarbagePropty", BytesSerializer);
+
+    let mut asset = Asset::new_with_custom_serializers(
+        Cursor::new(UNKNOWN_ASSET),
+        Some(Cursor::new(UNKNOWN_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+        Arc::new(custom_serializers),
+    )?;
+    shared::verify_binary_equality(UNKNOWN_ASSET, Some(UNKNOWN_BULK), &mut asset)?;
+
+    let custom = custom_properties(&asset);
+    assert!(!custom.is_empty());
+    for property in custom {
+        let custom_property = cast!(Property, CustomProperty, property).unwrap();
+        assert_eq!(custom_property.serialized_type, "GarbagePropty");
+    }
+
+    // the other unknown type is still read as raw bytes
+    assert!(asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| &e.properties)
+        .filter_map(|e| cast!(Property, UnknownProperty, e))
+        .any(|e| e.serialized_type == "EvenMoreGarbageTestingPropertyy"));
+
+    Ok(())
+}
+
+#[test]
+fn global_serializers() -> Result<(), Error> {
+    // only registered for a different engine version
+    CustomSerializers::register_global(
+        "GlobalBiome",
+        Some(EngineVersion::VER_UE4_25),
+        BytesSerializer,
+    );
+    let asset = Asset::new(
+        Cursor::new(MAP_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(custom_properties(&asset).is_empty());
+
+    // built-in types are never read with a registered serializer
+    CustomSerializers::register_global("Vector", Some(EngineVersion::VER_UE4_23), VectorSerializer);
+    CustomSerializers::register_global(
+        "GlobalBiome",
+        Some(EngineVersion::VER_UE4_23),
+        BytesSerializer,
+    );
+    let mut asset = Asset::new(
+        Cursor::new(MAP_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let custom = custom_properties(&asset);
+    assert!(!custom.is_empty());
+    for property in custom {
+        let custom_property = cast!(Property, CustomProperty, property).unwrap();
+        assert_eq!(custom_property.serialized_type, "GlobalBiome");
+        assert!(!custom_property.value.is_empty());
+        assert!(custom_property
+            .value
+            .iter()
+            .all(|e| cast!(Property, Int8Property, e).is_some()));
+    }
+
+    shared::verify_binary_equality(MAP_ASSET, None, &mut asset)?;
+
+    CustomSerializers::clear_global();
+    Ok(())
+}
+
+#[test]
+fn missing_serializer() -> Result<(), Error> {
+    let mut custom_serializers = CustomSerializers::new();
+    custom_serializers.register("GarbagePropty", BytesSerializer);
+
+    let mut asset = Asset::new_with_custom_serializers(
+        Cursor::new(UNKNOWN_ASSET),
+        Some(Cursor::new(UNKNOWN_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+        Arc::new(custom_serializers),
+    )?;
+
+    // writing without the serializer that read the properties fails
+    asset.asset_data.custom_serializers = None;
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    assert!(asset
+        .write_data(&mut cursor, Some(&mut bulk_cursor))
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn serializer_priority() {
+    let mut custom_serializers = CustomSerializers::new();
+    custom_serializers.register("Vector", BytesSerializer);
+    custom_serializers.register_for_version("Vector", EngineVersion::VER_UE4_23, VectorSerializer);
+
+    let get = |engine_version| {
+        custom_serializers
+            .get("Vector", engine_version)
+            .map(|e| format!("{e:?}"))
+    };
+    assert_eq!(
+        get(EngineVersion::VER_UE4_23),
+        Some(String::from("VectorSerializer"))
+    );
+    assert_eq!(
+        get(EngineVersion::VER_UE4_25),
+        Some(String::from("BytesSerializer"))
+    );
+    assert!(custom_serializers
+        .get("Rotator", EngineVersion::VER_UE4_23)
+        .is_none());
+}
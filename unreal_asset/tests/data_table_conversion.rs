@@ -0,0 +1,377 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    containers::IndexedMap,
+    custom_version::CustomVersion,
+    engine_version::EngineVersion,
+    error::DataTableError,
+    exports::{data_table_export::DataTable, Export},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{Property, PropertyDataTrait},
+    types::PackageIndex,
+    unversioned::{
+        properties::{shallow_property::UsmapShallowPropertyData, EPropertyType, UsmapProperty},
+        EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion, UsmapSchema,
+    },
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const ROOM_CHECK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "BloodStained/PB_DT_RandomizerRoomCheck.uasset"
+));
+
+const ITEM_MASTER: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "BloodStained/PB_DT_ItemMaster.uasset"
+));
+
+const COSTUMES: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        assets_folder!(),
+        "StarlitSeason/CharacterCostume_chr0001_DataTable.uasset"
+    )),
+    include_bytes!(concat!(
+        assets_folder!(),
+        "StarlitSeason/CharacterCostume_chr0001_DataTable.uexp"
+    )),
+);
+
+/// Data table export of every test asset
+const TABLE: PackageIndex = PackageIndex { index: 1 };
+
+fn get_table<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> &DataTable {
+    let export = asset.get_export(TABLE).unwrap();
+    &cast!(Export, DataTableExport, export).unwrap().table
+}
+
+fn get_cell<'a>(table: &'a DataTable, row: &str, column: &str) -> Option<&'a Property> {
+    table
+        .data
+        .iter()
+        .find(|e| e.name == row)?
+        .value
+        .iter()
+        .find(|e| e.get_name() == column)
+}
+
+/// Write an asset and read it back
+fn reload<C: std::io::Read + std::io::Seek>(
+    asset: &Asset<C>,
+    has_bulk: bool,
+    engine_version: EngineVersion,
+) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    let mut data = Cursor::new(Vec::new());
+    let mut bulk = Cursor::new(Vec::new());
+    asset.write_data(&mut data, has_bulk.then_some(&mut bulk))?;
+
+    Asset::new(
+        Cursor::new(data.into_inner()),
+        has_bulk.then(|| Cursor::new(bulk.into_inner())),
+        engine_version,
+        None,
+    )
+}
+
+#[test]
+fn csv_round_trip() -> Result<(), Error> {
+    for (data, engine_version) in [
+        (ROOM_CHECK, EngineVersion::VER_UE4_18),
+        (ITEM_MASTER, EngineVersion::VER_UE4_18),
+    ] {
+        let mut asset = Asset::new(Cursor::new(data), None, engine_version, None)?;
+        let original = get_table(&asset).clone();
+
+        let csv = asset.data_table_to_csv(TABLE)?;
+        assert!(csv.starts_with("---,"));
+        assert_eq!(csv.lines().count(), original.data.len() + 1);
+
+        asset.import_data_table_csv(TABLE, &csv)?;
+        assert_eq!(get_table(&asset), &original);
+        shared::verify_binary_equality(data, None, &mut asset)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn json_round_trip() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(COSTUMES.0),
+        Some(Cursor::new(COSTUMES.1)),
+        EngineVersion::VER_UE4_24,
+        None,
+    )?;
+    let original = get_table(&asset).clone();
+
+    let json = asset.data_table_to_json(TABLE)?;
+    assert_eq!(json.as_array().unwrap().len(), original.data.len());
+    assert_eq!(json[0]["Name"], "cos001A");
+    assert_eq!(json[0]["CostumeID"], "cos001A_a");
+    assert_eq!(json[0]["AccessoryScale1"], 1.0);
+    // structs with custom serialization are written like the editor writes them
+    assert_eq!(
+        json[0]["AccessoryAttachPos6"],
+        serde_json::json!({ "X": 0.0, "Y": 2.0, "Z": 0.0 })
+    );
+
+    asset.import_data_table_json(TABLE, &json)?;
+    assert_eq!(get_table(&asset), &original);
+    shared::verify_binary_equality(COSTUMES.0, Some(COSTUMES.1), &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn edit_csv() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(COSTUMES.0),
+        Some(Cursor::new(COSTUMES.1)),
+        EngineVersion::VER_UE4_24,
+        None,
+    )?;
+
+    let csv = asset.data_table_to_csv(TABLE)?;
+    assert!(csv.contains("\"(X=0.0,Y=2.0,Z=0.0)\""));
+
+    let csv = csv.replacen("cos001A_a", "cos001A_edited", 1).replacen(
+        "\"(X=0.0,Y=2.0,Z=0.0)\"",
+        "\"(X=1.5, Y=-2, Z=3)\"",
+        1,
+    );
+    asset.import_data_table_csv(TABLE, &csv)?;
+
+    let reloaded = reload(&asset, true, EngineVersion::VER_UE4_24)?;
+    let json = reloaded.data_table_to_json(TABLE)?;
+    assert_eq!(json[0]["CostumeID"], "cos001A_edited");
+    assert_eq!(
+        json[0]["AccessoryAttachPos6"],
+        serde_json::json!({ "X": 1.5, "Y": -2.0, "Z": 3.0 })
+    );
+    assert_eq!(json[1], asset.data_table_to_json(TABLE)?[1]);
+
+    Ok(())
+}
+
+#[test]
+fn add_and_remove_rows() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(ITEM_MASTER),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    let row_count = get_table(&asset).data.len();
+
+    let csv = asset.data_table_to_csv(TABLE)?;
+    let mut lines = csv.lines().collect::<Vec<_>>();
+    let removed = lines.remove(1).split(',').next().unwrap().to_string();
+    let csv = format!(
+        "{}\nNewItem,,ECarriedCatalog::Potion,,,42,,,,True,,,,,,(),\n",
+        lines.join("\n")
+    );
+    asset.import_data_table_csv(TABLE, &csv)?;
+
+    let reloaded = reload(&asset, false, EngineVersion::VER_UE4_18)?;
+    let table = get_table(&reloaded);
+    assert_eq!(table.data.len(), row_count);
+    assert!(table.data.iter().all(|e| e.name != removed.as_str()));
+    assert_eq!(table.data.last().unwrap().name, "NewItem");
+
+    // empty cells of new rows are left out, the engine uses default values for them
+    let json = reloaded.data_table_to_json(TABLE)?;
+    let new_row = json.as_array().unwrap().last().unwrap();
+    assert_eq!(
+        new_row,
+        &serde_json::json!({
+            "Name": "NewItem",
+            "ItemType": "ECarriedCatalog::Potion",
+            "max": 42,
+            "CarryToBossRushMode": true,
+            "SECategories": [],
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_input() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(ROOM_CHECK),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    let original = get_table(&asset).clone();
+
+    let result = asset.import_data_table_csv(TABLE, "---,Nothing\nRow,maybe\n");
+    assert!(matches!(
+        result,
+        Err(Error::DataTable(DataTableError::InvalidValue(..)))
+    ));
+
+    let result = asset.import_data_table_csv(TABLE, "---,DoesNotExist\nRow,1\n");
+    assert!(matches!(
+        result,
+        Err(Error::DataTable(DataTableError::UnknownColumn(..)))
+    ));
+
+    let result = asset.import_data_table_csv(TABLE, "---,Nothing\nRow,True\nRow,False\n");
+    assert!(matches!(
+        result,
+        Err(Error::DataTable(DataTableError::DuplicateRow(..)))
+    ));
+
+    let result = asset.import_data_table_json(TABLE, &serde_json::json!([{ "Nothing": true }]));
+    assert!(matches!(
+        result,
+        Err(Error::DataTable(DataTableError::Format(..)))
+    ));
+
+    let result = asset.import_data_table_csv(TABLE, "---,NearestGate\nRow,(\"a\"\n");
+    assert!(matches!(
+        result,
+        Err(Error::DataTable(DataTableError::InvalidValue(..)))
+    ));
+
+    // failed imports leave the table unchanged
+    assert_eq!(get_table(&asset), &original);
+
+    assert!(matches!(
+        asset.data_table_to_csv(PackageIndex::new(2)),
+        Err(Error::DataTable(DataTableError::NotADataTable(2)))
+    ));
+
+    Ok(())
+}
+
+/// Mappings with a row struct that has one more property than the rows of the table
+fn row_struct_mappings(row_struct: &str, asset: &Asset<Cursor<&[u8]>>) -> Usmap {
+    let mut properties = IndexedMap::new();
+    let row = &get_table(asset).data[0];
+    let bools = row.value.iter().map(|e| e.get_name().get_owned_content());
+    for (index, name) in bools.chain(["Weight".to_string()]).enumerate() {
+        let property_type = match name.as_str() {
+            "NearestGate" => EPropertyType::ArrayProperty,
+            "Weight" => EPropertyType::IntProperty,
+            _ => EPropertyType::BoolProperty,
+        };
+        let property = UsmapProperty {
+            name: name.clone(),
+            schema_index: index as u16,
+            array_size: 1,
+            array_index: 0,
+            property_data: UsmapShallowPropertyData { property_type }.into(),
+        };
+        properties.insert((name, 0), property);
+    }
+
+    let mut schemas = IndexedMap::new();
+    schemas.insert(
+        row_struct.to_string(),
+        UsmapSchema {
+            name: row_struct.to_string(),
+            super_type: String::new(),
+            prop_count: properties.len() as u16,
+            module_path: None,
+            properties,
+        },
+    );
+
+    Usmap {
+        version: EUsmapVersion::Latest,
+        name_map: Vec::new(),
+        enum_map: IndexedMap::new(),
+        schemas,
+        extension_version: UsmapExtensionVersion::NONE,
+        object_version: ObjectVersion::UNKNOWN,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::<CustomVersion>::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
+
+#[test]
+fn row_struct_schema() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(ROOM_CHECK),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+
+    let Some(Export::DataTableExport(export)) = asset.get_export(TABLE) else {
+        panic!("First export is not a data table");
+    };
+    let row_struct = export
+        .normal_export
+        .properties
+        .iter()
+        .find_map(|e| cast!(Property, ObjectProperty, e).filter(|e| e.name == "RowStruct"))
+        .map(|e| e.value)
+        .unwrap();
+    let row_struct = asset
+        .get_import(row_struct)
+        .unwrap()
+        .object_name
+        .get_owned_content();
+    asset.asset_data.mappings = Some(row_struct_mappings(&row_struct, &asset));
+
+    // properties the rows don't have are exported with their default values
+    let csv = asset.data_table_to_csv(TABLE)?;
+    let mut lines = csv.lines();
+    assert!(lines.next().unwrap().ends_with(",Weight"));
+    assert!(lines.next().unwrap().ends_with(",0"));
+
+    let row_name = get_table(&asset).data[0].name.get_owned_content();
+    asset.import_data_table_csv(TABLE, &format!("---,Weight\n{row_name},7\n"))?;
+
+    let table = get_table(&asset);
+    assert_eq!(table.data.len(), 1);
+    let Some(Property::IntProperty(weight)) = get_cell(table, &row_name, "Weight") else {
+        panic!("Weight was not created from the row struct schema");
+    };
+    assert_eq!(weight.value, 7);
+    // existing properties of the row are kept
+    assert!(get_cell(table, &row_name, "Nothing").is_some());
+
+    Ok(())
+}
+
+#[test]
+fn array_cells() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(ROOM_CHECK),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    let row_name = get_table(&asset).data[0].name.get_owned_content();
+
+    // elements of empty arrays are created from the array type
+    let csv = format!("---,NearestGate\n{row_name},\"(\"\"m01SIP_001\"\", \"\"m02VIL_000\"\")\"\n");
+    asset.import_data_table_csv(TABLE, &csv)?;
+
+    let reloaded = reload(&asset, false, EngineVersion::VER_UE4_18)?;
+    let json = reloaded.data_table_to_json(TABLE)?;
+    assert_eq!(
+        json[0]["NearestGate"],
+        serde_json::json!(["m01SIP_001", "m02VIL_000"])
+    );
+    assert!(reloaded
+        .data_table_to_csv(TABLE)?
+        .contains(r#""(""m01SIP_001"",""m02VIL_000"")""#));
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    diff::{ExportDiff, MergeConflict, PropertyChange},
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{Property, PropertyDataTrait},
+    types::PackageIndex,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+const CDO_PATH: &str = "Default__Augment_BroadBrush_C";
+const CATALOG_DATA_PATH: &str = "Default__Augment_BroadBrush_C:ItemCatalogData_0";
+
+fn read_asset() -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )
+}
+
+fn get_property_mut<'a>(
+    asset: &'a mut Asset<Cursor<&'static [u8]>>,
+    export_index: i32,
+    name: &str,
+) -> &'a mut Property {
+    asset
+        .asset_data
+        .get_export_mut(PackageIndex::new(export_index))
+        .and_then(|e| e.get_normal_export_mut())
+        .and_then(|e| e.properties.iter_mut().find(|e| e.get_name() == name))
+        .expect("Failed to find property")
+}
+
+fn set_int(asset: &mut Asset<Cursor<&'static [u8]>>, name: &str, value: i32) {
+    let property = get_property_mut(asset, 3, name);
+    cast!(Property, IntProperty, property)
+        .expect("Not an IntProperty")
+        .value = value;
+}
+
+fn set_bool(asset: &mut Asset<Cursor<&'static [u8]>>, name: &str, value: bool) {
+    let property = get_property_mut(asset, 2, name);
+    cast!(Property, BoolProperty, property)
+        .expect("Not a BoolProperty")
+        .value = value;
+}
+
+#[test]
+fn diff() -> Result<(), Error> {
+    let base = read_asset()?;
+    let mut modified = read_asset()?;
+
+    assert!(base.diff(&modified).is_empty());
+
+    set_int(&mut modified, "CategorySequenceNumber", 42);
+    modified
+        .asset_data
+        .get_export_mut(PackageIndex::new(3))
+        .and_then(|e| e.get_normal_export_mut())
+        .expect("Failed to find ItemCatalogData")
+        .properties
+        .retain(|e| e.get_name() != "bIsBaseItem");
+
+    let diff = base.diff(&modified);
+    assert_eq!(diff.exports.len(), 1);
+    assert!(matches!(
+        &diff.exports[0],
+        ExportDiff::Changed { path, .. } if path == CATALOG_DATA_PATH
+    ));
+
+    let properties = diff
+        .get_property_diffs(CATALOG_DATA_PATH)
+        .expect("Failed to get property diffs");
+    assert_eq!(properties.len(), 2);
+    assert_eq!(properties[0].name, "CategorySequenceNumber");
+    assert!(matches!(
+        properties[0].change,
+        PropertyChange::Changed { .. }
+    ));
+    assert_eq!(properties[1].name, "bIsBaseItem");
+    assert!(matches!(properties[1].change, PropertyChange::Removed(_)));
+
+    Ok(())
+}
+
+#[test]
+fn merge() -> Result<(), Error> {
+    let base = read_asset()?;
+
+    let mut ours = read_asset()?;
+    set_bool(&mut ours, "bCanBeScrapped", false);
+    set_int(&mut ours, "VariationSequenceNumber", 7);
+
+    let mut theirs = read_asset()?;
+    set_int(&mut theirs, "CategorySequenceNumber", 42);
+    // same change on both sides isn't a conflict
+    set_int(&mut theirs, "VariationSequenceNumber", 7);
+
+    let mut merged = ours
+        .merge(&base, &theirs)
+        .expect("Merge shouldn't have conflicts");
+
+    let diff = base.diff(&merged);
+    assert_eq!(
+        diff.get_property_diffs(CDO_PATH)
+            .map(|e| e.len())
+            .unwrap_or_default(),
+        1
+    );
+    let catalog_changes = diff
+        .get_property_diffs(CATALOG_DATA_PATH)
+        .expect("Failed to get property diffs")
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        catalog_changes,
+        ["CategorySequenceNumber", "VariationSequenceNumber"]
+    );
+
+    shared::verify_reparse(&mut merged, EngineVersion::VER_UE4_23)?;
+
+    Ok(())
+}
+
+#[test]
+fn merge_conflict() -> Result<(), Error> {
+    let base = read_asset()?;
+
+    let mut ours = read_asset()?;
+    set_int(&mut ours, "CategorySequenceNumber", 1);
+
+    let mut theirs = read_asset()?;
+    set_int(&mut theirs, "CategorySequenceNumber", 2);
+
+    let conflicts = ours
+        .merge(&base, &theirs)
+        .expect_err("Merge should have conflicts");
+
+    assert_eq!(conflicts.len(), 1);
+    assert!(matches!(
+        &conflicts[0],
+        MergeConflict::Property { path, name, .. }
+            if path == CATALOG_DATA_PATH && name == "CategorySequenceNumber"
+    ));
+
+    Ok(())
+}
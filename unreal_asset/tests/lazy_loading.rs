@@ -0,0 +1,320 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{raw_export::RawExport, Export, ExportBaseTrait, ExportNormalTrait},
+    properties::{int_property::IntProperty, property_path::PropertyAccess},
+    types::{PackageIndex, PackageIndexTrait},
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const MAP_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "Astroneer_prebulk/Staging_T2.umap"
+));
+const SPLIT_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(assets_folder!(), "pseudoregalia/Zone_Caves.umap")),
+    include_bytes!(concat!(assets_folder!(), "pseudoregalia/Zone_Caves.uexp")),
+);
+const CLASS_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        assets_folder!(),
+        "pseudoregalia/BP_looseWeapon.uasset"
+    )),
+    include_bytes!(concat!(
+        assets_folder!(),
+        "pseudoregalia/BP_looseWeapon.uexp"
+    )),
+);
+
+fn read_lazy(data: &'static [u8]) -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new_lazy(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)
+}
+
+/// Write a lazily read asset and check that it's unchanged
+fn verify_lazy_binary_equality<C: std::io::Read + std::io::Seek>(
+    data: &[u8],
+    bulk: Option<&[u8]>,
+    asset: &mut Asset<C>,
+) -> Result<(), Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = bulk.map(|_| Cursor::new(Vec::new()));
+    asset.write_lazy_data(&mut cursor, bulk_cursor.as_mut())?;
+
+    assert_eq!(cursor.into_inner(), data);
+    assert_eq!(bulk_cursor.map(Cursor::into_inner).as_deref(), bulk);
+    Ok(())
+}
+
+/// Indices of the exports that haven't been loaded
+fn pending_exports<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> Vec<usize> {
+    (0..asset.asset_data.exports.len())
+        .filter(|e| !asset.is_export_loaded(PackageIndex::new(*e as i32 + 1)))
+        .collect()
+}
+
+/// Get the first actor of the asset's level
+fn first_actor<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> PackageIndex {
+    asset
+        .asset_data
+        .exports
+        .iter()
+        .find_map(|e| match e {
+            Export::LevelExport(level) => level.actors.iter().copied().find(|e| e.is_export()),
+            _ => None,
+        })
+        .expect("asset has a level with actors")
+}
+
+#[test]
+fn untouched_exports_are_written_verbatim() -> Result<(), Error> {
+    let mut asset = read_lazy(MAP_ASSET)?;
+    assert!(asset
+        .asset_data
+        .exports
+        .iter()
+        .all(|e| matches!(e, Export::RawExport(_) | Export::ClassExport(_))));
+    // export data stays in the source until the export is loaded
+    assert!(asset.asset_data.exports.iter().all(|e| match e {
+        Export::RawExport(raw) => raw.data.is_empty(),
+        _ => true,
+    }));
+    let pending = pending_exports(&asset);
+    verify_lazy_binary_equality(MAP_ASSET, None, &mut asset)?;
+    // writing doesn't load the exports
+    assert_eq!(pending_exports(&asset), pending);
+    assert!(asset.asset_data.exports.iter().all(|e| match e {
+        Export::RawExport(raw) => raw.data.is_empty(),
+        _ => true,
+    }));
+
+    // exports have to be loaded to write the asset without its source
+    assert!(asset
+        .write_data(&mut Cursor::new(Vec::new()), None)
+        .is_err());
+
+    let (data, bulk) = SPLIT_ASSET;
+    let mut asset = Asset::new_lazy(
+        Cursor::new(data),
+        Some(Cursor::new(bulk)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    verify_lazy_binary_equality(data, Some(bulk), &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn load_single_export() -> Result<(), Error> {
+    let eager = Asset::new(
+        Cursor::new(MAP_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    // the level itself has to be loaded to find its actors
+    let mut asset = read_lazy(MAP_ASSET)?;
+    let loaded_count = |asset: &Asset<_>| {
+        (1..=asset.asset_data.exports.len() as i32)
+            .filter(|e| asset.is_export_loaded(PackageIndex::new(*e)))
+            .count()
+    };
+    let initially_loaded = loaded_count(&asset);
+    let level_index = eager
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| matches!(e, Export::LevelExport(_)))
+        .expect("asset has a level");
+    let level_index = PackageIndex::from_export(level_index as i32)?;
+    assert!(!asset.is_export_loaded(level_index));
+    asset.load_export(level_index)?;
+    assert!(asset.is_export_loaded(level_index));
+
+    let actor = first_actor(&asset);
+    assert_eq!(actor, first_actor(&eager));
+
+    assert!(!asset.is_export_loaded(actor));
+    let loaded = asset.load_export(actor)?.expect("actor exists");
+    assert_eq!(Some(&*loaded), eager.get_export(actor));
+    assert!(asset.is_export_loaded(actor));
+
+    assert_eq!(loaded_count(&asset), initially_loaded + 2);
+
+    assert!(asset.load_export(PackageIndex::new(0))?.is_none());
+    assert!(asset.load_export(PackageIndex::new(-1))?.is_none());
+    assert!(asset
+        .load_export(PackageIndex::new(asset.asset_data.exports.len() as i32 + 1))?
+        .is_none());
+
+    // edit the loaded actor, everything else is written back as is
+    asset.add_fname("IntProperty");
+    asset
+        .load_export(actor)?
+        .and_then(|e| e.get_normal_export_mut())
+        .expect("actor is a normal export")
+        .set_or_create("LazyValue", 5, |_, name, ancestry, duplication_index| {
+            Some(
+                IntProperty {
+                    name,
+                    ancestry,
+                    property_guid: None,
+                    duplication_index,
+                    value: 0,
+                }
+                .into(),
+            )
+        })?;
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_lazy_data(&mut cursor, None)?;
+    cursor.set_position(0);
+    let asset = Asset::new(cursor, None, EngineVersion::VER_UE4_23, None)?;
+
+    assert!(shared::verify_all_exports_parsed(&asset));
+    assert_eq!(
+        asset
+            .get_export(actor)
+            .and_then(|e| e.get_normal_export())
+            .and_then(|e| e.get::<i32>("LazyValue")),
+        Some(5)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn load_all_exports() -> Result<(), Error> {
+    let eager = Asset::new(
+        Cursor::new(MAP_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let mut asset = read_lazy(MAP_ASSET)?;
+    asset.load_all_exports()?;
+    assert_eq!(asset.asset_data.exports, eager.asset_data.exports);
+
+    shared::verify_binary_equality(MAP_ASSET, None, &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn class_exports_are_loaded_up_front() -> Result<(), Error> {
+    let (data, bulk) = CLASS_ASSET;
+    let mut asset = Asset::new_lazy(
+        Cursor::new(data),
+        Some(Cursor::new(bulk)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let class_index = PackageIndex::new(1);
+    assert!(matches!(
+        asset.get_export(class_index),
+        Some(Export::ClassExport(_))
+    ));
+    assert!(asset.is_export_loaded(class_index));
+    assert!(!asset.is_export_loaded(PackageIndex::new(5)));
+
+    // every class other exports are instances of is loaded, whatever its name
+    for export in &asset.asset_data.exports {
+        let class = export.get_base_export().class_index;
+        if class.is_export() {
+            assert!(asset.is_export_loaded(class));
+        }
+    }
+
+    verify_lazy_binary_equality(data, Some(bulk), &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn pending_state_follows_the_export() -> Result<(), Error> {
+    let eager = Asset::new(
+        Cursor::new(MAP_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let mut asset = read_lazy(MAP_ASSET)?;
+    let pending = pending_exports(&asset);
+    let (first, second, replaced) = (pending[0], pending[1], pending[2]);
+
+    // reordered exports are loaded from their own data
+    asset.asset_data.exports.swap(first, second);
+    asset.load_all_exports()?;
+    assert_eq!(
+        asset.asset_data.exports[first],
+        eager.asset_data.exports[second]
+    );
+    assert_eq!(
+        asset.asset_data.exports[second],
+        eager.asset_data.exports[first]
+    );
+
+    // replaced exports aren't loaded or written from the source
+    let mut asset = read_lazy(MAP_ASSET)?;
+    let base_export = asset.asset_data.exports[replaced].get_base_export().clone();
+    asset.asset_data.exports[replaced] = RawExport {
+        base_export,
+        data: b"replaced export".to_vec(),
+        pending: false,
+    }
+    .into();
+    let replacement = asset.asset_data.exports[replaced].clone();
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_lazy_data(&mut cursor, None)?;
+    assert!(cursor
+        .into_inner()
+        .windows(b"replaced export".len())
+        .any(|e| e == b"replaced export"));
+
+    let index = PackageIndex::from_export(replaced as i32)?;
+    assert!(asset.is_export_loaded(index));
+    assert_eq!(asset.load_export(index)?.cloned(), Some(replacement));
+
+    Ok(())
+}
+
+#[test]
+fn failed_load_keeps_export_pending() -> Result<(), Error> {
+    let eager = Asset::new(
+        Cursor::new(MAP_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let (last, offset) = eager
+        .asset_data
+        .exports
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (i, e.get_base_export().serial_offset as usize))
+        .max_by_key(|(_, offset)| *offset)
+        .expect("asset has exports");
+    let last = PackageIndex::from_export(last as i32)?;
+
+    // cut the asset off in the middle of its last export
+    let truncated: &'static [u8] = MAP_ASSET[..offset + 1].to_vec().leak();
+    let mut asset = read_lazy(truncated)?;
+    assert!(!asset.is_export_loaded(last));
+    assert!(asset.load_export(last).is_err());
+    assert!(!asset.is_export_loaded(last));
+
+    Ok(())
+}
@@ -0,0 +1,376 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    containers::IndexedMap,
+    engine_version::EngineVersion,
+    error::LocalizationError,
+    exports::{string_table_export::StringTableExport, Export},
+    localization::{LocMeta, LocMetaVersion, LocRes, LocResEntry, LocResVersion},
+    types::PackageIndex,
+    Asset, Error,
+};
+
+const ROOM_CHECK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/BloodStained/PB_DT_RandomizerRoomCheck.uasset"
+));
+
+const VERSIONS: [LocResVersion; 4] = [
+    LocResVersion::Legacy,
+    LocResVersion::Compact,
+    LocResVersion::OptimizedCrc32,
+    LocResVersion::OptimizedCityHash64Utf16,
+];
+
+fn push_string(data: &mut Vec<u8>, string: &str) {
+    data.extend_from_slice(&(string.len() as i32 + 1).to_le_bytes());
+    data.extend_from_slice(string.as_bytes());
+    data.push(0);
+}
+
+fn native() -> LocRes {
+    let mut locres = LocRes::new(LocResVersion::OptimizedCityHash64Utf16);
+    locres.insert(
+        "Game",
+        "Greeting",
+        LocResEntry::new("Hello", "Hello".to_string()),
+    );
+    locres.insert(
+        "Game",
+        "Farewell",
+        LocResEntry::new("Bye", "Bye".to_string()),
+    );
+    locres.insert(
+        "",
+        "Quote",
+        LocResEntry::new("\"Hi\",\n", "\"Hi\",\n".to_string()),
+    );
+    locres
+}
+
+fn translated() -> LocRes {
+    let mut locres = LocRes::new(LocResVersion::OptimizedCityHash64Utf16);
+    locres.insert(
+        "Game",
+        "Greeting",
+        LocResEntry::new("Hello", "Grüß dich".to_string()),
+    );
+    locres.insert("Game", "Yes", LocResEntry::new("Yes", "Ja".to_string()));
+    locres
+}
+
+fn write(locres: &LocRes) -> Result<Vec<u8>, Error> {
+    let mut data = Cursor::new(Vec::new());
+    locres.write(&mut data)?;
+    Ok(data.into_inner())
+}
+
+#[test]
+fn legacy_locres() -> Result<(), Error> {
+    let mut data = Vec::new();
+    data.extend_from_slice(&1u32.to_le_bytes());
+    push_string(&mut data, "Game");
+    data.extend_from_slice(&2u32.to_le_bytes());
+    push_string(&mut data, "Greeting");
+    data.extend_from_slice(&0x1234u32.to_le_bytes());
+    push_string(&mut data, "Hallo");
+    push_string(&mut data, "Empty");
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&0i32.to_le_bytes());
+
+    let locres = LocRes::read(&mut Cursor::new(&data))?;
+    assert_eq!(locres.version, LocResVersion::Legacy);
+    assert_eq!(
+        locres.get("Game", "Greeting"),
+        Some(&LocResEntry {
+            source_hash: 0x1234,
+            value: "Hallo".to_string()
+        })
+    );
+    assert_eq!(locres.get("Game", "Empty").unwrap().value, "");
+
+    assert_eq!(write(&locres)?, data);
+    Ok(())
+}
+
+#[test]
+fn locres_round_trip() -> Result<(), Error> {
+    let mut locres = native();
+    locres.insert(
+        "Game",
+        "Unicode",
+        LocResEntry::new("Yes", "はい".to_string()),
+    );
+    // shares its string with Greeting
+    locres.insert(
+        "Menu",
+        "Title",
+        LocResEntry::new("Hello", "Hello".to_string()),
+    );
+
+    let mut sizes = Vec::new();
+    for version in VERSIONS {
+        locres.version = version;
+        let data = write(&locres)?;
+        assert_eq!(LocRes::read(&mut Cursor::new(&data))?, locres);
+        sizes.push(data.len());
+    }
+
+    // identical strings are stored once from the compact version on
+    let mut distinct = locres.clone();
+    distinct.insert("Menu", "Title", LocResEntry::new("Hello", "Hi".to_string()));
+    distinct.version = LocResVersion::Compact;
+    assert_eq!(write(&distinct)?.len(), sizes[1] + 4 + "Hi\0".len());
+
+    Ok(())
+}
+
+#[test]
+fn locres_hashes() -> Result<(), Error> {
+    let mut locres = LocRes::new(LocResVersion::OptimizedCrc32);
+    locres.insert(
+        "Game",
+        "Greeting",
+        LocResEntry::new("", "Hallo".to_string()),
+    );
+    let data = write(&locres)?;
+
+    // magic, version, string array offset, entry count, namespace count
+    let namespace_hash = u32::from_le_bytes(data[33..37].try_into().unwrap());
+    assert_eq!(namespace_hash, 0xcbdd_d7e6);
+    // namespace, key count
+    let key_hash = u32::from_le_bytes(data[50..54].try_into().unwrap());
+    assert_eq!(key_hash, 0x7e95_3817);
+    assert_eq!(LocResEntry::new("", String::new()).source_hash, 0);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_locres() -> Result<(), Error> {
+    let mut data = write(&native())?;
+
+    let mut unknown_version = data.clone();
+    unknown_version[16] = 4;
+    assert!(matches!(
+        LocRes::read(&mut Cursor::new(&unknown_version)),
+        Err(Error::Localization(LocalizationError::UnknownVersion(_, 4)))
+    ));
+
+    // first string index of the first entry
+    let index_position = data.len()
+        - data
+            .windows(9)
+            .rev()
+            .position(|e| e == b"Greeting\0")
+            .unwrap()
+        + 4;
+    data[index_position..index_position + 4].copy_from_slice(&7i32.to_le_bytes());
+    assert!(matches!(
+        LocRes::read(&mut Cursor::new(&data)),
+        Err(Error::Localization(LocalizationError::InvalidStringIndex(
+            7,
+            _
+        )))
+    ));
+
+    assert!(LocRes::read(&mut Cursor::new(&data[..40])).is_err());
+    Ok(())
+}
+
+#[test]
+fn locmeta() -> Result<(), Error> {
+    let mut locmeta = LocMeta {
+        version: LocMetaVersion::AddedCompiledCultures,
+        native_culture: "en".to_string(),
+        native_locres: "en/Game.locres".to_string(),
+        compiled_cultures: vec!["en".to_string(), "de".to_string()],
+    };
+
+    let mut data = Vec::new();
+    locmeta.write(&mut data)?;
+    assert_eq!(LocMeta::read(&mut Cursor::new(&data))?, locmeta);
+
+    locmeta.version = LocMetaVersion::Initial;
+    locmeta.compiled_cultures.clear();
+    let mut initial = Vec::new();
+    locmeta.write(&mut initial)?;
+    assert_eq!(initial.len(), data.len() - 4 - 7 - 7);
+    assert_eq!(LocMeta::read(&mut Cursor::new(&initial))?, locmeta);
+
+    data[0] = 0;
+    assert!(matches!(
+        LocMeta::read(&mut Cursor::new(&data)),
+        Err(Error::InvalidFile(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn locres_csv() -> Result<(), Error> {
+    let native = native();
+    let translated = translated();
+
+    let csv = translated.to_csv(Some(&native))?;
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("Namespace,Key,SourceString,LocalizedString")
+    );
+    assert_eq!(lines.next(), Some("Game,Greeting,Hello,Grüß dich"));
+    assert_eq!(lines.next(), Some("Game,Farewell,Bye,"));
+    assert!(csv.ends_with("Game,Yes,,Ja\n"));
+
+    let mut imported = LocRes::new(LocResVersion::OptimizedCityHash64Utf16);
+    imported.import_csv(&csv)?;
+    assert_eq!(
+        imported.get("Game", "Greeting"),
+        translated.get("Game", "Greeting")
+    );
+    assert_eq!(imported.get("Game", "Farewell"), None);
+
+    let mut locres = translated.clone();
+    locres.import_csv(&csv.replace("Bye,", "Bye,Tschüss"))?;
+    assert_eq!(
+        locres.get("Game", "Farewell"),
+        Some(&LocResEntry::new("Bye", "Tschüss".to_string()))
+    );
+    assert_eq!(locres.get("Game", "Yes"), translated.get("Game", "Yes"));
+
+    assert!(matches!(
+        locres.import_csv("Namespace,Key,LocalizedString\nGame,Yes,Jo\n"),
+        Err(Error::Localization(LocalizationError::Format(_)))
+    ));
+    Ok(())
+}
+
+#[test]
+fn locres_po() -> Result<(), Error> {
+    let native = native();
+    let translated = translated();
+
+    let po = translated.to_po(Some(&native));
+    assert!(po.contains("msgctxt \"Game,Greeting\"\nmsgid \"Hello\"\nmsgstr \"Grüß dich\"\n"));
+    assert!(po.contains("msgctxt \",Quote\"\nmsgid \"\\\"Hi\\\",\\n\"\nmsgstr \"\"\n"));
+
+    let mut locres = translated.clone();
+    locres.import_po(&po)?;
+    assert_eq!(locres, translated);
+
+    // keys that only exist in the translated culture have no source string
+    assert!(po.contains("msgctxt \"Game,Yes\"\nmsgid \"\"\nmsgstr \"Ja\"\n"));
+    let mut imported = LocRes::new(LocResVersion::OptimizedCityHash64Utf16);
+    imported.import_po(&po)?;
+    assert_eq!(
+        imported.get("Game", "Yes").map(|e| e.value.as_str()),
+        Some("Ja")
+    );
+
+    let po = po.replace(
+        "msgid \"Bye\"\nmsgstr \"\"",
+        "msgid \"Bye\"\nmsgstr \"Tsch\"\n\"üss\"",
+    );
+    locres.import_po(&po)?;
+    assert_eq!(
+        locres.get("Game", "Farewell"),
+        Some(&LocResEntry::new("Bye", "Tschüss".to_string()))
+    );
+
+    let mut escaped = LocRes::new(LocResVersion::Compact);
+    escaped.insert("A,B", "C", LocResEntry::new("E\t\\", "E\t\\".to_string()));
+    escaped.insert("A", "Two\nLines", LocResEntry::new("F", "F".to_string()));
+    let po = escaped.to_po(None);
+    assert!(po.contains("#. Key:\tTwo\\nLines\n"));
+    let mut imported = LocRes::new(LocResVersion::Compact);
+    imported.import_po(&po)?;
+    assert_eq!(imported, escaped);
+
+    for invalid in [
+        "msgid \"Bye\"\nmsgstr \"Tschüss\"\n",
+        "msgctxt \"Game,Farewell\"\nmsgid \"Bye\nmsgstr \"\"\n",
+        "msgctxt \"Game,Farewell\"\nmsgid \"Bye\"\nmsgtxt \"\"\n",
+    ] {
+        assert!(matches!(
+            locres.import_po(invalid),
+            Err(Error::Localization(LocalizationError::Format(_)))
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn string_table() -> Result<(), Error> {
+    const TABLE: PackageIndex = PackageIndex { index: 1 };
+
+    let mut asset = Asset::new(
+        Cursor::new(ROOM_CHECK),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    assert!(matches!(
+        asset.string_table_to_csv(TABLE),
+        Err(Error::Localization(LocalizationError::NotAStringTable(1)))
+    ));
+
+    let normal_export = asset
+        .get_export(TABLE)
+        .and_then(|e| cast!(Export, DataTableExport, e))
+        .unwrap()
+        .normal_export
+        .clone();
+    *asset.get_export_mut(TABLE).unwrap() = StringTableExport {
+        normal_export,
+        namespace: Some("UI".to_string()),
+        table: IndexedMap::from([
+            ("Start".to_string(), "Start game".to_string()),
+            ("Quit".to_string(), "Quit, now".to_string()),
+        ]),
+    }
+    .into();
+    let get_table = |asset: &Asset<_>| {
+        cast!(Export, StringTableExport, asset.get_export(TABLE).unwrap())
+            .unwrap()
+            .table
+            .clone()
+    };
+    let original = get_table(&asset);
+
+    let csv = asset.string_table_to_csv(TABLE)?;
+    assert_eq!(
+        csv,
+        "Key,SourceString\nStart,Start game\nQuit,\"Quit, now\"\n"
+    );
+    asset.import_string_table_csv(TABLE, &csv)?;
+    assert_eq!(get_table(&asset), original);
+
+    asset.import_string_table_csv(TABLE, "Comment,Key,SourceString\n,Options,Options\n")?;
+    assert_eq!(
+        get_table(&asset),
+        IndexedMap::from([("Options".to_string(), "Options".to_string())])
+    );
+    assert!(matches!(
+        asset.import_string_table_csv(TABLE, "Key,SourceString\nA,B\nA,C\n"),
+        Err(Error::Localization(LocalizationError::Format(_)))
+    ));
+
+    asset.import_string_table_csv(TABLE, &csv)?;
+    let po = asset.string_table_to_po(TABLE)?;
+    assert!(po.contains("msgctxt \"UI,Quit\"\nmsgid \"Quit, now\"\nmsgstr \"Quit, now\"\n"));
+
+    let po = po
+        .replace("msgstr \"Start game\"", "msgstr \"Spiel starten\"")
+        .replace("msgstr \"Quit, now\"", "msgstr \"\"")
+        + "\nmsgctxt \"Other,Start\"\nmsgid \"Start\"\nmsgstr \"Los\"\n";
+    asset.import_string_table_po(TABLE, &po)?;
+    assert_eq!(
+        get_table(&asset),
+        IndexedMap::from([
+            ("Start".to_string(), "Spiel starten".to_string()),
+            ("Quit".to_string(), "Quit, now".to_string()),
+        ])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,341 @@
+use std::io::Cursor;
+
+use byteorder::{WriteBytesExt, LE};
+use proptest::prelude::*;
+
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    custom_version::FAssetRegistryVersionType,
+    engine_version::{get_object_versions, EngineVersion},
+    error::KismetError,
+    flags::EPackageFlags,
+    reader::{ArchiveTrait, RawReader},
+    registry::{
+        objects::{
+            asset_bundle_data::AssetBundleData,
+            asset_data::AssetData,
+            depends_node::{AssetIdentifier, DependsNode},
+        },
+        AssetRegistryState,
+    },
+    types::{FName, PackageIndex},
+    unversioned::Usmap,
+    Asset, KismetExpression,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+/// Asset data, bulk data and engine version
+type TestAsset = (&'static [u8], Option<&'static [u8]>, EngineVersion);
+
+/// Assets to mutate, blueprints are included to cover kismet bytecode
+const ASSETS: [TestAsset; 5] = [
+    (
+        include_bytes!(concat!(
+            assets_folder!(),
+            "Astroneer_prebulk/Augment_BroadBrush.uasset"
+        )),
+        None,
+        EngineVersion::VER_UE4_23,
+    ),
+    (
+        include_bytes!(concat!(
+            assets_folder!(),
+            "Astroneer_prebulk/ResourceProgressCurve.uasset"
+        )),
+        None,
+        EngineVersion::VER_UE4_23,
+    ),
+    (
+        include_bytes!(concat!(
+            assets_folder!(),
+            "Tekken/BP_TekkenPlayer_Modular.uasset"
+        )),
+        None,
+        EngineVersion::VER_UE4_25,
+    ),
+    (
+        include_bytes!(concat!(
+            assets_folder!(),
+            "pseudoregalia/BP_looseWeapon.uasset"
+        )),
+        Some(include_bytes!(concat!(
+            assets_folder!(),
+            "pseudoregalia/BP_looseWeapon.uexp"
+        ))),
+        EngineVersion::VER_UE5_1,
+    ),
+    (
+        include_bytes!(concat!(
+            assets_folder!(),
+            "StarlitSeason/CharacterCostume_chr0001_DataTable.uasset"
+        )),
+        Some(include_bytes!(concat!(
+            assets_folder!(),
+            "StarlitSeason/CharacterCostume_chr0001_DataTable.uexp"
+        ))),
+        EngineVersion::VER_UE4_24,
+    ),
+];
+
+/// Change to apply to valid input data
+#[derive(Debug, Clone)]
+enum Mutation {
+    /// Overwrite a byte, the position is a fraction of the data length
+    Byte(f64, u8),
+    /// Overwrite four bytes with an `i32`, lengths and offsets are usually `i32`s
+    Int(f64, i32),
+    /// Cut off the data
+    Truncate(f64),
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        (0.0..1.0, any::<u8>()).prop_map(|(position, value)| Mutation::Byte(position, value)),
+        (
+            0.0..1.0,
+            prop_oneof![
+                Just(-1),
+                Just(i32::MIN),
+                Just(i32::MAX),
+                Just(0x7fff),
+                any::<i32>()
+            ]
+        )
+            .prop_map(|(position, value)| Mutation::Int(position, value)),
+        (0.0..1.0).prop_map(Mutation::Truncate),
+    ]
+}
+
+fn mutate(data: &[u8], mutations: &[Mutation]) -> Vec<u8> {
+    let mut data = data.to_vec();
+    for mutation in mutations {
+        let position = |fraction: f64| (data.len() as f64 * fraction) as usize;
+        match *mutation {
+            Mutation::Byte(fraction, value) => {
+                let position = position(fraction);
+                if let Some(byte) = data.get_mut(position) {
+                    *byte = value;
+                }
+            }
+            Mutation::Int(fraction, value) => {
+                let position = position(fraction);
+                let end = (position + 4).min(data.len());
+                let bytes = value.to_le_bytes();
+                data[position..end].copy_from_slice(&bytes[..end - position]);
+            }
+            Mutation::Truncate(fraction) => data.truncate(position(fraction)),
+        }
+    }
+    data
+}
+
+/// Build a small usmap with an enum and a struct that uses every property data type
+fn usmap_data() -> Vec<u8> {
+    let names = [
+        "Color", "Red", "Green", "Item", "Object", "Tint", "Slots", "Amounts",
+    ];
+    let name = |name: &str| names.iter().position(|e| *e == name).unwrap() as i32;
+
+    let mut data = Vec::new();
+    data.write_i32::<LE>(names.len() as i32).unwrap();
+    for e in names {
+        data.push(e.len() as u8 + 1);
+        data.extend_from_slice(e.as_bytes());
+    }
+
+    // enums
+    data.write_u32::<LE>(1).unwrap();
+    data.write_i32::<LE>(name("Color")).unwrap();
+    data.push(2);
+    data.write_i32::<LE>(name("Red")).unwrap();
+    data.write_i32::<LE>(name("Green")).unwrap();
+
+    // schemas
+    data.write_u32::<LE>(1).unwrap();
+    data.write_i32::<LE>(name("Item")).unwrap();
+    data.write_i32::<LE>(name("Object")).unwrap();
+    data.write_u16::<LE>(3).unwrap();
+    data.write_u16::<LE>(3).unwrap();
+
+    // Tint: EnumProperty<ByteProperty, Color>
+    data.write_u16::<LE>(0).unwrap();
+    data.push(1);
+    data.write_i32::<LE>(name("Tint")).unwrap();
+    data.extend_from_slice(&[26, 0]);
+    data.write_i32::<LE>(name("Color")).unwrap();
+
+    // Slots: ArrayProperty<StructProperty<Item>>
+    data.write_u16::<LE>(1).unwrap();
+    data.push(1);
+    data.write_i32::<LE>(name("Slots")).unwrap();
+    data.extend_from_slice(&[8, 9]);
+    data.write_i32::<LE>(name("Item")).unwrap();
+
+    // Amounts: MapProperty<NameProperty, IntProperty>
+    data.write_u16::<LE>(2).unwrap();
+    data.push(1);
+    data.write_i32::<LE>(name("Amounts")).unwrap();
+    data.extend_from_slice(&[24, 5, 2]);
+
+    let mut usmap = Vec::new();
+    usmap.write_u16::<LE>(0xC430).unwrap();
+    usmap.push(0);
+    usmap.push(0);
+    usmap.write_u32::<LE>(data.len() as u32).unwrap();
+    usmap.write_u32::<LE>(data.len() as u32).unwrap();
+    usmap.extend_from_slice(&data);
+    usmap
+}
+
+/// Build a registry with assets and dependencies
+fn registry_data() -> Vec<u8> {
+    let version = FAssetRegistryVersionType::AddedCookedMD5Hash;
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_25);
+    let mut registry = AssetRegistryState::new_empty(version, object_version, object_version_ue5);
+
+    for (i, name) in ["Level", "Rock"].into_iter().enumerate() {
+        registry.add_asset_data(AssetData::from_data(
+            FName::from_slice(&format!("/Game/{name}.{name}")),
+            FName::from_slice(&format!("/Game/{name}")),
+            FName::from_slice("/Game"),
+            FName::from_slice(name),
+            Some(FName::from_slice("StaticMesh")),
+            None,
+            [(FName::from_slice("Tag"), Some(name.to_string()))]
+                .into_iter()
+                .collect(),
+            AssetBundleData::default(),
+            vec![0],
+            EPackageFlags::PKG_NONE,
+            version,
+        ));
+
+        let mut identifier = AssetIdentifier {
+            package_name: Some(FName::from_slice(&format!("/Game/{name}"))),
+            ..Default::default()
+        };
+        registry.import_fnames(&mut identifier);
+
+        let mut node = DependsNode::new(i as i32, version);
+        node.identifier = identifier;
+        node.hard_dependencies = vec![DependsNode::new(1 - i as i32, version)];
+        node.referencers = vec![DependsNode::new(1 - i as i32, version)];
+        registry.depends_nodes.push(node);
+    }
+
+    let mut cursor = Cursor::new(Vec::new());
+    registry.write(&mut cursor).unwrap();
+    cursor.into_inner()
+}
+
+fn read_registry(data: Vec<u8>) -> Result<AssetRegistryState, unreal_asset::Error> {
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_25);
+    let mut reader = RawReader::new(
+        Chain::new(Cursor::new(data), None),
+        object_version,
+        object_version_ue5,
+        false,
+        NameMap::new(),
+    );
+    AssetRegistryState::new(&mut reader)
+}
+
+/// Read kismet bytecode until the end of the data or the first error
+fn read_kismet(data: Vec<u8>) -> Result<Vec<KismetExpression>, unreal_asset::Error> {
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE4_25);
+    let name_map = NameMap::from_name_batch(&["None".to_string(), "Value".to_string()]);
+    let length = data.len() as u64;
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(Cursor::new(data), None),
+        object_version,
+        object_version_ue5,
+        false,
+        name_map,
+    );
+
+    let mut expressions = Vec::new();
+    while reader.position() < length {
+        expressions.push(KismetExpression::new(&mut reader)?);
+    }
+    Ok(expressions)
+}
+
+#[test]
+fn valid_inputs() {
+    let usmap = Usmap::new(Cursor::new(usmap_data())).unwrap();
+    assert_eq!(usmap.enum_map.len(), 1);
+    assert_eq!(usmap.schemas.len(), 1);
+    assert_eq!(
+        usmap.schemas.get_by_key("Item").unwrap().properties.len(),
+        3
+    );
+    assert_eq!(read_registry(registry_data()).unwrap().assets_data.len(), 2);
+    // int constant 5 followed by `return nothing`
+    let kismet = [0x1d, 5, 0, 0, 0, 0x04, 0x0b];
+    assert_eq!(read_kismet(kismet.to_vec()).unwrap().len(), 2);
+}
+
+#[test]
+fn nested_kismet() {
+    // `return` chains nest one expression per byte
+    let nested = |depth: usize| {
+        let mut data = vec![0x04; depth - 1];
+        data.push(0x0b);
+        data
+    };
+
+    // unoptimized builds need a lot of stack per nesting level
+    let reader = std::thread::Builder::new()
+        .stack_size(64 * 1024 * 1024)
+        .spawn(move || {
+            assert_eq!(read_kismet(nested(256)).unwrap().len(), 1);
+            assert!(matches!(
+                read_kismet(nested(100_000)),
+                Err(unreal_asset::Error::Kismet(KismetError::NestingTooDeep(_)))
+            ));
+            // the depth is reset after an error
+            assert!(read_kismet(nested(256)).is_ok());
+        })
+        .unwrap();
+    reader.join().unwrap();
+}
+
+proptest! {
+    #[test]
+    fn mutated_assets(
+        asset in 0..ASSETS.len(),
+        mutations in prop::collection::vec(mutation(), 1..4),
+        mutate_bulk in any::<bool>(),
+    ) {
+        let (data, bulk, engine_version) = ASSETS[asset];
+        let (data, bulk) = match (mutate_bulk, bulk) {
+            (true, Some(bulk)) => (data.to_vec(), Some(mutate(bulk, &mutations))),
+            _ => (mutate(data, &mutations), bulk.map(|e| e.to_vec())),
+        };
+
+        let _ = Asset::new(Cursor::new(data), bulk.map(Cursor::new), engine_version, None);
+    }
+
+    #[test]
+    fn mutated_usmaps(mutations in prop::collection::vec(mutation(), 1..4)) {
+        let _ = Usmap::new(Cursor::new(mutate(&usmap_data(), &mutations)));
+    }
+
+    #[test]
+    fn mutated_registries(mutations in prop::collection::vec(mutation(), 1..4)) {
+        let _ = read_registry(mutate(&registry_data(), &mutations));
+    }
+
+    #[test]
+    fn random_bytes(data in prop::collection::vec(any::<u8>(), 0..256)) {
+        let _ = Asset::new(Cursor::new(data.clone()), None, EngineVersion::VER_UE4_25, None);
+        let _ = Usmap::new(Cursor::new(data.clone()));
+        let _ = read_registry(data.clone());
+        let _ = read_kismet(data);
+    }
+}
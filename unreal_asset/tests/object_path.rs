@@ -0,0 +1,151 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, types::PackageIndex, Asset, Error};
+
+mod shared;
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+const PACKAGE_NAME: &str = "/Game/Items/ItemTypes/Components/Augment_BroadBrush";
+
+fn read_asset() -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )
+}
+
+#[test]
+fn object_paths() -> Result<(), Error> {
+    let asset = read_asset()?;
+
+    assert_eq!(asset.get_package_name().as_deref(), Some(PACKAGE_NAME));
+
+    let catalog_data_path =
+        format!("{PACKAGE_NAME}.Default__Augment_BroadBrush_C:ItemCatalogData_0");
+    assert_eq!(
+        asset.get_object_path(PackageIndex::new(3)),
+        Some(catalog_data_path.clone())
+    );
+    assert_eq!(
+        asset.find_object_by_path(&catalog_data_path),
+        Some(PackageIndex::new(3))
+    );
+
+    for index in (1..=asset.imports.len() as i32).map(|e| PackageIndex::new(-e)) {
+        let path = asset
+            .get_object_path(index)
+            .expect("Failed to get import path");
+        assert!(path.starts_with('/'), "{path}");
+        assert_eq!(asset.find_import_by_path(&path), Some(index));
+    }
+
+    assert_eq!(asset.get_object_path(PackageIndex::new(0)), None);
+    assert_eq!(
+        asset.get_object_path(PackageIndex::new(asset.asset_data.exports.len() as i32 + 1)),
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+fn add_import_by_path() -> Result<(), Error> {
+    let mut asset = read_asset()?;
+    let import_count = asset.imports.len();
+
+    let class_path = "/Game/Test/NewObject.NewObject_C";
+    let class_type = ("/Script/Engine", "BlueprintGeneratedClass");
+    let class = asset.add_import_by_path(class_path, &[class_type])?;
+
+    // the package import is created along with the class
+    assert_eq!(asset.imports.len(), import_count + 2);
+    assert!(asset.find_import_by_path("/Game/Test/NewObject").is_some());
+    assert_eq!(asset.get_object_path(class).as_deref(), Some(class_path));
+
+    // existing imports are reused
+    assert_eq!(asset.add_import_by_path(class_path, &[class_type])?, class);
+    assert_eq!(asset.imports.len(), import_count + 2);
+
+    // classes of outers that are already imported can be left out
+    let component_path = "/Game/Test/NewObject.NewObject_C:Component_1";
+    let component =
+        asset.add_import_by_path(component_path, &[("/Script/Engine", "SceneComponent")])?;
+    assert_eq!(
+        asset.get_object_path(component).as_deref(),
+        Some(component_path)
+    );
+    assert_eq!(asset.imports.len(), import_count + 3);
+
+    // missing outers are created with their classes
+    let chain_path = "/Game/Test/Chain.Chain_C:Component";
+    let chain_component = asset.add_import_by_path(
+        chain_path,
+        &[class_type, ("/Script/Engine", "SceneComponent")],
+    )?;
+    assert_eq!(asset.imports.len(), import_count + 6);
+    assert_eq!(
+        asset.get_object_path(chain_component).as_deref(),
+        Some(chain_path)
+    );
+    let chain_class = asset
+        .find_import_by_path("/Game/Test/Chain.Chain_C")
+        .and_then(|e| asset.get_import(e))
+        .expect("outer is imported");
+    assert_eq!(
+        chain_class.class_name.get_owned_content(),
+        "BlueprintGeneratedClass"
+    );
+    assert_eq!(
+        asset.get_import(chain_component).map(|e| e.outer_index),
+        asset.find_import_by_path("/Game/Test/Chain.Chain_C")
+    );
+
+    // the package itself can be given a class
+    let world_path = "/Game/Test/World";
+    let world = asset.add_import_by_path(world_path, &[("/Script/Engine", "World")])?;
+    assert_eq!(
+        asset
+            .get_import(world)
+            .map(|e| e.class_name.get_owned_content())
+            .as_deref(),
+        Some("World")
+    );
+    assert_eq!(asset.imports.len(), import_count + 7);
+
+    // outers that aren't imported need a class
+    assert!(asset
+        .add_import_by_path(
+            "/Game/Test/Missing.Missing_C:Component",
+            &[("/Script/Engine", "SceneComponent")]
+        )
+        .is_err());
+    assert!(asset
+        .add_import_by_path(
+            "/Game/Test/Missing.Missing_C",
+            &[class_type, class_type, class_type]
+        )
+        .is_err());
+    assert!(asset
+        .add_import_by_path(
+            "/Game/Test/NewObject.",
+            &[("/Script/Engine", "SceneComponent")]
+        )
+        .is_err());
+    assert_eq!(asset.imports.len(), import_count + 7);
+
+    shared::verify_reparse(&mut asset, EngineVersion::VER_UE4_23)?;
+
+    Ok(())
+}
@@ -0,0 +1,284 @@
+use std::io::Cursor;
+use std::str::FromStr;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{normal_export::NormalExport, ExportNormalTrait},
+    properties::{
+        map_property::MapProperty,
+        property_path::{FromProperty, PathSegment, PropertyAccess, PropertyPath},
+        str_property::StrProperty,
+        struct_property::StructProperty,
+        Property, PropertyDataTrait,
+    },
+    types::{fname::FName, PackageIndex},
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Augment_BroadBrush.uasset"));
+
+fn read_asset<C: std::io::Read + std::io::Seek>(data: C) -> Result<Asset<C>, Error> {
+    Asset::new(data, None, EngineVersion::VER_UE4_23, None)
+}
+
+fn export<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> &NormalExport<PackageIndex> {
+    asset
+        .asset_data
+        .get_export(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export())
+        .expect("export 2 is a normal export")
+}
+
+fn export_mut<C: std::io::Read + std::io::Seek>(
+    asset: &mut Asset<C>,
+) -> &mut NormalExport<PackageIndex> {
+    asset
+        .asset_data
+        .get_export_mut(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export_mut())
+        .expect("export 2 is a normal export")
+}
+
+#[test]
+fn parse_paths() -> Result<(), Error> {
+    let path = PropertyPath::from_str(r#"Items[3].Count["a.\"b\""][Gold]"#)?;
+    assert_eq!(
+        path.segments,
+        vec![
+            PathSegment::Name("Items".to_string()),
+            PathSegment::Index(3),
+            PathSegment::Name("Count".to_string()),
+            PathSegment::Key("a.\"b\"".to_string()),
+            PathSegment::Key("Gold".to_string()),
+        ]
+    );
+    assert_eq!(PropertyPath::from_str(&path.to_string())?, path);
+
+    for invalid in [
+        "", "A.", ".A", "A..B", "[0]", "A[0", "A[]", "A[0]B", "A[\"B]",
+    ] {
+        assert!(
+            PropertyPath::from_str(invalid).is_err(),
+            "{invalid} should not parse"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn get_values() -> Result<(), Error> {
+    let asset = read_asset(Cursor::new(TEST_ASSET))?;
+    let export = export(&asset);
+
+    assert_eq!(export.get::<bool>("bCanBeScrapped"), Some(true));
+    assert_eq!(
+        export.get::<PackageIndex>("CatalogData"),
+        Some(PackageIndex::new(3))
+    );
+    assert_eq!(
+        export.get::<f32>("ConstructionRecipe.Ingredients[0].Count"),
+        Some(1.0)
+    );
+    assert_eq!(
+        export.get::<f64>("ConstructionRecipe.Ingredients[0].Count"),
+        Some(1.0)
+    );
+
+    // wrong types and missing properties
+    assert_eq!(export.get::<i32>("bCanBeScrapped"), None);
+    assert_eq!(
+        export.get::<f32>("ConstructionRecipe.Ingredients[1].Count"),
+        None
+    );
+    assert_eq!(export.get::<f32>("ConstructionRecipe.Missing"), None);
+
+    let recipe = cast_struct(export.get_property("ConstructionRecipe"));
+    assert_eq!(recipe.get::<f32>("Ingredients[0].Count"), Some(1.0));
+
+    Ok(())
+}
+
+fn cast_struct(property: Option<&Property>) -> &StructProperty {
+    match property {
+        Some(Property::StructProperty(e)) => e,
+        _ => panic!("expected a struct property"),
+    }
+}
+
+#[test]
+fn set_values() -> Result<(), Error> {
+    let mut asset = read_asset(Cursor::new(TEST_ASSET))?;
+
+    let normal_export = export_mut(&mut asset);
+    normal_export.set("bCanBeScrapped", false)?;
+    normal_export.set("ConstructionRecipe.Ingredients[0].Count", 5.0f32)?;
+    normal_export.set(
+        "ConstructionRecipe.Ingredients[0].ItemType",
+        PackageIndex::new(-4),
+    )?;
+
+    // types of missing properties aren't guessed from the value
+    assert!(normal_export.set("NewCount", 7).is_err());
+    assert!(normal_export
+        .set("ConstructionRecipe.Ingredients[0].Weight", 0.5f64)
+        .is_err());
+
+    assert!(normal_export.set("bCanBeScrapped", 1).is_err());
+    assert!(normal_export
+        .set("ConstructionRecipe.Missing.Count", 1)
+        .is_err());
+    assert!(normal_export
+        .set("ConstructionRecipe.Ingredients[2]", 1.0f32)
+        .is_err());
+    assert!(normal_export
+        .set("ConstructionRecipe.Ingredients[1]", 1.0f32)
+        .is_err());
+    assert!(normal_export.set("Items[", 1).is_err());
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    cursor.set_position(0);
+    let asset = read_asset(cursor)?;
+    let export = export(&asset);
+
+    assert_eq!(export.get::<bool>("bCanBeScrapped"), Some(false));
+    assert_eq!(
+        export.get::<f32>("ConstructionRecipe.Ingredients[0].Count"),
+        Some(5.0)
+    );
+    assert_eq!(
+        export.get::<PackageIndex>("ConstructionRecipe.Ingredients[0].ItemType"),
+        Some(PackageIndex::new(-4))
+    );
+    assert_eq!(export.get_property("NewCount"), None);
+    assert_eq!(
+        export.get_property("ConstructionRecipe.Ingredients[0].Weight"),
+        None
+    );
+
+    Ok(())
+}
+
+/// Struct with a map and the first element of a static array
+fn reward_table() -> StructProperty {
+    StructProperty {
+        name: FName::from_slice("Rewards"),
+        struct_type: Some(FName::from_slice("RewardTable")),
+        value: vec![
+            MapProperty {
+                name: FName::from_slice("Amounts"),
+                key_type: FName::from_slice("StrProperty"),
+                value_type: FName::from_slice("IntProperty"),
+                ..Default::default()
+            }
+            .into(),
+            StrProperty {
+                name: FName::from_slice("Slots"),
+                ancestry: Default::default(),
+                property_guid: None,
+                duplication_index: 0,
+                value: None,
+            }
+            .into(),
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn maps_and_static_arrays() -> Result<(), Error> {
+    let mut property = reward_table();
+
+    property.set("Amounts[Gold]", 5)?;
+    property.set(r#"Amounts["Key.With.Dots"]"#, 2)?;
+    property.set("Amounts[Gold]", 6)?;
+    assert!(property.set("Amounts[Silver]", 1.0f32).is_err());
+
+    assert_eq!(property.get::<i32>("Amounts[Gold]"), Some(6));
+    assert_eq!(property.get::<i64>(r#"Amounts["Key.With.Dots"]"#), Some(2));
+    assert_eq!(property.get::<i32>("Amounts[Silver]"), None);
+
+    // indexing a non-container property selects a static array element,
+    // missing elements get the type of the existing ones
+    property.set("Slots[0]", "First")?;
+    property.set("Slots[1]", "Second")?;
+    assert!(property.set("Slots[2]", 3).is_err());
+    assert!(property.set("Missing[1]", "Second").is_err());
+    assert_eq!(
+        property.get::<String>("Slots[1]"),
+        Some("Second".to_string())
+    );
+    assert_eq!(property.get::<String>("Slots"), Some("First".to_string()));
+    assert_eq!(
+        property
+            .get_property("Slots[1]")
+            .map(|e| e.get_duplication_index()),
+        Some(1)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn find_properties() -> Result<(), Error> {
+    let mut property = reward_table();
+    property.set("Amounts[Gold]", 5)?;
+    property.set(r#"Amounts["Key.With.Dots"]"#, 2)?;
+    property.set("Slots[0]", "First")?;
+    property.set("Slots[2]", "Third")?;
+
+    let path = PropertyPath::from_str("Amounts[*]")?;
+    assert_eq!(path.segments[1], PathSegment::Any);
+    assert_eq!(path.to_string(), "Amounts[*]");
+
+    let found = property
+        .find_properties(&path)
+        .into_iter()
+        .map(|(path, value)| (path.to_string(), i32::from_property(value)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        vec![
+            (r#"Amounts["Gold"]"#.to_string(), Some(5)),
+            (r#"Amounts["Key.With.Dots"]"#.to_string(), Some(2)),
+        ]
+    );
+    // found paths resolve to the same properties
+    for (path, value) in found {
+        assert_eq!(property.get::<i32>(&path), value);
+    }
+
+    let found = property
+        .find_properties(&PropertyPath::from_str("Slots[*]")?)
+        .into_iter()
+        .map(|(path, _)| path.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(found, vec!["Slots[0]", "Slots[2]"]);
+    assert_eq!(property.get::<String>(&found[1]), Some("Third".to_string()));
+
+    assert_eq!(
+        property
+            .find_properties(&PropertyPath::from_str("Slots[2]")?)
+            .len(),
+        1
+    );
+    assert!(property
+        .find_properties(&PropertyPath::from_str("Missing[*]")?)
+        .is_empty());
+
+    // wildcards can't be used to get or set values
+    assert_eq!(property.get::<i32>("Amounts[*]"), None);
+    assert!(property.set("Amounts[*]", 1).is_err());
+
+    Ok(())
+}
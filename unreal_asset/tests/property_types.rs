@@ -0,0 +1,484 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        array_property::ArrayProperty,
+        field_path_property::FieldPathProperty,
+        instanced_struct_property::InstancedStructProperty,
+        movies::{
+            frame_number_property::FrameNumberProperty,
+            movie_scene_double_channel_property::{
+                MovieSceneDoubleChannel, MovieSceneDoubleChannelProperty, MovieSceneDoubleValue,
+            },
+        },
+        nav_agent_selector_property::NavAgentSelectorProperty,
+        object_property::{InterfaceProperty, LazyObjectProperty},
+        per_quality_level_property::{PerQualityLevelFloatProperty, PerQualityLevelIntProperty},
+        str_property::{AnsiStrProperty, Utf8StrProperty},
+        struct_property::StructProperty,
+        vector_property::{IntVector2Property, IntVectorProperty, VectorProperty},
+        Property, PropertyDataTrait,
+    },
+    types::{
+        fname::{FName, ToSerializedName},
+        movie::{FrameNumber, FrameRate},
+        vector::{Vector, Vector2},
+        PackageIndex,
+    },
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Augment_BroadBrush.uasset"));
+
+fn read_asset<C: std::io::Read + std::io::Seek>(data: C) -> Result<Asset<C>, Error> {
+    Asset::new(data, None, EngineVersion::VER_UE4_23, None)
+}
+
+fn reparse<C: std::io::Read + std::io::Seek>(
+    asset: &mut Asset<C>,
+) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    cursor.set_position(0);
+    read_asset(cursor)
+}
+
+fn find_property<'a, C: std::io::Read + std::io::Seek>(
+    asset: &'a Asset<C>,
+    name: &str,
+) -> Option<&'a Property> {
+    asset
+        .asset_data
+        .get_export(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export())
+        .and_then(|e| e.properties.iter().find(|e| e.get_name() == name))
+}
+
+fn push_properties<C: std::io::Read + std::io::Seek>(
+    asset: &mut Asset<C>,
+    properties: Vec<Property>,
+) {
+    // the name map is written before the exports, so type names have to be added up front
+    for property in &properties {
+        asset.add_fname(&property.to_serialized_name());
+    }
+    asset
+        .asset_data
+        .get_export_mut(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export_mut())
+        .expect("No normal export")
+        .properties
+        .extend(properties);
+}
+
+#[test]
+fn new_property_types() -> Result<(), Error> {
+    let mut asset = read_asset(Cursor::new(TEST_ASSET))?;
+
+    let guid = [0x11u8; 16];
+    let properties = vec![
+        Utf8StrProperty {
+            name: asset.add_fname("TestUtf8"),
+            ancestry: Default::default(),
+            property_guid: None,
+            duplication_index: 0,
+            value: Some(String::from("ünïcödé ✓")),
+        }
+        .into(),
+        AnsiStrProperty {
+            name: asset.add_fname("TestAnsi"),
+            ancestry: Default::default(),
+            property_guid: None,
+            duplication_index: 0,
+            value: Some(String::from("Latin-1 ÿ")),
+        }
+        .into(),
+        AnsiStrProperty {
+            name: asset.add_fname("TestAnsiNone"),
+            ancestry: Default::default(),
+            property_guid: None,
+            duplication_index: 0,
+            value: None,
+        }
+        .into(),
+        LazyObjectProperty {
+            name: asset.add_fname("TestLazyObject"),
+            ancestry: Default::default(),
+            property_guid: None,
+            duplication_index: 0,
+            value: guid.into(),
+        }
+        .into(),
+        InterfaceProperty {
+            name: asset.add_fname("TestInterface"),
+            ancestry: Default::default(),
+            property_guid: None,
+            duplication_index: 0,
+            value: PackageIndex::new(-1),
+        }
+        .into(),
+        FieldPathProperty {
+            name: asset.add_fname("TestFieldPath"),
+            ancestry: Default::default(),
+            property_guid: None,
+            duplication_index: 0,
+            path: vec![asset.add_fname("SomeField"), asset.add_fname("SomeStruct")],
+            resolved_owner: PackageIndex::new(0),
+        }
+        .into(),
+    ];
+    push_properties(&mut asset, properties);
+
+    let asset = reparse(&mut asset)?;
+
+    let utf8 = find_property(&asset, "TestUtf8").and_then(|e| cast!(Property, Utf8StrProperty, e));
+    assert_eq!(utf8.and_then(|e| e.value.as_deref()), Some("ünïcödé ✓"));
+
+    let ansi = find_property(&asset, "TestAnsi").and_then(|e| cast!(Property, AnsiStrProperty, e));
+    assert_eq!(ansi.and_then(|e| e.value.as_deref()), Some("Latin-1 ÿ"));
+
+    let ansi_none =
+        find_property(&asset, "TestAnsiNone").and_then(|e| cast!(Property, AnsiStrProperty, e));
+    assert_eq!(ansi_none.map(|e| e.value.is_none()), Some(true));
+
+    let lazy_object = find_property(&asset, "TestLazyObject")
+        .and_then(|e| cast!(Property, LazyObjectProperty, e));
+    assert_eq!(lazy_object.map(|e| e.value.0), Some(guid));
+
+    let interface =
+        find_property(&asset, "TestInterface").and_then(|e| cast!(Property, InterfaceProperty, e));
+    assert_eq!(interface.map(|e| e.value), Some(PackageIndex::new(-1)));
+
+    let field_path =
+        find_property(&asset, "TestFieldPath").and_then(|e| cast!(Property, FieldPathProperty, e));
+    let path = field_path
+        .map(|e| {
+            e.path
+                .iter()
+                .map(|e| e.get_owned_content())
+                .collect::<Vec<_>>()
+        })
+        .expect("No field path");
+    assert_eq!(path, ["SomeField", "SomeStruct"]);
+
+    Ok(())
+}
+
+#[test]
+fn new_property_types_in_array() -> Result<(), Error> {
+    let mut asset = read_asset(Cursor::new(TEST_ASSET))?;
+
+    let name = asset.add_fname("TestUtf8Array");
+    let values = ["first", "second ✓", ""];
+    let array = ArrayProperty {
+        name: name.clone(),
+        ancestry: Default::default(),
+        property_guid: None,
+        duplication_index: 0,
+        array_type: Some(asset.add_fname("Utf8StrProperty")),
+        value: values
+            .iter()
+            .map(|e| {
+                Utf8StrProperty {
+                    name: name.clone(),
+                    ancestry: Default::default(),
+                    property_guid: None,
+                    duplication_index: 0,
+                    value: (!e.is_empty()).then(|| e.to_string()),
+                }
+                .into()
+            })
+            .collect(),
+        dummy_property: None,
+    };
+    push_properties(&mut asset, vec![array.into()]);
+
+    let asset = reparse(&mut asset)?;
+
+    let array = find_property(&asset, "TestUtf8Array")
+        .and_then(|e| cast!(Property, ArrayProperty, e))
+        .expect("No array property");
+    let read_values = array
+        .value
+        .iter()
+        .map(|e| {
+            cast!(Property, Utf8StrProperty, e)
+                .map(|e| e.value.clone().unwrap_or_default())
+                .expect("Not a Utf8StrProperty")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(read_values, values);
+
+    Ok(())
+}
+
+#[test]
+fn ansi_out_of_range() -> Result<(), Error> {
+    let mut asset = read_asset(Cursor::new(TEST_ASSET))?;
+
+    let property = AnsiStrProperty {
+        name: asset.add_fname("TestAnsi"),
+        ancestry: Default::default(),
+        property_guid: None,
+        duplication_index: 0,
+        value: Some(String::from("not latin-1 ✓")),
+    };
+    push_properties(&mut asset, vec![property.into()]);
+
+    let mut cursor = Cursor::new(Vec::new());
+    assert!(asset.write_data(&mut cursor, None).is_err());
+
+    Ok(())
+}
+
+/// Wrap a natively serialized struct value in a `StructProperty`
+fn struct_property<C: std::io::Read + std::io::Seek>(
+    asset: &mut Asset<C>,
+    name: &str,
+    struct_type: &str,
+    value: Property,
+) -> Property {
+    StructProperty {
+        name: asset.add_fname(name),
+        ancestry: Default::default(),
+        struct_type: Some(asset.add_fname(struct_type)),
+        struct_guid: Some([0u8; 16].into()),
+        property_guid: None,
+        duplication_index: 0,
+        serialize_none: true,
+        value: vec![value],
+    }
+    .into()
+}
+
+/// Get the single natively serialized value of a struct
+fn struct_value<'a, C: std::io::Read + std::io::Seek>(
+    asset: &'a Asset<C>,
+    name: &str,
+) -> &'a Property {
+    find_property(asset, name)
+        .and_then(|e| cast!(Property, StructProperty, e))
+        .and_then(|e| e.value.first())
+        .expect("No struct value")
+}
+
+#[test]
+fn engine_structs() -> Result<(), Error> {
+    let mut asset = read_asset(Cursor::new(TEST_ASSET))?;
+
+    let vector_struct = asset.add_import_by_path(
+        "/Script/CoreUObject.Vector",
+        &[("/Script/CoreUObject", "ScriptStruct")],
+    )?;
+
+    let double_channel = MovieSceneDoubleChannel {
+        times_struct_length: 4,
+        times: vec![FrameNumber::new(0), FrameNumber::new(30)],
+        values_struct_length: 32,
+        values: vec![
+            MovieSceneDoubleValue {
+                value: 1.5.into(),
+                ..Default::default()
+            },
+            MovieSceneDoubleValue {
+                value: (-2.25).into(),
+                ..Default::default()
+            },
+        ],
+        default_value: 0.5.into(),
+        has_default_value: true,
+        tick_resolution: FrameRate::new(60000, 1),
+        ..Default::default()
+    };
+
+    let values: Vec<(&str, &str, Property)> = vec![
+        (
+            "TestIntVector",
+            "IntVector",
+            IntVectorProperty {
+                value: Vector::new(1, -2, 3),
+                ..Default::default()
+            }
+            .into(),
+        ),
+        (
+            "TestIntVector2",
+            "IntVector2",
+            IntVector2Property {
+                value: Vector2::new(-4, 5),
+                ..Default::default()
+            }
+            .into(),
+        ),
+        (
+            "TestFrameNumber",
+            "FrameNumber",
+            FrameNumberProperty {
+                value: FrameNumber::new(1234),
+                ..Default::default()
+            }
+            .into(),
+        ),
+        (
+            "TestNavAgentSelector",
+            "NavAgentSelector",
+            NavAgentSelectorProperty {
+                packed_bits: 0b101,
+                ..Default::default()
+            }
+            .into(),
+        ),
+        (
+            "TestPerQualityLevelInt",
+            "PerQualityLevelInt",
+            PerQualityLevelIntProperty {
+                cooked: false,
+                default: 3,
+                per_quality: vec![(0, 1), (3, 7)],
+                ..Default::default()
+            }
+            .into(),
+        ),
+        (
+            "TestPerQualityLevelFloat",
+            "PerQualityLevelFloat",
+            PerQualityLevelFloatProperty {
+                cooked: true,
+                default: 0.25.into(),
+                per_quality: vec![(1, 2.5.into())],
+                ..Default::default()
+            }
+            .into(),
+        ),
+        (
+            "TestDoubleChannel",
+            "MovieSceneDoubleChannel",
+            MovieSceneDoubleChannelProperty {
+                value: double_channel.clone(),
+                ..Default::default()
+            }
+            .into(),
+        ),
+        (
+            "TestInstancedStruct",
+            "InstancedStruct",
+            InstancedStructProperty {
+                struct_type: vector_struct,
+                value: Some(StructProperty {
+                    struct_type: Some(FName::from_slice("Vector")),
+                    serialize_none: true,
+                    value: vec![VectorProperty {
+                        value: Vector::new(1.0.into(), 2.0.into(), 3.0.into()),
+                        ..Default::default()
+                    }
+                    .into()],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+            .into(),
+        ),
+    ];
+
+    let properties = values
+        .into_iter()
+        .map(|(name, struct_type, value)| struct_property(&mut asset, name, struct_type, value))
+        .collect();
+    push_properties(&mut asset, properties);
+
+    let asset = reparse(&mut asset)?;
+
+    let int_vector = cast!(
+        Property,
+        IntVectorProperty,
+        struct_value(&asset, "TestIntVector")
+    );
+    assert_eq!(int_vector.map(|e| &e.value), Some(&Vector::new(1, -2, 3)));
+
+    let int_vector2 = cast!(
+        Property,
+        IntVector2Property,
+        struct_value(&asset, "TestIntVector2")
+    );
+    assert_eq!(int_vector2.map(|e| &e.value), Some(&Vector2::new(-4, 5)));
+
+    let frame_number = cast!(
+        Property,
+        FrameNumberProperty,
+        struct_value(&asset, "TestFrameNumber")
+    );
+    assert_eq!(frame_number.map(|e| e.value), Some(FrameNumber::new(1234)));
+
+    let nav_agent_selector = cast!(
+        Property,
+        NavAgentSelectorProperty,
+        struct_value(&asset, "TestNavAgentSelector")
+    )
+    .expect("Not a NavAgentSelector");
+    assert!(nav_agent_selector.contains(0));
+    assert!(!nav_agent_selector.contains(1));
+    assert!(nav_agent_selector.contains(2));
+
+    let per_quality_int = cast!(
+        Property,
+        PerQualityLevelIntProperty,
+        struct_value(&asset, "TestPerQualityLevelInt")
+    )
+    .expect("Not a PerQualityLevelInt");
+    assert!(!per_quality_int.cooked);
+    assert_eq!(per_quality_int.default, 3);
+    assert_eq!(per_quality_int.per_quality, [(0, 1), (3, 7)]);
+
+    let per_quality_float = cast!(
+        Property,
+        PerQualityLevelFloatProperty,
+        struct_value(&asset, "TestPerQualityLevelFloat")
+    )
+    .expect("Not a PerQualityLevelFloat");
+    assert!(per_quality_float.cooked);
+    assert_eq!(per_quality_float.per_quality, [(1, 2.5.into())]);
+
+    let channel = cast!(
+        Property,
+        MovieSceneDoubleChannelProperty,
+        struct_value(&asset, "TestDoubleChannel")
+    );
+    assert_eq!(channel.map(|e| &e.value), Some(&double_channel));
+
+    let instanced_struct = cast!(
+        Property,
+        InstancedStructProperty,
+        struct_value(&asset, "TestInstancedStruct")
+    )
+    .expect("Not an InstancedStruct");
+    assert_eq!(
+        asset
+            .get_object_path(instanced_struct.struct_type)
+            .as_deref(),
+        Some("/Script/CoreUObject.Vector")
+    );
+    let vector = instanced_struct
+        .value
+        .as_ref()
+        .and_then(|e| e.value.first())
+        .and_then(|e| cast!(Property, VectorProperty, e))
+        .map(|e| &e.value);
+    assert_eq!(
+        vector,
+        Some(&Vector::new(1.0.into(), 2.0.into(), 3.0.into()))
+    );
+
+    Ok(())
+}
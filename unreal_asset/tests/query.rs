@@ -0,0 +1,189 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    batch::{BatchInput, BatchOptions},
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{property_path::PropertyAccess, Property},
+    query::{self, Query, QueryMatch},
+    types::PackageIndex,
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const MAP_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Staging_T2.umap"));
+const AUGMENT_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Augment_BroadBrush.uasset"));
+
+fn read_asset() -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new(
+        Cursor::new(MAP_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )
+}
+
+fn exports(matches: &[QueryMatch]) -> Vec<i32> {
+    matches.iter().map(|e| e.export.index).collect()
+}
+
+fn paths(matches: &[QueryMatch]) -> Vec<String> {
+    matches
+        .iter()
+        .filter_map(|e| e.property_path.as_ref())
+        .map(|e| e.to_string())
+        .collect()
+}
+
+#[test]
+fn class_and_object_name() -> Result<(), Error> {
+    let asset = read_asset()?;
+
+    let matches = asset.query(&Query::new().with_class("CameraComponent"));
+    assert_eq!(exports(&matches), vec![80, 81, 82, 83]);
+    assert!(matches.iter().all(|e| e.property_path.is_none()));
+
+    let matches = asset.query(&Query::new().with_object_name("astroplayfabevent*"));
+    assert_eq!(exports(&matches), (37..=45).collect::<Vec<_>>());
+
+    let matches = asset.query(
+        &Query::new()
+            .with_class("AstroPlayFabEventNotificationComponent")
+            .with_object_name("*Fall"),
+    );
+    assert_eq!(exports(&matches), vec![37, 42]);
+
+    // exports whose class is declared in the same asset
+    let matches = asset.query(&Query::new().with_class("Staging_T2_C"));
+    assert_eq!(exports(&matches), vec![3, 429]);
+
+    assert!(asset
+        .query(
+            &Query::new()
+                .with_class("CameraComponent")
+                .with_object_name("Camera?")
+        )
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn property_conditions() -> Result<(), Error> {
+    let mut asset = read_asset()?;
+
+    let query = Query::new()
+        .with_class("CameraComponent")
+        .with_property("FieldOfView")?;
+    let matches = asset.query(&query);
+    assert_eq!(exports(&matches), vec![80, 81, 82]);
+    assert_eq!(paths(&matches), vec!["FieldOfView"; 3]);
+
+    let query = Query::new().with_class("CameraComponent").with_predicate(
+        "FieldOfView",
+        |e| matches!(e, Property::FloatProperty(e) if e.value.0 > 0.0),
+    )?;
+    assert_eq!(exports(&asset.query(&query)), vec![80, 81, 82]);
+
+    // matched paths can be used to edit the properties
+    let found = &asset.query(&query)[1];
+    asset
+        .get_export_mut(found.export)
+        .and_then(|e| e.get_normal_export_mut())
+        .unwrap()
+        .set(&found.property_path.as_ref().unwrap().to_string(), 123.0f32)?;
+
+    let query = Query::new()
+        .with_class("CameraComponent")
+        .with_value("FieldOfView", 123.0f32)?;
+    assert_eq!(exports(&asset.query(&query)), vec![81]);
+
+    let query = Query::new()
+        .with_class("ChildActorComponent")
+        .with_value("bNetAddressable", true)?;
+    assert_eq!(exports(&asset.query(&query)), vec![127, 128, 129, 130, 131]);
+
+    assert!(Query::new().with_property("Items[").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn wildcards() -> Result<(), Error> {
+    let asset = read_asset()?;
+    let export = asset
+        .get_export(PackageIndex::new(62))
+        .and_then(|e| e.get_normal_export())
+        .unwrap();
+
+    let query = Query::new()
+        .with_class("BP_Atmos_Mesh_Arid_C")
+        .with_property("BlueprintCreatedComponents[*]")?;
+    let matches = asset.query(&query);
+    let Some(Property::ArrayProperty(components)) =
+        export.get_property("BlueprintCreatedComponents")
+    else {
+        panic!("expected an array property");
+    };
+    assert_eq!(matches.len(), components.value.len());
+    assert!(!matches.is_empty());
+
+    for (i, found) in matches.iter().enumerate() {
+        let path = found.property_path.as_ref().unwrap().to_string();
+        assert_eq!(path, format!("BlueprintCreatedComponents[{i}]"));
+        assert!(export.get::<PackageIndex>(&path).is_some());
+    }
+
+    let last = export
+        .get::<PackageIndex>(&format!(
+            "BlueprintCreatedComponents[{}]",
+            matches.len() - 1
+        ))
+        .unwrap();
+    let query = Query::new().with_value("BlueprintCreatedComponents[*]", last)?;
+    let matches = asset.query(&query);
+    assert!(exports(&matches).contains(&62));
+    assert!(paths(&matches)
+        .iter()
+        .all(|e| e.starts_with("BlueprintCreatedComponents[")));
+
+    Ok(())
+}
+
+#[test]
+fn batch_queries() -> Result<(), Error> {
+    let inputs = [
+        ("Staging_T2.umap", MAP_ASSET),
+        ("Augment.uasset", AUGMENT_ASSET),
+    ]
+    .into_iter()
+    .map(|(path, data)| BatchInput::Memory {
+        path: path.to_string(),
+        asset: data.to_vec(),
+        bulk: None,
+    });
+
+    let query = Query::new()
+        .with_class("CameraComponent")
+        .with_property("FieldOfView")?;
+    let results = query::query_batch(
+        inputs,
+        &BatchOptions::new(EngineVersion::VER_UE4_23),
+        &query,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].path, "Staging_T2.umap");
+    let matches = results[0].result.as_ref().unwrap();
+    assert_eq!(exports(matches), vec![80, 81, 82]);
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::{Export, ExportBaseTrait, ExportNormalTrait},
+    properties::{Property, PropertyDataTrait},
+    types::{PackageIndex, PackageIndexContainer, PackageIndexTrait},
+    validation::Severity,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const AUGMENT_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Augment_BroadBrush.uasset"));
+const MAP_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Staging_T2.umap"));
+
+fn read_asset(data: &'static [u8]) -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)
+}
+
+/// Collect the object paths of every reference in the asset
+fn reference_paths(asset: &mut Asset<Cursor<&'static [u8]>>) -> Vec<Option<String>> {
+    let mut indices = Vec::new();
+    asset.traverse_package_indices(&mut |index| indices.push(*index));
+    indices
+        .into_iter()
+        .map(|e| asset.get_object_path(e))
+        .collect()
+}
+
+fn assert_no_errors(asset: &Asset<Cursor<&'static [u8]>>) {
+    let diagnostics = asset.validate();
+    assert!(
+        diagnostics.iter().all(|e| e.severity != Severity::Error),
+        "{diagnostics:#?}"
+    );
+}
+
+#[test]
+fn remove_export() -> Result<(), Error> {
+    let mut asset = read_asset(MAP_ASSET)?;
+
+    let (level_index, actor) = asset
+        .asset_data
+        .exports
+        .iter()
+        .enumerate()
+        .find_map(|(i, e)| match e {
+            Export::LevelExport(level) => level
+                .actors
+                .iter()
+                .find(|e| e.index > 0)
+                .map(|e| (PackageIndex::new(i as i32 + 1), *e)),
+            _ => None,
+        })
+        .expect("No level actors");
+
+    let export_count = asset.asset_data.exports.len();
+    let subobject_count = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter(|e| e.get_base_export().outer_index == actor)
+        .count();
+    let actor_path = asset.get_object_path(actor).expect("No actor path");
+    let level_path = asset.get_object_path(level_index).expect("No level path");
+
+    let paths_before = reference_paths(&mut asset);
+    asset.remove_export(actor)?;
+    let paths_after = reference_paths(&mut asset);
+
+    assert!(asset.asset_data.exports.len() <= export_count - 1 - subobject_count);
+    assert_eq!(asset.find_object_by_path(&actor_path), None);
+
+    let level_index = asset
+        .find_object_by_path(&level_path)
+        .expect("Level was removed");
+    let Some(Export::LevelExport(level)) = asset.asset_data.get_export(level_index) else {
+        panic!("Not a level export");
+    };
+    assert!(level
+        .actors
+        .iter()
+        .all(|e| e.index <= asset.asset_data.exports.len() as i32));
+
+    // renumbered references still point at the same objects
+    let removed_path = |path: &Option<String>| {
+        path.as_ref()
+            .is_some_and(|e| e == &actor_path || e.starts_with(&format!("{actor_path}.")))
+    };
+    let paths_before = paths_before
+        .into_iter()
+        .filter(|e| !removed_path(e))
+        .collect::<Vec<_>>();
+    let paths_after = paths_after
+        .into_iter()
+        .filter(|e| e.is_some())
+        .collect::<Vec<_>>();
+    assert!(paths_after.iter().all(|e| paths_before.contains(e)));
+
+    assert_no_errors(&asset);
+    shared::verify_reparse(&mut asset, EngineVersion::VER_UE4_23)?;
+
+    Ok(())
+}
+
+#[test]
+fn remove_import() -> Result<(), Error> {
+    let mut asset = read_asset(AUGMENT_ASSET)?;
+    let import_count = asset.imports.len();
+
+    // removing the package removes the objects imported from it
+    let class = asset.add_import_by_path(
+        "/Game/Test/NewObject.NewObject_C",
+        &[("/Script/Engine", "BlueprintGeneratedClass")],
+    )?;
+    let package = asset
+        .get_import(class)
+        .map(|e| e.outer_index)
+        .expect("No class import");
+    asset.remove_import(package)?;
+    assert_eq!(asset.imports.len(), import_count);
+
+    // references to a removed import are nulled, the rest are renumbered
+    let (property_name, referenced) = asset
+        .asset_data
+        .get_export(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export())
+        .and_then(|e| {
+            e.properties.iter().find_map(|e| {
+                cast!(Property, ObjectProperty, e)
+                    .filter(|e| e.value.index < 0)
+                    .map(|e| (e.get_name(), e.value))
+            })
+        })
+        .expect("No imported object reference");
+
+    let paths_before = reference_paths(&mut asset);
+    let removed_path = asset.get_object_path(referenced);
+    asset.remove_import(referenced)?;
+
+    let value = asset
+        .asset_data
+        .get_export(PackageIndex::new(2))
+        .and_then(|e| e.get_normal_export())
+        .and_then(|e| e.properties.iter().find(|e| e.get_name() == property_name))
+        .and_then(|e| cast!(Property, ObjectProperty, e))
+        .map(|e| e.value)
+        .expect("Property was removed");
+    assert_eq!(value, PackageIndex::new(0));
+
+    // the removed import's own outer reference goes away with it
+    let paths_after = reference_paths(&mut asset)
+        .into_iter()
+        .filter(|e| e.is_some())
+        .collect::<Vec<_>>();
+    assert!(!paths_after.contains(&removed_path));
+    assert!(paths_after.iter().all(|e| paths_before.contains(e)));
+    assert_eq!(asset.imports.len(), import_count - 1);
+
+    shared::verify_reparse(&mut asset, EngineVersion::VER_UE4_23)?;
+
+    Ok(())
+}
+
+#[test]
+fn remove_referenced_objects() -> Result<(), Error> {
+    let mut asset = read_asset(AUGMENT_ASSET)?;
+    let import_count = asset.imports.len();
+    let export_count = asset.asset_data.exports.len();
+
+    // the CDO is an instance of the class export
+    let cdo = PackageIndex::new(2);
+    let class = asset
+        .asset_data
+        .get_export(cdo)
+        .map(|e| e.get_base_export().class_index)
+        .expect("No CDO");
+    assert!(class.is_export());
+    let error = asset.remove_export(class).unwrap_err().to_string();
+    assert!(error.contains("is the class of"), "{error}");
+
+    // the class export's super class is imported
+    let super_class = asset
+        .asset_data
+        .get_export(class)
+        .map(|e| e.get_base_export().super_index)
+        .expect("No class");
+    assert!(super_class.is_import());
+    let error = asset.remove_import(super_class).unwrap_err().to_string();
+    assert!(error.contains("is the super of"), "{error}");
+
+    // nothing was removed or renumbered
+    assert_eq!(asset.imports.len(), import_count);
+    assert_eq!(asset.asset_data.exports.len(), export_count);
+    assert_eq!(
+        asset
+            .asset_data
+            .get_export(cdo)
+            .map(|e| e.get_base_export().class_index),
+        Some(class)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,449 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    containers::IndexedMap,
+    custom_version::CustomVersion,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{
+        int_property::{DoubleProperty, FloatProperty},
+        Property, PropertyDataTrait,
+    },
+    schema::{PropertyType, SchemaMismatch},
+    types::{fname::ToSerializedName, PackageIndex},
+    unversioned::{
+        properties::{
+            array_property::UsmapArrayPropertyData, shallow_property::UsmapShallowPropertyData,
+            EPropertyType, UsmapProperty, UsmapPropertyData,
+        },
+        EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion, UsmapSchema,
+    },
+    Asset, Error, Export,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/")
+    };
+}
+
+const FPROPERTY_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        assets_folder!(),
+        "general/pseudoregalia/BP_looseWeapon.uasset"
+    )),
+    include_bytes!(concat!(
+        assets_folder!(),
+        "general/pseudoregalia/BP_looseWeapon.uexp"
+    )),
+);
+
+const UPROPERTY_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "general/Astroneer_prebulk/DebugMenu.uasset"
+));
+
+const USER_DEFINED_STRUCT_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        assets_folder!(),
+        "user_defined_struct/achievements_STRUCT_entry.uasset"
+    )),
+    include_bytes!(concat!(
+        assets_folder!(),
+        "user_defined_struct/achievements_STRUCT_entry.uexp"
+    )),
+);
+
+/// BP_looseWeapon_C class export
+const CLASS: PackageIndex = PackageIndex { index: 1 };
+/// Default__BP_looseWeapon_C export
+const CDO: PackageIndex = PackageIndex { index: 5 };
+
+fn read_fproperty_asset() -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    Asset::new(
+        Cursor::new(FPROPERTY_ASSET.0.to_vec()),
+        Some(Cursor::new(FPROPERTY_ASSET.1.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )
+}
+
+fn cdo_properties<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> &Vec<Property> {
+    &asset
+        .get_export(CDO)
+        .and_then(|e| e.get_normal_export())
+        .expect("CDO is a normal export")
+        .properties
+}
+
+fn shallow(property_type: EPropertyType) -> UsmapPropertyData {
+    UsmapShallowPropertyData { property_type }.into()
+}
+
+fn usmap_property(name: &str, array_size: u8, property_data: UsmapPropertyData) -> UsmapProperty {
+    UsmapProperty {
+        name: name.to_string(),
+        schema_index: 0,
+        array_size,
+        array_index: 0,
+        property_data,
+    }
+}
+
+fn usmap_schema(name: &str, super_type: &str, properties: Vec<UsmapProperty>) -> UsmapSchema {
+    let mut map = IndexedMap::new();
+    for property in properties {
+        for array_index in 0..property.array_size as u16 {
+            let property = UsmapProperty {
+                schema_index: map.len() as u16,
+                array_index,
+                ..property.clone()
+            };
+            map.insert((property.name.clone(), array_index as u32), property);
+        }
+    }
+
+    UsmapSchema {
+        name: name.to_string(),
+        super_type: super_type.to_string(),
+        prop_count: map.len() as u16,
+        module_path: None,
+        properties: map,
+    }
+}
+
+fn test_mappings() -> Usmap {
+    let mut schemas = IndexedMap::new();
+    schemas.insert("Object".to_string(), usmap_schema("Object", "", Vec::new()));
+    schemas.insert(
+        "Actor".to_string(),
+        usmap_schema(
+            "Actor",
+            "Object",
+            vec![
+                usmap_property("bHidden", 1, shallow(EPropertyType::BoolProperty)),
+                usmap_property(
+                    "Tags",
+                    1,
+                    UsmapArrayPropertyData {
+                        inner_type: Box::new(shallow(EPropertyType::NameProperty)),
+                    }
+                    .into(),
+                ),
+                usmap_property("Layers", 2, shallow(EPropertyType::IntProperty)),
+            ],
+        ),
+    );
+
+    Usmap {
+        version: EUsmapVersion::Latest,
+        name_map: Vec::new(),
+        enum_map: IndexedMap::new(),
+        schemas,
+        extension_version: UsmapExtensionVersion::NONE,
+        object_version: ObjectVersion::UNKNOWN,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::<CustomVersion>::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
+
+#[test]
+fn fproperty_schema() -> Result<(), Error> {
+    let asset = read_fproperty_asset()?;
+
+    let schema = asset.get_schema(CLASS).expect("class has a schema");
+    assert_eq!(schema.name, "BP_looseWeapon_C");
+    assert_eq!(schema.unresolved_super.as_deref(), Some("Actor"));
+    assert!(!schema.is_complete());
+    assert_eq!(asset.get_export_schema(CDO), Some(schema.clone()));
+
+    let property_type = |name: &str| {
+        schema
+            .get_property(name)
+            .map(|e| e.property_type.clone())
+            .unwrap_or_else(|| panic!("{name} is in the schema"))
+    };
+    assert_eq!(
+        property_type("launchSpeed"),
+        PropertyType::Simple("DoubleProperty".to_string())
+    );
+    assert_eq!(
+        property_type("weaponState"),
+        PropertyType::Byte {
+            enum_type: Some("EN_WeaponState".to_string())
+        }
+    );
+    assert_eq!(
+        property_type("launchVelocity"),
+        PropertyType::Struct {
+            struct_type: Some("Vector".to_string())
+        }
+    );
+    assert_eq!(
+        property_type("hitActorsArray"),
+        PropertyType::Array(Box::new(PropertyType::Simple("ObjectProperty".to_string())))
+    );
+
+    assert_eq!(schema.validate(cdo_properties(&asset)), Vec::new());
+
+    // wrong types are reported, unknown properties aren't because the schema is missing Actor
+    let wrong_type: Property = FloatProperty {
+        name: asset.get_name_map().get_mut().add_fname("launchSpeed"),
+        ..Default::default()
+    }
+    .into();
+    let unknown: Property = DoubleProperty {
+        name: asset.get_name_map().get_mut().add_fname("bHidden"),
+        ..Default::default()
+    }
+    .into();
+    assert_eq!(
+        schema.validate(&[wrong_type, unknown]),
+        vec![SchemaMismatch::TypeMismatch {
+            name: "launchSpeed".to_string(),
+            expected: PropertyType::Simple("DoubleProperty".to_string()),
+            found: "FloatProperty".to_string(),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn uproperty_schema() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(UPROPERTY_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let schema = asset
+        .get_schema(PackageIndex::new(2))
+        .expect("class has a schema");
+    assert_eq!(schema.name, "DebugMenu_C");
+    assert_eq!(schema.unresolved_super.as_deref(), Some("UserWidget"));
+    assert_eq!(
+        schema
+            .get_property("IsCreativePaintingVisible")
+            .map(|e| &e.property_type),
+        Some(&PropertyType::Simple("BoolProperty".to_string()))
+    );
+
+    // property names include their instance numbers
+    let mut names = schema
+        .properties
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+    assert_eq!(names.len(), schema.properties.len());
+
+    let cdo = asset
+        .get_export(PackageIndex::new(3))
+        .and_then(|e| e.get_normal_export())
+        .expect("CDO is a normal export");
+    assert_eq!(schema.validate(&cdo.properties), Vec::new());
+
+    Ok(())
+}
+
+#[test]
+fn user_defined_struct_schema() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(USER_DEFINED_STRUCT_ASSET.0),
+        Some(Cursor::new(USER_DEFINED_STRUCT_ASSET.1)),
+        EngineVersion::VER_UE4_26,
+        None,
+    )?;
+
+    let schema = asset
+        .get_schema_by_name("achievements_STRUCT_entry")
+        .expect("struct has a schema");
+    assert!(schema.is_complete());
+    assert_eq!(
+        schema
+            .properties
+            .iter()
+            .map(|e| e.property_type.serialized_name())
+            .collect::<Vec<_>>(),
+        ["StrProperty", "IntProperty"]
+    );
+
+    let uds = asset
+        .asset_data
+        .exports
+        .iter()
+        .find_map(|e| cast!(Export, UserDefinedStructExport, e))
+        .expect("asset contains a user defined struct");
+    assert_eq!(schema.validate(&uds.default_struct_instance), Vec::new());
+
+    Ok(())
+}
+
+#[test]
+fn mappings_schema() -> Result<(), Error> {
+    let mut asset = read_fproperty_asset()?;
+    asset.asset_data.mappings = Some(test_mappings());
+
+    let schema = asset.get_schema(CLASS).expect("class has a schema");
+    assert!(schema.is_complete());
+    // super struct properties come first
+    assert_eq!(schema.properties[0].name, "bHidden");
+    assert_eq!(
+        schema.get_property("Tags").map(|e| &e.property_type),
+        Some(&PropertyType::Array(Box::new(PropertyType::Simple(
+            "NameProperty".to_string()
+        ))))
+    );
+    assert_eq!(schema.get_property("Layers").map(|e| e.array_size), Some(2));
+    assert_eq!(
+        asset
+            .get_schema_by_name("Actor")
+            .map(|e| e.properties.len()),
+        Some(3)
+    );
+
+    // unknown properties are reported for complete schemas
+    let mut name_map = asset.get_name_map();
+    let layer: Property = DoubleProperty {
+        name: name_map.get_mut().add_fname("Layers"),
+        duplication_index: 2,
+        ..Default::default()
+    }
+    .into();
+    let unknown: Property = DoubleProperty {
+        name: name_map.get_mut().add_fname("NotAProperty"),
+        ..Default::default()
+    }
+    .into();
+    assert_eq!(
+        schema.validate(&[layer, unknown]),
+        vec![
+            SchemaMismatch::InvalidArrayIndex {
+                name: "Layers".to_string(),
+                duplication_index: 2,
+                array_size: 2,
+            },
+            SchemaMismatch::TypeMismatch {
+                name: "Layers".to_string(),
+                expected: PropertyType::Simple("IntProperty".to_string()),
+                found: "DoubleProperty".to_string(),
+            },
+            SchemaMismatch::UnknownProperty {
+                name: "NotAProperty".to_string(),
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn create_properties() -> Result<(), Error> {
+    let mut asset = read_fproperty_asset()?;
+    let schema = asset.get_schema(CLASS).expect("class has a schema");
+    let ancestry = cdo_properties(&asset)[0].get_ancestry().clone();
+
+    let mut properties = Vec::new();
+    for name in [
+        "launchSpeed",
+        "weaponState",
+        "hitActorsArray",
+        "ST Hitbox Data",
+        "isEmbedded?",
+    ] {
+        let name = asset.add_fname(name);
+        let property = schema
+            .new_property(name, ancestry.clone(), 0)
+            .expect("property can be created");
+        // the name map is written before the exports, so type names have to be added up front
+        asset.add_fname(&property.to_serialized_name());
+        properties.push(property);
+    }
+
+    // structs with custom serialization and unknown properties can't be created
+    let name = asset.add_fname("launchVelocity");
+    assert_eq!(schema.new_property(name, ancestry.clone(), 0), None);
+    let name = asset.add_fname("NotAProperty");
+    assert_eq!(schema.new_property(name, ancestry.clone(), 0), None);
+
+    assert_eq!(schema.validate(&properties), Vec::new());
+
+    asset
+        .get_export_mut(CDO)
+        .and_then(|e| e.get_normal_export_mut())
+        .expect("CDO is a normal export")
+        .properties = properties.clone();
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    cursor.set_position(0);
+    bulk_cursor.set_position(0);
+    let reparsed = Asset::new(cursor, Some(bulk_cursor), EngineVersion::VER_UE5_1, None)?;
+
+    let reparsed_properties = cdo_properties(&reparsed);
+    assert_eq!(schema.validate(reparsed_properties), Vec::new());
+    assert_eq!(
+        reparsed_properties
+            .iter()
+            .map(|e| e.to_serialized_name())
+            .collect::<Vec<_>>(),
+        properties
+            .iter()
+            .map(|e| e.to_serialized_name())
+            .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn set_export_properties() -> Result<(), Error> {
+    let mut asset = read_fproperty_asset()?;
+    let ancestry = cdo_properties(&asset)[0].get_ancestry().clone();
+
+    // missing properties get the type declared in the class
+    asset
+        .get_export_mut(CDO)
+        .and_then(|e| e.get_normal_export_mut())
+        .expect("CDO is a normal export")
+        .properties
+        .retain(|e| e.get_name() != "launchSpeed");
+    asset.set_export_property(CDO, "launchSpeed", 2.5f32)?;
+    asset.add_fname("DoubleProperty");
+
+    let created = cdo_properties(&asset)
+        .iter()
+        .find(|e| e.get_name() == "launchSpeed")
+        .expect("launchSpeed was created");
+    assert_eq!(
+        cast!(Property, DoubleProperty, created).map(|e| e.value.0),
+        Some(2.5)
+    );
+    assert_eq!(created.get_ancestry(), &ancestry);
+
+    // values have to match the declared type, unknown properties can't be created
+    assert!(asset.set_export_property(CDO, "isEmbedded?", 1).is_err());
+    assert!(asset.set_export_property(CDO, "NotAProperty", 1).is_err());
+    assert!(asset
+        .set_export_property(PackageIndex::new(1000), "launchSpeed", 1.0f64)
+        .is_err());
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    cursor.set_position(0);
+    bulk_cursor.set_position(0);
+    let reparsed = Asset::new(cursor, Some(bulk_cursor), EngineVersion::VER_UE5_1, None)?;
+    let schema = reparsed.get_export_schema(CDO).expect("class has a schema");
+    assert_eq!(schema.validate(cdo_properties(&reparsed)), Vec::new());
+
+    Ok(())
+}
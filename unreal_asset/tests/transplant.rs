@@ -0,0 +1,220 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{Export, ExportBaseTrait},
+    types::PackageIndex,
+    validation::Severity,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const AUGMENT_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Augment_BroadBrush.uasset"));
+const CANISTER_ASSET: &[u8] =
+    include_bytes!(concat!(assets_folder!(), "LargeResourceCanister_IT.uasset"));
+const MAP_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Staging_T2.umap"));
+
+const AUGMENT_PACKAGE: &str = "/Game/Items/ItemTypes/Components/Augment_BroadBrush";
+
+fn read_asset(data: &'static [u8]) -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)
+}
+
+/// Serial sizes of edited exports are only updated on write, so only errors are checked
+fn assert_no_errors(asset: &Asset<Cursor<&'static [u8]>>) {
+    let diagnostics = asset.validate();
+    assert!(
+        diagnostics.iter().all(|e| e.severity != Severity::Error),
+        "{diagnostics:#?}"
+    );
+}
+
+#[test]
+fn transplant_export() -> Result<(), Error> {
+    let source = read_asset(AUGMENT_ASSET)?;
+    let mut asset = read_asset(CANISTER_ASSET)?;
+    let export_count = asset.asset_data.exports.len();
+
+    // the CDO together with its ItemCatalogData subobject
+    let cdo = asset.transplant_export(&source, PackageIndex::new(2), PackageIndex::new(0), true)?;
+    assert_eq!(cdo, PackageIndex::new(export_count as i32 + 1));
+    assert_eq!(asset.asset_data.exports.len(), export_count + 2);
+
+    let package_name = asset.get_package_name().expect("No package name");
+    assert_eq!(
+        asset.get_object_path(cdo),
+        Some(format!("{package_name}.Default__Augment_BroadBrush_C"))
+    );
+    let catalog_data = PackageIndex::new(cdo.index + 1);
+    assert_eq!(
+        asset.get_object_path(catalog_data),
+        Some(format!(
+            "{package_name}.Default__Augment_BroadBrush_C:ItemCatalogData_0"
+        ))
+    );
+
+    // the class wasn't copied, so it's imported from the source package
+    let class_path = format!("{AUGMENT_PACKAGE}.Augment_BroadBrush_C");
+    let class_import = asset
+        .find_import_by_path(&class_path)
+        .expect("Class wasn't imported");
+    assert_eq!(
+        asset.asset_data.exports[cdo.index as usize - 1]
+            .get_base_export()
+            .class_index,
+        class_import
+    );
+    assert_eq!(
+        asset
+            .get_import(class_import)
+            .map(|e| e.class_name.get_owned_content()),
+        Some(String::from("BlueprintGeneratedClass"))
+    );
+
+    // references inside of the copied exports point at the copies
+    let catalog_export =
+        asset.asset_data.exports[catalog_data.index as usize - 1].get_base_export();
+    assert_eq!(catalog_export.outer_index, cdo);
+
+    assert_no_errors(&asset);
+    shared::verify_reparse(&mut asset, EngineVersion::VER_UE4_23)?;
+
+    Ok(())
+}
+
+#[test]
+fn clone_export() -> Result<(), Error> {
+    let mut asset = read_asset(MAP_ASSET)?;
+
+    let (level_index, actor) = asset
+        .asset_data
+        .exports
+        .iter()
+        .enumerate()
+        .find_map(|(i, e)| match e {
+            Export::LevelExport(level) => level
+                .actors
+                .iter()
+                .find(|e| e.index > 0)
+                .map(|e| (PackageIndex::new(i as i32 + 1), *e)),
+            _ => None,
+        })
+        .expect("No level actors");
+
+    let get_actors =
+        |asset: &Asset<Cursor<&'static [u8]>>| match asset.asset_data.get_export(level_index) {
+            Some(Export::LevelExport(level)) => level.actors.clone(),
+            _ => panic!("Not a level export"),
+        };
+
+    let actor_count = get_actors(&asset).len();
+    let original_name = asset.asset_data.exports[actor.index as usize - 1]
+        .get_base_export()
+        .object_name
+        .clone();
+
+    let clone = asset.clone_export(actor, level_index, true)?;
+
+    let actors = get_actors(&asset);
+    assert_eq!(actors.len(), actor_count + 1);
+    assert_eq!(actors.last(), Some(&clone));
+
+    // the clone gets a unique name within the level
+    let clone_name = &asset.asset_data.exports[clone.index as usize - 1]
+        .get_base_export()
+        .object_name;
+    assert_eq!(
+        clone_name.get_owned_content(),
+        original_name.get_owned_content()
+    );
+    assert_ne!(clone_name.get_number(), original_name.get_number());
+
+    // subobjects of the clone are outered to the clone instead of the original actor
+    let clone_subobjects = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter(|e| e.get_base_export().outer_index == clone)
+        .count();
+    let original_subobjects = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter(|e| e.get_base_export().outer_index == actor)
+        .count();
+    assert_eq!(clone_subobjects, original_subobjects);
+
+    assert_no_errors(&asset);
+    shared::verify_reparse(&mut asset, EngineVersion::VER_UE4_23)?;
+
+    Ok(())
+}
+
+#[test]
+fn invalid_export() -> Result<(), Error> {
+    let source = read_asset(AUGMENT_ASSET)?;
+    let mut asset = read_asset(CANISTER_ASSET)?;
+    let export_count = asset.asset_data.exports.len();
+
+    assert!(asset
+        .transplant_export(
+            &source,
+            PackageIndex::new(source.asset_data.exports.len() as i32 + 1),
+            PackageIndex::new(0),
+            false
+        )
+        .is_err());
+    assert!(asset
+        .transplant_export(
+            &source,
+            PackageIndex::new(1),
+            PackageIndex::new(-1000),
+            false
+        )
+        .is_err());
+    assert!(asset
+        .transplant_export(&source, PackageIndex::new(0), PackageIndex::new(0), false)
+        .is_err());
+    assert_eq!(asset.asset_data.exports.len(), export_count);
+
+    Ok(())
+}
+
+#[test]
+fn failed_transplant_keeps_imports() -> Result<(), Error> {
+    let mut source = read_asset(AUGMENT_ASSET)?;
+    let mut asset = read_asset(CANISTER_ASSET)?;
+    let export_count = asset.asset_data.exports.len();
+    let import_count = asset.imports.len();
+
+    // the CDO is copied first and imports its class, then its subobject fails
+    let subobject = source
+        .asset_data
+        .exports
+        .iter_mut()
+        .map(|e| e.get_base_export_mut())
+        .find(|e| e.outer_index == PackageIndex::new(2))
+        .expect("CDO has no subobjects");
+    subobject.class_index = PackageIndex::new(-10000);
+
+    assert!(asset
+        .transplant_export(&source, PackageIndex::new(2), PackageIndex::new(0), true)
+        .is_err());
+    assert_eq!(asset.asset_data.exports.len(), export_count);
+    assert_eq!(asset.imports.len(), import_count);
+    assert!(asset
+        .find_import_by_path(&format!("{AUGMENT_PACKAGE}.Augment_BroadBrush_C"))
+        .is_none());
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::ExportBaseTrait,
+    types::{FName, PackageIndex},
+    validation::{DiagnosticKind, DiagnosticLocation, Severity},
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const TEST_ASSETS: [&[u8]; 5] = [
+    include_bytes!(concat!(assets_folder!(), "Augment_BroadBrush.uasset")),
+    include_bytes!(concat!(assets_folder!(), "DebugMenu.uasset")),
+    include_bytes!(concat!(assets_folder!(), "LargeResourceCanister_IT.uasset")),
+    include_bytes!(concat!(assets_folder!(), "ResourceProgressCurve.uasset")),
+    include_bytes!(concat!(assets_folder!(), "Staging_T2.umap")),
+];
+
+#[test]
+fn valid_assets() -> Result<(), Error> {
+    for test_asset in TEST_ASSETS {
+        let asset = Asset::new(
+            Cursor::new(test_asset),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        )?;
+
+        let diagnostics = asset.validate();
+        assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn invalid_asset() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSETS[0]),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let import_count = asset.imports.len() as i32;
+    let export_count = asset.asset_data.exports.len() as i32;
+
+    let first = asset.asset_data.exports[0].get_base_export_mut();
+    first.class_index = PackageIndex::new(-import_count - 1);
+    first.outer_index = PackageIndex::new(2);
+    first
+        .create_before_create_dependencies
+        .push(PackageIndex::new(export_count + 1));
+
+    let second = asset.asset_data.exports[1].get_base_export_mut();
+    second.outer_index = PackageIndex::new(1);
+    second.object_name = FName::from_slice("DummyName");
+
+    let diagnostics = asset.validate();
+
+    let has = |location: DiagnosticLocation, predicate: &dyn Fn(&DiagnosticKind) -> bool| {
+        diagnostics
+            .iter()
+            .any(|e| e.location == location && predicate(&e.kind))
+    };
+
+    assert!(has(DiagnosticLocation::Export(0), &|e| matches!(
+        e,
+        DiagnosticKind::InvalidPackageIndex { field, .. } if field == "class_index"
+    )));
+    assert!(has(DiagnosticLocation::Export(0), &|e| matches!(
+        e,
+        DiagnosticKind::OuterCycle { chain } if chain.len() == 3
+    )));
+    assert!(has(DiagnosticLocation::Export(0), &|e| matches!(
+        e,
+        DiagnosticKind::InvalidPreloadDependency {
+            list: "create_before_create_dependencies",
+            ..
+        }
+    )));
+    assert!(has(DiagnosticLocation::Export(1), &|e| matches!(
+        e,
+        DiagnosticKind::DummyFName { value } if value == "DummyName"
+    )));
+
+    // the cycle is only reported once
+    assert_eq!(
+        diagnostics
+            .iter()
+            .filter(|e| matches!(e.kind, DiagnosticKind::OuterCycle { .. }))
+            .count(),
+        1
+    );
+    assert!(diagnostics.iter().all(|e| e.severity == Severity::Error
+        || matches!(e.kind, DiagnosticKind::SerialSizeMismatch { .. })));
+
+    Ok(())
+}
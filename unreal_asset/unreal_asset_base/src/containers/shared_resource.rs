@@ -106,6 +106,18 @@ impl<T> SharedResource<T> {
     }
 }
 
+impl<T: ?Sized> SharedResource<T> {
+    /// Returns true if both `SharedResource`s point to the same allocation
+    ///
+    /// Unlike `==` this doesn't compare the values inside of the resources
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        #[cfg(not(feature = "threading"))]
+        return std::rc::Rc::ptr_eq(&this.resource, &other.resource);
+        #[cfg(feature = "threading")]
+        return std::sync::Arc::ptr_eq(&this.resource, &other.resource);
+    }
+}
+
 impl<T: CyclicSharedResource<T> + Clone> SharedResource<T> {
     /// Clone this shared resource with the value inside of it
     pub fn clone_resource(&self) -> SharedResource<T> {
@@ -188,6 +188,20 @@ pub fn cityhash64_to_lower(string: &str) -> u64 {
     cityhash64(aligned)
 }
 
+/// Generates a CRC32 hash for a string the way `FCrc::StrCrc32` does
+pub fn str_crc32(string: &str) -> u32 {
+    generate_crc32(string, 0)
+}
+
+/// Generates a cityhash64 hash for a string, folded to 32 bits the way `GetTypeHash(uint64)` does
+pub fn cityhash64_to_u32(string: &str) -> u32 {
+    let encoded = string.encode_utf16().collect::<Vec<_>>();
+    // this is safe because we know that this is a u16 array, therefore it can safely be aligned to u8
+    let (_, aligned, _) = unsafe { encoded.align_to::<u8>() };
+    let hash = cityhash64(aligned);
+    (hash as u32).wrapping_add(((hash >> 32) as u32).wrapping_mul(23))
+}
+
 fn to_upper(character: u16) -> u16 {
     if character.saturating_sub('a' as u16) < 26u16 {
         (character as u8 as char).to_uppercase().next().unwrap() as u16
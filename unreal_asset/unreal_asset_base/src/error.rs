@@ -20,6 +20,9 @@ pub enum KismetError {
     /// Unknown kismet expression
     #[error("{0}")]
     UnknownExpression(Box<str>),
+    /// Kismet expressions were nested deeper than the maximum depth
+    #[error("Kismet expressions are nested deeper than {0} levels")]
+    NestingTooDeep(u32),
 }
 
 impl KismetError {
@@ -32,6 +35,11 @@ impl KismetError {
     pub fn expression(msg: String) -> Self {
         KismetError::UnknownExpression(msg.into_boxed_str())
     }
+
+    /// Create a `KismetError` for expressions that are nested too deep
+    pub fn nesting_too_deep(max_depth: u32) -> Self {
+        KismetError::NestingTooDeep(max_depth)
+    }
 }
 
 /// Thrown when a usmap file failed to deserialize
@@ -129,6 +137,12 @@ pub enum PropertyError {
     /// An unversioned property schema was not found
     #[error("Unversioned property schema for {0} at index {1} was not found")]
     NoSchema(Box<str>, usize),
+    /// A property has a custom type, but no serializer was registered for it
+    #[error("No custom serializer registered for type {0}")]
+    NoCustomSerializer(Box<str>),
+    /// A property path could not be resolved
+    #[error("Invalid property path {0}: {1}")]
+    InvalidPath(Box<str>, Box<str>),
     /// Other
     #[error("{0}")]
     Other(Box<str>),
@@ -206,12 +220,122 @@ impl PropertyError {
         PropertyError::InvalidArrayType(msg.into_boxed_str())
     }
 
+    /// Create a `PropertyError` for a custom property type without a registered serializer
+    pub fn no_custom_serializer(type_name: &str) -> Self {
+        PropertyError::NoCustomSerializer(type_name.to_string().into_boxed_str())
+    }
+
+    /// Create a `PropertyError` for a property path that could not be resolved
+    pub fn invalid_path(path: &str, msg: &str) -> Self {
+        PropertyError::InvalidPath(
+            path.to_string().into_boxed_str(),
+            msg.to_string().into_boxed_str(),
+        )
+    }
+
     /// Create an other `PropertyError`
     pub fn other(msg: String) -> Self {
         PropertyError::Other(msg.into_boxed_str())
     }
 }
 
+/// Thrown when a data table failed to convert to or from CSV or JSON
+#[derive(Error, Debug)]
+pub enum DataTableError {
+    /// Export is not a data table
+    #[error("Export {0} is not a data table")]
+    NotADataTable(i32),
+    /// Column doesn't exist in the table or in the row struct
+    #[error("Unknown column {0}")]
+    UnknownColumn(Box<str>),
+    /// Row name is used more than once
+    #[error("Duplicate row {0}")]
+    DuplicateRow(Box<str>),
+    /// Value can't be stored in the property
+    #[error("Invalid value for {0}: {1}")]
+    InvalidValue(Box<str>, Box<str>),
+    /// Property type can't be converted
+    #[error("{0} of type {1} can't be converted")]
+    UnsupportedProperty(Box<str>, Box<str>),
+    /// Malformed CSV or JSON
+    #[error("{0}")]
+    Format(Box<str>),
+}
+
+impl DataTableError {
+    /// Create a `DataTableError` for an export that is not a data table
+    pub fn not_a_data_table(export_index: i32) -> Self {
+        DataTableError::NotADataTable(export_index)
+    }
+
+    /// Create a `DataTableError` for an unknown column
+    pub fn unknown_column(column: &str) -> Self {
+        DataTableError::UnknownColumn(column.to_string().into_boxed_str())
+    }
+
+    /// Create a `DataTableError` for a duplicate row
+    pub fn duplicate_row(row: &str) -> Self {
+        DataTableError::DuplicateRow(row.to_string().into_boxed_str())
+    }
+
+    /// Create a `DataTableError` for a value that can't be stored in a property
+    pub fn invalid_value(path: &str, msg: String) -> Self {
+        DataTableError::InvalidValue(path.to_string().into_boxed_str(), msg.into_boxed_str())
+    }
+
+    /// Create a `DataTableError` for a property type that can't be converted
+    pub fn unsupported_property(path: &str, type_name: String) -> Self {
+        DataTableError::UnsupportedProperty(
+            path.to_string().into_boxed_str(),
+            type_name.into_boxed_str(),
+        )
+    }
+
+    /// Create a `DataTableError` for malformed CSV or JSON
+    pub fn format(msg: String) -> Self {
+        DataTableError::Format(msg.into_boxed_str())
+    }
+}
+
+/// Thrown when a localization file failed to parse or convert
+#[derive(Error, Debug)]
+pub enum LocalizationError {
+    /// File version is newer than any known version
+    #[error("Unknown {0} version {1}")]
+    UnknownVersion(Box<str>, u8),
+    /// Entry points to a localized string that doesn't exist
+    #[error("Localized string index {0} is out of range, string count: {1}")]
+    InvalidStringIndex(i32, usize),
+    /// Export is not a string table
+    #[error("Export {0} is not a string table")]
+    NotAStringTable(i32),
+    /// Malformed CSV or PO
+    #[error("{0}")]
+    Format(Box<str>),
+}
+
+impl LocalizationError {
+    /// Create a `LocalizationError` for an unknown file version
+    pub fn unknown_version(file_type: &str, version: u8) -> Self {
+        LocalizationError::UnknownVersion(file_type.to_string().into_boxed_str(), version)
+    }
+
+    /// Create a `LocalizationError` for an out of range localized string index
+    pub fn invalid_string_index(index: i32, string_count: usize) -> Self {
+        LocalizationError::InvalidStringIndex(index, string_count)
+    }
+
+    /// Create a `LocalizationError` for an export that is not a string table
+    pub fn not_a_string_table(export_index: i32) -> Self {
+        LocalizationError::NotAStringTable(export_index)
+    }
+
+    /// Create a `LocalizationError` for malformed CSV or PO
+    pub fn format(msg: String) -> Self {
+        LocalizationError::Format(msg.into_boxed_str())
+    }
+}
+
 /// Thrown when an FName error occured
 #[derive(Error, Debug)]
 pub enum FNameError {
@@ -341,6 +465,12 @@ pub enum Error {
     /// A `PropertyError` occcured
     #[error(transparent)]
     Property(#[from] PropertyError),
+    /// A `DataTableError` occured
+    #[error(transparent)]
+    DataTable(#[from] DataTableError),
+    /// A `LocalizationError` occured
+    #[error(transparent)]
+    Localization(#[from] LocalizationError),
     /// A `RegistryError` occured
     #[error(transparent)]
     Registry(#[from] RegistryError),
@@ -1,13 +1,13 @@
 //! Asset Import struct
 
-use crate::FNameContainer;
+use crate::{FNameContainer, PackageIndexContainer};
 
 use crate::types::{FName, PackageIndex};
 
 /// Import struct for an Asset
 ///
 /// This is used for referencing other assets
-#[derive(FNameContainer, Debug, Clone, Eq, PartialEq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Eq, PartialEq)]
 pub struct Import {
     /// Class package
     pub class_package: FName,
@@ -15,6 +15,7 @@ pub struct Import {
     pub class_name: FName,
     /// Outer index
     #[container_ignore]
+    #[container_index]
     pub outer_index: PackageIndex,
     /// Object name
     pub object_name: FName,
@@ -22,7 +22,7 @@ pub mod reader;
 pub mod types;
 pub mod unversioned;
 
-pub use unreal_asset_proc_macro::FNameContainer;
+pub use unreal_asset_proc_macro::{FNameContainer, PackageIndexContainer};
 pub use unreal_helpers::Guid;
 
 /// Cast a Property/Export to a more specific type
@@ -4,6 +4,7 @@ use std::collections::HashSet;
 use std::io::{self, Read};
 
 use byteorder::{ReadBytesExt, LE};
+use unreal_helpers::read_ext::capped_capacity;
 
 use crate::crc;
 use crate::custom_version::CustomVersion;
@@ -125,13 +126,14 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
         if num_strings == 0 {
             return Ok((Vec::new(), 0));
         }
+        let capacity = capped_capacity(num_strings);
 
         let _strings_length = self.read_u64::<LE>()?;
         let hash_version = self.read_u64::<LE>()?;
 
         let hashes = match hash_version {
             hash if hash == enums::HASH_VERSION_CITYHASH64 => {
-                let mut hashes = Vec::with_capacity(num_strings as usize);
+                let mut hashes = Vec::with_capacity(capacity);
                 for _ in 0..num_strings {
                     hashes.push(self.read_u64::<LE>()?); // cityhash64 of crc::to_lower_string
                 }
@@ -143,12 +145,12 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
             ))),
         }?;
 
-        let mut name_headers = Vec::with_capacity(num_strings as usize);
+        let mut name_headers = Vec::with_capacity(capacity);
         for _ in 0..num_strings {
             name_headers.push(SerializedNameHeader::read(self)?);
         }
 
-        let mut name_batch = Vec::with_capacity(num_strings as usize);
+        let mut name_batch = Vec::with_capacity(capacity);
 
         for name_header in name_headers {
             name_batch.push(self.read_name_map_string(Some(name_header)).map(|e| e.0)?);
@@ -196,7 +198,7 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
         length: i32,
         getter: impl Fn(&mut Self) -> Result<T, Error>,
     ) -> Result<Vec<T>, Error> {
-        let mut array = Vec::with_capacity(length as usize);
+        let mut array = Vec::with_capacity(capped_capacity(length));
         for _ in 0..length {
             array.push(getter(self)?);
         }
@@ -214,6 +216,19 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
         self.read_array_with_length(length, getter)
     }
 
+    /// Read `length` bytes
+    ///
+    /// The buffer grows while reading, a length that exceeds the archive returns an error
+    /// instead of allocating the whole length up front.
+    fn read_bytes(&mut self, length: usize) -> Result<Vec<u8>, Error> {
+        let mut buf = Vec::with_capacity(capped_capacity(length));
+        (&mut *self).take(length as u64).read_to_end(&mut buf)?;
+        if buf.len() != length {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        Ok(buf)
+    }
+
     /// Read an FString
     fn read_fstring(&mut self) -> Result<Option<String>, Error>;
     /// Read an FString with a `SerializedNameHeader`
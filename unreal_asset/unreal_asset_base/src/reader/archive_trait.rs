@@ -1,5 +1,6 @@
 //! Archive property trait
 
+use std::any::Any;
 use std::fmt::Display;
 use std::io::{self, Seek, SeekFrom};
 
@@ -119,6 +120,13 @@ pub trait ArchiveTrait<Index: PackageIndexTrait>: Seek {
     /// Get .usmap mappings
     fn get_mappings(&self) -> Option<&Usmap>;
 
+    /// Get custom property serializers registered for this archive
+    ///
+    /// The registry is type-erased because it's defined in `unreal_asset_properties`
+    fn get_custom_serializers(&self) -> Option<&(dyn Any + Send + Sync)> {
+        None
+    }
+
     /// Get parent class export name
     fn get_parent_class_export_name(&self) -> Option<FName>;
 
@@ -2,6 +2,7 @@
 
 pub mod archive_reader;
 pub use archive_reader::ArchiveReader;
+pub use unreal_helpers::read_ext::capped_capacity;
 
 pub mod archive_trait;
 pub use archive_trait::ArchiveTrait;
@@ -126,6 +126,16 @@ impl FName {
         FName::new_dummy(value.to_string(), 0)
     }
 
+    /// Create a new `FName` in the same name map as this `FName`
+    ///
+    /// If this `FName` is a "dummy" `FName`, the new `FName` will be a "dummy" too
+    pub fn new_sibling(&self, value: &str) -> Self {
+        match self {
+            FName::Backed { name_map, .. } => name_map.clone().get_mut().add_fname(value),
+            FName::Dummy { .. } => FName::from_slice(value),
+        }
+    }
+
     /// Get access to this `FName`'s content
     pub fn get_content<T>(&self, func: impl FnOnce(&str) -> T) -> T {
         match self {
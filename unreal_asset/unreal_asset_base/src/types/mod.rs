@@ -9,6 +9,7 @@ pub mod vector;
 
 use std::hash::Hash;
 
+use crate::containers::{IndexedMap, SharedResource};
 use crate::reader::{ArchiveReader, ArchiveWriter};
 use crate::Error;
 use crate::Guid;
@@ -122,6 +123,109 @@ impl std::fmt::Display for PackageIndex {
     }
 }
 
+/// This must be implemented for everything that contains a PackageIndex
+pub trait PackageIndexContainer {
+    /// Traverse this package index container
+    ///
+    /// Traverse function must get called for each PackageIndex in this container
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F);
+}
+
+impl PackageIndexContainer for PackageIndex {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        traverse(self);
+    }
+}
+
+impl<T: PackageIndexContainer> PackageIndexContainer for Vec<T> {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        for e in self.iter_mut() {
+            e.traverse_package_indices(traverse);
+        }
+    }
+}
+
+impl<T: PackageIndexContainer> PackageIndexContainer for Box<T> {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        self.as_mut().traverse_package_indices(traverse)
+    }
+}
+
+impl<K, V> PackageIndexContainer for IndexedMap<K, V>
+where
+    K: Eq + Hash + PackageIndexContainer + Clone,
+    V: Eq + Hash + PackageIndexContainer + Clone,
+{
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        *self = std::mem::take(self)
+            .into_iter()
+            .map(|(_, mut key, mut value)| {
+                key.traverse_package_indices(traverse);
+                value.traverse_package_indices(traverse);
+                (key, value)
+            })
+            .collect::<IndexedMap<K, V>>();
+    }
+}
+
+impl<T: PackageIndexContainer> PackageIndexContainer for Option<T> {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        if let Some(e) = self {
+            e.traverse_package_indices(traverse);
+        }
+    }
+}
+
+impl<T: PackageIndexContainer> PackageIndexContainer for SharedResource<T> {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+        let mut mut_self = self.get_mut();
+        mut_self.traverse_package_indices(traverse);
+    }
+}
+
+impl<T: ordered_float::Float> PackageIndexContainer for ordered_float::OrderedFloat<T> {
+    fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, _: &mut F) {}
+}
+
+macro_rules! dummy_index_container_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl PackageIndexContainer for $ty {
+                fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, _: &mut F) {}
+            }
+        )*
+    };
+}
+
+macro_rules! tuple_index_container_impl {
+    ($($name:ident),*) => {
+        impl<$($name:PackageIndexContainer),*> PackageIndexContainer for ($($name,)*)
+        {
+            fn traverse_package_indices<F: FnMut(&mut PackageIndex)>(&mut self, traverse: &mut F) {
+                #[allow(non_snake_case)]
+                let ($($name,)+) = self;
+                $($name.traverse_package_indices(traverse);)*
+            }
+        }
+    };
+}
+
+dummy_index_container_impl!(
+    u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, bool, String, &str, Guid, FName
+);
+tuple_index_container_impl!(A);
+tuple_index_container_impl!(A, B);
+tuple_index_container_impl!(A, B, C);
+tuple_index_container_impl!(A, B, C, D);
+tuple_index_container_impl!(A, B, C, D, E);
+tuple_index_container_impl!(A, B, C, D, E, G);
+tuple_index_container_impl!(A, B, C, D, E, G, H);
+tuple_index_container_impl!(A, B, C, D, E, G, H, I);
+tuple_index_container_impl!(A, B, C, D, E, G, H, I, J);
+tuple_index_container_impl!(A, B, C, D, E, G, H, I, J, K);
+tuple_index_container_impl!(A, B, C, D, E, G, H, I, J, K, L);
+tuple_index_container_impl!(A, B, C, D, E, G, H, I, J, K, L, M);
+
 // /// Create a Guid from 4 u32 values
 // #[rustfmt::skip]
 // pub const fn new_guid(a: u32, b: u32, c: u32, d: u32) -> Guid {
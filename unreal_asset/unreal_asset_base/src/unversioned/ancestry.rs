@@ -1,11 +1,11 @@
 //! Unversioned properties ancestry
 
-use crate::FNameContainer;
+use crate::{FNameContainer, PackageIndexContainer};
 
 use crate::types::FName;
 
 /// Unversioned properties ancestry
-#[derive(FNameContainer, Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Ancestry {
     /// Ancestry array, last element is immediate parent
     pub ancestry: Vec<FName>,
@@ -3,7 +3,7 @@
 use bitvec::prelude::*;
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
-use crate::reader::{ArchiveReader, ArchiveWriter};
+use crate::reader::{capped_capacity, ArchiveReader, ArchiveWriter};
 use crate::types::PackageIndexTrait;
 use crate::Error;
 
@@ -117,7 +117,7 @@ impl UnversionedHeader {
             Ok(BitVec::from_vec(data.to_vec()))
         } else {
             let num_bytes = ((num_bits + 31) / 32) * 4;
-            let mut data = Vec::with_capacity(num_bytes as usize);
+            let mut data = Vec::with_capacity(capped_capacity(num_bytes));
             for _ in 0..num_bytes {
                 data.push(asset.read_u8()?);
             }
@@ -11,7 +11,7 @@ use crate::containers::{Chain, IndexedMap, NameMap};
 use crate::custom_version::CustomVersion;
 use crate::error::{Error, UsmapError};
 use crate::object_version::{ObjectVersion, ObjectVersionUE5};
-use crate::reader::{ArchiveReader, ArchiveTrait, RawReader};
+use crate::reader::{capped_capacity, ArchiveReader, ArchiveTrait, RawReader};
 
 use crate::types::{FName, PackageIndex};
 
@@ -109,7 +109,10 @@ impl UsmapSchema {
             for j in 0..property.array_size {
                 let mut property = property.clone();
                 property.array_index = j as u16;
-                property.schema_index += j as u16;
+                property.schema_index =
+                    property.schema_index.checked_add(j as u16).ok_or_else(|| {
+                        Error::invalid_file("Usmap schema index overflow".to_string())
+                    })?;
 
                 properties.insert(
                     (property.name.clone(), property.schema_index as u32),
@@ -267,8 +270,7 @@ impl Usmap {
         let compressed_size = reader.read_u32::<LE>()?;
         let decompressed_size = reader.read_u32::<LE>()?;
 
-        let mut compressed_data = vec![0u8; compressed_size as usize];
-        reader.read_exact(&mut compressed_data)?;
+        let compressed_data = reader.read_bytes(compressed_size as usize)?;
 
         let data = match self.compression_method {
             EUsmapCompressionMethod::None => {
@@ -281,7 +283,8 @@ impl Usmap {
                 compressed_data
             }
             EUsmapCompressionMethod::Brotli => {
-                let mut decompressed_data = Cursor::new(vec![0u8; decompressed_size as usize]);
+                let mut decompressed_data =
+                    Cursor::new(Vec::with_capacity(capped_capacity(decompressed_size)));
                 brotli::BrotliDecompress(
                     &mut Cursor::new(compressed_data),
                     &mut decompressed_data,
@@ -289,7 +292,8 @@ impl Usmap {
                 decompressed_data.into_inner()
             }
             EUsmapCompressionMethod::ZStandard => {
-                let mut decompressed_data = Cursor::new(vec![0u8; decompressed_size as usize]);
+                let mut decompressed_data =
+                    Cursor::new(Vec::with_capacity(capped_capacity(decompressed_size)));
                 zstd::stream::copy_decode(
                     &mut Cursor::new(compressed_data),
                     &mut decompressed_data,
@@ -333,13 +337,14 @@ impl Usmap {
 
         self.name_map = reader.read_array(|reader| {
             let name_length = reader.read_u8()?;
-            let mut buf = vec![0u8; name_length as usize - 1];
-            reader.read_exact(&mut buf)?;
-            Ok(String::from_utf8(buf)?)
+            let name_length = (name_length as usize)
+                .checked_sub(1)
+                .ok_or_else(|| Error::invalid_file("Usmap name with a length of 0".to_string()))?;
+            Ok(String::from_utf8(reader.read_bytes(name_length)?)?)
         })?;
 
         let enum_len = reader.read_u32::<LE>()?;
-        self.enum_map = IndexedMap::with_capacity(enum_len as usize);
+        self.enum_map = IndexedMap::with_capacity(capped_capacity(enum_len));
 
         let mut reader = UsmapReader::new(&mut reader, &self.name_map, &self.custom_versions);
 
@@ -357,7 +362,7 @@ impl Usmap {
         }
 
         let schemas_len = reader.read_u32::<LE>()?;
-        self.schemas = IndexedMap::with_capacity(schemas_len as usize);
+        self.schemas = IndexedMap::with_capacity(capped_capacity(schemas_len));
 
         for _ in 0..schemas_len {
             let schema = UsmapSchema::read(&mut reader)?;
@@ -385,7 +390,10 @@ impl Usmap {
                         true => reader.read_u16::<LE>()?,
                         false => reader.read_u8()? as u16,
                     };
-                    schema.module_path = Some(module_paths[index as usize].clone());
+                    let module_path = module_paths.get(index as usize).ok_or_else(|| {
+                        Error::invalid_file(format!("Invalid usmap module path index {index}"))
+                    })?;
+                    schema.module_path = Some(module_path.clone());
                 }
             }
         }
@@ -85,6 +85,10 @@ pub enum EPropertyType {
     EnumProperty,
     /// FieldPath
     FieldPathProperty,
+    /// Utf8Str, follows OptionalProperty which isn't supported
+    Utf8StrProperty = 29,
+    /// AnsiStr
+    AnsiStrProperty,
 
     /// Unknown
     Unknown = 0xFF,
@@ -121,6 +125,8 @@ impl std::fmt::Display for EPropertyType {
             EPropertyType::SetProperty => "SetProperty",
             EPropertyType::EnumProperty => "EnumProperty",
             EPropertyType::FieldPathProperty => "FieldPathProperty",
+            EPropertyType::Utf8StrProperty => "Utf8StrProperty",
+            EPropertyType::AnsiStrProperty => "AnsiStrProperty",
             EPropertyType::Unknown => "Unknown",
         })
     }
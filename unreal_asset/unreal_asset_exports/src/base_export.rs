@@ -9,7 +9,7 @@ use unreal_asset_base::{
     flags::EObjectFlags,
     reader::{ArchiveTrait, ArchiveWriter},
     types::{FName, PackageIndex, PackageIndexTrait},
-    Error, FNameContainer, Guid,
+    Error, FNameContainer, Guid, PackageIndexContainer,
 };
 
 use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
@@ -27,19 +27,23 @@ pub enum EExportFilterFlags {
 }
 
 /// Minimal information about an export
-#[derive(FNameContainer, Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct BaseExport<Index: PackageIndexTrait> {
     /// Class index
     #[container_ignore]
+    #[container_index]
     pub class_index: Index,
     /// Super index
     #[container_ignore]
+    #[container_index]
     pub super_index: Index,
     /// Template index
     #[container_ignore]
+    #[container_index]
     pub template_index: Index,
     /// Outer index
     #[container_ignore]
+    #[container_index]
     pub outer_index: Index,
     /// Object name
     pub object_name: FName,
@@ -74,18 +78,22 @@ pub struct BaseExport<Index: PackageIndexTrait> {
     pub first_export_dependency_offset: i32,
     /// Dependencies that should be serialized before this export is serialized
     #[container_ignore]
+    #[container_index]
     pub serialization_before_serialization_dependencies: Vec<PackageIndex>,
 
     /// Dependencies that should be created before this export is serialized
     #[container_ignore]
+    #[container_index]
     pub create_before_serialization_dependencies: Vec<PackageIndex>,
 
     /// Dependencies that should be serialized before this export is created
     #[container_ignore]
+    #[container_index]
     pub serialization_before_create_dependencies: Vec<PackageIndex>,
 
     /// Dependencies that should be created before this export is created
     #[container_ignore]
+    #[container_index]
     pub create_before_create_dependencies: Vec<PackageIndex>,
 }
 
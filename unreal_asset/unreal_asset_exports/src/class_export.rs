@@ -9,16 +9,16 @@ use unreal_asset_base::{
     containers::IndexedMap,
     flags::EClassFlags,
     object_version::ObjectVersion,
-    reader::{ArchiveReader, ArchiveWriter},
+    reader::{capped_capacity, ArchiveReader, ArchiveWriter},
     types::{FName, PackageIndex, PackageIndexTrait},
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 use crate::{BaseExport, StructExport};
 use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
 
 /// Serialized interface reference
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct SerializedInterfaceReference {
     /// Class
     pub class: PackageIndex,
@@ -40,7 +40,7 @@ impl SerializedInterfaceReference {
 }
 
 /// Class export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq)]
 pub struct ClassExport<Index: PackageIndexTrait> {
     /// Base struct export
     pub struct_export: StructExport<Index>,
@@ -52,14 +52,17 @@ pub struct ClassExport<Index: PackageIndexTrait> {
     pub class_flags: EClassFlags,
     /// Class within
     #[container_ignore]
+    #[container_index]
     pub class_within: PackageIndex,
     /// Class config name
     pub class_config_name: FName,
     /// Interfaces
     #[container_ignore]
+    #[container_index]
     pub interfaces: Vec<SerializedInterfaceReference>,
     /// Who this class was generated by
     #[container_ignore]
+    #[container_index]
     pub class_generated_by: PackageIndex,
     /// Deprecated force script order
     pub deprecated_force_script_order: bool,
@@ -67,6 +70,7 @@ pub struct ClassExport<Index: PackageIndexTrait> {
     pub cooked: Option<bool>,
     /// Class default object
     #[container_ignore]
+    #[container_index]
     pub class_default_object: PackageIndex,
 }
 
@@ -79,7 +83,7 @@ impl<Index: PackageIndexTrait> ClassExport<Index> {
         let struct_export = StructExport::from_base(base, asset)?;
 
         let num_func_index_entries = asset.read_i32::<LE>()? as usize;
-        let mut func_map = IndexedMap::with_capacity(num_func_index_entries);
+        let mut func_map = IndexedMap::with_capacity(capped_capacity(num_func_index_entries));
         for _i in 0..num_func_index_entries {
             let name = asset.read_fname()?;
             let function_export = PackageIndex::new(asset.read_i32::<LE>()?);
@@ -118,7 +122,7 @@ impl<Index: PackageIndexTrait> ClassExport<Index> {
             asset.seek(SeekFrom::Start(interfaces_start.unwrap()))?;
         }
         let num_interfaces = asset.read_i32::<LE>()? as usize;
-        let mut interfaces = Vec::with_capacity(num_interfaces);
+        let mut interfaces = Vec::with_capacity(capped_capacity(num_interfaces));
         for _i in 0..num_interfaces {
             interfaces.push(SerializedInterfaceReference::new(
                 PackageIndex::new(asset.read_i32::<LE>()?),
@@ -3,10 +3,10 @@
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 use unreal_asset_base::{
-    reader::{ArchiveReader, ArchiveWriter},
+    reader::{capped_capacity, ArchiveReader, ArchiveWriter},
     types::{FName, PackageIndexTrait},
     unversioned::Ancestry,
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 use unreal_asset_properties::{struct_property::StructProperty, Property, PropertyDataTrait};
 
@@ -16,7 +16,7 @@ use crate::ExportTrait;
 use crate::{BaseExport, NormalExport};
 
 /// Data table
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct DataTable {
     /// Data
     pub data: Vec<StructProperty>,
@@ -30,7 +30,7 @@ impl DataTable {
 }
 
 /// Data table export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DataTableExport<Index: PackageIndexTrait> {
     /// Base normal export
     pub normal_export: NormalExport<Index>,
@@ -61,7 +61,7 @@ impl<Index: PackageIndexTrait> DataTableExport<Index> {
 
         asset.read_i32::<LE>()?;
         let num_entries = asset.read_i32::<LE>()? as usize;
-        let mut data = Vec::with_capacity(num_entries);
+        let mut data = Vec::with_capacity(capped_capacity(num_entries));
 
         let ancestry = Ancestry::new(base.get_class_type_for_ancestry(asset));
 
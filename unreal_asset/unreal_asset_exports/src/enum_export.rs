@@ -10,7 +10,7 @@ use unreal_asset_base::{
     object_version::ObjectVersion,
     reader::{ArchiveReader, ArchiveWriter},
     types::{FName, PackageIndexTrait},
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 use crate::implement_get;
@@ -31,7 +31,7 @@ pub enum ECppForm {
 }
 
 /// Enum
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct UEnum {
     /// Enum names
     pub names: Vec<(FName, i64)>,
@@ -131,7 +131,7 @@ impl UEnum {
 }
 
 /// Enum export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EnumExport<Index: PackageIndexTrait> {
     /// Base normal export
     pub normal_export: NormalExport<Index>,
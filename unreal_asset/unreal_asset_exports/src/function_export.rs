@@ -6,14 +6,14 @@ use unreal_asset_base::{
     flags::EFunctionFlags,
     reader::{ArchiveReader, ArchiveWriter},
     types::PackageIndexTrait,
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 use crate::{BaseExport, StructExport};
 use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
 
 /// Function export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FunctionExport<Index: PackageIndexTrait> {
     /// Base struct export
     pub struct_export: StructExport<Index>,
@@ -5,7 +5,7 @@ use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 use unreal_asset_base::{
     reader::{ArchiveReader, ArchiveWriter},
     types::{PackageIndex, PackageIndexTrait},
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 use crate::implement_get;
@@ -13,7 +13,7 @@ use crate::ExportTrait;
 use crate::{BaseExport, NormalExport};
 
 /// Level URL info
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct URL {
     /// Level protocol like "unreal" or "http"
     pub protocol: Option<String>,
@@ -32,7 +32,7 @@ pub struct URL {
 }
 
 /// Level export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LevelExport<Index: PackageIndexTrait> {
     /// Base normal export
     pub normal_export: NormalExport<Index>,
@@ -41,21 +41,27 @@ pub struct LevelExport<Index: PackageIndexTrait> {
     pub url: URL,
     /// Level actors
     #[container_ignore]
+    #[container_index]
     pub actors: Vec<PackageIndex>,
     /// Model export reference
     #[container_ignore]
+    #[container_index]
     pub model: PackageIndex,
     /// Model component references
     #[container_ignore]
+    #[container_index]
     pub model_components: Vec<PackageIndex>,
     /// Level script reference
     #[container_ignore]
+    #[container_index]
     pub level_script: PackageIndex,
     /// start of the navigation component list
     #[container_ignore]
+    #[container_index]
     pub nav_list_start: PackageIndex,
     /// end of the navigation component list
     #[container_ignore]
+    #[container_index]
     pub nav_list_end: PackageIndex,
 }
 
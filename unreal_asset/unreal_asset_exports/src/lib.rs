@@ -5,7 +5,9 @@
 
 use std::fmt::Debug;
 
-use unreal_asset_base::{reader::ArchiveWriter, types::PackageIndexTrait, Error, FNameContainer};
+use unreal_asset_base::{
+    reader::ArchiveWriter, types::PackageIndexTrait, Error, FNameContainer, PackageIndexContainer,
+};
 
 pub mod properties;
 
@@ -86,7 +88,7 @@ pub trait ExportTrait<Index: PackageIndexTrait>: Debug + Clone + PartialEq + Eq
 }
 
 /// Export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq)]
 #[container_nobounds]
 pub enum Export<Index: PackageIndexTrait> {
     /// Base export
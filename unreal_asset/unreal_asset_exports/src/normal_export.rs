@@ -2,11 +2,13 @@
 
 use unreal_asset_base::{
     reader::{ArchiveReader, ArchiveWriter},
-    types::PackageIndexTrait,
+    types::{FName, PackageIndexTrait},
     unversioned::{header::UnversionedHeader, Ancestry},
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
+};
+use unreal_asset_properties::{
+    generate_unversioned_header, property_path::PropertyAccess, Property,
 };
-use unreal_asset_properties::{generate_unversioned_header, Property};
 
 use crate::BaseExport;
 use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
@@ -14,7 +16,7 @@ use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
 /// Normal export
 ///
 /// This export is usually the base export for all other exports
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NormalExport<Index: PackageIndexTrait> {
     /// Base export
     pub base_export: BaseExport<Index>,
@@ -69,6 +71,26 @@ impl<Index: PackageIndexTrait> NormalExport<Index> {
     }
 }
 
+impl<Index: PackageIndexTrait> PropertyAccess for NormalExport<Index> {
+    fn properties(&self) -> &[Property] {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut Vec<Property> {
+        &mut self.properties
+    }
+
+    fn parent_name(&self) -> &FName {
+        &self.base_export.object_name
+    }
+
+    fn properties_ancestry(&self) -> Ancestry {
+        // the class type can't be resolved without the asset,
+        // new properties take their ancestry from existing ones when possible
+        Ancestry::default()
+    }
+}
+
 impl<Index: PackageIndexTrait> ExportTrait<Index> for NormalExport<Index> {
     fn write<Writer: ArchiveWriter<Index>>(&self, asset: &mut Writer) -> Result<(), Error> {
         let (unversioned_header, sorted_properties) = match generate_unversioned_header(
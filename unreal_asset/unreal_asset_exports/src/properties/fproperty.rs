@@ -11,13 +11,15 @@ use unreal_asset_base::{
     flags::{EObjectFlags, EPropertyFlags},
     reader::{ArchiveReader, ArchiveWriter},
     types::{fname::ToSerializedName, FName, PackageIndex, PackageIndexTrait},
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 macro_rules! parse_simple_property {
     ($prop_name:ident) => {
         /// $prop_name
-        #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+        #[derive(
+            FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash,
+        )]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: FGenericProperty,
@@ -55,13 +57,14 @@ macro_rules! parse_simple_property_index {
         ),*
     ) => {
         /// $prop_name
-        #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+        #[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: FGenericProperty,
             $(
                 $(#[$inner $($args)*])*
                 #[container_ignore]
+                #[container_index]
                 pub $index_name: PackageIndex,
             )*
         }
@@ -99,7 +102,7 @@ macro_rules! parse_simple_property_prop {
         ),*
     ) => {
         /// $prop_name
-        #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+        #[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: FGenericProperty,
@@ -145,7 +148,7 @@ pub trait FPropertyTrait: Debug + Clone + PartialEq + Eq + Hash {
 
 /// FProperty
 #[enum_dispatch(FPropertyTrait)]
-#[derive(FNameContainer, Hash, PartialEq, Clone, Debug)]
+#[derive(FNameContainer, PackageIndexContainer, Hash, PartialEq, Clone, Debug)]
 #[container_nobounds]
 pub enum FProperty {
     /// Generic FProperty
@@ -187,6 +190,29 @@ pub enum FProperty {
 impl Eq for FProperty {}
 
 impl FProperty {
+    /// Get the generic property shared by all `FProperty` types
+    pub fn get_generic_property(&self) -> &FGenericProperty {
+        match self {
+            FProperty::FGenericProperty(e) => e,
+            FProperty::FEnumProperty(e) => &e.generic_property,
+            FProperty::FArrayProperty(e) => &e.generic_property,
+            FProperty::FSetProperty(e) => &e.generic_property,
+            FProperty::FObjectProperty(e) => &e.generic_property,
+            FProperty::FSoftObjectProperty(e) => &e.generic_property,
+            FProperty::FClassProperty(e) => &e.generic_property,
+            FProperty::FSoftClassProperty(e) => &e.generic_property,
+            FProperty::FDelegateProperty(e) => &e.generic_property,
+            FProperty::FMulticastDelegateProperty(e) => &e.generic_property,
+            FProperty::FMulticastInlineDelegateProperty(e) => &e.generic_property,
+            FProperty::FInterfaceProperty(e) => &e.generic_property,
+            FProperty::FMapProperty(e) => &e.generic_property,
+            FProperty::FBoolProperty(e) => &e.generic_property,
+            FProperty::FByteProperty(e) => &e.generic_property,
+            FProperty::FStructProperty(e) => &e.generic_property,
+            FProperty::FNumericProperty(e) => &e.generic_property,
+        }
+    }
+
     /// Read an `FProperty` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
@@ -265,7 +291,7 @@ impl ToSerializedName for FProperty {
 }
 
 /// Generic FProperty
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct FGenericProperty {
     /// Property name
     pub name: FName,
@@ -292,19 +318,20 @@ pub struct FGenericProperty {
 }
 
 /// Enum FProperty
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FEnumProperty {
     /// Generic property
     pub generic_property: FGenericProperty,
     /// Enum value
     #[container_ignore]
+    #[container_index]
     pub enum_value: PackageIndex,
     /// Underlying property
     pub underlying_prop: Box<FProperty>,
 }
 
 /// Boolean FProperty
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct FBoolProperty {
     /// Generic property
     pub generic_property: FGenericProperty,
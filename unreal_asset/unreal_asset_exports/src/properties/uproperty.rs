@@ -12,13 +12,13 @@ use unreal_asset_base::{
     flags::EPropertyFlags,
     reader::{ArchiveReader, ArchiveWriter},
     types::{FName, PackageIndex, PackageIndexTrait},
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 macro_rules! parse_simple_property {
     ($prop_name:ident) => {
         /// $prop_name
-        #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+        #[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: UGenericProperty
@@ -50,13 +50,14 @@ macro_rules! parse_simple_property {
         ),*
     ) => {
         /// $prop_name
-        #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+        #[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
         pub struct $prop_name {
             /// Generic property
             pub generic_property: UGenericProperty,
             $(
                 $(#[$inner $($args)*])*
                 #[container_ignore]
+                #[container_index]
                 pub $field_name: PackageIndex,
             )*
         }
@@ -97,7 +98,7 @@ pub trait UPropertyTrait: Debug + Clone + PartialEq + Eq + Hash {
 
 /// UProperty
 #[enum_dispatch(UPropertyTrait)]
-#[derive(FNameContainer, Debug, Clone, PartialEq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Hash)]
 #[container_nobounds]
 pub enum UProperty {
     /// Generic UProperty
@@ -161,6 +162,40 @@ pub enum UProperty {
 impl Eq for UProperty {}
 
 impl UProperty {
+    /// Get the generic property shared by all `UProperty` types
+    pub fn get_generic_property(&self) -> &UGenericProperty {
+        match self {
+            UProperty::UGenericProperty(e) => e,
+            UProperty::UEnumProperty(e) => &e.generic_property,
+            UProperty::UArrayProperty(e) => &e.generic_property,
+            UProperty::USetProperty(e) => &e.generic_property,
+            UProperty::UObjectProperty(e) => &e.generic_property,
+            UProperty::USoftObjectProperty(e) => &e.generic_property,
+            UProperty::ULazyObjectProperty(e) => &e.generic_property,
+            UProperty::UClassProperty(e) => &e.generic_property,
+            UProperty::USoftClassProperty(e) => &e.generic_property,
+            UProperty::UDelegateProperty(e) => &e.generic_property,
+            UProperty::UMulticastDelegateProperty(e) => &e.generic_property,
+            UProperty::UMulticastInlineDelegateProperty(e) => &e.generic_property,
+            UProperty::UInterfaceProperty(e) => &e.generic_property,
+            UProperty::UMapProperty(e) => &e.generic_property,
+            UProperty::UBoolProperty(e) => &e.generic_property,
+            UProperty::UByteProperty(e) => &e.generic_property,
+            UProperty::UStructProperty(e) => &e.generic_property,
+            UProperty::UDoubleProperty(e) => &e.generic_property,
+            UProperty::UFloatProperty(e) => &e.generic_property,
+            UProperty::UIntProperty(e) => &e.generic_property,
+            UProperty::UInt8Property(e) => &e.generic_property,
+            UProperty::UInt16Property(e) => &e.generic_property,
+            UProperty::UInt64Property(e) => &e.generic_property,
+            UProperty::UUInt8Property(e) => &e.generic_property,
+            UProperty::UUInt16Property(e) => &e.generic_property,
+            UProperty::UUInt64Property(e) => &e.generic_property,
+            UProperty::UNameProperty(e) => &e.generic_property,
+            UProperty::UStrProperty(e) => &e.generic_property,
+        }
+    }
+
     /// Read a `UProperty` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
@@ -205,17 +240,18 @@ impl UProperty {
 }
 
 /// UField
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct UField {
     /// Next field package index
     pub next: Option<PackageIndex>,
 }
 
 /// Generic UProperty
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct UGenericProperty {
     /// UField
     #[container_ignore]
+    #[container_index]
     pub u_field: UField,
     /// Array dimension
     #[container_ignore]
@@ -231,7 +267,7 @@ pub struct UGenericProperty {
 }
 
 /// Boolean UProperty
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct UBoolProperty {
     /// Generic property
     pub generic_property: UGenericProperty,
@@ -5,7 +5,7 @@ use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 use unreal_asset_base::{
     reader::{ArchiveReader, ArchiveWriter},
     types::PackageIndexTrait,
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 use crate::implement_get;
@@ -16,7 +16,7 @@ use crate::{BaseExport, NormalExport};
 /// Property export
 ///
 /// This is a `UProperty` export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PropertyExport<Index: PackageIndexTrait> {
     /// Base normal export
     pub normal_export: NormalExport<Index>,
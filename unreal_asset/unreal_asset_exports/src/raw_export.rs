@@ -3,19 +3,22 @@
 use unreal_asset_base::{
     reader::{ArchiveReader, ArchiveWriter},
     types::PackageIndexTrait,
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 use crate::BaseExport;
 use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
 
 /// An export that failed to deserialize is stored as `Vec<u8>`
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RawExport<Index: PackageIndexTrait> {
     /// Base export
     pub base_export: BaseExport<Index>,
     /// Raw data
     pub data: Vec<u8>,
+    /// The data hasn't been read yet, it is still in the source at the export's
+    /// `serial_offset` and `serial_size`
+    pub pending: bool,
 }
 
 impl<Index: PackageIndexTrait> ExportNormalTrait<Index> for RawExport<Index> {
@@ -46,18 +49,32 @@ impl<Index: PackageIndexTrait> RawExport<Index> {
         base: BaseExport<Index>,
         asset: &mut Reader,
     ) -> Result<Self, Error> {
-        let mut data = vec![0u8; base.serial_size as usize];
-        asset.read_exact(&mut data)?;
+        let data = asset.read_bytes(base.serial_size as usize)?;
 
         Ok(RawExport {
             base_export: base,
             data,
+            pending: false,
         })
     }
+
+    /// Create a `RawExport` whose data is left in the source
+    pub fn pending(base: BaseExport<Index>) -> Self {
+        RawExport {
+            base_export: base,
+            data: Vec::new(),
+            pending: true,
+        }
+    }
 }
 
 impl<Index: PackageIndexTrait> ExportTrait<Index> for RawExport<Index> {
     fn write<Writer: ArchiveWriter<Index>>(&self, asset: &mut Writer) -> Result<(), Error> {
+        if self.pending {
+            return Err(Error::no_data(
+                "RawExport data hasn't been read from the source".to_string(),
+            ));
+        }
         asset.write_all(&self.data)?;
         Ok(())
     }
@@ -6,7 +6,7 @@ use unreal_asset_base::{
     containers::IndexedMap,
     reader::{ArchiveReader, ArchiveWriter},
     types::PackageIndexTrait,
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 use crate::implement_get;
@@ -14,7 +14,7 @@ use crate::ExportTrait;
 use crate::{BaseExport, NormalExport};
 
 /// String table export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq)]
 pub struct StringTableExport<Index: PackageIndexTrait> {
     /// Base normal export
     pub normal_export: NormalExport<Index>,
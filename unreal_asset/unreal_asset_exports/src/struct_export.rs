@@ -7,9 +7,9 @@ use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 use unreal_asset_base::{
     custom_version::FCoreObjectVersion,
     engine_version::EngineVersion,
-    reader::{ArchiveReader, ArchiveWriter},
+    reader::{capped_capacity, ArchiveReader, ArchiveWriter},
     types::{PackageIndex, PackageIndexTrait},
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 use unreal_asset_kismet::KismetExpression;
 
@@ -19,18 +19,21 @@ use crate::ExportTrait;
 use crate::{BaseExport, NormalExport};
 
 /// Struct export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StructExport<Index: PackageIndexTrait> {
     /// Base normal export
     pub normal_export: NormalExport<Index>,
     /// Field
     #[container_ignore]
+    #[container_index]
     pub field: UField,
     /// Super struct
     #[container_ignore]
+    #[container_index]
     pub super_struct: PackageIndex,
     /// Children
     #[container_ignore]
+    #[container_index]
     pub children: Vec<PackageIndex>,
     /// Loaded properties
     pub loaded_properties: Vec<FProperty>,
@@ -56,7 +59,7 @@ impl<Index: PackageIndexTrait> StructExport<Index> {
         let super_struct = PackageIndex::new(asset.read_i32::<LE>()?);
 
         let num_index_entries = asset.read_i32::<LE>()?;
-        let mut children = Vec::with_capacity(num_index_entries as usize);
+        let mut children = Vec::with_capacity(capped_capacity(num_index_entries));
         for _i in 0..num_index_entries as usize {
             children.push(PackageIndex::new(asset.read_i32::<LE>()?));
         }
@@ -66,7 +69,7 @@ impl<Index: PackageIndexTrait> StructExport<Index> {
         {
             true => {
                 let num_props = asset.read_i32::<LE>()?;
-                let mut props = Vec::with_capacity(num_props as usize);
+                let mut props = Vec::with_capacity(capped_capacity(num_props));
                 for _i in 0..num_props as usize {
                     props.push(FProperty::new(asset)?);
                 }
@@ -89,8 +92,7 @@ impl<Index: PackageIndexTrait> StructExport<Index> {
             Some(_) => None,
             None => {
                 asset.seek(SeekFrom::Start(start_offset))?;
-                let mut data = vec![0u8; script_storage_size as usize];
-                asset.read_exact(&mut data)?;
+                let data = asset.read_bytes(script_storage_size as usize)?;
                 Some(data)
             }
         };
@@ -7,7 +7,7 @@ use unreal_asset_base::{
     reader::{ArchiveReader, ArchiveWriter},
     types::PackageIndexTrait,
     unversioned::{header::UnversionedHeader, Ancestry},
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 use unreal_asset_properties::Property;
 
@@ -15,7 +15,7 @@ use crate::{BaseExport, NormalExport, StructExport};
 use crate::{ExportBaseTrait, ExportNormalTrait, ExportTrait};
 
 /// Struct export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UserDefinedStructExport<Index: PackageIndexTrait> {
     /// Base struct export
     pub struct_export: StructExport<Index>,
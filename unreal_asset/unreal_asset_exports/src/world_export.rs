@@ -5,7 +5,7 @@ use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 use unreal_asset_base::{
     reader::{ArchiveReader, ArchiveWriter},
     types::{PackageIndex, PackageIndexTrait},
-    Error, FNameContainer,
+    Error, FNameContainer, PackageIndexContainer,
 };
 
 use crate::implement_get;
@@ -15,18 +15,21 @@ use crate::{BaseExport, NormalExport};
 /// World export
 ///
 /// This is a `World` export
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WorldExport<Index: PackageIndexTrait> {
     /// Base normal export
     pub normal_export: NormalExport<Index>,
     /// Persistent level - a LevelExport
     #[container_ignore]
+    #[container_index]
     pub persistent_level: PackageIndex,
     /// Extra objects
     #[container_ignore]
+    #[container_index]
     pub extra_objects: Vec<PackageIndex>,
     /// Levels streaming in the world
     #[container_ignore]
+    #[container_index]
     pub streaming_levels: Vec<PackageIndex>,
 }
 
@@ -3,6 +3,7 @@
 
 //! Unreal asset kismet byte code
 
+use std::cell::Cell;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::mem::size_of;
@@ -12,13 +13,13 @@ use enum_dispatch::enum_dispatch;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use ordered_float::OrderedFloat;
 
-use unreal_asset_base::FNameContainer;
+use unreal_asset_base::{FNameContainer, PackageIndexContainer};
 
 use unreal_asset_base::types::PackageIndexTrait;
 use unreal_asset_base::{
     error::KismetError,
     object_version::{ObjectVersion, ObjectVersionUE5},
-    reader::{ArchiveReader, ArchiveWriter},
+    reader::{capped_capacity, ArchiveReader, ArchiveWriter},
     types::{
         vector::{Transform, Vector, Vector4},
         {FName, PackageIndex},
@@ -348,12 +349,13 @@ pub enum EBlueprintTextLiteralType {
 }
 
 /// Kismet field path
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct FieldPath {
     /// Path
     pub path: Vec<FName>,
     /// Path owner
     #[container_ignore]
+    #[container_index]
     pub resolved_owner: PackageIndex,
 }
 
@@ -436,7 +438,7 @@ macro_rules! declare_expression {
         ),*
     ) => {
         #[doc = stringify!($name)]
-        #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+        #[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
         pub struct $name {
             /// Kismet token
             #[container_ignore]
@@ -466,7 +468,7 @@ macro_rules! implement_expression {
     ) => {
         $(
             $(#[$inner $($args)*])*
-            #[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+            #[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
             pub struct $name {
                 /// Kismet token
                 #[container_ignore]
@@ -556,7 +558,7 @@ macro_rules! implement_value_expression {
 }
 
 /// Kismet script text
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FScriptText {
     /// Literal type
     #[container_ignore]
@@ -573,6 +575,7 @@ pub struct FScriptText {
     pub literal_string: Option<KismetExpression>,
     /// String table asset this text is localized from
     #[container_ignore]
+    #[container_index]
     pub string_table_asset: Option<PackageIndex>,
     /// String table id in the string table asset
     pub string_table_id: Option<KismetExpression>,
@@ -725,10 +728,11 @@ impl FScriptText {
 
 // todo: replace with an enum with 2 variants
 /// Represents a Kismet bytecode pointer to an FProperty or FField.
-#[derive(FNameContainer, Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct KismetPropertyPointer {
     /// Pointer serialized as PackageIndex. Used in versions older than [`KismetPropertyPointer::XFER_PROP_POINTER_SWITCH_TO_SERIALIZING_AS_FIELD_PATH_VERSION`]
     #[container_ignore]
+    #[container_index]
     pub old: Option<PackageIndex>,
     /// Pointer serialized as an FFieldPath. Used in versions newer than [`KismetPropertyPointer::XFER_PROP_POINTER_SWITCH_TO_SERIALIZING_AS_FIELD_PATH_VERSION`]
     pub new: Option<FieldPath>,
@@ -762,7 +766,7 @@ impl KismetPropertyPointer {
             >= KismetPropertyPointer::XFER_PROP_POINTER_SWITCH_TO_SERIALIZING_AS_FIELD_PATH_VERSION
         {
             let num_entries = asset.read_i32::<LE>()?;
-            let mut names = Vec::with_capacity(num_entries as usize);
+            let mut names = Vec::with_capacity(capped_capacity(num_entries));
             for _i in 0..num_entries as usize {
                 names.push(asset.read_fname()?);
             }
@@ -807,7 +811,7 @@ impl KismetPropertyPointer {
 }
 
 /// Kismet switch case
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KismetSwitchCase {
     /// Case value index
     pub case_index_value_term: KismetExpression,
@@ -862,7 +866,7 @@ pub trait KismetExpressionEnumEqTrait {
     KismetExpressionEnumEqTrait,
     KismetExpressionDataTrait
 )]
-#[derive(FNameContainer, Debug, Clone, PartialEq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Hash)]
 #[container_nobounds]
 pub enum KismetExpression {
     /// A local variable.
@@ -1057,10 +1061,35 @@ pub enum KismetExpression {
 
 impl Eq for KismetExpression {}
 
+/// Maximum nesting depth of kismet expressions
+///
+/// Expressions are read recursively, malformed bytecode could otherwise overflow the stack.
+const MAX_EXPRESSION_DEPTH: u32 = 256;
+
+thread_local! {
+    /// Nesting depth of the kismet expression currently being read on this thread
+    static EXPRESSION_DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
 impl KismetExpression {
     /// Read a `KismetExpression` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let depth = EXPRESSION_DEPTH.get() + 1;
+        if depth > MAX_EXPRESSION_DEPTH {
+            return Err(KismetError::nesting_too_deep(MAX_EXPRESSION_DEPTH).into());
+        }
+
+        EXPRESSION_DEPTH.set(depth);
+        let expr = Self::read_expression(asset);
+        EXPRESSION_DEPTH.set(depth - 1);
+        expr
+    }
+
+    /// Read a `KismetExpression` without checking the nesting depth
+    fn read_expression<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
     ) -> Result<Self, Error> {
         let token: EExprToken = asset.read_u8()?.try_into()?;
         let expr: Result<Self, Error> = match token {
@@ -1254,6 +1283,7 @@ declare_expression!(
     ExObjectConst,
     /// Value
     #[container_ignore]
+    #[container_index]
     value: PackageIndex
 );
 impl ExObjectConst {
@@ -1641,6 +1671,7 @@ declare_expression!(
     ExCallMath,
     /// Stack node
     #[container_ignore]
+    #[container_index]
     stack_node: PackageIndex,
     /// Parameters
     parameters: Vec<KismetExpression>
@@ -1675,6 +1706,7 @@ declare_expression!(
     ExCallMulticastDelegate,
     /// Stack node
     #[container_ignore]
+    #[container_index]
     stack_node: PackageIndex,
     /// Parameters
     parameters: Vec<KismetExpression>,
@@ -1902,6 +1934,7 @@ declare_expression!(
     ExCrossInterfaceCast,
     /// Class pointer
     #[container_ignore]
+    #[container_index]
     class_ptr: PackageIndex,
     /// Cast target
     target: Box<KismetExpression>
@@ -1957,6 +1990,7 @@ declare_expression!(
     ExDynamicCast,
     /// Class pointer
     #[container_ignore]
+    #[container_index]
     class_ptr: PackageIndex,
     /// Cast target
     target_expression: Box<KismetExpression>
@@ -1988,6 +2022,7 @@ declare_expression!(
     ExFinalFunction,
     /// Stack node
     #[container_ignore]
+    #[container_index]
     stack_node: PackageIndex,
     /// Parameters
     parameters: Vec<KismetExpression>
@@ -2095,6 +2130,7 @@ declare_expression!(
     ExInterfaceToObjCast,
     /// Class pointer
     #[container_ignore]
+    #[container_index]
     class_ptr: PackageIndex,
     /// Cast target
     target: Box<KismetExpression>
@@ -2388,6 +2424,7 @@ declare_expression!(
     ExLocalFinalFunction,
     /// Stack node
     #[container_ignore]
+    #[container_index]
     stack_node: PackageIndex,
     /// Function parameters
     parameters: Vec<KismetExpression>
@@ -2545,6 +2582,7 @@ declare_expression!(
     ExMetaCast,
     /// Class pointer
     #[container_ignore]
+    #[container_index]
     class_ptr: PackageIndex,
     /// Target expression
     target_expression: Box<KismetExpression>
@@ -2576,6 +2614,7 @@ declare_expression!(
     ExObjToInterfaceCast,
     /// Class pointer
     #[container_ignore]
+    #[container_index]
     class_ptr: PackageIndex,
     /// Target expression
     target: Box<KismetExpression>
@@ -2831,6 +2870,7 @@ declare_expression!(
     assigning_property: Option<Box<KismetExpression>>,
     /// Array inner prop
     #[container_ignore]
+    #[container_index]
     array_inner_prop: Option<PackageIndex>,
     /// Elements
     elements: Vec<KismetExpression>
@@ -3031,6 +3071,7 @@ declare_expression!(
     ExStructConst,
     /// Struct value
     #[container_ignore]
+    #[container_index]
     struct_value: PackageIndex,
     /// Struct size
     struct_size: i32,
@@ -3117,7 +3158,7 @@ impl ExSwitchValue {
         let end_goto_offset = asset.read_u32::<LE>()?;
         let index_term = Box::new(KismetExpression::new(asset)?);
 
-        let mut cases = Vec::with_capacity(num_cases as usize);
+        let mut cases = Vec::with_capacity(capped_capacity(num_cases));
         for _i in 0..num_cases as usize {
             let term_a = KismetExpression::new(asset)?;
             let term_b = asset.read_u32::<LE>()?;
@@ -1,6 +1,6 @@
-//! FName Container derive macro
+//! Container derive macros
 //!
-//! This macro is used to grab all FNames inside of a container and traverse them
+//! These macros are used to grab all values of a type inside of a container and traverse them
 
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
@@ -8,16 +8,59 @@ use syn::{parse_macro_input, DataEnum, DataStruct, DeriveInput, FieldsNamed, Fie
 
 /// Ignore attribute name
 const IGNORE_ATTRIBUTE: &str = "container_ignore";
+/// Package index attribute name
+///
+/// Marks a field ignored by `FNameContainer` which still contains package indices
+const INDEX_ATTRIBUTE: &str = "container_index";
 /// No trait bounds attribute name
 /// This is used to prevent trait bounds resolution cycles
 const NO_BOUNDS: &str = "container_nobounds";
-/// Trait name
-const TRAIT_NAME: &str = "unreal_asset_base::types::fname::FNameContainer";
-/// FName path
-const FNAME_PATH: &str = "unreal_asset_base::types::fname::FName";
 
-/// FName Container derive macro
-pub fn derive_fname_container(input: TokenStream) -> TokenStream {
+/// Container trait description
+pub struct Container {
+    /// Trait name
+    trait_name: &'static str,
+    /// Traverse function name
+    function_name: &'static str,
+    /// Traversed item path
+    item_path: &'static str,
+    /// Should fields marked with the package index attribute be traversed
+    traverse_index_fields: bool,
+    /// Should generic type parameters be bounded when field bounds aren't generated
+    ///
+    /// Generic index types are only containers if they are bounded explicitly
+    bound_type_params: bool,
+}
+
+/// `FNameContainer` trait
+pub const FNAME_CONTAINER: Container = Container {
+    trait_name: "unreal_asset_base::types::fname::FNameContainer",
+    function_name: "traverse_fnames",
+    item_path: "unreal_asset_base::types::fname::FName",
+    traverse_index_fields: false,
+    bound_type_params: false,
+};
+
+/// `PackageIndexContainer` trait
+pub const PACKAGE_INDEX_CONTAINER: Container = Container {
+    trait_name: "unreal_asset_base::types::PackageIndexContainer",
+    function_name: "traverse_package_indices",
+    item_path: "unreal_asset_base::types::PackageIndex",
+    traverse_index_fields: true,
+    bound_type_params: true,
+};
+
+impl Container {
+    /// Check if a field should be traversed
+    fn should_traverse(&self, field: &syn::Field) -> bool {
+        let has_attribute = |name: &str| field.attrs.iter().any(|e| e.path().is_ident(name));
+        !has_attribute(IGNORE_ATTRIBUTE)
+            || (self.traverse_index_fields && has_attribute(INDEX_ATTRIBUTE))
+    }
+}
+
+/// Container derive macro
+pub fn derive_container(input: TokenStream, container: &Container) -> TokenStream {
     let DeriveInput {
         data,
         generics,
@@ -30,10 +73,10 @@ pub fn derive_fname_container(input: TokenStream) -> TokenStream {
 
     let body = match &data {
         syn::Data::Struct(e) => {
-            generate_body_for_struct(&name, &generics, e, should_generate_bounds, &[TRAIT_NAME])
+            generate_body_for_struct(&name, &generics, e, should_generate_bounds, container)
         }
         syn::Data::Enum(e) => {
-            generate_body_for_enum(&name, &generics, e, should_generate_bounds, &[TRAIT_NAME])
+            generate_body_for_enum(&name, &generics, e, should_generate_bounds, container)
         }
         syn::Data::Union(_) => panic!("This macro cannot be used on unit structs!"),
     };
@@ -46,13 +89,13 @@ fn generate_body_for_struct(
     generics: &syn::Generics,
     data_struct: &DataStruct,
     should_generate_bounds: bool,
-    traits: &[&str],
+    container: &Container,
 ) -> proc_macro2::TokenStream {
     let (impl_generics, ty_generics, old_where_clause) = generics.split_for_impl();
 
     let new_where_clause = match should_generate_bounds {
         true => {
-            let new_where_clause = add_trait_bounds_for_all_struct_fields(data_struct, traits);
+            let new_where_clause = add_trait_bounds_for_all_struct_fields(data_struct, container);
             let mut new_where_clause: syn::WhereClause = syn::parse2(new_where_clause).unwrap();
             if let Some(old_where_clause) = old_where_clause {
                 new_where_clause
@@ -61,21 +104,22 @@ fn generate_body_for_struct(
             }
             quote! { #new_where_clause }
         }
-        false => quote! { #old_where_clause },
+        false => type_param_bounds(generics, container),
     };
 
     let body = match &data_struct.fields {
-        syn::Fields::Named(e) => body_for_struct_named_fields(e),
-        syn::Fields::Unnamed(e) => body_for_struct_unnamed_fields(e),
+        syn::Fields::Named(e) => body_for_struct_named_fields(e, container),
+        syn::Fields::Unnamed(e) => body_for_struct_unnamed_fields(e, container),
         syn::Fields::Unit => quote! {},
     };
 
-    let trait_name: syn::Path = syn::parse_str(TRAIT_NAME).unwrap();
-    let fname_path: syn::Path = syn::parse_str(FNAME_PATH).unwrap();
+    let trait_name: syn::Path = syn::parse_str(container.trait_name).unwrap();
+    let item_path: syn::Path = syn::parse_str(container.item_path).unwrap();
+    let function_name = format_ident!("{}", container.function_name);
 
     quote! {
         impl #impl_generics #trait_name for #name #ty_generics #new_where_clause {
-            fn traverse_fnames<F: FnMut(&mut #fname_path)>(&mut self, traverse: &mut F) {
+            fn #function_name<F: FnMut(&mut #item_path)>(&mut self, traverse: &mut F) {
                 #body
             }
         }
@@ -87,13 +131,13 @@ fn generate_body_for_enum(
     generics: &syn::Generics,
     data_enum: &DataEnum,
     should_generate_bounds: bool,
-    traits: &[&str],
+    container: &Container,
 ) -> proc_macro2::TokenStream {
     let (impl_generics, ty_generics, old_where_clause) = generics.split_for_impl();
 
     let new_where_clause = match should_generate_bounds {
         true => {
-            let new_where_clause = add_trait_bounds_for_all_enum_variants(data_enum, traits);
+            let new_where_clause = add_trait_bounds_for_all_enum_variants(data_enum, container);
             let mut new_where_clause: syn::WhereClause = syn::parse2(new_where_clause).unwrap();
             if let Some(old_where_clause) = old_where_clause {
                 new_where_clause
@@ -102,17 +146,18 @@ fn generate_body_for_enum(
             }
             quote! { #new_where_clause }
         }
-        false => quote! { #old_where_clause },
+        false => type_param_bounds(generics, container),
     };
 
-    let body = body_for_enum_variants(data_enum);
+    let body = body_for_enum_variants(data_enum, container);
 
-    let trait_name: syn::Path = syn::parse_str(TRAIT_NAME).unwrap();
-    let fname_path: syn::Path = syn::parse_str(FNAME_PATH).unwrap();
+    let trait_name: syn::Path = syn::parse_str(container.trait_name).unwrap();
+    let item_path: syn::Path = syn::parse_str(container.item_path).unwrap();
+    let function_name = format_ident!("{}", container.function_name);
 
     quote! {
         impl #impl_generics #trait_name for #name #ty_generics #new_where_clause {
-            fn traverse_fnames<F: FnMut(&mut #fname_path)>(&mut self, traverse: &mut F) {
+            fn #function_name<F: FnMut(&mut #item_path)>(&mut self, traverse: &mut F) {
                 match self {
                     #body
                     _ => {}
@@ -122,13 +167,35 @@ fn generate_body_for_enum(
     }
 }
 
+/// Where clause bounding all generic type parameters, used when field bounds aren't generated
+fn type_param_bounds(generics: &syn::Generics, container: &Container) -> proc_macro2::TokenStream {
+    let (_, _, old_where_clause) = generics.split_for_impl();
+    if !container.bound_type_params || generics.type_params().next().is_none() {
+        return quote! { #old_where_clause };
+    }
+
+    let traits: syn::Type = syn::parse_str(container.trait_name).unwrap();
+    let bounds = generics.type_params().map(|e| {
+        let ident = &e.ident;
+        quote! { #ident: #traits, }
+    });
+    let old_predicates = old_where_clause.map(|e| {
+        let predicates = &e.predicates;
+        quote! { #predicates }
+    });
+
+    quote! {
+        where #(#bounds)* #old_predicates
+    }
+}
+
 fn add_trait_bounds_for_all_struct_fields(
     data_struct: &DataStruct,
-    traits: &[&str],
+    container: &Container,
 ) -> proc_macro2::TokenStream {
-    let traits: syn::Type = syn::parse_str(&traits.join(" + ")).unwrap();
+    let traits: syn::Type = syn::parse_str(container.trait_name).unwrap();
 
-    let bounds = for_each_field_type(&data_struct.fields, |field_type| {
+    let bounds = for_each_field_type(&data_struct.fields, container, |field_type| {
         quote! {
             #field_type: #traits,
         }
@@ -141,14 +208,14 @@ fn add_trait_bounds_for_all_struct_fields(
 
 fn add_trait_bounds_for_all_enum_variants(
     data_enum: &DataEnum,
-    traits: &[&str],
+    container: &Container,
 ) -> proc_macro2::TokenStream {
-    let traits: syn::Type = syn::parse_str(&traits.join(" + ")).unwrap();
+    let traits: syn::Type = syn::parse_str(container.trait_name).unwrap();
 
     let bounds =
         proc_macro2::TokenStream::from_iter(data_enum.variants.iter().map(|e| match &e.fields {
             syn::Fields::Unnamed(unnamed_fields) => {
-                for_each_unnamed_field_type(unnamed_fields, |field_type| {
+                for_each_unnamed_field_type(unnamed_fields, container, |field_type| {
                     quote! {
                         #field_type: #traits,
                     }
@@ -167,12 +234,15 @@ fn add_trait_bounds_for_all_enum_variants(
 
 fn for_each_field_type<F: Fn(&syn::Type) -> proc_macro2::TokenStream>(
     fields: &syn::Fields,
+    container: &Container,
     executor: F,
 ) -> proc_macro2::TokenStream {
     match fields {
-        syn::Fields::Named(fields_named) => for_each_named_field_type(fields_named, executor),
+        syn::Fields::Named(fields_named) => {
+            for_each_named_field_type(fields_named, container, executor)
+        }
         syn::Fields::Unnamed(fields_unnamed) => {
-            for_each_unnamed_field_type(fields_unnamed, executor)
+            for_each_unnamed_field_type(fields_unnamed, container, executor)
         }
         syn::Fields::Unit => quote! {},
     }
@@ -180,12 +250,13 @@ fn for_each_field_type<F: Fn(&syn::Type) -> proc_macro2::TokenStream>(
 
 fn for_each_named_field_type<F: Fn(&syn::Type) -> proc_macro2::TokenStream>(
     fields_named: &FieldsNamed,
+    container: &Container,
     executor: F,
 ) -> proc_macro2::TokenStream {
     let streams = fields_named
         .named
         .iter()
-        .filter(|e| !e.attrs.iter().any(|e| e.path().is_ident(IGNORE_ATTRIBUTE)))
+        .filter(|e| container.should_traverse(e))
         .map(|e| executor(&e.ty));
 
     quote! {
@@ -195,12 +266,13 @@ fn for_each_named_field_type<F: Fn(&syn::Type) -> proc_macro2::TokenStream>(
 
 fn for_each_unnamed_field_type<F: Fn(&syn::Type) -> proc_macro2::TokenStream>(
     fields_unnamed: &FieldsUnnamed,
+    container: &Container,
     executor: F,
 ) -> proc_macro2::TokenStream {
     let streams = fields_unnamed
         .unnamed
         .iter()
-        .filter(|e| !e.attrs.iter().any(|e| e.path().is_ident(IGNORE_ATTRIBUTE)))
+        .filter(|e| container.should_traverse(e))
         .map(|e| executor(&e.ty));
 
     quote! {
@@ -208,15 +280,19 @@ fn for_each_unnamed_field_type<F: Fn(&syn::Type) -> proc_macro2::TokenStream>(
     }
 }
 
-fn body_for_struct_named_fields(fields_named: &FieldsNamed) -> proc_macro2::TokenStream {
+fn body_for_struct_named_fields(
+    fields_named: &FieldsNamed,
+    container: &Container,
+) -> proc_macro2::TokenStream {
+    let function_name = format_ident!("{}", container.function_name);
     let streams = fields_named
         .named
         .iter()
-        .filter(|e| !e.attrs.iter().any(|e| e.path().is_ident(IGNORE_ATTRIBUTE)))
+        .filter(|e| container.should_traverse(e))
         .map(|e| {
             let name = e.ident.as_ref().unwrap();
             quote! {
-                self.#name.traverse_fnames(traverse)
+                self.#name.#function_name(traverse)
             }
         });
     quote! {
@@ -224,16 +300,20 @@ fn body_for_struct_named_fields(fields_named: &FieldsNamed) -> proc_macro2::Toke
     }
 }
 
-fn body_for_struct_unnamed_fields(fields_unnamed: &FieldsUnnamed) -> proc_macro2::TokenStream {
+fn body_for_struct_unnamed_fields(
+    fields_unnamed: &FieldsUnnamed,
+    container: &Container,
+) -> proc_macro2::TokenStream {
+    let function_name = format_ident!("{}", container.function_name);
     let streams = fields_unnamed
         .unnamed
         .iter()
-        .filter(|e| !e.attrs.iter().any(|e| e.path().is_ident(IGNORE_ATTRIBUTE)))
+        .filter(|e| container.should_traverse(e))
         .enumerate()
         .map(|(index, _)| {
             let index = syn::Index::from(index);
             quote! {
-                self.#index.traverse_fnames(traverse)
+                self.#index.#function_name(traverse)
             }
         });
 
@@ -242,7 +322,8 @@ fn body_for_struct_unnamed_fields(fields_unnamed: &FieldsUnnamed) -> proc_macro2
     }
 }
 
-fn body_for_enum_variants(data_enum: &DataEnum) -> proc_macro2::TokenStream {
+fn body_for_enum_variants(data_enum: &DataEnum, container: &Container) -> proc_macro2::TokenStream {
+    let function_name = format_ident!("{}", container.function_name);
     const VARIABLE_NAMES: [&str; 26] = [
         "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r",
         "s", "t", "u", "v", "w", "x", "y", "z",
@@ -254,7 +335,7 @@ fn body_for_enum_variants(data_enum: &DataEnum) -> proc_macro2::TokenStream {
         let fields = e
             .fields
             .iter()
-            .filter(|e| !e.attrs.iter().any(|e| e.path().is_ident(IGNORE_ATTRIBUTE)))
+            .filter(|e| container.should_traverse(e))
             .enumerate()
             .map(|(index, _)| format_ident!("{}", VARIABLE_NAMES[index]));
 
@@ -262,7 +343,7 @@ fn body_for_enum_variants(data_enum: &DataEnum) -> proc_macro2::TokenStream {
 
         quote! {
             Self::#name #((#fields,))* => {
-                #(#fields_.traverse_fnames(traverse);)*
+                #(#fields_.#function_name(traverse);)*
             }
         }
     });
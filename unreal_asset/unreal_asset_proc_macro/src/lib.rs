@@ -3,7 +3,7 @@
 
 use proc_macro::TokenStream;
 
-mod fname_container;
+mod container;
 
 extern crate proc_macro;
 
@@ -13,5 +13,19 @@ extern crate proc_macro;
 /// and generate a function which can iterate over all of them mutably
 #[proc_macro_derive(FNameContainer, attributes(container_ignore, container_nobounds))]
 pub fn derive_fname_container(input: TokenStream) -> TokenStream {
-    fname_container::derive_fname_container(input)
+    container::derive_container(input, &container::FNAME_CONTAINER)
+}
+
+/// PackageIndexContainer derive macro
+///
+/// This derive macro is used to grab all PackageIndex's inside of a struct
+/// and generate a function which can iterate over all of them mutably
+///
+/// Fields marked with `#[container_ignore]` are skipped unless they are also marked with `#[container_index]`
+#[proc_macro_derive(
+    PackageIndexContainer,
+    attributes(container_ignore, container_nobounds, container_index)
+)]
+pub fn derive_package_index_container(input: TokenStream) -> TokenStream {
+    container::derive_container(input, &container::PACKAGE_INDEX_CONTAINER)
 }
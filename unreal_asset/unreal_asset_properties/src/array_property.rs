@@ -3,7 +3,7 @@
 use crate::property_prelude::*;
 
 /// Array property
-#[derive(FNameContainer, Debug, Default, Clone, Hash, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Default, Clone, Hash, PartialEq, Eq)]
 pub struct ArrayProperty {
     /// Name
     pub name: FName,
@@ -4,7 +4,7 @@ use crate::property_prelude::*;
 use crate::vector_property::Vector4Property;
 
 /// Mesh to mesh vertex data
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MeshToMeshVertData {
     /// Position barycentric coords and distance
     pub position_bary_coords_and_dist: Vector4Property,
@@ -101,7 +101,7 @@ impl MeshToMeshVertData {
 }
 
 /// Cloth lod data property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct ClothLodDataProperty {
     /// Base struct property
     pub struct_property: StructProperty,
@@ -133,14 +133,15 @@ impl ClothLodDataProperty {
         )?;
 
         let transition_up_skin_data_len = asset.read_i32::<LE>()?;
-        let mut transition_up_skin_data = Vec::with_capacity(transition_up_skin_data_len as usize);
+        let mut transition_up_skin_data =
+            Vec::with_capacity(capped_capacity(transition_up_skin_data_len));
         for _ in 0..transition_up_skin_data_len {
             transition_up_skin_data.push(MeshToMeshVertData::new(asset)?);
         }
 
         let transition_down_skin_data_len = asset.read_i32::<LE>()?;
         let mut transition_down_skin_data =
-            Vec::with_capacity(transition_down_skin_data_len as usize);
+            Vec::with_capacity(capped_capacity(transition_down_skin_data_len));
         for _ in 0..transition_down_skin_data_len {
             transition_down_skin_data.push(MeshToMeshVertData::new(asset)?);
         }
@@ -0,0 +1,515 @@
+//! Custom property serializers registered at runtime
+//!
+//! Games often have native structs with their own `Serialize` implementation,
+//! those can't be parsed generically and would end up as an [`UnknownProperty`] with raw bytes.
+//!
+//! A [`CustomSerializer`] maps such a type to a list of regular properties and back.
+//! Serializers are registered in a [`CustomSerializers`] registry, either per asset
+//! or globally, optionally limited to a single engine version.
+//! Registries are only consulted for types this crate doesn't support, built-in types can't be overridden.
+//!
+//! [`UnknownProperty`]: crate::unknown_property::UnknownProperty
+
+use std::fmt::{Debug, Formatter};
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+
+use lazy_static::lazy_static;
+
+use unreal_asset_base::engine_version::EngineVersion;
+use unreal_asset_base::reader::ArchiveTrait;
+
+use crate::property_prelude::*;
+
+lazy_static! {
+    static ref GLOBAL_SERIALIZERS: RwLock<CustomSerializers> =
+        RwLock::new(CustomSerializers::new());
+}
+
+/// A reader for a struct or property type which isn't supported by this crate
+///
+/// The value is represented as a list of regular properties,
+/// it's up to the serializer to decide how the serialized data maps to those.
+pub trait CustomSerializer: Debug + Send + Sync {
+    /// Read a value
+    ///
+    /// `ancestry` should be used for the returned properties,
+    /// `length` is the serialized length of the value or 0 if it's unknown.
+    fn read(
+        &self,
+        reader: &mut dyn CustomReader,
+        ancestry: &Ancestry,
+        length: i64,
+    ) -> Result<Vec<Property>, Error>;
+
+    /// Write a value previously read with [`CustomSerializer::read`]
+    fn write(&self, writer: &mut dyn CustomWriter, value: &[Property]) -> Result<(), Error>;
+}
+
+/// Archive reader passed to a [`CustomSerializer`]
+pub trait CustomReader: Read {
+    /// Read an `FName`
+    fn read_fname(&mut self) -> Result<FName, Error>;
+    /// Read an FString
+    fn read_fstring(&mut self) -> Result<Option<String>, Error>;
+    /// Read a guid
+    fn read_guid(&mut self) -> io::Result<Guid>;
+    /// Read `bool`
+    fn read_bool(&mut self) -> io::Result<bool>;
+    /// Read a value of a built-in or registered type without a property tag
+    fn read_property(
+        &mut self,
+        type_name: &str,
+        name: FName,
+        ancestry: Ancestry,
+    ) -> Result<Property, Error>;
+    /// Read tagged properties until the terminating `None`
+    fn read_properties(&mut self, ancestry: Ancestry) -> Result<Vec<Property>, Error>;
+
+    /// Current archive cursor position
+    fn position(&mut self) -> u64;
+    /// Get archive's engine version
+    fn get_engine_version(&self) -> EngineVersion;
+    /// Get archive's object version
+    fn get_object_version(&self) -> ObjectVersion;
+    /// Get archive's UE5 object version
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5;
+}
+
+/// Archive writer passed to a [`CustomSerializer`]
+pub trait CustomWriter: Write {
+    /// Write an `FName`
+    fn write_fname(&mut self, fname: &FName) -> Result<(), Error>;
+    /// Write an FString
+    fn write_fstring(&mut self, value: Option<&str>) -> Result<usize, Error>;
+    /// Write a guid
+    fn write_guid(&mut self, guid: &Guid) -> io::Result<()>;
+    /// Write `bool`
+    fn write_bool(&mut self, value: bool) -> io::Result<()>;
+    /// Add a string slice to the name map as an `FName`
+    fn add_fname(&mut self, value: &str) -> FName;
+    /// Write a value without a property tag
+    fn write_property(&mut self, property: &Property) -> Result<(), Error>;
+    /// Write tagged properties followed by the terminating `None`
+    fn write_properties(
+        &mut self,
+        properties: &[Property],
+        parent_name: &FName,
+    ) -> Result<(), Error>;
+
+    /// Current archive cursor position
+    fn position(&mut self) -> u64;
+    /// Get archive's engine version
+    fn get_engine_version(&self) -> EngineVersion;
+    /// Get archive's object version
+    fn get_object_version(&self) -> ObjectVersion;
+    /// Get archive's UE5 object version
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5;
+}
+
+/// Registered serializer
+#[derive(Clone)]
+struct CustomSerializerEntry {
+    /// Serialized type name
+    type_name: String,
+    /// Engine version this serializer is limited to
+    engine_version: Option<EngineVersion>,
+    /// Serializer
+    serializer: Arc<dyn CustomSerializer>,
+}
+
+/// A registry of custom struct and property serializers
+///
+/// Serializers registered for a specific engine version take priority over ones registered for all versions,
+/// and an asset's own serializers take priority over global ones.
+#[derive(Clone, Default)]
+pub struct CustomSerializers {
+    /// Registered serializers
+    entries: Vec<CustomSerializerEntry>,
+}
+
+impl CustomSerializers {
+    /// Create a new empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a serializer for a struct or property type name
+    pub fn register(&mut self, type_name: &str, serializer: impl CustomSerializer + 'static) {
+        self.insert(type_name, None, Arc::new(serializer));
+    }
+
+    /// Register a serializer for a struct or property type name that is only used for one engine version
+    pub fn register_for_version(
+        &mut self,
+        type_name: &str,
+        engine_version: EngineVersion,
+        serializer: impl CustomSerializer + 'static,
+    ) {
+        self.insert(type_name, Some(engine_version), Arc::new(serializer));
+    }
+
+    /// Register a serializer for every asset, optionally limited to one engine version
+    pub fn register_global(
+        type_name: &str,
+        engine_version: Option<EngineVersion>,
+        serializer: impl CustomSerializer + 'static,
+    ) {
+        let mut global = GLOBAL_SERIALIZERS
+            .write()
+            .unwrap_or_else(|e| e.into_inner());
+        global.insert(type_name, engine_version, Arc::new(serializer));
+    }
+
+    /// Remove all global serializers
+    pub fn clear_global() {
+        let mut global = GLOBAL_SERIALIZERS
+            .write()
+            .unwrap_or_else(|e| e.into_inner());
+        global.entries.clear();
+    }
+
+    /// Get a serializer for a type name
+    pub fn get(
+        &self,
+        type_name: &str,
+        engine_version: EngineVersion,
+    ) -> Option<&Arc<dyn CustomSerializer>> {
+        let mut matching = self.entries.iter().filter(|e| e.type_name == type_name);
+        matching
+            .clone()
+            .find(|e| e.engine_version == Some(engine_version))
+            .or_else(|| matching.find(|e| e.engine_version.is_none()))
+            .map(|e| &e.serializer)
+    }
+
+    /// Find a serializer for a type name in an archive's registry or in the global registry
+    pub fn find<Index: PackageIndexTrait>(
+        archive: &impl ArchiveTrait<Index>,
+        type_name: &str,
+    ) -> Option<Arc<dyn CustomSerializer>> {
+        let engine_version = archive.get_engine_version();
+
+        let own = archive
+            .get_custom_serializers()
+            .and_then(|e| e.downcast_ref::<CustomSerializers>())
+            .and_then(|e| e.get(type_name, engine_version));
+        if let Some(serializer) = own {
+            return Some(serializer.clone());
+        }
+
+        let global = GLOBAL_SERIALIZERS.read().unwrap_or_else(|e| e.into_inner());
+        global.get(type_name, engine_version).cloned()
+    }
+
+    /// Add a serializer, replacing one registered for the same type and engine version
+    fn insert(
+        &mut self,
+        type_name: &str,
+        engine_version: Option<EngineVersion>,
+        serializer: Arc<dyn CustomSerializer>,
+    ) {
+        self.entries
+            .retain(|e| e.type_name != type_name || e.engine_version != engine_version);
+        self.entries.push(CustomSerializerEntry {
+            type_name: type_name.to_string(),
+            engine_version,
+            serializer,
+        });
+    }
+}
+
+impl Debug for CustomSerializers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(
+                self.entries
+                    .iter()
+                    .map(|e| ((&e.type_name, e.engine_version), &e.serializer)),
+            )
+            .finish()
+    }
+}
+
+impl PartialEq for CustomSerializers {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries.len() == other.entries.len()
+            && self.entries.iter().zip(&other.entries).all(|(a, b)| {
+                a.type_name == b.type_name
+                    && a.engine_version == b.engine_version
+                    && Arc::ptr_eq(&a.serializer, &b.serializer)
+            })
+    }
+}
+
+impl Eq for CustomSerializers {}
+
+/// Custom property
+///
+/// This gets created when a type with a registered [`CustomSerializer`] is deserialized
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+pub struct CustomProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Serialized type
+    pub serialized_type: FName,
+    /// Value read by the serializer
+    pub value: Vec<Property>,
+}
+impl_property_data_trait!(CustomProperty);
+
+impl CustomProperty {
+    /// Read a `CustomProperty` from an asset
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        length: i64,
+        duplication_index: i32,
+        serialized_type: FName,
+        serializer: &dyn CustomSerializer,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+
+        let new_ancestry = ancestry.with_parent(name.clone());
+        let value = serializer.read(&mut ReaderAdapter::new(asset), &new_ancestry, length)?;
+
+        Ok(CustomProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            serialized_type,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for CustomProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        let serializer = self.serialized_type.get_content(|ty| {
+            CustomSerializers::find(asset, ty)
+                .ok_or_else(|| PropertyError::no_custom_serializer(ty))
+        })?;
+
+        optional_guid_write!(self, asset, include_header);
+
+        let begin = asset.position();
+        serializer.write(&mut WriterAdapter::new(asset), &self.value)?;
+        Ok((asset.position() - begin) as usize)
+    }
+}
+
+/// [`CustomReader`] implementation for an [`ArchiveReader`]
+struct ReaderAdapter<'reader, Index: PackageIndexTrait, Reader: ArchiveReader<Index>> {
+    /// Underlying reader
+    reader: &'reader mut Reader,
+    /// Index marker
+    _index: PhantomData<Index>,
+}
+
+impl<'reader, Index: PackageIndexTrait, Reader: ArchiveReader<Index>>
+    ReaderAdapter<'reader, Index, Reader>
+{
+    /// Create a new `ReaderAdapter` instance
+    fn new(reader: &'reader mut Reader) -> Self {
+        ReaderAdapter {
+            reader,
+            _index: PhantomData,
+        }
+    }
+}
+
+impl<Index: PackageIndexTrait, Reader: ArchiveReader<Index>> Read
+    for ReaderAdapter<'_, Index, Reader>
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+impl<Index: PackageIndexTrait, Reader: ArchiveReader<Index>> CustomReader
+    for ReaderAdapter<'_, Index, Reader>
+{
+    fn read_fname(&mut self) -> Result<FName, Error> {
+        self.reader.read_fname()
+    }
+
+    fn read_fstring(&mut self) -> Result<Option<String>, Error> {
+        self.reader.read_fstring()
+    }
+
+    fn read_guid(&mut self) -> io::Result<Guid> {
+        self.reader.read_guid()
+    }
+
+    fn read_bool(&mut self) -> io::Result<bool> {
+        self.reader.read_bool()
+    }
+
+    fn read_property(
+        &mut self,
+        type_name: &str,
+        name: FName,
+        ancestry: Ancestry,
+    ) -> Result<Property, Error> {
+        Property::from_type(
+            self.reader,
+            &FName::from_slice(type_name),
+            name,
+            ancestry,
+            false,
+            0,
+            0,
+            0,
+            false,
+        )
+    }
+
+    fn read_properties(&mut self, ancestry: Ancestry) -> Result<Vec<Property>, Error> {
+        let mut properties = Vec::new();
+        let mut unversioned_header = UnversionedHeader::new(self.reader)?;
+        while let Some(property) = Property::new(
+            self.reader,
+            ancestry.clone(),
+            unversioned_header.as_mut(),
+            true,
+        )? {
+            properties.push(property);
+        }
+        Ok(properties)
+    }
+
+    fn position(&mut self) -> u64 {
+        self.reader.position()
+    }
+
+    fn get_engine_version(&self) -> EngineVersion {
+        self.reader.get_engine_version()
+    }
+
+    fn get_object_version(&self) -> ObjectVersion {
+        self.reader.get_object_version()
+    }
+
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5 {
+        self.reader.get_object_version_ue5()
+    }
+}
+
+/// [`CustomWriter`] implementation for an [`ArchiveWriter`]
+struct WriterAdapter<'writer, Index: PackageIndexTrait, Writer: ArchiveWriter<Index>> {
+    /// Underlying writer
+    writer: &'writer mut Writer,
+    /// Index marker
+    _index: PhantomData<Index>,
+}
+
+impl<'writer, Index: PackageIndexTrait, Writer: ArchiveWriter<Index>>
+    WriterAdapter<'writer, Index, Writer>
+{
+    /// Create a new `WriterAdapter` instance
+    fn new(writer: &'writer mut Writer) -> Self {
+        WriterAdapter {
+            writer,
+            _index: PhantomData,
+        }
+    }
+}
+
+impl<Index: PackageIndexTrait, Writer: ArchiveWriter<Index>> Write
+    for WriterAdapter<'_, Index, Writer>
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<Index: PackageIndexTrait, Writer: ArchiveWriter<Index>> CustomWriter
+    for WriterAdapter<'_, Index, Writer>
+{
+    fn write_fname(&mut self, fname: &FName) -> Result<(), Error> {
+        self.writer.write_fname(fname)
+    }
+
+    fn write_fstring(&mut self, value: Option<&str>) -> Result<usize, Error> {
+        self.writer.write_fstring(value)
+    }
+
+    fn write_guid(&mut self, guid: &Guid) -> io::Result<()> {
+        self.writer.write_guid(guid)
+    }
+
+    fn write_bool(&mut self, value: bool) -> io::Result<()> {
+        self.writer.write_bool(value)
+    }
+
+    fn add_fname(&mut self, value: &str) -> FName {
+        self.writer.add_fname(value)
+    }
+
+    fn write_property(&mut self, property: &Property) -> Result<(), Error> {
+        property.write(self.writer, false)?;
+        Ok(())
+    }
+
+    fn write_properties(
+        &mut self,
+        properties: &[Property],
+        parent_name: &FName,
+    ) -> Result<(), Error> {
+        let (unversioned_header, sorted_properties) =
+            match generate_unversioned_header(self.writer, properties, parent_name)? {
+                Some((a, b)) => (Some(a), Some(b)),
+                None => (None, None),
+            };
+
+        if let Some(unversioned_header) = unversioned_header {
+            unversioned_header.write(self.writer)?;
+        }
+
+        let properties = sorted_properties.as_deref().unwrap_or(properties);
+        for property in properties {
+            Property::write(property, self.writer, true)?;
+        }
+
+        if !self.writer.has_unversioned_properties() {
+            let none = self.writer.add_fname("None");
+            self.writer.write_fname(&none)?;
+        }
+        Ok(())
+    }
+
+    fn position(&mut self) -> u64 {
+        self.writer.position()
+    }
+
+    fn get_engine_version(&self) -> EngineVersion {
+        self.writer.get_engine_version()
+    }
+
+    fn get_object_version(&self) -> ObjectVersion {
+        self.writer.get_object_version()
+    }
+
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5 {
+        self.writer.get_object_version_ue5()
+    }
+}
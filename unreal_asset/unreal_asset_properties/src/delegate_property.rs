@@ -3,10 +3,11 @@
 use crate::property_prelude::*;
 
 /// Delegate
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Delegate {
     /// Delegate object
     #[container_ignore]
+    #[container_index]
     pub object: PackageIndex,
     /// Delegate name
     pub delegate: FName,
@@ -20,7 +21,7 @@ impl Delegate {
 }
 
 /// Delegate property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct DelegateProperty {
     /// Name
     pub name: FName,
@@ -79,7 +80,9 @@ impl PropertyTrait for DelegateProperty {
 macro_rules! impl_multicast {
     ($property_name:ident) => {
         /// $property_name
-        #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+        #[derive(
+            FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash,
+        )]
         pub struct $property_name {
             /// Name
             pub name: FName,
@@ -106,7 +109,7 @@ macro_rules! impl_multicast {
                 let property_guid = optional_guid!(asset, include_header);
 
                 let length = asset.read_i32::<LE>()?;
-                let mut value = Vec::with_capacity(length as usize);
+                let mut value = Vec::with_capacity(capped_capacity(length));
                 for _ in 0..length {
                     value.push(Delegate::new(
                         PackageIndex::new(asset.read_i32::<LE>()?),
@@ -0,0 +1,91 @@
+//! Field path property
+
+use unreal_asset_base::custom_version::FReleaseObjectVersion;
+use unreal_asset_base::reader::ArchiveTrait;
+
+use crate::property_prelude::*;
+
+/// Field path property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct FieldPathProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Path to the field, starting with the field name
+    pub path: Vec<FName>,
+    /// Struct owning the field
+    #[container_ignore]
+    #[container_index]
+    pub resolved_owner: PackageIndex,
+}
+impl_property_data_trait!(FieldPathProperty);
+
+/// Check if a field path owner is serialized
+fn has_owner<Index: PackageIndexTrait>(asset: &impl ArchiveTrait<Index>) -> bool {
+    asset
+        .get_custom_version::<FFortniteMainBranchObjectVersion>()
+        .version
+        >= FFortniteMainBranchObjectVersion::FFieldPathOwnerSerialization as i32
+        || asset.get_custom_version::<FReleaseObjectVersion>().version
+            >= FReleaseObjectVersion::FFieldPathOwnerSerialization as i32
+}
+
+impl FieldPathProperty {
+    /// Read a `FieldPathProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+
+        let path_length = asset.read_i32::<LE>()?;
+        let mut path = Vec::new();
+        for _ in 0..path_length {
+            path.push(asset.read_fname()?);
+        }
+
+        let resolved_owner = match has_owner(asset) {
+            true => PackageIndex::new(asset.read_i32::<LE>()?),
+            false => PackageIndex::new(0),
+        };
+
+        Ok(FieldPathProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            path,
+            resolved_owner,
+        })
+    }
+}
+
+impl PropertyTrait for FieldPathProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        let begin = asset.position();
+
+        asset.write_i32::<LE>(self.path.len() as i32)?;
+        for entry in &self.path {
+            asset.write_fname(entry)?;
+        }
+
+        if has_owner(asset) {
+            asset.write_i32::<LE>(self.resolved_owner.index)?;
+        }
+
+        Ok((asset.position() - begin) as usize)
+    }
+}
@@ -50,7 +50,7 @@ impl FontCharacter {
 }
 
 /// Font character property
-#[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, PartialEq, Eq)]
 pub struct FontCharacterProperty {
     /// Name
     pub name: FName,
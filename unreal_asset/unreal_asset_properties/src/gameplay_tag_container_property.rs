@@ -3,7 +3,7 @@
 use crate::property_prelude::*;
 
 /// Gameplay tag container property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct GameplayTagContainerProperty {
     /// Name
     pub name: FName,
@@ -31,7 +31,7 @@ impl GameplayTagContainerProperty {
         let property_guid = optional_guid!(asset, include_header);
 
         let length = asset.read_i32::<LE>()?;
-        let mut value = Vec::with_capacity(length as usize);
+        let mut value = Vec::with_capacity(capped_capacity(length));
         for _i in 0..length as usize {
             value.push(asset.read_fname()?);
         }
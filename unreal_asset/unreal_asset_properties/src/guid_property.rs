@@ -3,7 +3,7 @@
 use crate::property_prelude::*;
 
 /// Guid property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct GuidProperty {
     /// Name
     pub name: FName,
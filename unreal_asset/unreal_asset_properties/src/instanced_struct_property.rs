@@ -0,0 +1,113 @@
+//! Instanced struct property
+
+use crate::property_prelude::*;
+
+/// Instanced struct property
+///
+/// Holds a struct of any type, the type is referenced by a package index
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct InstancedStructProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Serialization version
+    pub version: u8,
+    /// Struct type
+    #[container_ignore]
+    #[container_index]
+    pub struct_type: PackageIndex,
+    /// Struct value, `None` if the struct type is null
+    pub value: Option<StructProperty>,
+}
+impl_property_data_trait!(InstancedStructProperty);
+
+impl InstancedStructProperty {
+    /// Read an `InstancedStructProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+
+        let version = asset.read_u8()?;
+        let struct_type = PackageIndex::new(asset.read_i32::<LE>()?);
+        let serial_size = asset.read_i32::<LE>()?;
+
+        let value = match struct_type.index == 0 {
+            true => {
+                // the engine skips data of structs that no longer exist
+                asset.seek(SeekFrom::Current(serial_size.max(0) as i64))?;
+                None
+            }
+            false => {
+                let struct_name =
+                    asset
+                        .get_object_name_packageindex(struct_type)
+                        .ok_or_else(|| {
+                            PropertyError::invalid_struct(format!(
+                                "Invalid instanced struct type {}",
+                                struct_type.index
+                            ))
+                        })?;
+
+                Some(StructProperty::custom_header(
+                    asset,
+                    name.clone(),
+                    ancestry.clone(),
+                    serial_size as i64,
+                    0,
+                    Some(struct_name),
+                    None,
+                    None,
+                )?)
+            }
+        };
+
+        Ok(InstancedStructProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            version,
+            struct_type,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for InstancedStructProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        let begin = asset.position();
+
+        asset.write_u8(self.version)?;
+        asset.write_i32::<LE>(self.struct_type.index)?;
+
+        let size_offset = asset.position();
+        asset.write_i32::<LE>(0)?;
+
+        let serial_size = match self.value {
+            Some(ref value) => value.write_with_type(asset, false, value.struct_type.clone())?,
+            None => 0,
+        };
+
+        let end = asset.position();
+        asset.seek(SeekFrom::Start(size_offset))?;
+        asset.write_i32::<LE>(serial_size as i32)?;
+        asset.seek(SeekFrom::Start(end))?;
+
+        Ok((end - begin) as usize)
+    }
+}
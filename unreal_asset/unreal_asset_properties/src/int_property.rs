@@ -32,7 +32,7 @@ macro_rules! impl_int_property {
 }
 
 /// Int8 property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Int8Property {
     /// Name
     pub name: FName,
@@ -48,7 +48,7 @@ pub struct Int8Property {
 impl_property_data_trait!(Int8Property);
 
 /// Byte property value
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BytePropertyValue {
     /// Byte variant
     Byte(u8),
@@ -63,7 +63,7 @@ impl Default for BytePropertyValue {
 }
 
 /// Byte property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct ByteProperty {
     /// Name
     pub name: FName,
@@ -81,7 +81,7 @@ pub struct ByteProperty {
 impl_property_data_trait!(ByteProperty);
 
 /// Bool property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct BoolProperty {
     /// Name
     pub name: FName,
@@ -97,7 +97,7 @@ pub struct BoolProperty {
 impl_property_data_trait!(BoolProperty);
 
 /// Int32 property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct IntProperty {
     /// Name
     pub name: FName,
@@ -113,7 +113,7 @@ pub struct IntProperty {
 impl_property_data_trait!(IntProperty);
 
 /// Int16 property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Int16Property {
     /// Name
     pub name: FName,
@@ -129,7 +129,7 @@ pub struct Int16Property {
 impl_property_data_trait!(Int16Property);
 
 /// Int64 property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Int64Property {
     /// Name
     pub name: FName,
@@ -145,7 +145,7 @@ pub struct Int64Property {
 impl_property_data_trait!(Int64Property);
 
 /// UInt16 property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct UInt16Property {
     /// Name
     pub name: FName,
@@ -161,7 +161,7 @@ pub struct UInt16Property {
 impl_property_data_trait!(UInt16Property);
 
 /// UInt32 property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct UInt32Property {
     /// Name
     pub name: FName,
@@ -177,7 +177,7 @@ pub struct UInt32Property {
 impl_property_data_trait!(UInt32Property);
 
 /// UInt64 property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct UInt64Property {
     /// Name
     pub name: FName,
@@ -193,7 +193,7 @@ pub struct UInt64Property {
 impl_property_data_trait!(UInt64Property);
 
 /// Float property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct FloatProperty {
     /// Name
     pub name: FName,
@@ -209,7 +209,7 @@ pub struct FloatProperty {
 impl_property_data_trait!(FloatProperty);
 
 /// Double property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct DoubleProperty {
     /// Name
     pub name: FName,
@@ -25,27 +25,33 @@ use unreal_asset_base::unversioned::header::UnversionedHeaderFragment;
 use unreal_asset_base::unversioned::{
     header::UnversionedHeader, properties::UsmapPropertyDataTrait,
 };
-use unreal_asset_base::FNameContainer;
+use unreal_asset_base::{FNameContainer, PackageIndexContainer};
 
 pub mod array_property;
 pub mod cloth_lod_property;
 pub mod color_property;
+pub mod custom_property;
 pub mod date_property;
 pub mod delegate_property;
 pub mod empty_property;
 pub mod enum_property;
+pub mod field_path_property;
 pub mod float_range_property;
 pub mod font_character_property;
 pub mod game_framework;
 pub mod gameplay_tag_container_property;
 pub mod guid_property;
+pub mod instanced_struct_property;
 pub mod int_property;
 pub mod map_property;
 pub mod material_input_property;
 pub mod movies;
+pub mod nav_agent_selector_property;
 pub mod niagara;
 pub mod object_property;
 pub mod per_platform_property;
+pub mod per_quality_level_property;
+pub mod property_path;
 pub mod raw_struct_property;
 pub mod rich_curve_key_property;
 pub mod sampler_property;
@@ -63,6 +69,7 @@ pub mod world_tile_property;
 use array_property::ArrayProperty;
 use cloth_lod_property::ClothLodDataProperty;
 use color_property::{ColorProperty, LinearColorProperty};
+use custom_property::{CustomProperty, CustomSerializers};
 use date_property::{DateTimeProperty, TimeSpanProperty};
 use delegate_property::{
     DelegateProperty, MulticastDelegateProperty, MulticastInlineDelegateProperty,
@@ -70,11 +77,13 @@ use delegate_property::{
 };
 use empty_property::EmptyProperty;
 use enum_property::EnumProperty;
+use field_path_property::FieldPathProperty;
 use float_range_property::FloatRangeProperty;
 use font_character_property::FontCharacterProperty;
 use game_framework::unique_net_id_property::UniqueNetIdProperty;
 use gameplay_tag_container_property::GameplayTagContainerProperty;
 use guid_property::GuidProperty;
+use instanced_struct_property::InstancedStructProperty;
 use int_property::{
     BoolProperty, ByteProperty, DoubleProperty, FloatProperty, Int16Property, Int64Property,
     Int8Property, IntProperty, UInt16Property, UInt32Property, UInt64Property,
@@ -85,6 +94,8 @@ use material_input_property::{
     ScalarMaterialInputProperty, ShadingModelMaterialInputProperty, Vector2MaterialInputProperty,
     VectorMaterialInputProperty,
 };
+use movies::frame_number_property::FrameNumberProperty;
+use movies::movie_scene_double_channel_property::MovieSceneDoubleChannelProperty;
 use movies::movie_scene_eval_template_ptr_property::MovieSceneEvalTemplatePtrProperty;
 use movies::movie_scene_evaluation_field_entity_tree_property::MovieSceneEvaluationFieldEntityTreeProperty;
 use movies::movie_scene_evaluation_key_property::MovieSceneEvaluationKeyProperty;
@@ -102,13 +113,17 @@ use movies::movie_scene_track_field_data_property::MovieSceneTrackFieldDataPrope
 use movies::movie_scene_track_identifier_property::MovieSceneTrackIdentifierProperty;
 use movies::movie_scene_track_implementation_ptr_property::MovieSceneTrackImplementationPtrProperty;
 use movies::section_evaluation_data_tree_property::SectionEvaluationDataTreeProperty;
+use nav_agent_selector_property::NavAgentSelectorProperty;
 use niagara::niagara_variable_property::{
     NiagaraVariableProperty, NiagaraVariableWithOffsetProperty,
 };
-use object_property::{AssetObjectProperty, ObjectProperty, SoftObjectProperty};
+use object_property::{
+    AssetObjectProperty, InterfaceProperty, LazyObjectProperty, ObjectProperty, SoftObjectProperty,
+};
 use per_platform_property::{
     PerPlatformBoolProperty, PerPlatformFloatProperty, PerPlatformIntProperty,
 };
+use per_quality_level_property::{PerQualityLevelFloatProperty, PerQualityLevelIntProperty};
 use raw_struct_property::RawStructProperty;
 use rich_curve_key_property::RichCurveKeyProperty;
 use sampler_property::{
@@ -122,12 +137,13 @@ use soft_path_property::{
     SoftAssetPathProperty, SoftClassPathProperty, SoftObjectPathProperty,
     StringAssetReferenceProperty,
 };
-use str_property::{NameProperty, StrProperty, TextProperty};
+use str_property::{AnsiStrProperty, NameProperty, StrProperty, TextProperty, Utf8StrProperty};
 use struct_property::StructProperty;
 use unknown_property::UnknownProperty;
 use vector_property::{
-    Box2DProperty, BoxProperty, IntPointProperty, PlaneProperty, QuatProperty, RotatorProperty,
-    Vector2DProperty, Vector4Property, VectorProperty,
+    Box2DProperty, BoxProperty, IntPointProperty, IntVector2Property, IntVectorProperty,
+    PlaneProperty, QuatProperty, RotatorProperty, Vector2DProperty, Vector4Property,
+    VectorProperty,
 };
 use view_target_blend_property::ViewTargetBlendParamsProperty;
 
@@ -148,7 +164,7 @@ mod property_prelude {
     };
     pub use unreal_asset_base::error::PropertyError;
     pub use unreal_asset_base::object_version::{ObjectVersion, ObjectVersionUE5};
-    pub use unreal_asset_base::reader::{ArchiveReader, ArchiveWriter};
+    pub use unreal_asset_base::reader::{capped_capacity, ArchiveReader, ArchiveWriter};
     pub use unreal_asset_base::types::{
         fname::{FName, ToSerializedName},
         PackageIndex, PackageIndexTrait,
@@ -160,6 +176,7 @@ mod property_prelude {
     };
     pub use unreal_asset_base::Error;
     pub use unreal_asset_base::FNameContainer;
+    pub use unreal_asset_base::PackageIndexContainer;
 
     pub use super::generate_unversioned_header;
     pub use super::impl_property_data_trait;
@@ -245,7 +262,7 @@ macro_rules! impl_property_data_trait {
     };
 }
 
-const CUSTOM_SERIALIZATION: [&str; 57] = [
+const CUSTOM_SERIALIZATION: [&str; 65] = [
     "SkeletalMeshSamplingLODBuiltData",
     "SkeletalMeshAreaWeightedTriangleSampler",
     "SmartName",
@@ -287,6 +304,12 @@ const CUSTOM_SERIALIZATION: [&str; 57] = [
     "ClothLODData",
     "FloatRange",
     "RawStructProperty",
+    "IntVector",
+    "IntVector2",
+    "PerQualityLevelInt",
+    "PerQualityLevelFloat",
+    "InstancedStruct",
+    "NavAgentSelector",
     //
     "MovieSceneEvalTemplatePtr",
     "MovieSceneTrackImplementationPtr",
@@ -304,6 +327,8 @@ const CUSTOM_SERIALIZATION: [&str; 57] = [
     "MovieSceneTrackIdentifier",
     "MovieSceneSequenceId",
     "MovieSceneEvaluationKey",
+    "MovieSceneDoubleChannel",
+    "FrameNumber",
 ];
 
 /// This must be implemented for all properties
@@ -337,7 +362,7 @@ pub trait PropertyTrait: PropertyDataTrait + Debug + Hash + Clone + PartialEq +
 /// Property
 #[allow(clippy::large_enum_variant)]
 #[enum_dispatch(PropertyTrait, PropertyDataTrait)]
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 #[container_nobounds]
 pub enum Property {
     /// Bool property
@@ -366,6 +391,10 @@ pub enum Property {
     NameProperty,
     /// String property
     StrProperty,
+    /// UTF-8 string property
+    Utf8StrProperty,
+    /// ANSI string property
+    AnsiStrProperty,
     /// Text property
     TextProperty,
     /// Object property
@@ -374,8 +403,18 @@ pub enum Property {
     AssetObjectProperty,
     /// Soft object property
     SoftObjectProperty,
+    /// Lazy object property
+    LazyObjectProperty,
+    /// Interface property
+    InterfaceProperty,
+    /// Field path property
+    FieldPathProperty,
     /// Int point property
     IntPointProperty,
+    /// Int vector property
+    IntVectorProperty,
+    /// Int vector2 property
+    IntVector2Property,
     /// Vector property
     VectorProperty,
     /// Vector4 property
@@ -414,6 +453,10 @@ pub enum Property {
     PerPlatformIntProperty,
     /// Per-platform float property
     PerPlatformFloatProperty,
+    /// Per-quality level int property
+    PerQualityLevelIntProperty,
+    /// Per-quality level float property
+    PerQualityLevelFloatProperty,
     /// Material attributes input property
     MaterialAttributesInputProperty,
     /// Expression input property
@@ -478,6 +521,10 @@ pub enum Property {
     FloatRangeProperty,
     /// Raw struct property
     RawStructProperty,
+    /// Instanced struct property
+    InstancedStructProperty,
+    /// Nav agent selector property
+    NavAgentSelectorProperty,
     /// Movie scene eval template pointer property
     MovieSceneEvalTemplatePtrProperty,
     /// Movie scene track implementation pointer property
@@ -510,7 +557,13 @@ pub enum Property {
     MovieSceneSequenceIdProperty,
     /// Movie scene evaluation key property
     MovieSceneEvaluationKeyProperty,
+    /// Movie scene double channel property
+    MovieSceneDoubleChannelProperty,
+    /// Frame number property
+    FrameNumberProperty,
 
+    /// Property with a registered custom serializer
+    CustomProperty,
     /// Empty unversioned property
     EmptyProperty,
     /// Unknown property
@@ -750,6 +803,14 @@ impl Property {
                     StrProperty::new(asset, name, ancestry, include_header, duplication_index)?
                         .into()
                 }
+                "Utf8StrProperty" => {
+                    Utf8StrProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                        .into()
+                }
+                "AnsiStrProperty" => {
+                    AnsiStrProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                        .into()
+                }
                 "TextProperty" => {
                     TextProperty::new(asset, name, ancestry, include_header, duplication_index)?
                         .into()
@@ -775,11 +836,51 @@ impl Property {
                     duplication_index,
                 )?
                 .into(),
+                "LazyObjectProperty" => LazyObjectProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "InterfaceProperty" => InterfaceProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "FieldPathProperty" => FieldPathProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
 
                 "IntPoint" => {
                     IntPointProperty::new(asset, name, ancestry, include_header, duplication_index)?
                         .into()
                 }
+                "IntVector" => IntVectorProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "IntVector2" => IntVector2Property::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
                 "Vector" => {
                     VectorProperty::new(asset, name, ancestry, include_header, duplication_index)?
                         .into()
@@ -888,6 +989,22 @@ impl Property {
                     duplication_index,
                 )?
                 .into(),
+                "PerQualityLevelInt" => PerQualityLevelIntProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "PerQualityLevelFloat" => PerQualityLevelFloatProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
 
                 "MaterialAttributesInput" => MaterialAttributesInputProperty::new(
                     asset,
@@ -1312,17 +1429,63 @@ impl Property {
                     duplication_index,
                 )?
                 .into(),
-
-                _ => UnknownProperty::new(
+                "MovieSceneDoubleChannel" => MovieSceneDoubleChannelProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "FrameNumber" => FrameNumberProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "InstancedStruct" => InstancedStructProperty::new(
                     asset,
                     name,
                     ancestry,
                     include_header,
-                    length,
                     duplication_index,
-                    type_name.clone(),
                 )?
                 .into(),
+                "NavAgentSelector" => NavAgentSelectorProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+
+                // registered serializers are only looked up for types that aren't built in
+                _ => match CustomSerializers::find(asset, ty) {
+                    Some(serializer) => CustomProperty::new(
+                        asset,
+                        name,
+                        ancestry,
+                        include_header,
+                        length,
+                        duplication_index,
+                        type_name.clone(),
+                        serializer.as_ref(),
+                    )?
+                    .into(),
+                    None => UnknownProperty::new(
+                        asset,
+                        name,
+                        ancestry,
+                        include_header,
+                        length,
+                        duplication_index,
+                        type_name.clone(),
+                    )?
+                    .into(),
+                },
             })
         })
     }
@@ -1372,6 +1535,8 @@ macro_rules! property_inner_serialized_name {
                     )*
                     Self::UnknownProperty(unk) => unk
                         .serialized_type.get_owned_content(),
+                    Self::CustomProperty(custom) => custom
+                        .serialized_type.get_owned_content(),
                     Self::EmptyProperty(empty) => empty.type_name.get_owned_content()
                 }
             }
@@ -1404,6 +1569,8 @@ property_inner_serialized_name! {
     DateTimeProperty: "DateTime",
     GuidProperty: "Guid",
     IntPointProperty: "IntPoint",
+    IntVectorProperty: "IntVector",
+    IntVector2Property: "IntVector2",
     LinearColorProperty: "LinearColor",
     QuatProperty: "Quat",
     RotatorProperty: "Rotator",
@@ -1413,6 +1580,8 @@ property_inner_serialized_name! {
     BoxProperty: "Box",
     Box2DProperty: "Box2D",
     PerPlatformFloatProperty: "PerPlatformFloat",
+    PerQualityLevelIntProperty: "PerQualityLevelInt",
+    PerQualityLevelFloatProperty: "PerQualityLevelFloat",
     Vector4Property: "Vector4",
     VectorProperty: "Vector",
     ViewTargetBlendParamsProperty: "ViewTargetBlendParams",
@@ -1437,7 +1606,12 @@ property_inner_serialized_name! {
     ObjectProperty: "ObjectProperty",
     AssetObjectProperty: "AssetObjectProperty",
     SoftObjectProperty: "SoftObjectProperty",
+    LazyObjectProperty: "LazyObjectProperty",
+    InterfaceProperty: "InterfaceProperty",
+    FieldPathProperty: "FieldPathProperty",
     StrProperty: "StrProperty",
+    Utf8StrProperty: "Utf8StrProperty",
+    AnsiStrProperty: "AnsiStrProperty",
     TextProperty: "TextProperty",
     UInt16Property: "UInt16Property",
     UInt32Property: "UInt32Property",
@@ -1450,6 +1624,8 @@ property_inner_serialized_name! {
     FontDataProperty: "FontData",
     FloatRangeProperty: "FloatRange",
     RawStructProperty: "RawStructProperty",
+    InstancedStructProperty: "InstancedStruct",
+    NavAgentSelectorProperty: "NavAgentSelector",
 
     MovieSceneEvalTemplatePtrProperty: "MovieSceneEvalTemplatePtr",
     MovieSceneTrackImplementationPtrProperty: "MovieSceneTrackImplementationPtr",
@@ -1466,7 +1642,9 @@ property_inner_serialized_name! {
     MovieSceneSegmentIdentifierProperty: "MovieSceneSegmentIdentifier",
     MovieSceneTrackIdentifierProperty: "MovieSceneTrackIdentifier",
     MovieSceneSequenceIdProperty: "MovieSceneSequenceId",
-    MovieSceneEvaluationKeyProperty: "MovieSceneEvaluationKey"
+    MovieSceneEvaluationKeyProperty: "MovieSceneEvaluationKey",
+    MovieSceneDoubleChannelProperty: "MovieSceneDoubleChannel",
+    FrameNumberProperty: "FrameNumber"
 }
 
 /// Generate property unversioned header
@@ -7,7 +7,7 @@ use unreal_asset_base::containers::indexed_map::IndexedMap;
 use crate::property_prelude::*;
 
 /// Map property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq)]
 pub struct MapProperty {
     /// Name
     pub name: FName,
@@ -156,7 +156,7 @@ impl MapProperty {
         let type_2 = type_2.ok_or_else(|| Error::invalid_file("No type2".to_string()))?;
 
         for _ in 0..num_keys_to_remove as usize {
-            let mut vec = Vec::with_capacity(num_keys_to_remove as usize);
+            let mut vec = Vec::with_capacity(capped_capacity(num_keys_to_remove));
             vec.push(MapProperty::map_type_to_class(
                 asset,
                 type_1.clone(),
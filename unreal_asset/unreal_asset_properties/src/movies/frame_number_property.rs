@@ -0,0 +1,57 @@
+//! Frame number property
+
+use unreal_asset_base::types::movie::FrameNumber;
+
+use crate::property_prelude::*;
+
+/// Frame number property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FrameNumberProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Value
+    #[container_ignore]
+    pub value: FrameNumber,
+}
+impl_property_data_trait!(FrameNumberProperty);
+
+impl FrameNumberProperty {
+    /// Read a `FrameNumberProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+
+        let value = FrameNumber::new(asset.read_i32::<LE>()?);
+
+        Ok(FrameNumberProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for FrameNumberProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_i32::<LE>(self.value.value)?;
+        Ok(size_of::<i32>())
+    }
+}
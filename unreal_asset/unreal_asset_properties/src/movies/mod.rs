@@ -4,6 +4,8 @@ use crate::property_prelude::*;
 use crate::rich_curve_key_property::RichCurveTangentWeightMode;
 
 pub mod enums;
+pub mod frame_number_property;
+pub mod movie_scene_double_channel_property;
 pub mod movie_scene_eval_template_ptr_property;
 pub mod movie_scene_evaluation;
 pub mod movie_scene_evaluation_field_entity_tree_property;
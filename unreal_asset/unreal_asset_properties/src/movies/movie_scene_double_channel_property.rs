@@ -0,0 +1,256 @@
+//! Movie scene double channel property
+
+use unreal_asset_base::types::movie::{FrameNumber, FrameRate};
+
+use crate::property_prelude::*;
+use crate::rich_curve_key_property::{
+    RichCurveExtrapolation, RichCurveInterpMode, RichCurveTangentMode,
+};
+
+use super::MovieSceneTangentData;
+
+/// Movie scene double value
+///
+/// Double channels bulk serialize their values, so this is read with the in-memory layout
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneDoubleValue {
+    /// Value
+    pub value: OrderedFloat<f64>,
+    /// Tangent
+    pub tangent: MovieSceneTangentData,
+    /// Interpolation mode
+    pub interp_mode: RichCurveInterpMode,
+    /// Tangent mode
+    pub tangent_mode: RichCurveTangentMode,
+}
+
+impl MovieSceneDoubleValue {
+    /// Read a `MovieSceneDoubleValue` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        struct_length: i32,
+    ) -> Result<Self, Error> {
+        let begin = asset.position();
+
+        let value = asset.read_f64::<LE>()?;
+        let tangent = MovieSceneTangentData::new(asset, false)?;
+        // tangent data alignment
+        asset.seek(SeekFrom::Current(3))?;
+        let interp_mode: RichCurveInterpMode = RichCurveInterpMode::try_from(asset.read_i8()?)?;
+        let tangent_mode: RichCurveTangentMode = RichCurveTangentMode::try_from(asset.read_i8()?)?;
+
+        // padding byte and struct alignment
+        let padding = struct_length as i64 - (asset.position() - begin) as i64;
+        asset.seek(SeekFrom::Current(padding.max(0)))?;
+
+        Ok(MovieSceneDoubleValue {
+            value: OrderedFloat(value),
+            tangent,
+            interp_mode,
+            tangent_mode,
+        })
+    }
+
+    /// Write a `MovieSceneDoubleValue` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        struct_length: i32,
+    ) -> Result<(), Error> {
+        let begin = asset.position();
+
+        asset.write_f64::<LE>(self.value.0)?;
+        self.tangent.write(asset)?;
+        asset.write_all(&[0u8; 3])?;
+        asset.write_i8(self.interp_mode as i8)?;
+        asset.write_i8(self.tangent_mode as i8)?;
+
+        let padding = struct_length as i64 - (asset.position() - begin) as i64;
+        asset.write_all(&vec![0u8; padding.max(0) as usize])?;
+        Ok(())
+    }
+}
+
+/// Movie scene double channel
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneDoubleChannel {
+    /// Pre infinity extrapolation
+    pub pre_infinity_extrap: RichCurveExtrapolation,
+    /// Post infinity extrapolation
+    pub post_infinity_extrap: RichCurveExtrapolation,
+
+    /// Frame times structure length
+    pub times_struct_length: i32,
+    /// Frame times
+    pub times: Vec<FrameNumber>,
+
+    /// Values structure length
+    pub values_struct_length: i32,
+    /// Values
+    pub values: Vec<MovieSceneDoubleValue>,
+
+    /// Default value
+    pub default_value: OrderedFloat<f64>,
+    /// Has default value
+    pub has_default_value: bool,
+    /// Tick resolution
+    pub tick_resolution: FrameRate,
+    /// Should the curve be shown in the editor, `None` for assets serialized before this was added
+    pub show_curve: Option<bool>,
+}
+
+impl MovieSceneDoubleChannel {
+    /// Read a `MovieSceneDoubleChannel` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let pre_infinity_extrap: RichCurveExtrapolation =
+            RichCurveExtrapolation::try_from(asset.read_u8()?)?;
+        let post_infinity_extrap: RichCurveExtrapolation =
+            RichCurveExtrapolation::try_from(asset.read_u8()?)?;
+
+        let times_struct_length = asset.read_i32::<LE>()?;
+        let times_length = asset.read_i32::<LE>()?;
+
+        let mut times = Vec::with_capacity(capped_capacity(times_length));
+        for _ in 0..times_length {
+            times.push(FrameNumber::new(asset.read_i32::<LE>()?));
+        }
+
+        let values_struct_length = asset.read_i32::<LE>()?;
+        let values_length = asset.read_i32::<LE>()?;
+
+        let mut values = Vec::with_capacity(capped_capacity(values_length));
+        for _ in 0..values_length {
+            values.push(MovieSceneDoubleValue::new(asset, values_struct_length)?);
+        }
+
+        let default_value = asset.read_f64::<LE>()?;
+        let has_default_value = asset.read_i32::<LE>()? == 1;
+
+        let tick_resolution = FrameRate::new(asset.read_i32::<LE>()?, asset.read_i32::<LE>()?);
+
+        let show_curve = match asset
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            >= FFortniteMainBranchObjectVersion::SerializeFloatChannelShowCurve as i32
+        {
+            true => Some(asset.read_i32::<LE>()? == 1),
+            false => None,
+        };
+
+        Ok(MovieSceneDoubleChannel {
+            pre_infinity_extrap,
+            post_infinity_extrap,
+            times_struct_length,
+            times,
+            values_struct_length,
+            values,
+            default_value: OrderedFloat(default_value),
+            has_default_value,
+            tick_resolution,
+            show_curve,
+        })
+    }
+
+    /// Write a `MovieSceneDoubleChannel` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        asset.write_u8(self.pre_infinity_extrap as u8)?;
+        asset.write_u8(self.post_infinity_extrap as u8)?;
+
+        asset.write_i32::<LE>(self.times_struct_length)?;
+        asset.write_i32::<LE>(self.times.len() as i32)?;
+
+        for time in &self.times {
+            asset.write_i32::<LE>(time.value)?;
+        }
+
+        asset.write_i32::<LE>(self.values_struct_length)?;
+        asset.write_i32::<LE>(self.values.len() as i32)?;
+
+        for value in &self.values {
+            value.write(asset, self.values_struct_length)?;
+        }
+
+        asset.write_f64::<LE>(self.default_value.0)?;
+        asset.write_i32::<LE>(match self.has_default_value {
+            true => 1,
+            false => 0,
+        })?;
+
+        asset.write_i32::<LE>(self.tick_resolution.numerator)?;
+        asset.write_i32::<LE>(self.tick_resolution.denominator)?;
+
+        if asset
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            >= FFortniteMainBranchObjectVersion::SerializeFloatChannelShowCurve as i32
+        {
+            asset.write_i32::<LE>(match self.show_curve.unwrap_or_default() {
+                true => 1,
+                false => 0,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Movie scene double channel property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneDoubleChannelProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Value
+    #[container_ignore]
+    pub value: MovieSceneDoubleChannel,
+}
+impl_property_data_trait!(MovieSceneDoubleChannelProperty);
+
+impl MovieSceneDoubleChannelProperty {
+    /// Read a `MovieSceneDoubleChannelProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+
+        let value = MovieSceneDoubleChannel::new(asset)?;
+
+        Ok(MovieSceneDoubleChannelProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for MovieSceneDoubleChannelProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let begin = asset.position();
+
+        self.value.write(asset)?;
+
+        Ok((asset.position() - begin) as usize)
+    }
+}
@@ -46,7 +46,7 @@ impl MovieSceneEvaluationKey {
 }
 
 /// Movie scene evaluation key property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneEvaluationKeyProperty {
     /// Name
     pub name: FName,
@@ -51,7 +51,7 @@ impl MovieSceneFloatValue {
 }
 
 /// Movie scene float value property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneFloatValueProperty {
     /// Name
     pub name: FName,
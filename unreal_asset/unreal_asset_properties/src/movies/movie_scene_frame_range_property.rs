@@ -37,7 +37,7 @@ impl Int32RangeBound {
 }
 
 /// Movie scene frame range property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneFrameRangeProperty {
     /// Name
     pub name: FName,
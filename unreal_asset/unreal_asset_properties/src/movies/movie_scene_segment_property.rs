@@ -32,7 +32,7 @@ impl MovieSceneSegmentIdentifier {
 }
 
 /// Movie scene segment
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneSegment {
     /// Name
     pub name: FName,
@@ -60,7 +60,7 @@ impl MovieSceneSegment {
         let allow_empty = asset.read_i32::<LE>()? != 0;
 
         let impls_length = asset.read_i32::<LE>()?;
-        let mut impls = Vec::with_capacity(impls_length as usize);
+        let mut impls = Vec::with_capacity(capped_capacity(impls_length));
 
         for _ in 0..impls_length {
             let mut properties_list = Vec::new();
@@ -124,7 +124,7 @@ impl MovieSceneSegment {
 }
 
 /// Movie scene segment property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneSegmentProperty {
     /// Name
     pub name: FName,
@@ -180,7 +180,7 @@ impl PropertyTrait for MovieSceneSegmentProperty {
 }
 
 /// Movie scene segment identifier property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneSegmentIdentifierProperty {
     /// Name
     pub name: FName,
@@ -30,7 +30,7 @@ impl MovieSceneSequenceId {
 }
 
 /// Movie scene sequence identifier property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneSequenceIdProperty {
     /// Name
     pub name: FName,
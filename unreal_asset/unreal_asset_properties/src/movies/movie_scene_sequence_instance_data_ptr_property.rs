@@ -3,7 +3,7 @@
 use crate::property_prelude::*;
 
 /// Movie scene sequence instance data pointer property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneSequenceInstanceDataPtrProperty {
     /// Name
     pub name: FName,
@@ -15,6 +15,7 @@ pub struct MovieSceneSequenceInstanceDataPtrProperty {
     pub duplication_index: i32,
     /// Value
     #[container_ignore]
+    #[container_index]
     pub value: PackageIndex,
 }
 impl_property_data_trait!(MovieSceneSequenceInstanceDataPtrProperty);
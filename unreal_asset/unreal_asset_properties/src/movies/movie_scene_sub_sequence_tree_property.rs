@@ -73,7 +73,7 @@ impl MovieSceneSubSequenceTree {
 }
 
 /// Movie scene sub sequence tree property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneSubSequenceTreeProperty {
     /// Name
     pub name: FName,
@@ -41,7 +41,7 @@ impl MovieSceneTrackFieldData {
 }
 
 /// Movie scene track field data property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneTrackFieldDataProperty {
     /// Name
     pub name: FName,
@@ -30,7 +30,7 @@ impl MovieSceneTrackIdentifier {
 }
 
 /// Movie scene track identifier property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct MovieSceneTrackIdentifierProperty {
     /// Name
     pub name: FName,
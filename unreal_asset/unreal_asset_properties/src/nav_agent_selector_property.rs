@@ -0,0 +1,59 @@
+//! Nav agent selector property
+
+use crate::property_prelude::*;
+
+/// Nav agent selector property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+pub struct NavAgentSelectorProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Bitmask of supported nav agents
+    pub packed_bits: u32,
+}
+impl_property_data_trait!(NavAgentSelectorProperty);
+
+impl NavAgentSelectorProperty {
+    /// Read a `NavAgentSelectorProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+        let packed_bits = asset.read_u32::<LE>()?;
+
+        Ok(NavAgentSelectorProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            packed_bits,
+        })
+    }
+
+    /// Check if a nav agent is supported
+    pub fn contains(&self, agent_index: u32) -> bool {
+        agent_index < u32::BITS && self.packed_bits & (1 << agent_index) != 0
+    }
+
+    /// Set if a nav agent is supported
+    pub fn set(&mut self, agent_index: u32, supported: bool) {
+        if agent_index >= u32::BITS {
+            return;
+        }
+        match supported {
+            true => self.packed_bits |= 1 << agent_index,
+            false => self.packed_bits &= !(1 << agent_index),
+        }
+    }
+}
+
+simple_property_write!(NavAgentSelectorProperty, write_u32, packed_bits, u32);
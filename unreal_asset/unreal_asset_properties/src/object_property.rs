@@ -3,7 +3,7 @@
 use crate::property_prelude::*;
 
 /// Object property
-#[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct ObjectProperty {
     /// Name
     pub name: FName,
@@ -15,12 +15,47 @@ pub struct ObjectProperty {
     pub duplication_index: i32,
     /// Value
     #[container_ignore]
+    #[container_index]
     pub value: PackageIndex,
 }
 impl_property_data_trait!(ObjectProperty);
 
+/// Lazy object property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct LazyObjectProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Unique object guid
+    pub value: Guid,
+}
+impl_property_data_trait!(LazyObjectProperty);
+
+/// Interface property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct InterfaceProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Object implementing the interface
+    #[container_ignore]
+    #[container_index]
+    pub value: PackageIndex,
+}
+impl_property_data_trait!(InterfaceProperty);
+
 /// Asset object property
-#[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct AssetObjectProperty {
     /// Name
     pub name: FName,
@@ -36,7 +71,7 @@ pub struct AssetObjectProperty {
 impl_property_data_trait!(AssetObjectProperty);
 
 /// Top level asset path
-#[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct TopLevelAssetPath {
     /// Package name that contains the asset e.g. /Some/Path/Package
     /// Only present in 5.1 and higher
@@ -95,7 +130,7 @@ impl TopLevelAssetPath {
 }
 
 /// Soft object path
-#[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct SoftObjectPath {
     /// Asset path
     pub asset_path: TopLevelAssetPath,
@@ -130,7 +165,7 @@ impl SoftObjectPath {
 }
 
 /// Soft object property
-#[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct SoftObjectProperty {
     /// Name
     pub name: FName,
@@ -178,6 +213,72 @@ impl PropertyTrait for ObjectProperty {
     }
 }
 
+impl LazyObjectProperty {
+    /// Read a `LazyObjectProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+        let value = asset.read_guid()?;
+        Ok(LazyObjectProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for LazyObjectProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_guid(&self.value)?;
+        Ok(16)
+    }
+}
+
+impl InterfaceProperty {
+    /// Read an `InterfaceProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+        let value = asset.read_i32::<LE>()?;
+        Ok(InterfaceProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            value: PackageIndex::new(value),
+        })
+    }
+}
+
+impl PropertyTrait for InterfaceProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_i32::<LE>(self.value.index)?;
+        Ok(size_of::<i32>())
+    }
+}
+
 impl AssetObjectProperty {
     /// Read an `AssetObjectProperty` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
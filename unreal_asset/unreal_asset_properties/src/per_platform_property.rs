@@ -3,7 +3,7 @@
 use crate::property_prelude::*;
 
 /// Per platform bool property
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct PerPlatformBoolProperty {
     /// Name
     pub name: FName,
@@ -19,7 +19,7 @@ pub struct PerPlatformBoolProperty {
 impl_property_data_trait!(PerPlatformBoolProperty);
 
 /// Per platform int property
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct PerPlatformIntProperty {
     /// Name
     pub name: FName,
@@ -35,7 +35,7 @@ pub struct PerPlatformIntProperty {
 impl_property_data_trait!(PerPlatformIntProperty);
 
 /// Per platform float property
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct PerPlatformFloatProperty {
     /// Name
     pub name: FName,
@@ -63,7 +63,7 @@ impl PerPlatformBoolProperty {
         let property_guid = optional_guid!(asset, include_header);
 
         let num_entries = asset.read_i32::<LE>()?;
-        let mut value = Vec::with_capacity(num_entries as usize);
+        let mut value = Vec::with_capacity(capped_capacity(num_entries));
 
         for _i in 0..num_entries as usize {
             value.push(asset.read_bool()?);
@@ -107,7 +107,7 @@ impl PerPlatformIntProperty {
         let property_guid = optional_guid!(asset, include_header);
 
         let num_entries = asset.read_i32::<LE>()?;
-        let mut value = Vec::with_capacity(num_entries as usize);
+        let mut value = Vec::with_capacity(capped_capacity(num_entries));
 
         for _i in 0..num_entries as usize {
             value.push(asset.read_i32::<LE>()?);
@@ -151,7 +151,7 @@ impl PerPlatformFloatProperty {
         let property_guid = optional_guid!(asset, include_header);
 
         let num_entries = asset.read_i32::<LE>()?;
-        let mut value = Vec::with_capacity(num_entries as usize);
+        let mut value = Vec::with_capacity(capped_capacity(num_entries));
 
         for _i in 0..num_entries as usize {
             value.push(OrderedFloat(asset.read_f32::<LE>()?));
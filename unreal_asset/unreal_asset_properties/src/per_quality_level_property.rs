@@ -0,0 +1,158 @@
+//! Per quality level properties
+
+use crate::property_prelude::*;
+
+/// Per quality level int property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+pub struct PerQualityLevelIntProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Is cooked
+    pub cooked: bool,
+    /// Default value
+    pub default: i32,
+    /// Values for each quality level
+    #[container_ignore]
+    pub per_quality: Vec<(i32, i32)>,
+}
+impl_property_data_trait!(PerQualityLevelIntProperty);
+
+/// Per quality level float property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+pub struct PerQualityLevelFloatProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Is cooked
+    pub cooked: bool,
+    /// Default value
+    pub default: OrderedFloat<f32>,
+    /// Values for each quality level
+    #[container_ignore]
+    pub per_quality: Vec<(i32, OrderedFloat<f32>)>,
+}
+impl_property_data_trait!(PerQualityLevelFloatProperty);
+
+impl PerQualityLevelIntProperty {
+    /// Read a `PerQualityLevelIntProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+
+        let cooked = asset.read_i32::<LE>()? == 1;
+        let default = asset.read_i32::<LE>()?;
+
+        let num_entries = asset.read_i32::<LE>()?;
+        let mut per_quality = Vec::with_capacity(capped_capacity(num_entries));
+        for _ in 0..num_entries {
+            per_quality.push((asset.read_i32::<LE>()?, asset.read_i32::<LE>()?));
+        }
+
+        Ok(PerQualityLevelIntProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            cooked,
+            default,
+            per_quality,
+        })
+    }
+}
+
+impl PropertyTrait for PerQualityLevelIntProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_i32::<LE>(match self.cooked {
+            true => 1,
+            false => 0,
+        })?;
+        asset.write_i32::<LE>(self.default)?;
+
+        asset.write_i32::<LE>(self.per_quality.len() as i32)?;
+        for (quality, value) in &self.per_quality {
+            asset.write_i32::<LE>(*quality)?;
+            asset.write_i32::<LE>(*value)?;
+        }
+        Ok(size_of::<i32>() * 3 + size_of::<i32>() * 2 * self.per_quality.len())
+    }
+}
+
+impl PerQualityLevelFloatProperty {
+    /// Read a `PerQualityLevelFloatProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+
+        let cooked = asset.read_i32::<LE>()? == 1;
+        let default = OrderedFloat(asset.read_f32::<LE>()?);
+
+        let num_entries = asset.read_i32::<LE>()?;
+        let mut per_quality = Vec::with_capacity(capped_capacity(num_entries));
+        for _ in 0..num_entries {
+            per_quality.push((
+                asset.read_i32::<LE>()?,
+                OrderedFloat(asset.read_f32::<LE>()?),
+            ));
+        }
+
+        Ok(PerQualityLevelFloatProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            cooked,
+            default,
+            per_quality,
+        })
+    }
+}
+
+impl PropertyTrait for PerQualityLevelFloatProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_i32::<LE>(match self.cooked {
+            true => 1,
+            false => 0,
+        })?;
+        asset.write_f32::<LE>(self.default.0)?;
+
+        asset.write_i32::<LE>(self.per_quality.len() as i32)?;
+        for (quality, value) in &self.per_quality {
+            asset.write_i32::<LE>(*quality)?;
+            asset.write_f32::<LE>(value.0)?;
+        }
+        Ok(size_of::<i32>() * 2
+            + size_of::<f32>()
+            + (size_of::<i32>() + size_of::<f32>()) * self.per_quality.len())
+    }
+}
@@ -0,0 +1,1019 @@
+//! Typed property access by path
+//!
+//! A path is a list of property names separated by dots, e.g. `Stats.MaxHealth`.
+//! Brackets index into arrays, sets and static arrays (`Items[3].Count`),
+//! or look up map values by key (`Rewards[Gold]`, `Rewards["Key.With.Dots"]`).
+//! When searching with [`PropertyAccess::find_properties`], `[*]` matches every element.
+
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+use crate::array_property::ArrayProperty;
+use crate::enum_property::EnumProperty;
+use crate::int_property::{
+    BoolProperty, ByteProperty, BytePropertyValue, DoubleProperty, FloatProperty, Int16Property,
+    Int64Property, Int8Property, IntProperty, UInt16Property, UInt32Property, UInt64Property,
+};
+use crate::map_property::MapProperty;
+use crate::object_property::ObjectProperty;
+use crate::property_prelude::*;
+use crate::str_property::NameProperty;
+
+/// Property path segment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// Property name
+    Name(String),
+    /// Array or set element, static array element or integer map key
+    Index(usize),
+    /// Map key
+    Key(String),
+    /// Every element or map value, only matches when searching
+    Any,
+}
+
+/// Property path
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyPath {
+    /// Path segments, always starting with a property name
+    pub segments: Vec<PathSegment>,
+}
+
+impl FromStr for PropertyPath {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let invalid = |msg: &str| Error::from(PropertyError::invalid_path(path, msg));
+
+        let mut segments = Vec::new();
+        let mut chars = path.chars().peekable();
+        let mut expect_name = true;
+
+        while let Some(&c) = chars.peek() {
+            match c {
+                '.' => {
+                    if expect_name {
+                        return Err(invalid("empty property name"));
+                    }
+                    chars.next();
+                    expect_name = true;
+                }
+                '[' => {
+                    if expect_name {
+                        return Err(invalid("index without a property name"));
+                    }
+                    chars.next();
+
+                    let segment = match chars.peek() {
+                        Some('"') => {
+                            chars.next();
+                            let mut key = String::new();
+                            loop {
+                                match chars.next() {
+                                    Some('\\') => key.push(
+                                        chars.next().ok_or_else(|| invalid("unterminated key"))?,
+                                    ),
+                                    Some('"') => break,
+                                    Some(c) => key.push(c),
+                                    None => return Err(invalid("unterminated key")),
+                                }
+                            }
+                            PathSegment::Key(key)
+                        }
+                        _ => {
+                            let mut key = String::new();
+                            while let Some(&c) = chars.peek() {
+                                if c == ']' {
+                                    break;
+                                }
+                                key.push(c);
+                                chars.next();
+                            }
+                            if key.is_empty() {
+                                return Err(invalid("empty index"));
+                            }
+                            match key.parse::<usize>() {
+                                Ok(index) => PathSegment::Index(index),
+                                Err(_) if key == "*" => PathSegment::Any,
+                                Err(_) => PathSegment::Key(key),
+                            }
+                        }
+                    };
+
+                    if chars.next() != Some(']') {
+                        return Err(invalid("expected ]"));
+                    }
+                    segments.push(segment);
+                }
+                _ => {
+                    if !expect_name {
+                        return Err(invalid("expected . or ["));
+                    }
+
+                    let mut name = String::new();
+                    while let Some(&c) = chars.peek() {
+                        match c {
+                            '.' | '[' => break,
+                            ']' | '"' => return Err(invalid("unexpected character in name")),
+                            _ => name.push(c),
+                        }
+                        chars.next();
+                    }
+                    segments.push(PathSegment::Name(name));
+                    expect_name = false;
+                }
+            }
+        }
+
+        if expect_name {
+            return Err(invalid(match segments.is_empty() {
+                true => "empty path",
+                false => "path ends with .",
+            }));
+        }
+
+        Ok(PropertyPath { segments })
+    }
+}
+
+impl Display for PropertyPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Name(name) => {
+                    if i != 0 {
+                        f.write_char('.')?;
+                    }
+                    f.write_str(name)?;
+                }
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+                PathSegment::Any => f.write_str("[*]")?,
+                PathSegment::Key(key) => {
+                    f.write_str("[\"")?;
+                    for c in key.chars() {
+                        if c == '"' || c == '\\' {
+                            f.write_char('\\')?;
+                        }
+                        f.write_char(c)?;
+                    }
+                    f.write_str("\"]")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A value that can be read from a property
+pub trait FromProperty: Sized {
+    /// Read a value from a property, `None` if the property doesn't hold a compatible value
+    fn from_property(property: &Property) -> Option<Self>;
+}
+
+/// A value that can be stored in a property
+pub trait IntoProperty {
+    /// Store this value in an existing property
+    ///
+    /// Returns `false` if the property can't hold this value
+    fn set_property(&self, property: &mut Property) -> bool;
+
+    /// Create a new property of the type best matching this value
+    ///
+    /// Only used for array elements and map entries, whose type is checked against the container's type.
+    fn into_property(self, name: FName, ancestry: Ancestry, duplication_index: i32) -> Property;
+}
+
+/// Get the value of an integer property
+fn integer_value(property: &Property) -> Option<i128> {
+    match property {
+        Property::Int8Property(e) => Some(e.value as i128),
+        Property::Int16Property(e) => Some(e.value as i128),
+        Property::IntProperty(e) => Some(e.value as i128),
+        Property::Int64Property(e) => Some(e.value as i128),
+        Property::UInt16Property(e) => Some(e.value as i128),
+        Property::UInt32Property(e) => Some(e.value as i128),
+        Property::UInt64Property(e) => Some(e.value as i128),
+        Property::ByteProperty(ByteProperty {
+            value: BytePropertyValue::Byte(value),
+            ..
+        }) => Some(*value as i128),
+        _ => None,
+    }
+}
+
+/// Set the value of an integer property, `false` if the value doesn't fit
+fn set_integer_value(property: &mut Property, value: i128) -> bool {
+    macro_rules! try_set {
+        ($target:expr) => {
+            match value.try_into() {
+                Ok(value) => {
+                    $target = value;
+                    true
+                }
+                Err(_) => false,
+            }
+        };
+    }
+
+    match property {
+        Property::Int8Property(e) => try_set!(e.value),
+        Property::Int16Property(e) => try_set!(e.value),
+        Property::IntProperty(e) => try_set!(e.value),
+        Property::Int64Property(e) => try_set!(e.value),
+        Property::UInt16Property(e) => try_set!(e.value),
+        Property::UInt32Property(e) => try_set!(e.value),
+        Property::UInt64Property(e) => try_set!(e.value),
+        Property::ByteProperty(ByteProperty {
+            value: BytePropertyValue::Byte(byte),
+            ..
+        }) => try_set!(*byte),
+        _ => false,
+    }
+}
+
+macro_rules! impl_integer_value {
+    ($ty:ty, $property:ident) => {
+        impl FromProperty for $ty {
+            fn from_property(property: &Property) -> Option<Self> {
+                integer_value(property).and_then(|e| e.try_into().ok())
+            }
+        }
+
+        impl IntoProperty for $ty {
+            fn set_property(&self, property: &mut Property) -> bool {
+                set_integer_value(property, *self as i128)
+            }
+
+            fn into_property(
+                self,
+                name: FName,
+                ancestry: Ancestry,
+                duplication_index: i32,
+            ) -> Property {
+                $property {
+                    name,
+                    ancestry,
+                    property_guid: None,
+                    duplication_index,
+                    value: self,
+                }
+                .into()
+            }
+        }
+    };
+}
+
+impl_integer_value!(i8, Int8Property);
+impl_integer_value!(i16, Int16Property);
+impl_integer_value!(i32, IntProperty);
+impl_integer_value!(i64, Int64Property);
+impl_integer_value!(u16, UInt16Property);
+impl_integer_value!(u32, UInt32Property);
+impl_integer_value!(u64, UInt64Property);
+
+impl FromProperty for u8 {
+    fn from_property(property: &Property) -> Option<Self> {
+        integer_value(property).and_then(|e| e.try_into().ok())
+    }
+}
+
+impl IntoProperty for u8 {
+    fn set_property(&self, property: &mut Property) -> bool {
+        set_integer_value(property, *self as i128)
+    }
+
+    fn into_property(self, name: FName, ancestry: Ancestry, duplication_index: i32) -> Property {
+        ByteProperty {
+            enum_type: Some(name.new_sibling("None")),
+            name,
+            ancestry,
+            property_guid: None,
+            duplication_index,
+            value: BytePropertyValue::Byte(self),
+        }
+        .into()
+    }
+}
+
+macro_rules! impl_float_value {
+    ($ty:ty, $property:ident) => {
+        impl FromProperty for $ty {
+            fn from_property(property: &Property) -> Option<Self> {
+                match property {
+                    Property::FloatProperty(e) => Some(e.value.0 as $ty),
+                    Property::DoubleProperty(e) => Some(e.value.0 as $ty),
+                    _ => None,
+                }
+            }
+        }
+
+        impl IntoProperty for $ty {
+            fn set_property(&self, property: &mut Property) -> bool {
+                match property {
+                    Property::FloatProperty(e) => e.value = OrderedFloat(*self as f32),
+                    Property::DoubleProperty(e) => e.value = OrderedFloat(*self as f64),
+                    _ => return false,
+                }
+                true
+            }
+
+            fn into_property(
+                self,
+                name: FName,
+                ancestry: Ancestry,
+                duplication_index: i32,
+            ) -> Property {
+                $property {
+                    name,
+                    ancestry,
+                    property_guid: None,
+                    duplication_index,
+                    value: OrderedFloat(self),
+                }
+                .into()
+            }
+        }
+    };
+}
+
+impl_float_value!(f32, FloatProperty);
+impl_float_value!(f64, DoubleProperty);
+
+impl FromProperty for bool {
+    fn from_property(property: &Property) -> Option<Self> {
+        match property {
+            Property::BoolProperty(e) => Some(e.value),
+            _ => None,
+        }
+    }
+}
+
+impl IntoProperty for bool {
+    fn set_property(&self, property: &mut Property) -> bool {
+        match property {
+            Property::BoolProperty(e) => {
+                e.value = *self;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn into_property(self, name: FName, ancestry: Ancestry, duplication_index: i32) -> Property {
+        BoolProperty {
+            name,
+            ancestry,
+            property_guid: None,
+            duplication_index,
+            value: self,
+        }
+        .into()
+    }
+}
+
+impl FromProperty for FName {
+    fn from_property(property: &Property) -> Option<Self> {
+        match property {
+            Property::NameProperty(e) => Some(e.value.clone()),
+            Property::EnumProperty(e) => e.value.clone(),
+            Property::ByteProperty(ByteProperty {
+                value: BytePropertyValue::FName(value),
+                ..
+            }) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromProperty for String {
+    fn from_property(property: &Property) -> Option<Self> {
+        match property {
+            Property::StrProperty(e) => e.value.clone(),
+            Property::TextProperty(e) => e.value.clone(),
+            _ => FName::from_property(property).map(|e| e.get_owned_content()),
+        }
+    }
+}
+
+impl IntoProperty for &str {
+    fn set_property(&self, property: &mut Property) -> bool {
+        match property {
+            Property::StrProperty(e) => e.value = Some(self.to_string()),
+            Property::NameProperty(e) => e.value = e.name.new_sibling(self),
+            Property::EnumProperty(e) => e.value = Some(e.name.new_sibling(self)),
+            Property::ByteProperty(e) if matches!(e.value, BytePropertyValue::FName(_)) => {
+                e.value = BytePropertyValue::FName(e.name.new_sibling(self))
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn into_property(self, name: FName, ancestry: Ancestry, duplication_index: i32) -> Property {
+        StrProperty {
+            name,
+            ancestry,
+            property_guid: None,
+            duplication_index,
+            value: Some(self.to_string()),
+        }
+        .into()
+    }
+}
+
+impl IntoProperty for String {
+    fn set_property(&self, property: &mut Property) -> bool {
+        self.as_str().set_property(property)
+    }
+
+    fn into_property(self, name: FName, ancestry: Ancestry, duplication_index: i32) -> Property {
+        self.as_str()
+            .into_property(name, ancestry, duplication_index)
+    }
+}
+
+impl IntoProperty for FName {
+    fn set_property(&self, property: &mut Property) -> bool {
+        self.get_owned_content().as_str().set_property(property)
+    }
+
+    fn into_property(self, name: FName, ancestry: Ancestry, duplication_index: i32) -> Property {
+        NameProperty {
+            value: name.new_sibling(&self.get_owned_content()),
+            name,
+            ancestry,
+            property_guid: None,
+            duplication_index,
+        }
+        .into()
+    }
+}
+
+impl FromProperty for PackageIndex {
+    fn from_property(property: &Property) -> Option<Self> {
+        match property {
+            Property::ObjectProperty(e) => Some(e.value),
+            Property::InterfaceProperty(e) => Some(e.value),
+            _ => None,
+        }
+    }
+}
+
+impl IntoProperty for PackageIndex {
+    fn set_property(&self, property: &mut Property) -> bool {
+        match property {
+            Property::ObjectProperty(e) => e.value = *self,
+            Property::InterfaceProperty(e) => e.value = *self,
+            _ => return false,
+        }
+        true
+    }
+
+    fn into_property(self, name: FName, ancestry: Ancestry, duplication_index: i32) -> Property {
+        ObjectProperty {
+            name,
+            ancestry,
+            property_guid: None,
+            duplication_index,
+            value: self,
+        }
+        .into()
+    }
+}
+
+/// Check if a map key matches a path segment
+fn key_matches(key: &Property, segment: &PathSegment) -> bool {
+    match segment {
+        PathSegment::Index(index) => integer_value(key) == Some(*index as i128),
+        PathSegment::Key(name) => match integer_value(key) {
+            Some(value) => name.parse::<i128>().is_ok_and(|e| e == value),
+            None => String::from_property(key).is_some_and(|e| &e == name),
+        },
+        PathSegment::Name(_) | PathSegment::Any => false,
+    }
+}
+
+/// Get a path segment addressing a map key
+///
+/// Returns `None` for keys that can't be addressed by a path, like structs
+fn key_segment(key: &Property) -> Option<PathSegment> {
+    match integer_value(key) {
+        Some(value) => Some(PathSegment::Key(value.to_string())),
+        None => String::from_property(key).map(PathSegment::Key),
+    }
+}
+
+/// Find all properties in a list of properties matching a path
+fn find_matching<'a>(
+    properties: &'a [Property],
+    segments: &[PathSegment],
+    prefix: &mut Vec<PathSegment>,
+    found: &mut Vec<(PropertyPath, &'a Property)>,
+) {
+    let Some(PathSegment::Name(name)) = segments.first() else {
+        return;
+    };
+    let named = properties
+        .iter()
+        .filter(|e| e.get_name() == name.as_str())
+        .collect::<Vec<_>>();
+    let Some(first) = named.iter().find(|e| e.get_duplication_index() == 0) else {
+        return;
+    };
+
+    let is_container = matches!(
+        first,
+        Property::ArrayProperty(_) | Property::SetProperty(_) | Property::MapProperty(_)
+    );
+
+    // static array elements
+    let elements = match (segments.get(1), is_container) {
+        (Some(PathSegment::Any), false) => named,
+        (Some(PathSegment::Index(index)), false) => named
+            .into_iter()
+            .filter(|e| e.get_duplication_index() == *index as i32)
+            .collect(),
+        _ => {
+            prefix.push(PathSegment::Name(name.clone()));
+            find_matching_in(first, &segments[1..], prefix, found);
+            prefix.pop();
+            return;
+        }
+    };
+
+    for element in elements {
+        prefix.push(PathSegment::Name(name.clone()));
+        prefix.push(PathSegment::Index(element.get_duplication_index() as usize));
+        find_matching_in(element, &segments[2..], prefix, found);
+        prefix.truncate(prefix.len() - 2);
+    }
+}
+
+/// Find all properties inside of a property matching the rest of a path
+fn find_matching_in<'a>(
+    property: &'a Property,
+    segments: &[PathSegment],
+    prefix: &mut Vec<PathSegment>,
+    found: &mut Vec<(PropertyPath, &'a Property)>,
+) {
+    let Some(segment) = segments.first() else {
+        found.push((
+            PropertyPath {
+                segments: prefix.clone(),
+            },
+            property,
+        ));
+        return;
+    };
+
+    let mut visit = |segment: PathSegment, element: &'a Property| {
+        prefix.push(segment);
+        find_matching_in(element, &segments[1..], prefix, found);
+        prefix.pop();
+    };
+
+    match (property, segment) {
+        (Property::StructProperty(e), PathSegment::Name(_)) => {
+            find_matching(&e.value, segments, prefix, found)
+        }
+        (Property::ArrayProperty(e), PathSegment::Index(index)) => {
+            if let Some(element) = e.value.get(*index) {
+                visit(PathSegment::Index(*index), element);
+            }
+        }
+        (Property::ArrayProperty(e), PathSegment::Any) => {
+            for (i, element) in e.value.iter().enumerate() {
+                visit(PathSegment::Index(i), element);
+            }
+        }
+        (Property::SetProperty(e), PathSegment::Index(index)) => {
+            if let Some(element) = e.value.value.get(*index) {
+                visit(PathSegment::Index(*index), element);
+            }
+        }
+        (Property::SetProperty(e), PathSegment::Any) => {
+            for (i, element) in e.value.value.iter().enumerate() {
+                visit(PathSegment::Index(i), element);
+            }
+        }
+        (Property::MapProperty(e), PathSegment::Index(_) | PathSegment::Key(_)) => {
+            if let Some((_, _, value)) =
+                e.value.iter().find(|(_, key, _)| key_matches(key, segment))
+            {
+                visit(segment.clone(), value);
+            }
+        }
+        (Property::MapProperty(e), PathSegment::Any) => {
+            for (_, key, value) in e.value.iter() {
+                if let Some(key) = key_segment(key) {
+                    visit(key, value);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Find a named property in a list of properties
+///
+/// If the property isn't a container, an index following the name selects a static array element.
+/// Returns the property index and the amount of consumed segments.
+fn find_named(properties: &[Property], segments: &[PathSegment]) -> Option<(usize, usize)> {
+    let Some(PathSegment::Name(name)) = segments.first() else {
+        return None;
+    };
+    let position = |duplication_index: i32| {
+        properties.iter().position(|e| {
+            e.get_duplication_index() == duplication_index && e.get_name() == name.as_str()
+        })
+    };
+
+    let first = position(0);
+    if let Some(PathSegment::Index(index)) = segments.get(1) {
+        let is_container = first.is_some_and(|e| {
+            matches!(
+                properties[e],
+                Property::ArrayProperty(_) | Property::SetProperty(_) | Property::MapProperty(_)
+            )
+        });
+        if !is_container {
+            return position(*index as i32).map(|e| (e, 2));
+        }
+    }
+    first.map(|e| (e, 1))
+}
+
+/// Resolve a path in a list of properties
+fn resolve<'a>(properties: &'a [Property], segments: &[PathSegment]) -> Option<&'a Property> {
+    let (index, consumed) = find_named(properties, segments)?;
+    resolve_in(&properties[index], &segments[consumed..])
+}
+
+/// Resolve the rest of a path inside of a property
+fn resolve_in<'a>(property: &'a Property, segments: &[PathSegment]) -> Option<&'a Property> {
+    let Some(segment) = segments.first() else {
+        return Some(property);
+    };
+
+    match (property, segment) {
+        (Property::StructProperty(e), PathSegment::Name(_)) => resolve(&e.value, segments),
+        (Property::ArrayProperty(e), PathSegment::Index(index)) => {
+            resolve_in(e.value.get(*index)?, &segments[1..])
+        }
+        (Property::SetProperty(e), PathSegment::Index(index)) => {
+            resolve_in(e.value.value.get(*index)?, &segments[1..])
+        }
+        (Property::MapProperty(e), PathSegment::Index(_) | PathSegment::Key(_)) => {
+            let (_, _, value) = e
+                .value
+                .iter()
+                .find(|(_, key, _)| key_matches(key, segment))?;
+            resolve_in(value, &segments[1..])
+        }
+        _ => None,
+    }
+}
+
+/// Resolve a path in a list of properties mutably
+fn resolve_mut<'a>(
+    properties: &'a mut [Property],
+    segments: &[PathSegment],
+) -> Option<&'a mut Property> {
+    let (index, consumed) = find_named(properties, segments)?;
+    resolve_in_mut(&mut properties[index], &segments[consumed..])
+}
+
+/// Resolve the rest of a path inside of a property mutably
+fn resolve_in_mut<'a>(
+    property: &'a mut Property,
+    segments: &[PathSegment],
+) -> Option<&'a mut Property> {
+    let Some(segment) = segments.first() else {
+        return Some(property);
+    };
+
+    match (property, segment) {
+        (Property::StructProperty(e), PathSegment::Name(_)) => resolve_mut(&mut e.value, segments),
+        (Property::ArrayProperty(e), PathSegment::Index(index)) => {
+            resolve_in_mut(e.value.get_mut(*index)?, &segments[1..])
+        }
+        (Property::SetProperty(e), PathSegment::Index(index)) => {
+            resolve_in_mut(e.value.value.get_mut(*index)?, &segments[1..])
+        }
+        (Property::MapProperty(e), PathSegment::Index(_) | PathSegment::Key(_)) => {
+            let (_, _, value) = e
+                .value
+                .iter_mut()
+                .find(|(_, key, _)| key_matches(key, segment))?;
+            resolve_in_mut(value, &segments[1..])
+        }
+        _ => None,
+    }
+}
+
+/// Creates a missing property of the declared type
+///
+/// Gets the struct type the property is missing from, `None` for the accessed properties themselves,
+/// the property name, its ancestry and its duplication index.
+type NewProperty<'a> = dyn FnMut(Option<&FName>, FName, Ancestry, i32) -> Option<Property> + 'a;
+
+/// Set a value in a list of properties, creating the property if it doesn't exist
+fn set_value(
+    properties: &mut Vec<Property>,
+    segments: &[PathSegment],
+    value: impl IntoProperty,
+    (parent_name, struct_type): (&FName, Option<&FName>),
+    ancestry: &Ancestry,
+    new_property: &mut NewProperty,
+) -> Result<(), &'static str> {
+    if let Some((index, consumed)) = find_named(properties, segments) {
+        return set_value_in(
+            &mut properties[index],
+            &segments[consumed..],
+            value,
+            new_property,
+        );
+    }
+
+    let (name, duplication_index) = match segments {
+        [PathSegment::Name(name)] => (name, 0),
+        [PathSegment::Name(name), PathSegment::Index(index)] => (name, *index as i32),
+        _ => return Err("property not found, only values can be created"),
+    };
+
+    let ancestry = properties
+        .first()
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_else(|| ancestry.clone());
+    let name = parent_name.new_sibling(name);
+
+    // other elements of a static array have the same type
+    if let Some(sibling) = properties.iter().find(|e| e.get_name() == name) {
+        let element = value.into_property(name, ancestry, duplication_index);
+        if element.to_serialized_name() != sibling.to_serialized_name() {
+            return Err("value doesn't match the static array type");
+        }
+        properties.push(element);
+        return Ok(());
+    }
+
+    let mut property = new_property(struct_type, name, ancestry, duplication_index)
+        .ok_or("property not found and its type is unknown")?;
+    if !value.set_property(&mut property) {
+        return Err("value doesn't match the property type");
+    }
+    properties.push(property);
+    Ok(())
+}
+
+/// Set a value inside of a property
+fn set_value_in(
+    property: &mut Property,
+    segments: &[PathSegment],
+    value: impl IntoProperty,
+    new_property: &mut NewProperty,
+) -> Result<(), &'static str> {
+    let Some(segment) = segments.first() else {
+        return match value.set_property(property) {
+            true => Ok(()),
+            false => Err("value doesn't match the property type"),
+        };
+    };
+
+    match (property, segment) {
+        (Property::StructProperty(e), PathSegment::Name(_)) => {
+            let ancestry = e
+                .ancestry
+                .with_parent(e.struct_type.clone().unwrap_or_else(|| e.name.clone()));
+            set_value(
+                &mut e.value,
+                segments,
+                value,
+                (&e.name, e.struct_type.as_ref()),
+                &ancestry,
+                new_property,
+            )
+        }
+        (Property::ArrayProperty(e), PathSegment::Index(index)) => {
+            set_array_value(e, *index, &segments[1..], value, new_property)
+        }
+        (Property::SetProperty(e), PathSegment::Index(index)) => {
+            set_array_value(&mut e.value, *index, &segments[1..], value, new_property)
+        }
+        (Property::MapProperty(e), PathSegment::Index(_) | PathSegment::Key(_)) => {
+            set_map_value(e, segment, &segments[1..], value, new_property)
+        }
+        _ => Err("property can't be indexed"),
+    }
+}
+
+/// Set an array element, appending it if the index is one past the end
+fn set_array_value(
+    array: &mut ArrayProperty,
+    index: usize,
+    segments: &[PathSegment],
+    value: impl IntoProperty,
+    new_property: &mut NewProperty,
+) -> Result<(), &'static str> {
+    if let Some(element) = array.value.get_mut(index) {
+        return set_value_in(element, segments, value, new_property);
+    }
+
+    if index != array.value.len() || !segments.is_empty() {
+        return Err("array index out of bounds");
+    }
+
+    let element_type = match (array.value.first(), &array.array_type) {
+        (Some(first), _) => first.to_serialized_name(),
+        (None, Some(array_type)) => array_type.get_owned_content(),
+        (None, None) => return Err("array type is unknown"),
+    };
+    let ancestry = match array.value.first() {
+        Some(first) => first.get_ancestry().clone(),
+        None => array.ancestry.with_parent(array.name.clone()),
+    };
+
+    let element = value.into_property(array.name.clone(), ancestry, 0);
+    if element.to_serialized_name() != element_type {
+        return Err("value doesn't match the array type");
+    }
+    array.value.push(element);
+    Ok(())
+}
+
+/// Set a map value, inserting it if the key doesn't exist
+fn set_map_value(
+    map: &mut MapProperty,
+    key: &PathSegment,
+    segments: &[PathSegment],
+    value: impl IntoProperty,
+    new_property: &mut NewProperty,
+) -> Result<(), &'static str> {
+    if let Some((_, _, entry)) = map.value.iter_mut().find(|(_, e, _)| key_matches(e, key)) {
+        return set_value_in(entry, segments, value, new_property);
+    }
+
+    if !segments.is_empty() {
+        return Err("map key not found, only values can be created");
+    }
+
+    let key_content = match key {
+        PathSegment::Index(index) => index.to_string(),
+        PathSegment::Key(key) => key.clone(),
+        PathSegment::Name(_) | PathSegment::Any => unreachable!(),
+    };
+    let ancestry = map.ancestry.with_parent(map.name.clone());
+    let key_property = map
+        .key_type
+        .get_content(|ty| match ty {
+            "IntProperty" => key_content
+                .parse::<i32>()
+                .ok()
+                .map(|e| e.into_property(map.name.clone(), ancestry.clone(), 0)),
+            "Int64Property" => key_content
+                .parse::<i64>()
+                .ok()
+                .map(|e| e.into_property(map.name.clone(), ancestry.clone(), 0)),
+            "StrProperty" => Some(key_content.as_str().into_property(
+                map.name.clone(),
+                ancestry.clone(),
+                0,
+            )),
+            "NameProperty" => Some(map.name.new_sibling(&key_content).into_property(
+                map.name.clone(),
+                ancestry.clone(),
+                0,
+            )),
+            "EnumProperty" => Some(
+                EnumProperty {
+                    name: map.name.clone(),
+                    ancestry: ancestry.clone(),
+                    value: Some(map.name.new_sibling(&key_content)),
+                    ..Default::default()
+                }
+                .into(),
+            ),
+            _ => None,
+        })
+        .ok_or("map key can't be created for this key type")?;
+
+    let value_property = value.into_property(map.name.clone(), ancestry, 0);
+    if value_property.to_serialized_name() != map.value_type.get_owned_content() {
+        return Err("value doesn't match the map value type");
+    }
+    map.value.insert(key_property, value_property);
+    Ok(())
+}
+
+/// Typed access to properties by path
+pub trait PropertyAccess {
+    /// Get the accessed properties
+    fn properties(&self) -> &[Property];
+
+    /// Get the accessed properties mutably
+    fn properties_mut(&mut self) -> &mut Vec<Property>;
+
+    /// Get an `FName` from the name map new property names should be added to
+    fn parent_name(&self) -> &FName;
+
+    /// Get the ancestry of the accessed properties
+    fn properties_ancestry(&self) -> Ancestry;
+
+    /// Get a property by path
+    fn get_property(&self, path: &str) -> Option<&Property> {
+        let path = PropertyPath::from_str(path).ok()?;
+        resolve(self.properties(), &path.segments)
+    }
+
+    /// Get a mutable property by path
+    fn get_property_mut(&mut self, path: &str) -> Option<&mut Property> {
+        let path = PropertyPath::from_str(path).ok()?;
+        resolve_mut(self.properties_mut(), &path.segments)
+    }
+
+    /// Find all properties matching a path
+    ///
+    /// `[*]` in the path matches every array, set and static array element and every map value.
+    /// Returns the concrete path of every found property, which can be used with the other methods.
+    fn find_properties(&self, path: &PropertyPath) -> Vec<(PropertyPath, &Property)> {
+        let mut found = Vec::new();
+        find_matching(
+            self.properties(),
+            &path.segments,
+            &mut Vec::new(),
+            &mut found,
+        );
+        found
+    }
+
+    /// Get a property value by path
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use unreal_asset_properties::{property_path::PropertyAccess, struct_property::StructProperty};
+    /// # let stats = StructProperty::default();
+    /// let max_health = stats.get::<f32>("Health.Max");
+    /// ```
+    fn get<T: FromProperty>(&self, path: &str) -> Option<T> {
+        self.get_property(path).and_then(T::from_property)
+    }
+
+    /// Set a property value by path
+    ///
+    /// An array element one past the end is appended and a missing map key is inserted.
+    /// Missing static array elements are created with the type of the other elements,
+    /// other missing properties can't be created without knowing their declared type,
+    /// see [`PropertyAccess::set_or_create`].
+    fn set(&mut self, path: &str, value: impl IntoProperty) -> Result<(), Error> {
+        self.set_or_create(path, value, |_, _, _, _| None)
+    }
+
+    /// Set a property value by path, creating missing properties with `new_property`
+    ///
+    /// `new_property` gets the struct type the property is missing from, `None` for the accessed properties themselves,
+    /// and the name, ancestry and duplication index of the property.
+    /// It should return a property of the declared type, e.g. from a property schema, the value is then stored in it.
+    fn set_or_create(
+        &mut self,
+        path: &str,
+        value: impl IntoProperty,
+        mut new_property: impl FnMut(Option<&FName>, FName, Ancestry, i32) -> Option<Property>,
+    ) -> Result<(), Error> {
+        let parsed = PropertyPath::from_str(path)?;
+        let parent_name = self.parent_name().clone();
+        let ancestry = self.properties_ancestry();
+
+        set_value(
+            self.properties_mut(),
+            &parsed.segments,
+            value,
+            (&parent_name, None),
+            &ancestry,
+            &mut new_property,
+        )
+        .map_err(|e| PropertyError::invalid_path(path, e).into())
+    }
+}
+
+impl PropertyAccess for StructProperty {
+    fn properties(&self) -> &[Property] {
+        &self.value
+    }
+
+    fn properties_mut(&mut self) -> &mut Vec<Property> {
+        &mut self.value
+    }
+
+    fn parent_name(&self) -> &FName {
+        &self.name
+    }
+
+    fn properties_ancestry(&self) -> Ancestry {
+        self.ancestry.with_parent(
+            self.struct_type
+                .clone()
+                .unwrap_or_else(|| self.name.clone()),
+        )
+    }
+}
@@ -69,7 +69,7 @@ pub enum RichCurveTangentWeightMode {
 }
 
 /// Rich curve key property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct RichCurveKeyProperty {
     /// Name
     pub name: FName,
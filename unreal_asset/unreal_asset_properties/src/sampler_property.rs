@@ -3,7 +3,7 @@
 use crate::property_prelude::*;
 
 /// Weighted random sampler property
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct WeightedRandomSamplerProperty {
     /// Name
     pub name: FName,
@@ -23,7 +23,7 @@ pub struct WeightedRandomSamplerProperty {
 impl_property_data_trait!(WeightedRandomSamplerProperty);
 
 /// Skeletal mesh area weighted triangle sampler
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct SkeletalMeshAreaWeightedTriangleSampler {
     /// Name
     pub name: FName,
@@ -43,7 +43,7 @@ pub struct SkeletalMeshAreaWeightedTriangleSampler {
 impl_property_data_trait!(SkeletalMeshAreaWeightedTriangleSampler);
 
 /// Skeleetal mesh sampling lod built data property
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct SkeletalMeshSamplingLODBuiltDataProperty {
     /// Name
     pub name: FName,
@@ -71,13 +71,13 @@ impl WeightedRandomSamplerProperty {
         let property_guid = optional_guid!(asset, include_header);
 
         let size = asset.read_i32::<LE>()?;
-        let mut prob = Vec::with_capacity(size as usize);
+        let mut prob = Vec::with_capacity(capped_capacity(size));
         for _i in 0..size as usize {
             prob.push(OrderedFloat(asset.read_f32::<LE>()?));
         }
 
         let size = asset.read_i32::<LE>()?;
-        let mut alias = Vec::with_capacity(size as usize);
+        let mut alias = Vec::with_capacity(capped_capacity(size));
         for _i in 0..size as usize {
             alias.push(asset.read_i32::<LE>()?);
         }
@@ -135,13 +135,13 @@ impl SkeletalMeshAreaWeightedTriangleSampler {
         let property_guid = optional_guid!(asset, include_header);
 
         let size = asset.read_i32::<LE>()?;
-        let mut prob = Vec::with_capacity(size as usize);
+        let mut prob = Vec::with_capacity(capped_capacity(size));
         for _i in 0..size as usize {
             prob.push(OrderedFloat(asset.read_f32::<LE>()?));
         }
 
         let size = asset.read_i32::<LE>()?;
-        let mut alias = Vec::with_capacity(size as usize);
+        let mut alias = Vec::with_capacity(capped_capacity(size));
         for _i in 0..size as usize {
             alias.push(asset.read_i32::<LE>()?);
         }
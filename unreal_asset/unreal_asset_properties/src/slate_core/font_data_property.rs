@@ -33,10 +33,11 @@ pub enum EFontLoadingPolicy {
 }
 
 /// Font data
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct FontData {
     /// UObject
     #[container_ignore]
+    #[container_index]
     local_font_face_asset: PackageIndex,
     /// Font filename
     font_filename: Option<String>,
@@ -127,7 +128,7 @@ impl FontData {
 }
 
 /// Font data property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct FontDataProperty {
     /// Name
     pub name: FName,
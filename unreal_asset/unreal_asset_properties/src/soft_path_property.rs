@@ -3,7 +3,7 @@
 use crate::property_prelude::*;
 
 /// Soft path property value
-#[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, PartialEq, Eq)]
 pub enum SoftObjectPathPropertyValue {
     /// asset.get_object_version() < ObjectVersion::VER_UE4_ADDED_SOFT_OBJECT_PATH
     Old(Option<String>),
@@ -47,7 +47,7 @@ impl SoftObjectPathPropertyValue {
 }
 
 /// Soft asset path property
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct SoftAssetPathProperty {
     /// Name
     pub name: FName,
@@ -63,7 +63,7 @@ pub struct SoftAssetPathProperty {
 impl_property_data_trait!(SoftAssetPathProperty);
 
 /// Soft object path property
-#[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, PartialEq, Eq)]
 pub struct SoftObjectPathProperty {
     /// Name
     pub name: FName,
@@ -79,7 +79,7 @@ pub struct SoftObjectPathProperty {
 impl_property_data_trait!(SoftObjectPathProperty);
 
 /// Soft class path property
-#[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, PartialEq, Eq)]
 pub struct SoftClassPathProperty {
     /// Name
     pub name: FName,
@@ -95,7 +95,7 @@ pub struct SoftClassPathProperty {
 impl_property_data_trait!(SoftClassPathProperty);
 
 /// String asset reference property
-#[derive(FNameContainer, Debug, Hash, Clone, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, PartialEq, Eq)]
 pub struct StringAssetReferenceProperty {
     /// Name
     pub name: FName,
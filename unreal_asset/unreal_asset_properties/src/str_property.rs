@@ -5,6 +5,7 @@ use crate::property_prelude::*;
 /// Text history type
 #[derive(
     FNameContainer,
+    PackageIndexContainer,
     Debug,
     Copy,
     Clone,
@@ -51,7 +52,7 @@ pub enum TextHistoryType {
 }
 
 /// String property
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StrProperty {
     /// Name
     pub name: FName,
@@ -66,8 +67,42 @@ pub struct StrProperty {
 }
 impl_property_data_trait!(StrProperty);
 
+/// UTF-8 string property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Utf8StrProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// FUtf8String value
+    pub value: Option<String>,
+}
+impl_property_data_trait!(Utf8StrProperty);
+
+/// ANSI string property
+///
+/// Characters are stored as single bytes, so only characters up to U+00FF can be written
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnsiStrProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// FAnsiString value
+    pub value: Option<String>,
+}
+impl_property_data_trait!(AnsiStrProperty);
+
 /// Text property
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TextProperty {
     /// Name
     pub name: FName,
@@ -93,7 +128,7 @@ pub struct TextProperty {
 impl_property_data_trait!(TextProperty);
 
 /// Name property
-#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NameProperty {
     /// Name
     pub name: FName,
@@ -142,6 +177,131 @@ impl PropertyTrait for StrProperty {
     }
 }
 
+/// Read a null-terminated single byte string prefixed with its length
+fn read_byte_string<Reader: ArchiveReader<impl PackageIndexTrait>>(
+    asset: &mut Reader,
+) -> Result<Option<Vec<u8>>, Error> {
+    let length = asset.read_i32::<LE>()?;
+    if length == 0 {
+        return Ok(None);
+    }
+    if length < 0 {
+        return Err(Error::invalid_file(format!(
+            "Invalid single byte string length {length}"
+        )));
+    }
+
+    let mut data = asset.read_bytes(length as usize)?;
+    if data.pop() != Some(0) {
+        return Err(Error::invalid_file(
+            "Single byte string is not null-terminated".to_string(),
+        ));
+    }
+    Ok(Some(data))
+}
+
+/// Write a null-terminated single byte string prefixed with its length
+fn write_byte_string<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    asset: &mut Writer,
+    value: Option<&[u8]>,
+) -> Result<usize, Error> {
+    let Some(value) = value else {
+        asset.write_i32::<LE>(0)?;
+        return Ok(size_of::<i32>());
+    };
+
+    asset.write_i32::<LE>(value.len() as i32 + 1)?;
+    asset.write_all(value)?;
+    asset.write_u8(0)?;
+    Ok(size_of::<i32>() + value.len() + 1)
+}
+
+impl Utf8StrProperty {
+    /// Read a `Utf8StrProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+        let value = read_byte_string(asset)?
+            .map(String::from_utf8)
+            .transpose()?;
+
+        Ok(Utf8StrProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for Utf8StrProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        write_byte_string(asset, self.value.as_deref().map(str::as_bytes))
+    }
+}
+
+impl AnsiStrProperty {
+    /// Read an `AnsiStrProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+        let value =
+            read_byte_string(asset)?.map(|e| e.into_iter().map(char::from).collect::<String>());
+
+        Ok(AnsiStrProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for AnsiStrProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let value = self
+            .value
+            .as_ref()
+            .map(|value| {
+                value
+                    .chars()
+                    .map(|e| {
+                        u8::try_from(e).map_err(|_| {
+                            PropertyError::other(format!(
+                                "Character {e:?} can't be stored in an ANSI string"
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        write_byte_string(asset, value.as_deref())
+    }
+}
+
 impl TextProperty {
     /// Read a `TextProperty` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
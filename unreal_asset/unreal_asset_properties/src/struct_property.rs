@@ -1,9 +1,10 @@
 //! Struct property
 
+use crate::custom_property::CustomSerializers;
 use crate::property_prelude::*;
 
 /// Struct property
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct StructProperty {
     /// Name
     pub name: FName,
@@ -104,7 +105,10 @@ impl StructProperty {
         }
 
         let mut custom_serialization = match struct_type {
-            Some(ref e) => e.get_content(Property::has_custom_serialization),
+            Some(ref e) => e.get_content(|ty| {
+                Property::has_custom_serialization(ty)
+                    || CustomSerializers::find(asset, ty).is_some()
+            }),
             None => false,
         };
 
@@ -181,13 +185,20 @@ impl StructProperty {
 
         if custom_serialization {
             let new_ancestry = ancestry.with_parent(name.clone());
+            let struct_type_name = struct_type.as_ref().unwrap();
+            // registered serializers get the length of the value, built-in types know their own
+            let value_length =
+                match struct_type_name.get_content(Property::has_custom_serialization) {
+                    true => 0,
+                    false => length,
+                };
             let property = Property::from_type(
                 asset,
-                struct_type.as_ref().unwrap(),
+                struct_type_name,
                 name.clone(),
                 new_ancestry,
                 false,
-                0,
+                value_length,
                 0,
                 0,
                 false,
@@ -283,6 +294,10 @@ impl StructProperty {
             }
         }
 
+        if let [Property::CustomProperty(_)] = self.value.as_slice() {
+            has_custom_serialization = true;
+        }
+
         if has_custom_serialization {
             if self.value.len() != 1 {
                 return Err(PropertyError::invalid_struct(
@@ -5,7 +5,7 @@ use crate::property_prelude::*;
 /// Unknown property
 ///
 /// This gets created when an unknown property was encountered while deserializing
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct UnknownProperty {
     /// Name
     pub name: FName,
@@ -34,8 +34,7 @@ impl UnknownProperty {
         serialized_type: FName,
     ) -> Result<Self, Error> {
         let property_guid = optional_guid!(asset, include_header);
-        let mut value = vec![0u8; length as usize];
-        asset.read_exact(&mut value)?;
+        let value = asset.read_bytes(length as usize)?;
 
         Ok(UnknownProperty {
             name,
@@ -5,7 +5,7 @@ use unreal_asset_base::types::vector::{Plane, Vector, Vector2, Vector4};
 use crate::property_prelude::*;
 
 /// Vector property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct VectorProperty {
     /// Name
     pub name: FName,
@@ -22,7 +22,7 @@ pub struct VectorProperty {
 impl_property_data_trait!(VectorProperty);
 
 /// Int point property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct IntPointProperty {
     /// Name
     pub name: FName,
@@ -38,8 +38,42 @@ pub struct IntPointProperty {
 }
 impl_property_data_trait!(IntPointProperty);
 
+/// Int vector property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntVectorProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Value
+    #[container_ignore]
+    pub value: Vector<i32>,
+}
+impl_property_data_trait!(IntVectorProperty);
+
+/// Int vector2 property
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntVector2Property {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Value
+    #[container_ignore]
+    pub value: Vector2<i32>,
+}
+impl_property_data_trait!(IntVector2Property);
+
 /// Vector4 property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Vector4Property {
     /// Name
     pub name: FName,
@@ -56,7 +90,7 @@ pub struct Vector4Property {
 impl_property_data_trait!(Vector4Property);
 
 /// Vector2D property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Vector2DProperty {
     /// Name
     pub name: FName,
@@ -73,7 +107,7 @@ pub struct Vector2DProperty {
 impl_property_data_trait!(Vector2DProperty);
 
 /// Quaternion property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct QuatProperty {
     /// Name
     pub name: FName,
@@ -90,7 +124,7 @@ pub struct QuatProperty {
 impl_property_data_trait!(QuatProperty);
 
 /// Rotator property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct RotatorProperty {
     /// Name
     pub name: FName,
@@ -107,7 +141,7 @@ pub struct RotatorProperty {
 impl_property_data_trait!(RotatorProperty);
 
 /// Box property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct BoxProperty {
     /// Name
     pub name: FName,
@@ -127,7 +161,7 @@ pub struct BoxProperty {
 impl_property_data_trait!(BoxProperty);
 
 /// Box2D property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Box2DProperty {
     /// Name
     pub name: FName,
@@ -147,7 +181,7 @@ pub struct Box2DProperty {
 impl_property_data_trait!(Box2DProperty);
 
 /// Plane property
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct PlaneProperty {
     /// Name
     pub name: FName,
@@ -257,6 +291,80 @@ impl PropertyTrait for IntPointProperty {
     }
 }
 
+impl IntVectorProperty {
+    /// Read an `IntVectorProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+        let x = asset.read_i32::<LE>()?;
+        let y = asset.read_i32::<LE>()?;
+        let z = asset.read_i32::<LE>()?;
+
+        Ok(IntVectorProperty {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            value: Vector::new(x, y, z),
+        })
+    }
+}
+
+impl PropertyTrait for IntVectorProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_i32::<LE>(self.value.x)?;
+        asset.write_i32::<LE>(self.value.y)?;
+        asset.write_i32::<LE>(self.value.z)?;
+        Ok(size_of::<i32>() * 3)
+    }
+}
+
+impl IntVector2Property {
+    /// Read an `IntVector2Property` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let property_guid = optional_guid!(asset, include_header);
+        let x = asset.read_i32::<LE>()?;
+        let y = asset.read_i32::<LE>()?;
+
+        Ok(IntVector2Property {
+            name,
+            ancestry,
+            property_guid,
+            duplication_index,
+            value: Vector2::new(x, y),
+        })
+    }
+}
+
+impl PropertyTrait for IntVector2Property {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_i32::<LE>(self.value.x)?;
+        asset.write_i32::<LE>(self.value.y)?;
+        Ok(size_of::<i32>() * 2)
+    }
+}
+
 impl Vector4Property {
     /// Read a `Vector4Property` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
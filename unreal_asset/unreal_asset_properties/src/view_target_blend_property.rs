@@ -22,7 +22,7 @@ pub enum ViewTargetBlendFunction {
 }
 
 /// View target blend params property
-#[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct ViewTargetBlendParamsProperty {
     /// Name
     pub name: FName,
@@ -7,7 +7,7 @@ use crate::vector_property::{BoxProperty, IntPointProperty};
 
 //todo: what is this file even doing in properties?
 /// World tile layer
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct FWorldTileLayer {
     /// Name
     pub name: Option<String>,
@@ -90,7 +90,9 @@ impl FWorldTileLayer {
 }
 
 /// World tile lod info
-#[derive(FNameContainer, Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(
+    FNameContainer, PackageIndexContainer, Debug, Copy, Clone, Default, PartialEq, Eq, Hash,
+)]
 pub struct FWorldTileLODInfo {
     /// Relative streaming distance
     pub relative_streaming_distance: i32,
@@ -133,7 +135,7 @@ impl FWorldTileLODInfo {
 }
 
 /// World tile ifno
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(FNameContainer, PackageIndexContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct FWorldTileInfo {
     /// Position
     #[container_ignore]
@@ -186,7 +188,7 @@ impl FWorldTileInfo {
         let mut lod_list = None;
         if object_version >= ObjectVersion::VER_UE4_WORLD_LEVEL_INFO_LOD_LIST {
             let num_entries = asset.read_i32::<LE>()? as usize;
-            let mut list = Vec::with_capacity(num_entries);
+            let mut list = Vec::with_capacity(capped_capacity(num_entries));
             for _i in 0..num_entries {
                 list.push(FWorldTileLODInfo::new(asset)?);
             }
@@ -0,0 +1,245 @@
+//! Asset registry lookups
+//!
+//! [`AssetRegistryIndex`] borrows an [`AssetRegistryState`] and builds lookup tables for it,
+//! so repeated queries don't have to scan every asset.
+//! All names are compared case insensitively, like Unreal does.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use unreal_asset_registry::unreal_asset;
+//! use unreal_asset::registry::{objects::depends_node::DependencyType, AssetRegistryState};
+//!
+//! # fn print_assets(registry: &AssetRegistryState) {
+//! let index = registry.index();
+//!
+//! for asset in index.find_by_class("/Script/Engine.StaticMesh") {
+//!     println!("{}", asset.object_path.get_owned_content());
+//! }
+//!
+//! for dependency in index.get_dependencies("/Game/Maps/Level", DependencyType::Hard) {
+//!     println!("{:?}", dependency.identifier.package_name);
+//! }
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use unreal_asset_base::types::FName;
+
+use crate::objects::{
+    asset_data::AssetData,
+    asset_package_data::AssetPackageData,
+    depends_node::{AssetIdentifier, DependencyType, DependsNode},
+};
+use crate::AssetRegistryState;
+
+/// Get the lookup key of an `FName`, including its instance number
+pub(crate) fn name_key(name: &FName) -> String {
+    let content = name.get_content(str::to_ascii_lowercase);
+    match name.get_number() {
+        0 => content,
+        number => format!("{}_{}", content, number - 1),
+    }
+}
+
+/// Get all lookup keys of an asset's class
+///
+/// Class paths are reachable both by their full path and by their short class name.
+fn class_keys(asset_data: &AssetData) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Some(asset_class) = &asset_data.asset_class {
+        keys.push(name_key(asset_class));
+    }
+    if let Some(asset_path) = &asset_data.asset_path {
+        let asset_name = name_key(&asset_path.asset_name);
+        keys.push(format!(
+            "{}.{}",
+            name_key(&asset_path.package_name),
+            asset_name
+        ));
+        keys.push(asset_name);
+    }
+    keys
+}
+
+/// Check if a `DependsNode` identifier is a package identifier
+fn is_package_identifier(identifier: &AssetIdentifier) -> bool {
+    identifier.package_name.is_some()
+        && identifier.primary_asset_type.is_none()
+        && identifier.object_name.is_none()
+        && identifier.value_name.is_none()
+}
+
+/// Lookup tables for an [`AssetRegistryState`]
+#[derive(Debug)]
+pub struct AssetRegistryIndex<'state> {
+    /// Indexed registry
+    state: &'state AssetRegistryState,
+    /// Asset indices by package name
+    packages: HashMap<String, Vec<usize>>,
+    /// Asset indices by object path
+    object_paths: HashMap<String, usize>,
+    /// Asset indices by class
+    classes: HashMap<String, Vec<usize>>,
+    /// Asset indices by tag name
+    tags: HashMap<String, Vec<usize>>,
+    /// Package data indices by package name
+    package_data: HashMap<String, usize>,
+    /// Package `DependsNode` indices by package name
+    depends_nodes: HashMap<String, usize>,
+}
+
+impl<'state> AssetRegistryIndex<'state> {
+    /// Build lookup tables for an `AssetRegistryState`
+    pub fn new(state: &'state AssetRegistryState) -> Self {
+        let mut packages = HashMap::<_, Vec<_>>::new();
+        let mut object_paths = HashMap::new();
+        let mut classes = HashMap::<_, Vec<_>>::new();
+        let mut tags = HashMap::<_, Vec<_>>::new();
+
+        for (i, asset_data) in state.assets_data.iter().enumerate() {
+            packages
+                .entry(name_key(&asset_data.package_name))
+                .or_default()
+                .push(i);
+            object_paths
+                .entry(name_key(&asset_data.object_path))
+                .or_insert(i);
+
+            for key in class_keys(asset_data) {
+                classes.entry(key).or_default().push(i);
+            }
+            for (_, tag, _) in &asset_data.tags_and_values {
+                tags.entry(name_key(tag)).or_default().push(i);
+            }
+        }
+
+        let mut package_data = HashMap::new();
+        for (i, data) in state.package_data.iter().enumerate() {
+            package_data
+                .entry(name_key(&data.package_name))
+                .or_insert(i);
+        }
+
+        let mut depends_nodes = HashMap::new();
+        for (i, node) in state.depends_nodes.iter().enumerate() {
+            if let (true, Some(package_name)) = (
+                is_package_identifier(&node.identifier),
+                &node.identifier.package_name,
+            ) {
+                depends_nodes.entry(name_key(package_name)).or_insert(i);
+            }
+        }
+
+        AssetRegistryIndex {
+            state,
+            packages,
+            object_paths,
+            classes,
+            tags,
+            package_data,
+            depends_nodes,
+        }
+    }
+
+    /// Get the indexed `AssetRegistryState`
+    pub fn get_state(&self) -> &'state AssetRegistryState {
+        self.state
+    }
+
+    /// Get assets by their indices
+    fn get_assets(&self, indices: Option<&Vec<usize>>) -> Vec<&'state AssetData> {
+        indices
+            .into_iter()
+            .flatten()
+            .map(|e| &self.state.assets_data[*e])
+            .collect()
+    }
+
+    /// Find all assets inside of a package, e.g. `/Game/Maps/Level`
+    pub fn find_by_package(&self, package_name: &str) -> Vec<&'state AssetData> {
+        self.get_assets(self.packages.get(&package_name.to_ascii_lowercase()))
+    }
+
+    /// Find an asset by its object path, e.g. `/Game/Maps/Level.Level`
+    pub fn find_by_object_path(&self, object_path: &str) -> Option<&'state AssetData> {
+        self.object_paths
+            .get(&object_path.to_ascii_lowercase())
+            .map(|e| &self.state.assets_data[*e])
+    }
+
+    /// Find all assets of a class
+    ///
+    /// The class can be a short class name like `StaticMesh`,
+    /// or a full class path like `/Script/Engine.StaticMesh` for registries that store class paths.
+    pub fn find_by_class(&self, class: &str) -> Vec<&'state AssetData> {
+        self.get_assets(self.classes.get(&class.to_ascii_lowercase()))
+    }
+
+    /// Find all assets that have a tag
+    ///
+    /// If `value` is `Some`, only assets where the tag has that value are returned.
+    pub fn find_by_tag(&self, tag: &str, value: Option<&str>) -> Vec<&'state AssetData> {
+        let tag = tag.to_ascii_lowercase();
+        let mut assets = self.get_assets(self.tags.get(&tag));
+        if let Some(value) = value {
+            assets.retain(|asset_data| {
+                asset_data
+                    .tags_and_values
+                    .iter()
+                    .any(|(_, key, e)| name_key(key) == tag && e.as_deref() == Some(value))
+            });
+        }
+        assets
+    }
+
+    /// Get package data of a package
+    pub fn get_package_data(&self, package_name: &str) -> Option<&'state AssetPackageData> {
+        self.package_data
+            .get(&package_name.to_ascii_lowercase())
+            .map(|e| &self.state.package_data[*e])
+    }
+
+    /// Get the `DependsNode` of a package
+    pub fn get_depends_node(&self, package_name: &str) -> Option<&'state DependsNode> {
+        self.depends_nodes
+            .get(&package_name.to_ascii_lowercase())
+            .map(|e| &self.state.depends_nodes[*e])
+    }
+
+    /// Resolve nodes that are stored by index to the full nodes of the registry
+    fn resolve_nodes(&self, nodes: &[DependsNode]) -> Vec<&'state DependsNode> {
+        nodes
+            .iter()
+            .filter_map(|e| self.state.depends_nodes.get(e.get_index() as usize))
+            .collect()
+    }
+
+    /// Get dependencies of a package
+    pub fn get_dependencies(
+        &self,
+        package_name: &str,
+        ty: DependencyType,
+    ) -> Vec<&'state DependsNode> {
+        self.get_depends_node(package_name)
+            .map(|e| self.resolve_nodes(e.get_dependencies(ty)))
+            .unwrap_or_default()
+    }
+
+    /// Get nodes referencing a package
+    pub fn get_referencers(&self, package_name: &str) -> Vec<&'state DependsNode> {
+        self.get_depends_node(package_name)
+            .map(|e| self.resolve_nodes(&e.referencers))
+            .unwrap_or_default()
+    }
+}
+
+impl AssetRegistryState {
+    /// Build lookup tables for this registry
+    ///
+    /// See [`AssetRegistryIndex`]
+    pub fn index(&self) -> AssetRegistryIndex<'_> {
+        AssetRegistryIndex::new(self)
+    }
+}
@@ -19,11 +19,13 @@ use unreal_asset_base::{
     custom_version::FAssetRegistryVersionType,
     error::RegistryError,
     object_version::{ObjectVersion, ObjectVersionUE5},
-    reader::{ArchiveReader, ArchiveTrait, ArchiveWriter, RawWriter},
+    reader::{capped_capacity, ArchiveReader, ArchiveTrait, ArchiveWriter, RawWriter},
     types::{PackageIndex, PackageIndexTrait},
     Error,
 };
 
+pub mod index;
+mod merge;
 pub(crate) mod name_table_reader;
 pub(crate) mod name_table_writer;
 pub mod objects;
@@ -62,6 +64,23 @@ pub struct AssetRegistryState {
 }
 
 impl AssetRegistryState {
+    /// Check that a `DependsNode` count read from an asset fits into the rest of the asset
+    ///
+    /// Nodes are created before their data is read, every node takes up at least 4 bytes.
+    fn check_depends_node_count<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        count: i32,
+    ) -> Result<(), Error> {
+        let remaining = asset.data_length()?.saturating_sub(asset.position());
+        if count as i64 * 4 > remaining as i64 {
+            return Err(RegistryError::other(format!(
+                "DependsNode count {count} exceeds the remaining {remaining} bytes"
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
     /// Read an `AssetRegistryState` from an asset
     fn load<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
@@ -74,7 +93,8 @@ impl AssetRegistryState {
 
         if version < FAssetRegistryVersionType::AddedDependencyFlags {
             let local_num_depends_nodes = asset.read_i32::<LE>()?;
-            *depends_nodes = Vec::with_capacity(local_num_depends_nodes as usize);
+            Self::check_depends_node_count(asset, local_num_depends_nodes)?;
+            *depends_nodes = Vec::with_capacity(capped_capacity(local_num_depends_nodes));
 
             for i in 0..local_num_depends_nodes {
                 depends_nodes.push(DependsNode::new(i, version));
@@ -88,10 +108,16 @@ impl AssetRegistryState {
             }
         } else {
             let dependency_section_size = asset.read_i64::<LE>()?;
-            let dependency_section_end = asset.position() + dependency_section_size as u64;
+            let dependency_section_end = asset
+                .position()
+                .checked_add_signed(dependency_section_size)
+                .ok_or_else(|| {
+                    RegistryError::other("Invalid dependency section size".to_string())
+                })?;
             let local_num_depends_nodes = asset.read_i32::<LE>()?;
+            Self::check_depends_node_count(asset, local_num_depends_nodes)?;
 
-            *depends_nodes = Vec::with_capacity(local_num_depends_nodes as usize);
+            *depends_nodes = Vec::with_capacity(capped_capacity(local_num_depends_nodes));
             for i in 0..local_num_depends_nodes {
                 depends_nodes.push(DependsNode::new(i, version));
             }
@@ -237,6 +263,30 @@ impl AssetRegistryState {
         })
     }
 
+    /// Create an empty `AssetRegistryState`
+    ///
+    /// Can be used to create a registry that only contains modded assets,
+    /// which can later be merged into the game's registry with [`AssetRegistryState::merge`].
+    pub fn new_empty(
+        version: FAssetRegistryVersionType,
+        object_version: ObjectVersion,
+        object_version_ue5: ObjectVersionUE5,
+    ) -> Self {
+        let name_map = (version < FAssetRegistryVersionType::FixedTags).then(NameMap::new);
+
+        Self {
+            version,
+            assets_data: Vec::new(),
+            depends_nodes: Vec::new(),
+            package_data: Vec::new(),
+
+            name_map,
+
+            object_version,
+            object_version_ue5,
+        }
+    }
+
     /// Writes asset registry to a binary cursor
     ///
     /// # Errors
@@ -350,6 +400,11 @@ impl AssetRegistryState {
         self.add_name_reference(string, false)
     }
 
+    /// Gets the name map of this registry, registries with version >= FixedTags don't have one
+    pub fn get_name_map(&self) -> Option<SharedResource<NameMap>> {
+        self.name_map.clone()
+    }
+
     /// Gets current AssetRegistry version
     pub fn get_version(&self) -> FAssetRegistryVersionType {
         self.version
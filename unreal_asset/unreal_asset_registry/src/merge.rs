@@ -0,0 +1,227 @@
+//! Asset registry editing and merging
+
+use std::collections::HashMap;
+
+use unreal_asset_base::{
+    error::RegistryError,
+    types::{fname::FNameContainer, FName},
+    Error,
+};
+
+use crate::index::name_key;
+use crate::objects::{
+    asset_data::AssetData, asset_package_data::AssetPackageData, depends_node::AssetIdentifier,
+};
+use crate::AssetRegistryState;
+
+/// Get the lookup key of a `DependsNode` identifier
+fn identifier_key(identifier: &AssetIdentifier) -> [Option<String>; 4] {
+    [
+        identifier.package_name.as_ref().map(name_key),
+        identifier.primary_asset_type.as_ref().map(name_key),
+        identifier.object_name.as_ref().map(name_key),
+        identifier.value_name.as_ref().map(name_key),
+    ]
+}
+
+impl AssetRegistryState {
+    /// Move all `FName`s of a container into this registry's name map
+    ///
+    /// Names can only be serialized by the registry whose name map they belong to.
+    /// Registries without a name map leave names as they are.
+    pub fn import_fnames(&mut self, container: &mut impl FNameContainer) {
+        let Some(mut name_map) = self.name_map.clone() else {
+            return;
+        };
+
+        container.traverse_fnames(&mut |name: &mut FName| {
+            let content = name.get_owned_content();
+            let number = name.get_number();
+            *name = name_map.get_mut().add_fname_with_number(&content, number);
+        });
+    }
+
+    /// Add an asset, replacing any asset with the same object path
+    ///
+    /// Names of the asset are imported into this registry's name map, so they can be created
+    /// with [`FName::from_slice`]. Package data is created for the asset's package if it doesn't exist yet.
+    ///
+    /// Returns the replaced asset.
+    pub fn add_asset_data(&mut self, mut asset_data: AssetData) -> Option<AssetData> {
+        self.import_fnames(&mut asset_data);
+
+        let package_key = name_key(&asset_data.package_name);
+        if !self
+            .package_data
+            .iter()
+            .any(|e| name_key(&e.package_name) == package_key)
+        {
+            self.package_data.push(AssetPackageData::from_package_name(
+                asset_data.package_name.clone(),
+                self.object_version,
+                self.object_version_ue5,
+                self.version,
+            ));
+        }
+
+        let object_path = name_key(&asset_data.object_path);
+        match self
+            .assets_data
+            .iter_mut()
+            .find(|e| name_key(&e.object_path) == object_path)
+        {
+            Some(existing) => Some(std::mem::replace(existing, asset_data)),
+            None => {
+                self.assets_data.push(asset_data);
+                None
+            }
+        }
+    }
+
+    /// Add package data, replacing any package data of the same package
+    ///
+    /// Returns the replaced package data.
+    pub fn add_package_data(
+        &mut self,
+        mut package_data: AssetPackageData,
+    ) -> Option<AssetPackageData> {
+        self.import_fnames(&mut package_data);
+
+        let package_name = name_key(&package_data.package_name);
+        match self
+            .package_data
+            .iter_mut()
+            .find(|e| name_key(&e.package_name) == package_name)
+        {
+            Some(existing) => Some(std::mem::replace(existing, package_data)),
+            None => {
+                self.package_data.push(package_data);
+                None
+            }
+        }
+    }
+
+    /// Remove an asset by its object path
+    ///
+    /// Package data and dependencies of the asset's package are kept,
+    /// use [`AssetRegistryState::remove_package`] to remove a whole package.
+    pub fn remove_asset_data(&mut self, object_path: &str) -> Option<AssetData> {
+        let object_path = object_path.to_ascii_lowercase();
+        let index = self
+            .assets_data
+            .iter()
+            .position(|e| name_key(&e.object_path) == object_path)?;
+        Some(self.assets_data.remove(index))
+    }
+
+    /// Remove a package with all of its assets, package data and `DependsNode`s
+    ///
+    /// Dependencies on the removed nodes are removed from all other nodes.
+    ///
+    /// Returns the removed assets.
+    pub fn remove_package(&mut self, package_name: &str) -> Vec<AssetData> {
+        let package_name = package_name.to_ascii_lowercase();
+        let is_package = |name: &FName| name_key(name) == package_name;
+
+        let (removed, kept) = std::mem::take(&mut self.assets_data)
+            .into_iter()
+            .partition(|e| is_package(&e.package_name));
+        self.assets_data = kept;
+        self.package_data.retain(|e| !is_package(&e.package_name));
+
+        // node indices after removing the package's nodes, `None` for removed nodes
+        let mut next_index = 0;
+        let new_indices = self
+            .depends_nodes
+            .iter()
+            .map(
+                |e| match e.identifier.package_name.as_ref().is_some_and(is_package) {
+                    true => None,
+                    false => {
+                        next_index += 1;
+                        Some(next_index - 1)
+                    }
+                },
+            )
+            .collect::<Vec<_>>();
+
+        let nodes = std::mem::take(&mut self.depends_nodes);
+        for (mut node, new_index) in nodes.into_iter().zip(&new_indices) {
+            let Some(new_index) = new_index else {
+                continue;
+            };
+
+            node.set_index(*new_index);
+            node.remap_dependencies(|e| new_indices.get(e as usize).copied().flatten());
+            self.depends_nodes.push(node);
+        }
+
+        removed
+    }
+
+    /// Merge another registry into this one
+    ///
+    /// Assets and package data of `other` replace entries with the same object path or package name.
+    /// `DependsNode`s with the same identifier are merged by adding all dependencies and referencers
+    /// this registry doesn't have yet, all other nodes are appended.
+    ///
+    /// # Errors
+    ///
+    /// If the registries have different versions throws ['RegistryError']
+    ///
+    /// ['RegistryError']: error/enum.RegistryError.html
+    pub fn merge(&mut self, other: AssetRegistryState) -> Result<(), Error> {
+        if self.version != other.version {
+            return Err(RegistryError::other(format!(
+                "Cannot merge an asset registry with version {} into one with version {}",
+                other.version, self.version
+            ))
+            .into());
+        }
+
+        for asset_data in other.assets_data {
+            self.add_asset_data(asset_data);
+        }
+        for package_data in other.package_data {
+            self.add_package_data(package_data);
+        }
+
+        let mut existing_nodes = HashMap::new();
+        for (i, node) in self.depends_nodes.iter().enumerate() {
+            existing_nodes
+                .entry(identifier_key(&node.identifier))
+                .or_insert(i);
+        }
+
+        // indices of other's nodes in this registry, and whether they are new
+        let mut next_index = self.depends_nodes.len();
+        let node_indices = other
+            .depends_nodes
+            .iter()
+            .map(
+                |e| match existing_nodes.get(&identifier_key(&e.identifier)) {
+                    Some(existing) => (*existing, false),
+                    None => {
+                        next_index += 1;
+                        (next_index - 1, true)
+                    }
+                },
+            )
+            .collect::<Vec<_>>();
+        let map = |index: i32| node_indices.get(index as usize).map(|e| e.0 as i32);
+
+        for (mut node, (index, is_new)) in other.depends_nodes.into_iter().zip(&node_indices) {
+            match is_new {
+                true => {
+                    self.import_fnames(&mut node.identifier);
+                    node.set_index(*index as i32);
+                    node.remap_dependencies(map);
+                    self.depends_nodes.push(node);
+                }
+                false => self.depends_nodes[*index].merge_dependencies(&node, map),
+            }
+        }
+
+        Ok(())
+    }
+}
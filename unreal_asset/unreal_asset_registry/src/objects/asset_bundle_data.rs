@@ -4,7 +4,7 @@ use byteorder::{WriteBytesExt, LE};
 
 use unreal_asset_base::{
     reader::{ArchiveReader, ArchiveWriter},
-    types::{FName, PackageIndexTrait},
+    types::{fname::FNameContainer, FName, PackageIndexTrait},
     unversioned::Ancestry,
     Error,
 };
@@ -67,6 +67,13 @@ impl AssetBundleEntry {
     }
 }
 
+impl FNameContainer for AssetBundleEntry {
+    fn traverse_fnames<F: FnMut(&mut FName)>(&mut self, traverse: &mut F) {
+        traverse(&mut self.bundle_name);
+        self.bundle_assets.traverse_fnames(traverse);
+    }
+}
+
 /// Bundle data
 #[derive(Debug, Default, Clone)]
 pub struct AssetBundleData {
@@ -103,3 +110,9 @@ impl AssetBundleData {
         Self { bundles }
     }
 }
+
+impl FNameContainer for AssetBundleData {
+    fn traverse_fnames<F: FnMut(&mut FName)>(&mut self, traverse: &mut F) {
+        self.bundles.traverse_fnames(traverse);
+    }
+}
@@ -7,7 +7,7 @@ use unreal_asset_base::{
     custom_version::FAssetRegistryVersionType,
     flags::EPackageFlags,
     reader::{ArchiveReader, ArchiveWriter},
-    types::{FName, PackageIndexTrait},
+    types::{fname::FNameContainer, FName, PackageIndexTrait},
     Error,
 };
 
@@ -47,6 +47,13 @@ impl TopLevelAssetPath {
     }
 }
 
+impl FNameContainer for TopLevelAssetPath {
+    fn traverse_fnames<F: FnMut(&mut FName)>(&mut self, traverse: &mut F) {
+        traverse(&mut self.package_name);
+        traverse(&mut self.asset_name);
+    }
+}
+
 /// Asset data
 #[derive(Debug, Clone)]
 pub struct AssetData {
@@ -212,3 +219,24 @@ impl AssetData {
         Ok(())
     }
 }
+
+impl FNameContainer for AssetData {
+    fn traverse_fnames<F: FnMut(&mut FName)>(&mut self, traverse: &mut F) {
+        traverse(&mut self.object_path);
+        traverse(&mut self.package_name);
+        traverse(&mut self.package_path);
+        traverse(&mut self.asset_name);
+        self.asset_class.traverse_fnames(traverse);
+        self.asset_path.traverse_fnames(traverse);
+
+        self.tags_and_values = std::mem::take(&mut self.tags_and_values)
+            .into_iter()
+            .map(|(_, mut key, value)| {
+                traverse(&mut key);
+                (key, value)
+            })
+            .collect();
+
+        self.tagged_asset_bundles.traverse_fnames(traverse);
+    }
+}
@@ -8,8 +8,9 @@ use unreal_asset_base::types::PackageIndexTrait;
 use unreal_asset_base::{
     custom_version::{CustomVersion, FAssetRegistryVersionType},
     error::RegistryError,
+    object_version::{ObjectVersion, ObjectVersionUE5},
     reader::{ArchiveReader, ArchiveWriter},
-    types::FName,
+    types::{fname::FNameContainer, FName},
     Error,
 };
 
@@ -99,6 +100,40 @@ impl AssetPackageData {
         })
     }
 
+    /// Create an `AssetPackageData` for a package that has no data other than its name
+    ///
+    /// File versions are set to the given object versions, every other field is empty.
+    pub fn from_package_name(
+        package_name: FName,
+        object_version: ObjectVersion,
+        object_version_ue5: ObjectVersionUE5,
+        version: FAssetRegistryVersionType,
+    ) -> Self {
+        let cooked_hash = (version >= FAssetRegistryVersionType::AddedCookedMD5Hash)
+            .then_some(FMD5Hash { hash: None });
+        let ue5_version = (version >= FAssetRegistryVersionType::PackageFileSummaryVersionChange)
+            .then_some(object_version_ue5 as i32);
+        let custom_versions =
+            (version >= FAssetRegistryVersionType::WorkspaceDomain).then(Vec::new);
+        let imported_classes =
+            (version >= FAssetRegistryVersionType::PackageImportedClasses).then(Vec::new);
+
+        Self {
+            package_name,
+            package_guid: Guid::default(),
+            cooked_hash,
+            imported_classes,
+            disk_size: 0,
+            file_version: object_version as i32,
+            ue5_version,
+            file_version_licensee_ue: 0,
+            custom_versions,
+            flags: 0,
+
+            version,
+        }
+    }
+
     /// Write `AssetPackageData` to an asset
     pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
@@ -154,3 +189,10 @@ impl AssetPackageData {
         Ok(())
     }
 }
+
+impl FNameContainer for AssetPackageData {
+    fn traverse_fnames<F: FnMut(&mut FName)>(&mut self, traverse: &mut F) {
+        traverse(&mut self.package_name);
+        self.imported_classes.traverse_fnames(traverse);
+    }
+}
@@ -10,8 +10,8 @@ use unreal_asset_base::{
     custom_version::FAssetRegistryVersionType,
     error::RegistryError,
     flags::EDependencyProperty,
-    reader::{ArchiveReader, ArchiveWriter},
-    types::{FName, PackageIndexTrait},
+    reader::{capped_capacity, ArchiveReader, ArchiveWriter},
+    types::{fname::FNameContainer, FName, PackageIndexTrait},
     Error,
 };
 
@@ -30,6 +30,47 @@ pub struct AssetIdentifier {
 
 type LoadedDependencyNodes = (Vec<DependsNode>, Vec<DependsNode>, BitVec<u32, Lsb0>);
 
+/// Dependency node index, whether the dependency is hard, and its flags
+type FlaggedDependency = (i32, bool, BitVec<u32, Lsb0>);
+
+/// Dependency type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DependencyType {
+    /// Hard package dependency
+    Hard,
+    /// Soft package dependency
+    Soft,
+    /// Searchable name dependency
+    Name,
+    /// Hard manage dependency
+    HardManage,
+    /// Soft manage dependency
+    SoftManage,
+}
+
+/// Dependency lists of a `DependsNode` that share flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DependencyList {
+    /// Hard and soft package dependencies
+    Package,
+    /// Name dependencies
+    Name,
+    /// Hard and soft manage dependencies
+    Manage,
+    /// Referencers
+    Referencers,
+}
+
+impl DependencyList {
+    /// All dependency lists
+    const ALL: [DependencyList; 4] = [
+        DependencyList::Package,
+        DependencyList::Name,
+        DependencyList::Manage,
+        DependencyList::Referencers,
+    ];
+}
+
 impl AssetIdentifier {
     /// Read an `AssetIdentifier` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
@@ -92,6 +133,15 @@ impl AssetIdentifier {
     }
 }
 
+impl FNameContainer for AssetIdentifier {
+    fn traverse_fnames<F: FnMut(&mut FName)>(&mut self, traverse: &mut F) {
+        self.package_name.traverse_fnames(traverse);
+        self.primary_asset_type.traverse_fnames(traverse);
+        self.object_name.traverse_fnames(traverse);
+        self.value_name.traverse_fnames(traverse);
+    }
+}
+
 /// Depends node
 #[derive(Clone, Debug)]
 pub struct DependsNode {
@@ -224,7 +274,7 @@ impl DependsNode {
             }
         }
 
-        let mut out_flag_bits = BitVec::with_capacity(num_flag_bits as usize);
+        let mut out_flag_bits = BitVec::repeat(false, num_flag_bits as usize);
         for write_index in 0..in_dependencies.len() as i32 {
             let read_index = &sort_indexes[write_index as usize];
 
@@ -460,7 +510,7 @@ impl DependsNode {
     ) -> Result<(), Error> {
         for _ in 0..num {
             let index = asset.read_i32::<LE>()?;
-            if index < 0 || nodes.len() <= index as usize {
+            if index < 0 || preallocated_depends_node_buffer.len() <= index as usize {
                 return Err(RegistryError::InvalidIndex(index).into());
             }
 
@@ -489,8 +539,8 @@ impl DependsNode {
         };
         let num_referencers = asset.read_i32::<LE>()?;
 
-        let mut name_dependencies = Vec::with_capacity(num_name as usize);
-        let mut referencers = Vec::with_capacity(num_referencers as usize);
+        let mut name_dependencies = Vec::with_capacity(capped_capacity(num_name));
+        let mut referencers = Vec::with_capacity(capped_capacity(num_referencers));
 
         Self::read_node_array(
             asset,
@@ -582,4 +632,141 @@ impl DependsNode {
 
         Ok(())
     }
+
+    /// Get this node's index in [`AssetRegistryState::depends_nodes`]
+    ///
+    /// Dependencies and referencers of a node only carry the index of the node they point to.
+    ///
+    /// [`AssetRegistryState::depends_nodes`]: crate::AssetRegistryState::depends_nodes
+    pub fn get_index(&self) -> i32 {
+        self.index
+    }
+
+    /// Set this node's index
+    pub(crate) fn set_index(&mut self, index: i32) {
+        self.index = index;
+    }
+
+    /// Get dependencies of a type
+    pub fn get_dependencies(&self, ty: DependencyType) -> &[DependsNode] {
+        match ty {
+            DependencyType::Hard => &self.hard_dependencies,
+            DependencyType::Soft => &self.soft_dependencies,
+            DependencyType::Name => &self.name_dependencies,
+            DependencyType::HardManage => &self.hard_manage_dependencies,
+            DependencyType::SoftManage => &self.soft_manage_dependencies,
+        }
+    }
+
+    /// Get a dependency list together with the flags of each dependency
+    ///
+    /// Flags are laid out the same way they are written, hard dependencies first.
+    fn get_flagged_dependencies(&self, list: DependencyList) -> Vec<FlaggedDependency> {
+        let (hard, soft, flags, width): (_, &[DependsNode], _, _) = match list {
+            DependencyList::Package => (
+                &self.hard_dependencies,
+                &self.soft_dependencies,
+                self.package_flags.as_ref(),
+                PACKAGE_FLAG_SET_WIDTH as usize,
+            ),
+            DependencyList::Manage => (
+                &self.hard_manage_dependencies,
+                &self.soft_manage_dependencies,
+                self.manage_flags.as_ref(),
+                MANAGE_FLAG_SET_WIDTH as usize,
+            ),
+            DependencyList::Name => (&self.name_dependencies, &[], None, 0),
+            DependencyList::Referencers => (&self.referencers, &[], None, 0),
+        };
+
+        hard.iter()
+            .map(|e| (e, true))
+            .chain(soft.iter().map(|e| (e, false)))
+            .enumerate()
+            .map(|(i, (node, is_hard))| {
+                let node_flags = flags
+                    .and_then(|e| e.get(i * width..(i + 1) * width))
+                    .map(|e| e.to_bitvec())
+                    .unwrap_or_else(|| BitVec::repeat(false, width));
+                (node.index, is_hard, node_flags)
+            })
+            .collect()
+    }
+
+    /// Replace a dependency list
+    fn set_flagged_dependencies(
+        &mut self,
+        list: DependencyList,
+        dependencies: Vec<FlaggedDependency>,
+    ) {
+        let version = self.version;
+        let (hard, soft): (Vec<_>, Vec<_>) = dependencies.into_iter().partition(|e| e.1);
+
+        let mut flags = BitVec::<u32, Lsb0>::new();
+        for (_, _, node_flags) in hard.iter().chain(&soft) {
+            flags.extend_from_bitslice(node_flags);
+        }
+
+        let to_nodes = |dependencies: Vec<FlaggedDependency>| {
+            dependencies
+                .into_iter()
+                .map(|(index, _, _)| DependsNode::new(index, version))
+                .collect::<Vec<_>>()
+        };
+
+        match list {
+            DependencyList::Package => {
+                self.hard_dependencies = to_nodes(hard);
+                self.soft_dependencies = to_nodes(soft);
+                if self.package_flags.is_some() {
+                    self.package_flags = Some(flags);
+                }
+            }
+            DependencyList::Manage => {
+                self.hard_manage_dependencies = to_nodes(hard);
+                self.soft_manage_dependencies = to_nodes(soft);
+                if self.manage_flags.is_some() {
+                    self.manage_flags = Some(flags);
+                }
+            }
+            DependencyList::Name => self.name_dependencies = to_nodes(hard),
+            DependencyList::Referencers => self.referencers = to_nodes(hard),
+        }
+    }
+
+    /// Change the node indices of all dependencies and referencers
+    ///
+    /// Dependencies mapped to `None` are removed.
+    pub(crate) fn remap_dependencies(&mut self, map: impl Fn(i32) -> Option<i32>) {
+        for list in DependencyList::ALL {
+            let dependencies = self
+                .get_flagged_dependencies(list)
+                .into_iter()
+                .filter_map(|(index, is_hard, flags)| Some((map(index)?, is_hard, flags)))
+                .collect();
+            self.set_flagged_dependencies(list, dependencies);
+        }
+    }
+
+    /// Add all dependencies and referencers of another node that this node doesn't have yet
+    ///
+    /// `map` maps node indices of the other node's registry to node indices of this node's registry.
+    pub(crate) fn merge_dependencies(
+        &mut self,
+        other: &DependsNode,
+        map: impl Fn(i32) -> Option<i32>,
+    ) {
+        for list in DependencyList::ALL {
+            let mut dependencies = self.get_flagged_dependencies(list);
+            for (index, is_hard, flags) in other.get_flagged_dependencies(list) {
+                let Some(index) = map(index) else {
+                    continue;
+                };
+                if dependencies.iter().all(|(e, _, _)| *e != index) {
+                    dependencies.push((index, is_hard, flags));
+                }
+            }
+            self.set_flagged_dependencies(list, dependencies);
+        }
+    }
 }
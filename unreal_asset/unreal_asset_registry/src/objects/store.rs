@@ -252,8 +252,7 @@ impl Store {
                 Ok(asset.read_i32::<LE>()?)
             })?;
 
-        let mut ansi_strings_buf = vec![0u8; ansi_strings_size as usize];
-        asset.read_exact(&mut ansi_strings_buf)?;
+        let ansi_strings_buf = asset.read_bytes(ansi_strings_size as usize)?;
 
         let mut ansi_strings = Vec::new();
 
@@ -274,8 +273,7 @@ impl Store {
             ansi_strings.push(ansi_string);
         }
 
-        let mut wide_strings_buf = vec![0u8; (wide_strings_size * 2) as usize];
-        asset.read_exact(&mut wide_strings_buf)?;
+        let wide_strings_buf = asset.read_bytes(wide_strings_size.max(0) as usize * 2)?;
 
         let mut wide_strings = Vec::new();
 
@@ -7,6 +7,18 @@ use byteorder::{ReadBytesExt, LE};
 
 use crate::error::FStringError;
 
+/// Maximum amount of elements allocated up front when reading an array with a length from the data
+const MAX_PREALLOCATION: usize = 0x10000;
+
+/// Get the capacity to allocate up front for an array with a length read from the data
+///
+/// Longer arrays still get read, but grow while reading,
+/// so that a corrupted length fails with an EOF error instead of allocating all memory.
+/// Negative lengths get no capacity.
+pub fn capped_capacity<T: TryInto<usize>>(length: T) -> usize {
+    length.try_into().unwrap_or(0).min(MAX_PREALLOCATION)
+}
+
 /// Extension for anything that implements `Read` to more easily read Unreal data formats.
 pub trait UnrealReadExt {
     /// Read u8 as bool.
@@ -34,8 +46,11 @@ impl<R: Read + Seek> UnrealReadExt for R {
     }
 
     fn read_vec(&mut self, len: usize) -> io::Result<Vec<u8>> {
-        let mut buf = vec![0; len];
-        self.read_exact(&mut buf)?;
+        let mut buf = Vec::with_capacity(capped_capacity(len));
+        self.take(len as u64).read_to_end(&mut buf)?;
+        if buf.len() != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
         Ok(buf)
     }
 
@@ -43,8 +58,9 @@ impl<R: Read + Seek> UnrealReadExt for R {
         &mut self,
         mut f: impl FnMut(&mut Self) -> io::Result<T>,
     ) -> io::Result<Vec<T>> {
-        let mut buf = Vec::with_capacity(self.read_u32::<LE>()? as usize);
-        for _ in 0..buf.capacity() {
+        let len = self.read_u32::<LE>()? as usize;
+        let mut buf = Vec::with_capacity(capped_capacity(len));
+        for _ in 0..len {
             buf.push(f(self)?);
         }
         Ok(buf)
@@ -54,7 +70,7 @@ impl<R: Read + Seek> UnrealReadExt for R {
         let len = self.read_i32::<LE>()?;
 
         let (len, is_wide) = match len < 0 {
-            true => (-len, true),
+            true => (len.saturating_neg(), true),
             false => (len, false),
         };
         read_fstring_len(self, len, is_wide)
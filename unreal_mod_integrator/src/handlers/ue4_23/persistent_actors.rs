@@ -14,7 +14,7 @@ use unreal_asset::{
         object_property::ObjectProperty, Property, PropertyDataTrait,
     },
     types::{PackageIndex, PackageIndexTrait},
-    Asset, Import,
+    Asset,
 };
 use unreal_pak::{PakMemory, PakReader};
 
@@ -110,33 +110,14 @@ pub fn handle_persistent_actors(
             };
             let mut actor_template = actor_template.clone();
 
-            let package_import = Import {
-                class_package: asset.add_fname("/Script/CoreUObject"),
-                class_name: asset.add_fname("Package"),
-                outer_index: PackageIndex::new(0),
-                object_name: asset.add_fname(&component_path_raw),
-                optional: false,
-            };
-            let package_import = asset.add_import(package_import);
-
-            let blueprint_generated_class_import = Import {
-                class_package: asset.add_fname("/Script/Engine"),
-                class_name: asset.add_fname("BlueprintGeneratedClass"),
-                outer_index: package_import,
-                object_name: asset.add_fname(&(String::from(component) + "_C")),
-                optional: false,
-            };
-            let blueprint_generated_class_import =
-                asset.add_import(blueprint_generated_class_import);
-
-            let default_import = Import {
-                class_package: asset.add_fname(&component_path_raw),
-                class_name: asset.add_fname(&(String::from(component) + "_C")),
-                outer_index: package_import,
-                object_name: asset.add_fname(&(String::from("Default__") + component + "_C")),
-                optional: false,
-            };
-            let default_import = asset.add_import(default_import);
+            let blueprint_generated_class_import = asset.add_import_by_path(
+                &format!("{component_path_raw}.{component}_C"),
+                &[("/Script/Engine", "BlueprintGeneratedClass")],
+            )?;
+            let default_import = asset.add_import_by_path(
+                &format!("{component_path_raw}.Default__{component}_C"),
+                &[(&component_path_raw, &format!("{component}_C"))],
+            )?;
 
             actor_template.base_export.class_index = blueprint_generated_class_import;
             actor_template.base_export.object_name = asset.add_fname(component);
@@ -228,8 +209,7 @@ pub fn handle_persistent_actors(
                         original_category: PackageIndex::new(known_node_category),
                     };
 
-                    let mut first_import = None;
-                    let mut second_import = None;
+                    let mut component_class = None;
 
                     for property in &known_category.properties {
                         property.get_name().get_content(|name| {
@@ -251,13 +231,13 @@ pub fn handle_persistent_actors(
                                             .ok_or_else(|| {
                                                 io::Error::new(ErrorKind::Other, "No import")
                                             })?;
+                                        let path = actor_asset
+                                            .get_object_path(object_property.value)
+                                            .ok_or_else(|| {
+                                                io::Error::new(ErrorKind::Other, "No import")
+                                            })?;
 
-                                        second_import = Some(
-                                            actor_asset.get_import(import.outer_index).ok_or_else(
-                                                || io::Error::new(ErrorKind::Other, "No import"),
-                                            )?,
-                                        );
-                                        first_import = Some(import);
+                                        component_class = Some((path, import));
                                     }
                                 }
                                 "ChildNodes" => {
@@ -289,31 +269,14 @@ pub fn handle_persistent_actors(
                         })?
                     }
 
-                    if let (Some(first_import), Some(second_import)) = (first_import, second_import)
-                    {
-                        if asset
-                            .find_import(
-                                &second_import.class_package,
-                                &second_import.class_name,
-                                second_import.outer_index,
-                                &second_import.object_name,
-                            )
-                            .is_none()
-                        {
-                            asset.add_import(second_import);
-                        }
-
-                        let type_link = match asset.find_import(
-                            &first_import.class_package,
-                            &first_import.class_name,
-                            first_import.outer_index,
-                            &first_import.object_name,
-                        ) {
-                            Some(e) => PackageIndex::new(e),
-                            None => asset.add_import(first_import),
-                        };
-
-                        new_scs.type_link = type_link;
+                    if let Some((path, import)) = component_class {
+                        new_scs.type_link = asset.add_import_by_path(
+                            &path,
+                            &[(
+                                &import.class_package.get_owned_content(),
+                                &import.class_name.get_owned_content(),
+                            )],
+                        )?;
                     }
                     created_components.push(new_scs);
                 }
@@ -409,16 +372,13 @@ pub fn handle_persistent_actors(
                     .get_import(created_component.type_link)
                     .ok_or_else(|| io::Error::new(ErrorKind::Other, "No type link"))?;
 
-                let import = Import {
-                    class_package: asset.add_fname("/Script/Engine"),
-                    class_name: type_link.object_name.clone(),
-                    outer_index: actor_template.base_export.class_index,
-                    object_name: asset.add_fname(
-                        &(created_component.internal_variable_name.clone() + "_GEN_VARIABLE"),
+                asset.add_import_by_path(
+                    &format!(
+                        "{component_path_raw}.{component}_C:{}_GEN_VARIABLE",
+                        created_component.internal_variable_name
                     ),
-                    optional: false,
-                };
-                asset.add_import(import);
+                    &[("/Script/Engine", &type_link.object_name.get_owned_content())],
+                )?;
             }
 
             for (export_index, correction_queue) in attach_parent_correcting {
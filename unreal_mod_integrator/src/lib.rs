@@ -168,6 +168,12 @@ pub trait IntegratorConfig<'data, D, E: std::error::Error + 'static> {
 
     fn get_baked_mods(&self) -> Vec<IntegratorMod<E>>;
 
+    /// Pak entry names of game files that the handlers modify,
+    /// mods that override these are warned about
+    fn get_handled_files(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     const GAME_NAME: &'static str;
     const INTEGRATOR_VERSION: &'static str;
     const ENGINE_VERSION: EngineVersion;
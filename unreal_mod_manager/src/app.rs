@@ -1,4 +1,6 @@
 use std::cell::Cell;
+use std::fs;
+use std::path::Path;
 use std::sync::{
     atomic::{AtomicBool, AtomicI32, Ordering},
     mpsc::Sender,
@@ -22,6 +24,7 @@ use crate::background_work::BackgroundThreadMessage;
 use crate::error::{ModLoaderError, ModLoaderWarning};
 use crate::game_mod::{GameMod, SelectedVersion};
 use crate::mod_processing::dependencies::DependencyGraph;
+use crate::modpack::MODPACK_EXTENSION;
 use crate::profile::{Profile, ProfileMod};
 use crate::update_info::UpdateInfo;
 use crate::{FileToProcess, ModLoaderAppData};
@@ -553,6 +556,17 @@ impl ModLoaderApp {
                             .background_tx
                             .send(BackgroundThreadMessage::integrate());
                     };
+                    if ui
+                        .checkbox(
+                            &mut data.compare_conflicting_assets,
+                            "Compare conflicting assets",
+                        )
+                        .changed()
+                    {
+                        let _ = self
+                            .background_tx
+                            .send(BackgroundThreadMessage::integrate());
+                    };
 
                     ui.label(format!(
                         "Time since last integration {}s",
@@ -642,21 +656,65 @@ impl ModLoaderApp {
         }
 
         // Collect dropped files
-        let files_to_import = ctx.input(|e| {
+        let (modpacks, files_to_import): (Vec<_>, Vec<_>) = ctx.input(|e| {
             e.raw
                 .dropped_files
                 .iter()
-                .map(|e| FileToProcess::new(e.path.as_ref().unwrap().to_owned(), true))
-                .collect::<Vec<_>>()
+                .map(|e| e.path.as_ref().unwrap().to_owned())
+                .partition(|path| {
+                    path.extension()
+                        .is_some_and(|e| e.eq_ignore_ascii_case(MODPACK_EXTENSION))
+                })
         });
 
-        if !files_to_import.is_empty() {
-            let _ = self
-                .background_tx
-                .send(BackgroundThreadMessage::Import(files_to_import));
+        if modpacks.is_empty() && files_to_import.is_empty() {
+            return;
+        }
+
+        for modpack in modpacks {
             let _ = self
                 .background_tx
-                .send(BackgroundThreadMessage::integrate());
+                .send(BackgroundThreadMessage::ImportModpack(modpack));
+        }
+        if !files_to_import.is_empty() {
+            let _ = self.background_tx.send(BackgroundThreadMessage::Import(
+                files_to_import
+                    .into_iter()
+                    .map(|path| FileToProcess::new(path, true))
+                    .collect(),
+            ));
+        }
+        let _ = self
+            .background_tx
+            .send(BackgroundThreadMessage::integrate());
+    }
+
+    /// Export a profile to the `Modpacks` folder next to the mods folder and show it
+    fn export_modpack(&self, profile_name: &str, mods_path: Option<&Path>) {
+        let Some(modpacks_path) = mods_path.and_then(Path::parent).map(|e| e.join("Modpacks"))
+        else {
+            return;
+        };
+
+        let file_name = profile_name
+            .chars()
+            .map(|c| match c.is_alphanumeric() || c == '-' || c == ' ' {
+                true => c,
+                false => '_',
+            })
+            .collect::<String>();
+        let path = modpacks_path.join(format!("{file_name}.{MODPACK_EXTENSION}"));
+
+        let _ = self
+            .background_tx
+            .send(BackgroundThreadMessage::ExportModpack(
+                profile_name.to_owned(),
+                path,
+                false,
+            ));
+
+        if fs::create_dir_all(&modpacks_path).is_ok() {
+            let _ = open::that(&modpacks_path);
         }
     }
 
@@ -808,7 +866,7 @@ impl ModLoaderApp {
                             TableBuilder::new(ui)
                                 .striped(true)
                                 .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-                                .column(Column::exact(280.0))
+                                .column(Column::exact(220.0))
                                 .column(Column::auto())
                                 .resizable(false)
                                 .header(20.0, |mut header| {
@@ -858,25 +916,23 @@ impl ModLoaderApp {
                                                 };
 
                                                 if ui.button("Load").clicked() {
-                                                    for (mod_id, game_mod) in
-                                                        data.game_mods.iter_mut()
-                                                    {
-                                                        if let Some(_profile_entry) =
-                                                            profile.mods.get(mod_id)
-                                                        {
-                                                            game_mod.enabled = true;
-
-                                                            // TODO load version
-                                                        } else {
-                                                            game_mod.enabled = false;
-                                                        }
-                                                    }
-
+                                                    let _ = self.background_tx.send(
+                                                        BackgroundThreadMessage::ApplyProfile(
+                                                            profile.name.clone(),
+                                                        ),
+                                                    );
                                                     let _ = self
                                                         .background_tx
                                                         .send(BackgroundThreadMessage::integrate());
                                                 }
 
+                                                if ui.button("Export").clicked() {
+                                                    self.export_modpack(
+                                                        &profile.name,
+                                                        data.mods_path.as_deref(),
+                                                    );
+                                                }
+
                                                 if ui.button("Delete").clicked() {
                                                     remove = Some(i);
                                                 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env;
 use std::fs::{self, File};
 use std::io::{self, Error};
@@ -14,25 +14,33 @@ use std::time::Instant;
 use directories::BaseDirs;
 use log::{debug, error, warn};
 use parking_lot::Mutex;
-use semver::Version;
+use semver::{Comparator, Op, Version, VersionReq};
 use sha2::{Digest, Sha256};
 
+use unreal_asset::engine_version::EngineVersion;
 use unreal_mod_integrator::{
     integrate_mods, FileMod, IntegratorConfig, IntegratorModInfo, INTEGRATOR_PAK_FILE_NAME,
 };
-use unreal_mod_metadata::Metadata;
+use unreal_mod_metadata::{Dependency, DownloadInfo, DownloadMode, Metadata};
 use unreal_pak::PakReader;
 
 use crate::config;
 use crate::error::{ModLoaderError, ModLoaderWarning};
-use crate::game_mod::{self, GameModVersion};
+use crate::game_mod::{self, GameMod, GameModVersion};
+use crate::game_platform_managers::WineSettings;
 use crate::mod_config::{load_config, write_config};
 use crate::mod_processing::{
+    conflicts::{self, ConflictScanner},
     dependencies::{DependencyGraph, ModWithDependencies},
     index_file::{download_index_file, IndexFileModVersion},
+    load_order::{self, LoadOrderEntry},
     process_modfiles,
+    resolver::{self, DependencyProvider},
+    verify,
 };
+use crate::modpack::Modpack;
 use crate::update_info::UpdateInfo;
+use crate::version::GameBuild;
 use crate::FileToProcess;
 use crate::ModLoaderAppData;
 use crate::UntrustedMod;
@@ -53,6 +61,20 @@ pub(crate) struct BackgroundThreadData {
 pub(crate) enum BackgroundThreadMessage {
     Import(Vec<FileToProcess>),
     RemoveMod(String),
+    SetGamePlatform(String),
+    /// Compare the exports of conflicting assets when integrating
+    SetCompareAssets(bool),
+    /// Override the Wine settings of a platform, `None` for the selected one.
+    /// Unset settings keep their current value, unless the overrides are reset first.
+    SetWineSettings(Option<String>, WineSettings, bool),
+    SetEnabled(String, bool),
+    /// Select a version of a mod, `None` selects the latest version
+    SelectVersion(String, Option<Version>),
+    ApplyProfile(String),
+    /// Install the mods of a modpack and apply it as a profile
+    ImportModpack(PathBuf),
+    /// Export a profile to a modpack file, optionally bundling all mod files
+    ExportModpack(String, PathBuf, bool),
     Integrate(Instant),
     WriteConfig,
     UpdateApp,
@@ -71,15 +93,7 @@ fn download_mod(
     mod_version: &IndexFileModVersion,
 ) -> Result<(Metadata, PathBuf), ModLoaderWarning> {
     // this is safe because the filename has already been validated
-    let mut response = reqwest::blocking::get(mod_version.download_url.as_str())
-        .map_err(|e| ModLoaderWarning::download_failed(mod_version.file_name.clone(), e))?;
-
-    let file_path = mods_path.join(mod_version.file_name.clone());
-    let mut file = fs::File::create(&file_path)?;
-
-    io::copy(&mut response, &mut file)?;
-
-    drop(file);
+    let file_path = mod_version.download(mods_path)?;
     let file = fs::File::open(&file_path)?;
 
     let mut pak = PakReader::new(&file);
@@ -101,9 +115,9 @@ fn download_mods(mods_path: &Path, files_to_download: &[GameModVersion]) -> Vec<
         .iter()
         .filter(|v| !v.downloaded)
         .filter_map(|v| {
-            v.download_url
-                .as_ref()
-                .map(|url| IndexFileModVersion::new(url.clone(), v.file_name.clone()))
+            v.download_url.as_ref().map(|url| {
+                IndexFileModVersion::new(url.clone(), v.file_name.clone(), v.sha256.clone())
+            })
         })
     {
         if let Err(err) = download_mod(mods_path, &mod_version) {
@@ -115,6 +129,245 @@ fn download_mods(mods_path: &Path, files_to_download: &[GameModVersion]) -> Vec<
     warnings
 }
 
+/// Priority prefix of a validated mod file name
+fn file_priority(file_name: &str) -> u32 {
+    file_name.split('-').next().unwrap().parse::<u32>().unwrap()
+}
+
+/// Warn about mods that are incompatible with each other or with the game build
+fn check_compatibility<'a>(
+    mods: impl Iterator<Item = &'a GameModVersion> + Clone,
+    game_build: Option<GameBuild>,
+) -> Vec<ModLoaderWarning> {
+    let mut warnings = Vec::new();
+
+    for mod_version in mods.clone() {
+        let Some(ref metadata) = mod_version.metadata else {
+            continue;
+        };
+
+        if let Some(game_build) = game_build {
+            let version = Version::new(
+                game_build.major as u64,
+                game_build.minor as u64,
+                game_build.patch as u64,
+            );
+            if !metadata.supports_game_build(&version) {
+                warnings.push(ModLoaderWarning::unsupported_game_build(
+                    mod_version.mod_id.clone(),
+                    game_build,
+                ));
+            }
+        }
+
+        for (other_id, version_req) in &metadata.incompatible_with {
+            let incompatible = mods.clone().any(|other| {
+                &other.mod_id == other_id
+                    && other
+                        .metadata
+                        .as_ref()
+                        .and_then(|e| Version::parse(&e.mod_version).ok())
+                        .is_some_and(|e| version_req.matches(&e))
+            });
+            if incompatible {
+                warnings.push(ModLoaderWarning::incompatible_mod(
+                    mod_version.mod_id.clone(),
+                    other_id.clone(),
+                ));
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Check the paks of the mods that are about to be integrated for files that overlap
+///
+/// If `compare_assets` is set, conflicting assets are compared
+/// and conflicts between identical assets are not reported.
+fn find_conflicts(
+    mods: &[(String, PathBuf)],
+    handled_files: &[String],
+    engine_version: EngineVersion,
+    compare_assets: bool,
+) -> Vec<ModLoaderWarning> {
+    let mut scanner = match ConflictScanner::new(mods.iter().cloned()) {
+        Ok(scanner) => scanner,
+        Err(err) => return vec![err.into()],
+    };
+
+    let mut warnings = Vec::new();
+    for mut conflict in scanner.file_conflicts() {
+        if compare_assets && conflicts::is_asset(&conflict.file_name) {
+            if let Err(err) = scanner.compare_assets(&mut conflict, engine_version) {
+                debug!("Failed to compare {}: {}", conflict.file_name, err);
+            }
+        }
+
+        if conflict.differing_exports == Some(Vec::new()) {
+            continue;
+        }
+        warnings.push(ModLoaderWarning::file_conflict(conflict));
+    }
+
+    for (mod_id, file_name) in scanner.handled_file_overrides(handled_files) {
+        warnings.push(ModLoaderWarning::handled_file_override(mod_id, file_name));
+    }
+
+    warnings
+}
+
+/// Directory inside of the mods directory that mods are downloaded to while resolving dependencies
+const RESOLVE_DOWNLOADS_DIR: &str = "ResolveDownloads";
+
+/// Provides installed mods and the versions listed in index files to the dependency resolver
+///
+/// Versions that have to be downloaded to read their dependencies are downloaded to [`RESOLVE_DOWNLOADS_DIR`],
+/// downloads that aren't moved into the mods directory with [`ModProvider::keep_download`] are deleted on drop.
+struct ModProvider {
+    download_path: PathBuf,
+    versions: HashMap<String, BTreeMap<Version, GameModVersion>>,
+    baked_mods: HashSet<String>,
+    /// file names of mods that were downloaded while resolving
+    downloaded: HashSet<String>,
+    warnings: Vec<ModLoaderWarning>,
+}
+
+impl ModProvider {
+    fn new(
+        mods_path: &Path,
+        game_mods: &BTreeMap<String, GameMod>,
+        baked_mods: HashSet<String>,
+    ) -> Self {
+        let download_path = mods_path.join(RESOLVE_DOWNLOADS_DIR);
+        // left over if the mod manager exited while resolving
+        let _ = fs::remove_dir_all(&download_path);
+
+        ModProvider {
+            download_path,
+            versions: game_mods
+                .iter()
+                .map(|(mod_id, game_mod)| (mod_id.clone(), game_mod.versions.clone()))
+                .collect(),
+            baked_mods,
+            downloaded: HashSet::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Move a mod that was downloaded while resolving into the mods directory
+    fn keep_download(&self, file_name: &str, mods_path: &Path) -> io::Result<()> {
+        fs::rename(
+            self.download_path.join(file_name),
+            mods_path.join(file_name),
+        )
+    }
+}
+
+impl Drop for ModProvider {
+    fn drop(&mut self) {
+        if self.downloaded.is_empty() {
+            return;
+        }
+        if let Err(err) = fs::remove_dir_all(&self.download_path) {
+            warn!("Failed to remove mods downloaded while resolving: {}", err);
+        }
+    }
+}
+
+impl DependencyProvider for ModProvider {
+    fn versions(&mut self, mod_id: &str, downloads: &[DownloadInfo]) -> Vec<Version> {
+        let versions = self.versions.entry(mod_id.to_owned()).or_default();
+
+        for download in downloads
+            .iter()
+            .filter(|e| e.download_mode == DownloadMode::IndexFile)
+        {
+            match download_index_file(mod_id.to_owned(), download) {
+                Ok((_, index_file)) => {
+                    for (version, index_version) in index_file.versions {
+                        if !verify::verify_mod_file_name(&index_version.file_name) {
+                            self.warnings
+                                .push(ModLoaderWarning::invalid_index_file(mod_id.to_owned()));
+                            continue;
+                        }
+
+                        versions.entry(version).or_insert_with(|| GameModVersion {
+                            mod_id: mod_id.to_owned(),
+                            file_name: index_version.file_name,
+                            downloaded: false,
+                            download_url: Some(index_version.download_url),
+                            sha256: index_version.sha256,
+                            metadata: None,
+                        });
+                    }
+                }
+                Err(err) => self.warnings.push(err),
+            }
+        }
+
+        versions.keys().cloned().collect()
+    }
+
+    fn dependencies(
+        &mut self,
+        mod_id: &str,
+        version: &Version,
+    ) -> Option<HashMap<String, Dependency>> {
+        let mod_version = self.versions.get_mut(mod_id)?.get_mut(version)?;
+
+        if mod_version.metadata.is_none() {
+            let index_version = IndexFileModVersion::new(
+                mod_version.download_url.clone()?,
+                mod_version.file_name.clone(),
+                mod_version.sha256.clone(),
+            );
+            let downloaded = fs::create_dir_all(&self.download_path)
+                .map_err(ModLoaderWarning::from)
+                .and_then(|_| download_mod(&self.download_path, &index_version));
+            match downloaded {
+                Ok((metadata, _)) => {
+                    mod_version.metadata = Some(metadata);
+                    mod_version.downloaded = true;
+                    self.downloaded.insert(mod_version.file_name.clone());
+                }
+                Err(err) => {
+                    debug!("Failed to download {:?} {:?}", mod_version.file_name, err);
+                    self.warnings.push(err);
+                    return None;
+                }
+            }
+        }
+
+        mod_version
+            .metadata
+            .as_ref()
+            .map(|e| e.dependencies.clone())
+    }
+
+    fn optional_dependencies(
+        &mut self,
+        mod_id: &str,
+        version: &Version,
+    ) -> HashMap<String, VersionReq> {
+        self.versions
+            .get(mod_id)
+            .and_then(|e| e.get(version))
+            .and_then(|e| e.metadata.as_ref())
+            .map(|e| {
+                e.optional_dependencies
+                    .iter()
+                    .map(|(mod_id, dependency)| (mod_id.clone(), dependency.version.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_builtin(&self, mod_id: &str) -> bool {
+        self.baked_mods.contains(mod_id)
+    }
+}
+
 pub(crate) fn background_work<'data, GC, IC, D: 'data, E: 'static + std::error::Error + Send>(
     config: GC,
     mut background_thread_data: BackgroundThreadData,
@@ -259,6 +512,151 @@ where
                 data_guard.game_mods.remove(&mod_id);
                 data_guard.warnings.extend(deletion_warnings);
             }
+            BackgroundThreadMessage::SetGamePlatform(platform) => {
+                let mut data_guard = background_thread_data.data.lock();
+                if data_guard.set_game_platform(&platform).is_err() {
+                    data_guard.warnings.push(ModLoaderWarning::other(format!(
+                        "Unknown game platform {platform:?}"
+                    )));
+                }
+            }
+            BackgroundThreadMessage::SetCompareAssets(compare_assets) => {
+                background_thread_data
+                    .data
+                    .lock()
+                    .compare_conflicting_assets = compare_assets;
+            }
+            BackgroundThreadMessage::SetWineSettings(platform, settings, reset) => {
+                let mut data_guard = background_thread_data.data.lock();
+                let Some(platform) = platform.or_else(|| data_guard.selected_game_platform.clone())
+                else {
+                    data_guard.warnings.push(ModLoaderWarning::other(
+                        "No game platform selected".to_owned(),
+                    ));
+                    continue;
+                };
+
+                let manager = data_guard
+                    .install_managers
+                    .get(platform.as_str())
+                    .and_then(|manager| Some((manager, manager.get_wine_settings()?)));
+                match manager {
+                    Some((manager, current)) => manager.set_wine_settings(match reset {
+                        true => settings,
+                        false => settings.or(&current),
+                    }),
+                    None => {
+                        data_guard.warnings.push(ModLoaderWarning::other(format!(
+                            "Game platform {platform:?} doesn't use Wine"
+                        )));
+                        continue;
+                    }
+                }
+
+                // paths of the selected platform depend on the settings
+                if data_guard.selected_game_platform.as_ref() == Some(&platform) {
+                    let _ = data_guard.set_game_platform(&platform);
+                } else {
+                    write_config(&data_guard);
+                }
+            }
+            BackgroundThreadMessage::SetEnabled(mod_id, enabled) => {
+                let mut data_guard = background_thread_data.data.lock();
+                match data_guard.game_mods.get_mut(&mod_id) {
+                    Some(game_mod) => game_mod.enabled = enabled,
+                    None => data_guard.warnings.push(
+                        ModLoaderWarning::other("Mod is not installed".to_owned())
+                            .with_mod_id(mod_id),
+                    ),
+                }
+            }
+            BackgroundThreadMessage::SelectVersion(mod_id, version) => {
+                let mut data_guard = background_thread_data.data.lock();
+                let selected = match (data_guard.game_mods.get_mut(&mod_id), version) {
+                    (Some(game_mod), Some(version)) => game_mod.select_version(&version),
+                    (Some(game_mod), None) => {
+                        game_mod.select_latest_version();
+                        true
+                    }
+                    (None, _) => false,
+                };
+                if !selected {
+                    data_guard
+                        .warnings
+                        .push(ModLoaderWarning::invalid_version(mod_id));
+                }
+            }
+            BackgroundThreadMessage::ApplyProfile(name) => {
+                let mut data_guard = background_thread_data.data.lock();
+                let data_guard = &mut *data_guard;
+                match data_guard.profiles.iter().find(|e| e.name == name) {
+                    Some(profile) => {
+                        let warnings = profile.apply(&mut data_guard.game_mods);
+                        data_guard.warnings.extend(warnings);
+                    }
+                    None => data_guard
+                        .warnings
+                        .push(ModLoaderWarning::other(format!("Unknown profile {name:?}"))),
+                }
+            }
+            BackgroundThreadMessage::ImportModpack(path) => {
+                background_thread_data
+                    .working
+                    .store(true, Ordering::Release);
+
+                let modpack = match Modpack::read(&path) {
+                    Ok(modpack) => modpack,
+                    Err(err) => {
+                        background_thread_data.data.lock().warnings.push(err);
+                        continue;
+                    }
+                };
+
+                let (installed, mut warnings) = modpack.install(&path, &mods_path);
+                let files_to_process = installed
+                    .into_iter()
+                    .map(|path| FileToProcess::new(path, true))
+                    .collect::<Vec<_>>();
+                warnings.extend(process_modfiles(
+                    &files_to_process,
+                    &background_thread_data.data,
+                    false,
+                ));
+
+                // an imported modpack replaces a profile with the same name
+                let profile = modpack.to_profile();
+                let mut data_guard = background_thread_data.data.lock();
+                let data_guard = &mut *data_guard;
+                warnings.extend(profile.apply(&mut data_guard.game_mods));
+                data_guard.profiles.retain(|e| e.name != profile.name);
+                data_guard.profiles.push(profile);
+                data_guard.warnings.extend(warnings);
+
+                write_config(data_guard);
+            }
+            BackgroundThreadMessage::ExportModpack(name, path, bundle) => {
+                let mut data_guard = background_thread_data.data.lock();
+                let Some(profile) = data_guard.profiles.iter().find(|e| e.name == name) else {
+                    data_guard
+                        .warnings
+                        .push(ModLoaderWarning::other(format!("Unknown profile {name:?}")));
+                    continue;
+                };
+                let (modpack, warnings) = Modpack::from_profile(profile, &data_guard.game_mods);
+                data_guard.warnings.extend(warnings);
+
+                // drop here because bundling mods takes time
+                drop(data_guard);
+
+                let result = path
+                    .parent()
+                    .map_or(Ok(()), fs::create_dir_all)
+                    .map_err(ModLoaderWarning::from)
+                    .and_then(|_| modpack.write(&path, &mods_path, bundle));
+                if let Err(err) = result {
+                    background_thread_data.data.lock().warnings.push(err);
+                }
+            }
             BackgroundThreadMessage::UpdateApp => {
                 let newer_update = background_thread_data.newer_update.lock();
                 if newer_update.is_some() {
@@ -289,11 +687,13 @@ where
 
                 let mut data_guard = background_thread_data.data.lock();
 
-                let paks_path = data_guard.paks_path.as_ref().unwrap().clone();
-                let install_path = match data_guard.game_install_path {
-                    Some(ref install_path) => install_path.clone(),
-                    None => continue,
-                };
+                let (paks_path, install_path) =
+                    match (&data_guard.paks_path, &data_guard.game_install_path) {
+                        (Some(paks_path), Some(install_path)) => {
+                            (paks_path.clone(), install_path.clone())
+                        }
+                        _ => continue,
+                    };
                 #[cfg(feature = "cpp_loader")]
                 let cpp_loader_extract_path = data_guard.cpp_loader_extract_path.clone();
 
@@ -374,132 +774,105 @@ where
                         false,
                     ));
 
-                    // fetch dependencies
-                    let mut first_round = Vec::new();
-
-                    for (mod_id, enabled_mod) in background_thread_data
-                        .data
-                        .lock()
+                    // resolve dependencies
+                    let data_guard = background_thread_data.data.lock();
+                    let requested = data_guard
                         .game_mods
                         .iter()
                         .filter(|(_, game_mod)| game_mod.enabled)
-                    {
-                        let selected_version = match &enabled_mod.selected_version {
-                            game_mod::SelectedVersion::Latest(version) => version,
-                            game_mod::SelectedVersion::LatestIndirect(_) => {
-                                let mut versions =
-                                    enabled_mod.versions.keys().collect::<Vec<&Version>>();
-                                versions.sort();
-                                *versions.last().unwrap()
-                            }
-                            game_mod::SelectedVersion::Specific(version) => version,
-                        };
-                        first_round.push(ModWithDependencies::new(
-                            mod_id.clone(),
-                            Vec::from([selected_version.clone()]),
-                            enabled_mod.dependencies.clone(),
-                        ));
-                    }
-
-                    let mut graph = DependencyGraph::default();
+                        .map(|(mod_id, game_mod)| {
+                            let requirement = match game_mod.selected_version {
+                                game_mod::SelectedVersion::Specific(ref version) => VersionReq {
+                                    comparators: vec![Comparator {
+                                        op: Op::Exact,
+                                        major: version.major,
+                                        minor: Some(version.minor),
+                                        patch: Some(version.patch),
+                                        pre: version.pre.clone(),
+                                    }],
+                                },
+                                _ => VersionReq::STAR,
+                            };
+                            (mod_id.clone(), requirement)
+                        })
+                        .collect::<Vec<_>>();
 
-                    let mut download_pool = HashMap::new();
-                    let mut dependencies = graph.add_mods(&first_round);
+                    let baked_mods = config.get_integrator_config().get_baked_mods();
+                    let mut provider = ModProvider::new(
+                        &mods_path,
+                        &data_guard.game_mods,
+                        baked_mods.iter().map(|e| e.get_mod_id()).collect(),
+                    );
+                    drop(data_guard);
 
-                    let mut next_round = Vec::new();
+                    let resolution = resolver::resolve(&requested, &mut provider);
+                    warnings.append(&mut provider.warnings);
 
-                    loop {
-                        next_round.clear();
-                        for (mod_id, (version_req, downloads)) in &dependencies {
-                            let entry = download_pool
-                                .entry(mod_id.clone())
-                                .or_insert_with(HashMap::new);
+                    let mut mods_to_integrate = Vec::new();
+                    let mut downloaded_mods = Vec::new();
+                    let mut to_enable = Vec::new();
+                    let mut graph = DependencyGraph::default();
+                    match resolution {
+                        Ok(versions) => {
+                            let mut resolved_mods = Vec::new();
+                            let mut required_baked_mods = HashSet::new();
+
+                            for (mod_id, version) in versions {
+                                let mod_version = provider.versions[&mod_id][&version].clone();
+                                let dependencies = mod_version
+                                    .metadata
+                                    .as_ref()
+                                    .map(|e| e.dependencies.clone())
+                                    .unwrap_or_default();
+                                required_baked_mods.extend(
+                                    dependencies
+                                        .keys()
+                                        .filter(|e| provider.is_builtin(e))
+                                        .cloned(),
+                                );
+                                resolved_mods.push(ModWithDependencies::new(
+                                    mod_id.clone(),
+                                    Vec::from([version]),
+                                    dependencies,
+                                ));
 
-                            for download in downloads {
-                                match download_index_file(mod_id.clone(), download) {
-                                    Ok((_, index_file)) => {
-                                        for (version, index_version) in index_file.versions {
-                                            entry.entry(version).or_insert(index_version);
-                                        }
-                                    }
-                                    Err(err) => warnings.push(err),
+                                if provider.downloaded.contains(&mod_version.file_name) {
+                                    provider.keep_download(&mod_version.file_name, &mods_path)?;
+                                    downloaded_mods.push(FileToProcess::new(
+                                        mods_path.join(&mod_version.file_name),
+                                        false,
+                                    ));
                                 }
-                            }
-
-                            let matching_version = entry
-                                .iter()
-                                .find(|(version, _)| version_req.matches(version));
-
-                            if let Some((_, index_version)) = matching_version {
-                                let file = download_mod(&mods_path, index_version);
 
-                                if let Ok((metadata, _)) = file {
-                                    let mod_info = ModWithDependencies::new(
-                                        mod_id.clone(),
-                                        entry.keys().cloned().collect(),
-                                        metadata.dependencies,
-                                    );
-                                    next_round.push(mod_info);
+                                // enabled mods might have been resolved to another version
+                                match mods_to_install.iter_mut().find(|e| e.mod_id == mod_id) {
+                                    Some(existing) => *existing = mod_version,
+                                    None => {
+                                        to_enable.push(mod_id);
+                                        mods_to_install.push(mod_version);
+                                    }
                                 }
                             }
-                        }
-                        dependencies.clear();
-                        dependencies = graph.add_mods(&next_round);
 
-                        if next_round.is_empty() {
-                            break;
+                            graph.add_mods(&resolved_mods);
+                            mods_to_integrate.extend(
+                                baked_mods
+                                    .into_iter()
+                                    .filter(|e| required_baked_mods.contains(&e.get_mod_id())),
+                            );
                         }
-                    }
-
-                    let (mut to_download, graph_warnings) = graph.validate_graph();
-                    warnings.extend(graph_warnings);
-
-                    for existing_mod in first_round {
-                        to_download.remove(&existing_mod.mod_id);
-                    }
-
-                    let mut mods_to_integrate = Vec::new();
-                    for baked_mod in config.get_integrator_config().get_baked_mods() {
-                        if to_download.contains_key(&baked_mod.get_mod_id()) {
-                            to_download.remove(&baked_mod.get_mod_id());
-                            mods_to_integrate.push(baked_mod);
-                        }
-                    }
-
-                    let mut downloaded_mods = Vec::new();
-                    let mut to_enable = Vec::new();
-                    for (mod_id, version) in to_download {
-                        let available_versions =
-                            download_pool.get(&mod_id).and_then(|e| e.get(&version));
-                        match available_versions {
-                            Some(available_version) => {
-                                match download_mod(&mods_path, available_version) {
-                                    Ok((metadata, mod_path)) => {
-                                        mods_to_install.push(GameModVersion {
-                                            mod_id: metadata.mod_id.clone(),
-                                            file_name: available_version.file_name.clone(),
-                                            downloaded: true,
-                                            download_url: Some(
-                                                available_version.download_url.clone(),
-                                            ),
-                                            metadata: Some(metadata),
-                                        });
-
-                                        to_enable.push(mod_id.clone());
-                                        downloaded_mods.push(FileToProcess::new(mod_path, false));
-                                    }
-                                    Err(err) => warnings.push(err),
-                                }
-                            }
-                            None => {
-                                let dependents = graph.find_mod_dependents_with_version(&mod_id);
-                                warnings.push(ModLoaderWarning::unresolved_dependency(
-                                    mod_id.clone(),
-                                    dependents,
-                                ))
-                            }
+                        Err(conflict) => {
+                            warn!("Failed to resolve dependencies: {}", conflict);
+                            background_thread_data
+                                .data
+                                .lock()
+                                .warnings
+                                .append(&mut warnings);
+                            return Err(ModLoaderWarning::dependency_conflict(conflict));
                         }
                     }
+                    // unpicked downloads are deleted
+                    drop(provider);
 
                     background_thread_data.data.lock().dependency_graph = Some(graph);
 
@@ -534,15 +907,60 @@ where
                         })
                         .collect::<Vec<_>>();
 
+                    let game_build = data_guard.game_build;
                     drop(data_guard);
 
+                    warnings.extend(check_compatibility(
+                        mods_to_install.iter().map(|(_, e)| e),
+                        game_build,
+                    ));
+
+                    let load_order = mods_to_install
+                        .iter()
+                        .map(|(_, e)| {
+                            LoadOrderEntry::new(
+                                e.mod_id.clone(),
+                                file_priority(&e.file_name),
+                                e.metadata.as_ref(),
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    let priorities = match load_order::assign_priorities(&load_order) {
+                        Ok(priorities) => priorities,
+                        Err(cycle) => {
+                            warn!("{}", cycle);
+                            warnings.push(ModLoaderWarning::load_order_cycle(cycle));
+                            load_order
+                                .into_iter()
+                                .map(|e| (e.mod_id, e.priority))
+                                .collect()
+                        }
+                    };
+
+                    let mut installed_paks = Vec::new();
+                    let mut handled_files = config.get_integrator_config().get_handled_files();
                     for (version_string, mod_version) in mods_to_install {
-                        let dst_path = paks_path.join(mod_version.file_name.as_str());
+                        // the priority prefix decides which pak overrides the other
+                        let priority = priorities[&mod_version.mod_id];
+                        let (_, file_name) = mod_version.file_name.split_once('-').unwrap();
+                        let dst_path = paks_path.join(format!("{priority:03}-{file_name}"));
                         fs::copy(mods_path.join(mod_version.file_name.as_str()), &dst_path)
                             .map(|_| ())?;
 
-                        // extract DLLS
+                        installed_paks.push((mod_version.mod_id.clone(), dst_path.clone()));
+
                         if let Some(ref metadata) = mod_version.metadata {
+                            if let Some(maps) = metadata
+                                .integrator
+                                .get("persistent_actor_maps")
+                                .and_then(|e| e.as_array())
+                            {
+                                handled_files.extend(
+                                    maps.iter().filter_map(|e| e.as_str()).map(str::to_owned),
+                                );
+                            }
+
+                            // extract DLLS
                             if !metadata.cpp_loader_dlls.is_empty() {
                                 let mut hasher = Sha256::new();
                                 let mut file = File::open(&dst_path)?;
@@ -565,13 +983,7 @@ where
                             FileMod {
                                 path: dst_path,
                                 mod_id: mod_version.mod_id.clone(),
-                                priority: mod_version
-                                    .file_name
-                                    .split('-')
-                                    .next()
-                                    .unwrap()
-                                    .parse::<u32>()
-                                    .unwrap(),
+                                priority,
                             }
                             .into(),
                         );
@@ -581,6 +993,18 @@ where
 
                     mods_to_integrate.sort_by_key(|a| a.get_priority());
 
+                    installed_paks.sort_by(|a, b| a.1.cmp(&b.1));
+                    let compare_assets = background_thread_data
+                        .data
+                        .lock()
+                        .compare_conflicting_assets;
+                    warnings.extend(find_conflicts(
+                        &installed_paks,
+                        &handled_files,
+                        IC::ENGINE_VERSION,
+                        compare_assets,
+                    ));
+
                     debug!(
                         "Pre Integration took {} milliseconds",
                         start_pre.elapsed().as_millis()
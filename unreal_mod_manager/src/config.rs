@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 
 use unreal_mod_integrator::IntegratorConfig;
 
+use crate::game_platform_managers::WineSettings;
 use crate::version::GameBuild;
 use crate::{
     error::{ModLoaderError, ModLoaderWarning},
@@ -38,6 +39,13 @@ install_manager_trait!(
     fn get_paks_path(&self) -> Option<PathBuf>;
     fn get_game_build(&self) -> Option<GameBuild>;
     fn launch_game(&self) -> Result<(), ModLoaderWarning>;
+
+    /// Settings of platforms that use Wine, `None` for other platforms
+    fn get_wine_settings(&self) -> Option<WineSettings> {
+        None
+    }
+    /// Override the defaults of a platform that uses Wine
+    fn set_wine_settings(&self, _settings: WineSettings) {}
 );
 
 pub trait GameConfig<'data, IC, D, E: std::error::Error + 'static>: std::marker::Send
@@ -4,6 +4,12 @@ use std::io;
 
 use reqwest::StatusCode;
 use unreal_pak::error::PakError;
+use zip::result::ZipError;
+
+use crate::mod_processing::{
+    conflicts::FileConflict, load_order::LoadOrderCycle, resolver::Conflict,
+};
+use crate::version::GameBuild;
 
 /// For critical errors that can happen during runtime which prevent further
 /// operation of the modloader and cannot be handled gracefully.
@@ -76,6 +82,14 @@ impl From<PakError> for ModLoaderError {
     }
 }
 
+impl From<ModLoaderWarning> for ModLoaderError {
+    fn from(warning: ModLoaderWarning) -> Self {
+        ModLoaderError {
+            kind: ModLoaderErrorKind::Generic(Box::new(warning)),
+        }
+    }
+}
+
 impl error::Error for ModLoaderError {}
 
 /// For non-critical errors that can happen during runtime which can be
@@ -98,10 +112,19 @@ pub enum ModLoaderWarningKind {
     IntegratorError(unreal_mod_integrator::error::Error),
 
     UnresolvedDependency(String, Vec<(String, String)>),
+    DependencyConflict(Conflict),
+    FileConflict(FileConflict),
+    /// File that is also modified by integrator handlers
+    HandledFileOverride(String),
+    LoadOrderCycle(LoadOrderCycle),
+    /// Id of the other mod
+    IncompatibleMod(String),
+    UnsupportedGameBuild(GameBuild),
     ReferencedByOtherMods(String, Vec<String>),
 
     SteamError,
     WinStoreError,
+    WineError,
 
     MissingMetadata,
     InvalidMetadata,
@@ -112,7 +135,12 @@ pub enum ModLoaderWarningKind {
     IndexFileDownloadFailedStatus(StatusCode),
     InvalidIndexFile,
     IndexFileMissingMod,
+    InvalidIndexFileSignature,
     DownloadFailed(reqwest::Error),
+    /// Expected and actual SHA-256 hash of a downloaded file
+    ChecksumMismatch(String, String),
+    ZipError(ZipError),
+    InvalidModpack(String),
 
     #[cfg(feature = "cpp_loader")]
     DllInjector(dll_injector::error::InjectorError),
@@ -145,6 +173,48 @@ impl ModLoaderWarning {
         }
     }
 
+    pub fn dependency_conflict(conflict: Conflict) -> Self {
+        ModLoaderWarning {
+            mod_id: Some(conflict.mod_id.clone()),
+            kind: ModLoaderWarningKind::DependencyConflict(conflict),
+        }
+    }
+
+    pub fn file_conflict(conflict: FileConflict) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::FileConflict(conflict),
+            mod_id: None,
+        }
+    }
+
+    pub fn handled_file_override(mod_id: String, file_name: String) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::HandledFileOverride(file_name),
+            mod_id: Some(mod_id),
+        }
+    }
+
+    pub fn load_order_cycle(cycle: LoadOrderCycle) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::LoadOrderCycle(cycle),
+            mod_id: None,
+        }
+    }
+
+    pub fn incompatible_mod(mod_id: String, other_mod_id: String) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::IncompatibleMod(other_mod_id),
+            mod_id: Some(mod_id),
+        }
+    }
+
+    pub fn unsupported_game_build(mod_id: String, game_build: GameBuild) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::UnsupportedGameBuild(game_build),
+            mod_id: Some(mod_id),
+        }
+    }
+
     pub fn referenced_by_other_mods(mod_id: String, referencers: Vec<String>) -> Self {
         ModLoaderWarning {
             kind: ModLoaderWarningKind::ReferencedByOtherMods(mod_id.clone(), referencers),
@@ -164,6 +234,12 @@ impl ModLoaderWarning {
             mod_id: None,
         }
     }
+    pub fn wine_error() -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::WineError,
+            mod_id: None,
+        }
+    }
     pub fn missing_metadata(mod_id: String) -> Self {
         ModLoaderWarning {
             kind: ModLoaderWarningKind::MissingMetadata,
@@ -218,12 +294,30 @@ impl ModLoaderWarning {
             mod_id: Some(mod_id),
         }
     }
+    pub fn invalid_index_file_signature(mod_id: String) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::InvalidIndexFileSignature,
+            mod_id: Some(mod_id),
+        }
+    }
     pub fn download_failed(mod_id: String, err: reqwest::Error) -> Self {
         ModLoaderWarning {
             kind: ModLoaderWarningKind::DownloadFailed(err),
             mod_id: Some(mod_id),
         }
     }
+    pub fn checksum_mismatch(mod_id: String, expected: String, actual: String) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::ChecksumMismatch(expected, actual),
+            mod_id: Some(mod_id),
+        }
+    }
+    pub fn invalid_modpack(message: String) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::InvalidModpack(message),
+            mod_id: None,
+        }
+    }
 
     pub fn other(message: String) -> Self {
         ModLoaderWarning {
@@ -256,6 +350,9 @@ impl fmt::Display for ModLoaderWarning {
             ModLoaderWarningKind::WinStoreError => {
                 "Failed to locate WinStore installation".to_string()
             }
+            ModLoaderWarningKind::WineError => {
+                "Failed to locate game installation in Wine prefix".to_string()
+            }
 
             ModLoaderWarningKind::MissingMetadata => format!("{mod_name}Missing metadata"),
             ModLoaderWarningKind::InvalidMetadata => format!("{mod_name}Invalid metadata"),
@@ -274,9 +371,19 @@ impl fmt::Display for ModLoaderWarning {
             ModLoaderWarningKind::IndexFileMissingMod => {
                 format!("{mod_name}Index file missing mod")
             }
+            ModLoaderWarningKind::InvalidIndexFileSignature => {
+                format!("{mod_name}Index file signature is invalid")
+            }
             ModLoaderWarningKind::DownloadFailed(ref err) => {
                 format!("{mod_name}Download failed: {err}")
             }
+            ModLoaderWarningKind::ChecksumMismatch(ref expected, ref actual) => {
+                format!("{mod_name}Checksum mismatch, expected SHA-256 {expected}, got {actual}")
+            }
+            ModLoaderWarningKind::ZipError(ref err) => format!("{mod_name}Zip error: {err}"),
+            ModLoaderWarningKind::InvalidModpack(ref message) => {
+                format!("Invalid modpack: {message}")
+            }
 
             #[cfg(feature = "cpp_loader")]
             ModLoaderWarningKind::DllInjector(ref err) => format!("Injector: {err}"),
@@ -287,6 +394,18 @@ impl fmt::Display for ModLoaderWarning {
 
             ModLoaderWarningKind::Other(ref message) => format!("{mod_name}{message}"),
             ModLoaderWarningKind::Generic(ref err) => format!("{mod_name}Error: {err}"),
+            ModLoaderWarningKind::DependencyConflict(ref conflict) => format!("Error: {conflict}"),
+            ModLoaderWarningKind::FileConflict(ref conflict) => format!("Conflict: {conflict}"),
+            ModLoaderWarningKind::HandledFileOverride(ref file_name) => {
+                format!("{mod_name}Overrides {file_name}, which is also modified by the integrator")
+            }
+            ModLoaderWarningKind::LoadOrderCycle(ref cycle) => format!("Error: {cycle}"),
+            ModLoaderWarningKind::IncompatibleMod(ref other_mod_id) => {
+                format!("{mod_name}Incompatible with {other_mod_id}")
+            }
+            ModLoaderWarningKind::UnsupportedGameBuild(ref game_build) => {
+                format!("{mod_name}Doesn't support game build {game_build}")
+            }
             ModLoaderWarningKind::UnresolvedDependency(ref dependency, ref requesters) => {
                 format!(
                     "Error: Unresolved dependency {} for mods: \n{}",
@@ -329,6 +448,15 @@ impl From<PakError> for ModLoaderWarning {
     }
 }
 
+impl From<ZipError> for ModLoaderWarning {
+    fn from(err: ZipError) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::ZipError(err),
+            mod_id: None,
+        }
+    }
+}
+
 impl From<unreal_mod_integrator::error::Error> for ModLoaderWarning {
     fn from(err: unreal_mod_integrator::error::Error) -> Self {
         ModLoaderWarning {
@@ -2,7 +2,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 
 use semver::Version;
-use unreal_mod_metadata::{Dependency, DownloadInfo, Metadata, SyncMode};
+use unreal_mod_metadata::{Dependency, DownloadInfo, DownloadMode, Metadata, SyncMode};
 
 use crate::version::GameBuild;
 
@@ -76,11 +76,45 @@ pub struct GameMod {
     pub size: u64,
 }
 
+impl GameMod {
+    /// Select the newest version, mods with an index file will also follow future updates
+    pub fn select_latest_version(&mut self) {
+        let has_index_file = self
+            .download
+            .as_ref()
+            .is_some_and(|e| e.download_mode == DownloadMode::IndexFile);
+
+        self.selected_version = match self.latest_version.clone() {
+            Some(latest_version) if has_index_file => SelectedVersion::Latest(latest_version),
+            latest_version => SelectedVersion::LatestIndirect(
+                latest_version.or_else(|| self.versions.keys().next_back().cloned()),
+            ),
+        };
+    }
+
+    /// Select a known version, returns `false` if the version doesn't exist
+    ///
+    /// Selecting the newest version keeps following updates, like the version dropdown does.
+    pub fn select_version(&mut self, version: &Version) -> bool {
+        if !self.versions.contains_key(version) {
+            return false;
+        }
+
+        self.selected_version = match self.latest_version.as_ref() == Some(version) {
+            true => SelectedVersion::LatestIndirect(Some(version.clone())),
+            false => SelectedVersion::Specific(version.clone()),
+        };
+        true
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GameModVersion {
     pub mod_id: String,
     pub file_name: String,
     pub downloaded: bool,
     pub download_url: Option<String>,
+    /// Hex encoded SHA-256 hash of the file from the index file
+    pub sha256: Option<String>,
     pub metadata: Option<Metadata>,
 }
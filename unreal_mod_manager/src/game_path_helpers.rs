@@ -1,10 +1,13 @@
-use std::path::PathBuf;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use directories::BaseDirs;
 use lazy_static::lazy_static;
 use log::{trace, warn};
 use regex::Regex;
 use steamlocate::SteamDir;
+use walkdir::WalkDir;
 
 #[cfg(windows)]
 use winreg::{enums::HKEY_CURRENT_USER, RegKey};
@@ -38,6 +41,16 @@ pub fn determine_installed_mods_path_steam(game_name: &str) -> Option<PathBuf> {
 }
 
 pub fn determine_prefix_path_proton(app_id: u32) -> Option<PathBuf> {
+    // the prefix is in the same library as the game, which isn't always the main library
+    let library_prefix = determine_install_path_steam(app_id)
+        .ok()
+        .and_then(|e| Some(e.parent()?.parent()?.join("compatdata")))
+        .map(|e| e.join(app_id.to_string()).join("pfx"))
+        .filter(|e| e.is_dir());
+    if library_prefix.is_some() {
+        return library_prefix;
+    }
+
     Some(
         SteamDir::locate()?
             .path
@@ -49,24 +62,91 @@ pub fn determine_prefix_path_proton(app_id: u32) -> Option<PathBuf> {
 }
 
 pub fn determine_user_path_proton(app_id: u32) -> Option<PathBuf> {
+    let prefix_path = determine_prefix_path_proton(app_id)?;
     Some(
-        determine_prefix_path_proton(app_id)?
-            .join("drive_c")
-            .join("users")
-            .join("steamuser"),
+        determine_user_path_wine(&prefix_path)
+            .unwrap_or_else(|| prefix_path.join("drive_c").join("users").join("steamuser")),
     )
 }
 
 pub fn determine_installed_mods_path_proton(game_name: &str, app_id: u32) -> Option<PathBuf> {
-    let data_dir: PathBuf = determine_user_path_proton(app_id)?
-        .join("AppData")
-        .join("Local");
+    let data_dir = local_app_data_path_wine(&determine_user_path_proton(app_id)?);
+    let base_path = Some(data_dir.join(game_name).join("Saved").join("Paks"));
+    trace!("base_path: {:?}", base_path);
+
+    base_path
+}
+
+/// Wine prefix from `WINEPREFIX`, `~/.wine` if it isn't set
+pub fn determine_prefix_path_wine() -> Option<PathBuf> {
+    if let Some(prefix_path) = env::var_os("WINEPREFIX").filter(|e| !e.is_empty()) {
+        return Some(PathBuf::from(prefix_path));
+    }
+
+    Some(BaseDirs::new()?.home_dir().join(".wine"))
+}
+
+/// User folder in a Wine prefix
+///
+/// Proton prefixes use `steamuser`, other prefixes are named after the user that created them.
+/// The folder of the current user is preferred if there are multiple.
+pub fn determine_user_path_wine(prefix_path: &Path) -> Option<PathBuf> {
+    let users_path = prefix_path.join("drive_c").join("users");
+
+    let mut users = fs::read_dir(&users_path)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_ok_and(|e| e.is_dir()))
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .filter(|e| e != "Public")
+        .collect::<Vec<_>>();
+    users.sort();
+
+    let current_user = env::var("USER").ok();
+    let user = users
+        .iter()
+        .find(|e| current_user.as_ref() == Some(*e))
+        .or_else(|| users.iter().find(|e| *e == "steamuser"))
+        .or_else(|| users.first())?;
+
+    Some(users_path.join(user))
+}
+
+/// `%LocalAppData%` of a Wine user, older Wine versions use `Local Settings\Application Data`
+fn local_app_data_path_wine(user_path: &Path) -> PathBuf {
+    let legacy_path = user_path.join("Local Settings").join("Application Data");
+    match !user_path.join("AppData").is_dir() && legacy_path.is_dir() {
+        true => legacy_path,
+        false => user_path.join("AppData").join("Local"),
+    }
+}
+
+pub fn determine_installed_mods_path_wine(prefix_path: &Path, game_name: &str) -> Option<PathBuf> {
+    let data_dir = local_app_data_path_wine(&determine_user_path_wine(prefix_path)?);
     let base_path = Some(data_dir.join(game_name).join("Saved").join("Paks"));
     trace!("base_path: {:?}", base_path);
 
     base_path
 }
 
+/// Search `drive_c` of a Wine prefix for the install folder of a game
+///
+/// The install folder is the one containing `<game_name>/Binaries`, e.g.
+/// `drive_c/Program Files/Publisher/Game` for `Game/GameName/Binaries`.
+pub fn determine_install_path_wine(prefix_path: &Path, game_name: &str) -> Option<PathBuf> {
+    WalkDir::new(prefix_path.join("drive_c"))
+        .max_depth(4)
+        .sort_by_file_name()
+        .into_iter()
+        // users and windows are big and never contain games
+        .filter_entry(|e| {
+            e.depth() != 1 || !matches!(e.file_name().to_str(), Some("users" | "windows"))
+        })
+        .filter_map(|e| e.ok())
+        .find(|e| e.file_type().is_dir() && e.path().join(game_name).join("Binaries").is_dir())
+        .map(|e| e.into_path())
+}
+
 #[cfg(windows)]
 pub fn determine_game_package_path_winstore(store_info: &MsStoreInfo) -> Option<PathBuf> {
     let base_dirs = BaseDirs::new();
@@ -17,6 +17,9 @@ pub use msstore::MsStoreInstallManager;
 mod proton;
 pub use proton::ProtonInstallManager;
 
+mod wine;
+pub use wine::{set_dll_override, WineInstallManager, WineSettings};
+
 #[cfg(windows)]
 mod steam;
 #[cfg(windows)]
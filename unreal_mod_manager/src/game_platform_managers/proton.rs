@@ -1,14 +1,12 @@
 use std::cell::RefCell;
 use std::path::PathBuf;
 
-#[cfg(feature = "cpp_loader")]
-use std::io::Write;
-
 use crate::config::InstallManager;
 use crate::error::ModLoaderWarning;
 use crate::game_path_helpers;
 use crate::version::GameBuild;
 
+use super::wine::{self, WineSettings};
 use super::GetGameBuildTrait;
 
 #[derive(Debug)]
@@ -16,6 +14,8 @@ pub struct ProtonInstallManager {
     pub game_path: RefCell<Option<PathBuf>>,
     pub mods_path: RefCell<Option<PathBuf>>,
 
+    settings: WineSettings,
+    overrides: RefCell<WineSettings>,
     app_id: u32,
     game_name: &'static str,
     game_build_getter: Box<dyn GetGameBuildTrait<ProtonInstallManager>>,
@@ -31,28 +31,64 @@ impl ProtonInstallManager {
             game_path: RefCell::new(None),
             mods_path: RefCell::new(None),
 
+            settings: WineSettings::default(),
+            overrides: RefCell::new(WineSettings::default()),
             app_id,
             game_name,
             game_build_getter,
         }
     }
+
+    /// Launch the game with a shell command instead of through Steam
+    pub fn with_launch_command(mut self, launch_command: String) -> Self {
+        self.settings.launch_command = Some(launch_command);
+        self
+    }
+
+    /// Settings overridden by the user, with the defaults of the game for the rest
+    pub fn settings(&self) -> WineSettings {
+        self.overrides.borrow().clone().or(&self.settings)
+    }
+
+    /// Prefix in `steamapps/compatdata`, unless a different one is set
+    pub fn get_prefix_path(&self) -> Option<PathBuf> {
+        self.settings()
+            .prefix_path
+            .or_else(|| game_path_helpers::determine_prefix_path_proton(self.app_id))
+    }
+
+    #[cfg(feature = "cpp_loader")]
+    fn get_user_path(&self) -> Option<PathBuf> {
+        match self.settings().prefix_path {
+            Some(prefix_path) => game_path_helpers::determine_user_path_wine(&prefix_path),
+            None => game_path_helpers::determine_user_path_proton(self.app_id),
+        }
+    }
 }
 
 impl InstallManager for ProtonInstallManager {
     fn get_game_install_path(&self) -> Option<PathBuf> {
         if self.game_path.borrow().is_none() {
-            *self.game_path.borrow_mut() =
-                game_path_helpers::determine_install_path_steam(self.app_id).ok();
+            *self.game_path.borrow_mut() = self
+                .settings()
+                .game_path
+                .or_else(|| game_path_helpers::determine_install_path_steam(self.app_id).ok());
         }
         self.game_path.borrow().clone()
     }
 
     fn get_paks_path(&self) -> Option<PathBuf> {
         if self.mods_path.borrow().is_none() {
-            *self.mods_path.borrow_mut() = game_path_helpers::determine_installed_mods_path_proton(
-                self.game_name,
-                self.app_id,
-            );
+            *self.mods_path.borrow_mut() = match self.settings().prefix_path {
+                Some(prefix_path) => game_path_helpers::determine_installed_mods_path_wine(
+                    &prefix_path,
+                    self.game_name,
+                ),
+                None => game_path_helpers::determine_installed_mods_path_proton(
+                    self.game_name,
+                    self.app_id,
+                ),
+            };
         }
 
         self.mods_path.borrow().clone()
@@ -63,15 +99,33 @@ impl InstallManager for ProtonInstallManager {
     }
 
     fn launch_game(&self) -> Result<(), ModLoaderWarning> {
+        if let Some(launch_command) = self.settings().launch_command {
+            return wine::run_launch_command(
+                &launch_command,
+                self.get_prefix_path().as_deref(),
+                self.get_game_install_path().as_deref(),
+            );
+        }
+
         open::that(format!("steam://run/{}", self.app_id))?;
         Ok(())
     }
+
+    fn get_wine_settings(&self) -> Option<WineSettings> {
+        Some(self.overrides.borrow().clone())
+    }
+
+    fn set_wine_settings(&self, settings: WineSettings) {
+        *self.overrides.borrow_mut() = settings;
+        *self.game_path.borrow_mut() = None;
+        *self.mods_path.borrow_mut() = None;
+    }
 }
 
 #[cfg(feature = "cpp_loader")]
 impl unreal_cpp_bootstrapper::CppLoaderInstallExtension<ModLoaderWarning> for ProtonInstallManager {
     fn get_config_location(&self) -> Result<PathBuf, ModLoaderWarning> {
-        game_path_helpers::determine_user_path_proton(self.app_id)
+        self.get_user_path()
             .map(|e| {
                 e.join("Temp")
                     .join("unrealmodding")
@@ -82,7 +136,8 @@ impl unreal_cpp_bootstrapper::CppLoaderInstallExtension<ModLoaderWarning> for Pr
     }
 
     fn get_extract_path(&self) -> Result<PathBuf, ModLoaderWarning> {
-        Ok(game_path_helpers::determine_user_path_proton(self.app_id)
+        Ok(self
+            .get_user_path()
             .ok_or(ModLoaderWarning::other(String::from(
                 "Failed to find proton user path!",
             )))?
@@ -97,35 +152,16 @@ impl unreal_cpp_bootstrapper::CppLoaderInstallExtension<ModLoaderWarning> for Pr
             return Err(ModLoaderWarning::steam_error());
         };
 
-        let Some(prefix_path) = game_path_helpers::determine_prefix_path_proton(self.app_id) else {
+        let Some(prefix_path) = self.get_prefix_path() else {
             return Err(ModLoaderWarning::steam_error());
         };
 
-        let registry_path = install_path.join("reg.reg");
-        let file = std::fs::OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(registry_path)?;
-
-        let mut writer = std::io::BufWriter::new(file);
-        write!(writer, "Windows Registry Editor Version 5.00")?;
-
-        write!(
-            writer,
-            "[HKEY_CURRENT_USER\\Software\\Wine\\AppDefaults\\{}-Win64-Shipping.exe\\DllOverrides]",
-            self.game_name
+        wine::set_dll_override(
+            &prefix_path,
+            &format!("{}-Win64-Shipping.exe", self.game_name),
+            "xinput1_3",
         )?;
 
-        write!(writer, "\"xinput1_3\"=\"native,builtin\"")?;
-
-        drop(writer);
-
-        let _ = std::process::Command::new("wine")
-            .args(["regedit", "C:\\Users\\steamuser\\reg.reg"])
-            .env("WINEPREFIX", prefix_path.to_str().unwrap())
-            .output()?;
-
         let dest_path = install_path
             .join(self.game_name)
             .join("Binaries")
@@ -0,0 +1,294 @@
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::InstallManager;
+use crate::error::ModLoaderWarning;
+use crate::game_path_helpers;
+use crate::version::GameBuild;
+
+use super::GetGameBuildTrait;
+
+/// Settings of platforms that run the game with Wine, unset settings are detected
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WineSettings {
+    /// Wine prefix, e.g. the one Lutris or Heroic created for the game
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix_path: Option<PathBuf>,
+    /// Game install folder, for games that are installed outside of the prefix
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_path: Option<PathBuf>,
+    /// Shell command that launches the game, `WINEPREFIX` and `GAME_PATH` are set for it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub launch_command: Option<String>,
+}
+
+impl WineSettings {
+    /// Use the settings of `other` where these aren't set
+    pub fn or(self, other: &WineSettings) -> WineSettings {
+        WineSettings {
+            prefix_path: self.prefix_path.or_else(|| other.prefix_path.clone()),
+            game_path: self.game_path.or_else(|| other.game_path.clone()),
+            launch_command: self.launch_command.or_else(|| other.launch_command.clone()),
+        }
+    }
+}
+
+/// Run a launch command from [`WineSettings`] with `sh`
+pub(crate) fn run_launch_command(
+    launch_command: &str,
+    prefix_path: Option<&Path>,
+    game_path: Option<&Path>,
+) -> Result<(), ModLoaderWarning> {
+    let mut command = Command::new("sh");
+    command.arg("-c").arg(launch_command);
+    if let Some(prefix_path) = prefix_path {
+        command.env("WINEPREFIX", prefix_path);
+    }
+    if let Some(game_path) = game_path {
+        command.env("GAME_PATH", game_path);
+    }
+
+    command.spawn()?;
+    Ok(())
+}
+
+/// Make Wine load the native version of `dll_name` for `exe_name`
+///
+/// Edits `user.reg` of the prefix directly, so this must not be called while the prefix is in use.
+pub fn set_dll_override(prefix_path: &Path, exe_name: &str, dll_name: &str) -> io::Result<()> {
+    let registry_path = prefix_path.join("user.reg");
+    let registry = match fs::read_to_string(&registry_path) {
+        Ok(registry) => registry,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => "WINE REGISTRY Version 2\n".to_owned(),
+        Err(err) => return Err(err),
+    };
+
+    // keys and value names are case insensitive
+    let section = format!("[Software\\\\Wine\\\\AppDefaults\\\\{exe_name}\\\\DllOverrides]");
+    let value_name = format!("\"{dll_name}\"=").to_lowercase();
+    let value = format!("\"{dll_name}\"=\"native,builtin\"");
+
+    let mut lines = registry.lines().map(str::to_owned).collect::<Vec<_>>();
+    let section_start = lines
+        .iter()
+        .position(|e| e.to_lowercase().starts_with(&section.to_lowercase()));
+
+    match section_start {
+        Some(start) => {
+            let end = lines[start + 1..]
+                .iter()
+                .position(|e| e.starts_with('['))
+                .map_or(lines.len(), |e| start + 1 + e);
+
+            match lines[start + 1..end]
+                .iter()
+                .position(|e| e.to_lowercase().starts_with(&value_name))
+            {
+                Some(i) => lines[start + 1 + i] = value,
+                None => {
+                    let mut insert = end;
+                    while insert > start + 1 && lines[insert - 1].is_empty() {
+                        insert -= 1;
+                    }
+                    lines.insert(insert, value);
+                }
+            }
+        }
+        None => {
+            let time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |e| e.as_secs());
+            if lines.last().is_some_and(|e| !e.is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(format!("{section} {time}"));
+            lines.push(value);
+        }
+    }
+
+    let mut registry = lines.join("\n");
+    registry.push('\n');
+    fs::write(registry_path, registry)
+}
+
+/// Games running in a Wine prefix that isn't managed by Steam, e.g. with Lutris or Heroic
+#[derive(Debug)]
+pub struct WineInstallManager {
+    pub game_path: RefCell<Option<PathBuf>>,
+    pub mods_path: RefCell<Option<PathBuf>>,
+
+    settings: WineSettings,
+    overrides: RefCell<WineSettings>,
+    game_name: &'static str,
+    game_build_getter: Box<dyn GetGameBuildTrait<WineInstallManager>>,
+}
+
+impl WineInstallManager {
+    /// Without further settings the prefix from `WINEPREFIX` or `~/.wine` is searched for the game
+    pub fn new(
+        game_name: &'static str,
+        game_build_getter: Box<dyn GetGameBuildTrait<WineInstallManager>>,
+    ) -> Self {
+        WineInstallManager {
+            game_path: RefCell::new(None),
+            mods_path: RefCell::new(None),
+
+            settings: WineSettings::default(),
+            overrides: RefCell::new(WineSettings::default()),
+            game_name,
+            game_build_getter,
+        }
+    }
+
+    pub fn with_prefix_path(mut self, prefix_path: PathBuf) -> Self {
+        self.settings.prefix_path = Some(prefix_path);
+        self
+    }
+
+    pub fn with_game_path(mut self, game_path: PathBuf) -> Self {
+        self.settings.game_path = Some(game_path);
+        self
+    }
+
+    pub fn with_launch_command(mut self, launch_command: String) -> Self {
+        self.settings.launch_command = Some(launch_command);
+        self
+    }
+
+    /// Settings overridden by the user, with the defaults of the game for the rest
+    pub fn settings(&self) -> WineSettings {
+        self.overrides.borrow().clone().or(&self.settings)
+    }
+
+    pub fn get_prefix_path(&self) -> Option<PathBuf> {
+        self.settings()
+            .prefix_path
+            .or_else(game_path_helpers::determine_prefix_path_wine)
+    }
+
+    #[cfg(feature = "cpp_loader")]
+    fn binaries_path(&self) -> Option<PathBuf> {
+        Some(
+            self.get_game_install_path()?
+                .join(self.game_name)
+                .join("Binaries")
+                .join("Win64"),
+        )
+    }
+}
+
+impl InstallManager for WineInstallManager {
+    fn get_game_install_path(&self) -> Option<PathBuf> {
+        if self.game_path.borrow().is_none() {
+            *self.game_path.borrow_mut() = self.settings().game_path.or_else(|| {
+                game_path_helpers::determine_install_path_wine(
+                    &self.get_prefix_path()?,
+                    self.game_name,
+                )
+            });
+        }
+        self.game_path.borrow().clone()
+    }
+
+    fn get_paks_path(&self) -> Option<PathBuf> {
+        if self.mods_path.borrow().is_none() {
+            *self.mods_path.borrow_mut() = game_path_helpers::determine_installed_mods_path_wine(
+                &self.get_prefix_path()?,
+                self.game_name,
+            );
+        }
+
+        self.mods_path.borrow().clone()
+    }
+
+    fn get_game_build(&self) -> Option<GameBuild> {
+        self.game_build_getter.get_game_build(self)
+    }
+
+    fn launch_game(&self) -> Result<(), ModLoaderWarning> {
+        let prefix_path = self.get_prefix_path();
+        let game_path = self.get_game_install_path();
+
+        if let Some(launch_command) = self.settings().launch_command {
+            return run_launch_command(
+                &launch_command,
+                prefix_path.as_deref(),
+                game_path.as_deref(),
+            );
+        }
+
+        let (Some(prefix_path), Some(game_path)) = (prefix_path, game_path) else {
+            return Err(ModLoaderWarning::wine_error());
+        };
+        Command::new("wine")
+            .arg(game_path.join(format!("{}.exe", self.game_name)))
+            .current_dir(&game_path)
+            .env("WINEPREFIX", prefix_path)
+            .spawn()?;
+        Ok(())
+    }
+
+    fn get_wine_settings(&self) -> Option<WineSettings> {
+        Some(self.overrides.borrow().clone())
+    }
+
+    fn set_wine_settings(&self, settings: WineSettings) {
+        *self.overrides.borrow_mut() = settings;
+        *self.game_path.borrow_mut() = None;
+        *self.mods_path.borrow_mut() = None;
+    }
+}
+
+#[cfg(feature = "cpp_loader")]
+impl unreal_cpp_bootstrapper::CppLoaderInstallExtension<ModLoaderWarning> for WineInstallManager {
+    fn get_config_location(&self) -> Result<PathBuf, ModLoaderWarning> {
+        Ok(self.get_extract_path()?.with_file_name("config.json"))
+    }
+
+    fn get_extract_path(&self) -> Result<PathBuf, ModLoaderWarning> {
+        self.get_prefix_path()
+            .and_then(|e| game_path_helpers::determine_user_path_wine(&e))
+            .map(|e| {
+                e.join("Temp")
+                    .join("unrealmodding")
+                    .join("cpp_loader")
+                    .join("mods")
+            })
+            .ok_or_else(ModLoaderWarning::wine_error)
+    }
+
+    fn prepare_load(&self) -> Result<(), ModLoaderWarning> {
+        let (Some(prefix_path), Some(dest_path)) = (self.get_prefix_path(), self.binaries_path())
+        else {
+            return Err(ModLoaderWarning::wine_error());
+        };
+
+        set_dll_override(
+            &prefix_path,
+            &format!("{}-Win64-Shipping.exe", self.game_name),
+            "xinput1_3",
+        )?;
+
+        super::write_loader_dll(dest_path.as_path())?;
+        super::write_proxy_dll(dest_path.as_path())?;
+
+        Ok(())
+    }
+
+    // the dll override makes the game load xinput1_3.dll by itself
+    fn load(&self) -> Result<(), ModLoaderWarning> {
+        Ok(())
+    }
+
+    fn remove(&self) {
+        if let Some(dest_path) = self.binaries_path() {
+            super::remove_dlls(dest_path.as_path());
+        }
+    }
+}
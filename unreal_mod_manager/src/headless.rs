@@ -0,0 +1,393 @@
+//! Headless mode, manages mods without starting the GUI
+//!
+//! Useful for dedicated servers and automated setups. A game can offer both modes
+//! by calling [`run_cli`] instead of [`run`](crate::run) when it was started with arguments.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::sync::{
+    atomic::{AtomicBool, AtomicI32},
+    mpsc, Arc,
+};
+use std::time::Instant;
+
+use clap::Parser;
+use log::debug;
+use parking_lot::Mutex;
+use semver::Version;
+
+use unreal_mod_integrator::IntegratorConfig;
+
+use crate::background_work::{self, BackgroundThreadData, BackgroundThreadMessage};
+use crate::config::GameConfig;
+use crate::error::{ModLoaderError, ModLoaderWarning};
+use crate::game_platform_managers::WineSettings;
+use crate::{FileToProcess, ModLoaderAppData};
+
+/// Version to select for a mod, parsed from `MOD_ID=VERSION` or `MOD_ID=latest`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionSelection {
+    pub mod_id: String,
+    /// `None` selects the latest version
+    pub version: Option<Version>,
+}
+
+impl FromStr for VersionSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mod_id, version) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Expected MOD_ID=VERSION, got {s:?}"))?;
+
+        let version = match version {
+            "latest" => None,
+            _ => Some(Version::parse(version).map_err(|e| e.to_string())?),
+        };
+
+        Ok(VersionSelection {
+            mod_id: mod_id.to_owned(),
+            version,
+        })
+    }
+}
+
+/// Profile to export, parsed from `NAME=FILE`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModpackExport {
+    pub profile: String,
+    pub path: PathBuf,
+}
+
+impl FromStr for ModpackExport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (profile, path) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Expected NAME=FILE, got {s:?}"))?;
+
+        Ok(ModpackExport {
+            profile: profile.to_owned(),
+            path: PathBuf::from(path),
+        })
+    }
+}
+
+/// Actions to run in headless mode
+///
+/// Actions are run in the order of the fields, the mod config is saved afterwards.
+#[derive(Parser, Debug, Default, Clone)]
+#[command(about = "Manage mods without starting the GUI")]
+pub struct HeadlessArgs {
+    /// Game platform to use, e.g. "Steam"
+    #[arg(long)]
+    pub platform: Option<String>,
+    /// Wine prefix of the game, for platforms that use Wine
+    #[arg(long, value_name = "PATH")]
+    pub wine_prefix: Option<PathBuf>,
+    /// Game install folder, for platforms that use Wine
+    #[arg(long, value_name = "PATH")]
+    pub game_path: Option<PathBuf>,
+    /// Shell command that launches the game, for platforms that use Wine
+    #[arg(long, value_name = "COMMAND")]
+    pub launch_command: Option<String>,
+    /// Clear the saved Wine settings of the platform before applying the ones given
+    #[arg(long)]
+    pub reset_wine_settings: bool,
+    /// Compare the exports of assets that are overridden by multiple mods,
+    /// instead of only reporting the overlapping files
+    #[arg(long, value_name = "BOOL")]
+    pub compare_assets: Option<bool>,
+    /// Install mod pak files
+    #[arg(long, value_name = "PAK")]
+    pub install: Vec<PathBuf>,
+    /// Install the mods of modpacks and apply them as profiles
+    #[arg(long, value_name = "MODPACK")]
+    pub import_modpack: Vec<PathBuf>,
+    /// Remove installed mods
+    #[arg(long, value_name = "MOD_ID")]
+    pub remove: Vec<String>,
+    /// Apply a saved profile, enabling its mods and disabling all others
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// Enable mods
+    #[arg(long, value_name = "MOD_ID")]
+    pub enable: Vec<String>,
+    /// Disable mods
+    #[arg(long, value_name = "MOD_ID")]
+    pub disable: Vec<String>,
+    /// Select a mod version
+    #[arg(long, value_name = "MOD_ID=VERSION|latest")]
+    pub select_version: Vec<VersionSelection>,
+    /// Export a saved profile to a modpack file
+    #[arg(long, value_name = "NAME=FILE")]
+    pub export_modpack: Vec<ModpackExport>,
+    /// Bundle all mod files in exported modpacks, not only the ones that can't be downloaded
+    #[arg(long)]
+    pub bundle_paks: bool,
+    /// Don't integrate the enabled mods
+    #[arg(long)]
+    pub no_integrate: bool,
+    /// Launch the game
+    #[arg(long)]
+    pub launch: bool,
+    /// List the installed mods
+    #[arg(long)]
+    pub list: bool,
+}
+
+/// Action run in headless mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeadlessAction {
+    SetGamePlatform(String),
+    /// Override the Wine settings of a platform, `None` for the selected one,
+    /// optionally resetting the saved overrides first
+    SetWineSettings(Option<String>, WineSettings, bool),
+    SetCompareAssets(bool),
+    Install(Vec<PathBuf>),
+    ImportModpack(PathBuf),
+    RemoveMod(String),
+    ApplyProfile(String),
+    SetEnabled(String, bool),
+    SelectVersion(VersionSelection),
+    /// Export a profile to a modpack file, optionally bundling all mod files
+    ExportModpack(ModpackExport, bool),
+    Integrate,
+    LaunchGame,
+}
+
+impl From<HeadlessAction> for BackgroundThreadMessage {
+    fn from(action: HeadlessAction) -> Self {
+        match action {
+            HeadlessAction::SetGamePlatform(platform) => {
+                BackgroundThreadMessage::SetGamePlatform(platform)
+            }
+            HeadlessAction::SetWineSettings(platform, settings, reset) => {
+                BackgroundThreadMessage::SetWineSettings(platform, settings, reset)
+            }
+            HeadlessAction::SetCompareAssets(compare_assets) => {
+                BackgroundThreadMessage::SetCompareAssets(compare_assets)
+            }
+            HeadlessAction::Install(paths) => BackgroundThreadMessage::Import(
+                paths
+                    .into_iter()
+                    .map(|path| FileToProcess::new(path, true))
+                    .collect(),
+            ),
+            HeadlessAction::ImportModpack(path) => BackgroundThreadMessage::ImportModpack(path),
+            HeadlessAction::RemoveMod(mod_id) => BackgroundThreadMessage::RemoveMod(mod_id),
+            HeadlessAction::ApplyProfile(profile) => BackgroundThreadMessage::ApplyProfile(profile),
+            HeadlessAction::SetEnabled(mod_id, enabled) => {
+                BackgroundThreadMessage::SetEnabled(mod_id, enabled)
+            }
+            HeadlessAction::SelectVersion(selection) => {
+                BackgroundThreadMessage::SelectVersion(selection.mod_id, selection.version)
+            }
+            HeadlessAction::ExportModpack(export, bundle_paks) => {
+                BackgroundThreadMessage::ExportModpack(export.profile, export.path, bundle_paks)
+            }
+            HeadlessAction::Integrate => BackgroundThreadMessage::integrate(),
+            HeadlessAction::LaunchGame => BackgroundThreadMessage::LaunchGame,
+        }
+    }
+}
+
+impl HeadlessArgs {
+    /// Get the actions to run in the order they are run in
+    pub fn actions(&self) -> Vec<HeadlessAction> {
+        let mut actions = Vec::new();
+
+        if let Some(platform) = &self.platform {
+            actions.push(HeadlessAction::SetGamePlatform(platform.clone()));
+        }
+        let wine_settings = WineSettings {
+            prefix_path: self.wine_prefix.clone(),
+            game_path: self.game_path.clone(),
+            launch_command: self.launch_command.clone(),
+        };
+        if self.reset_wine_settings || wine_settings != WineSettings::default() {
+            actions.push(HeadlessAction::SetWineSettings(
+                self.platform.clone(),
+                wine_settings,
+                self.reset_wine_settings,
+            ));
+        }
+        if let Some(compare_assets) = self.compare_assets {
+            actions.push(HeadlessAction::SetCompareAssets(compare_assets));
+        }
+        if !self.install.is_empty() {
+            actions.push(HeadlessAction::Install(self.install.clone()));
+        }
+        for path in &self.import_modpack {
+            actions.push(HeadlessAction::ImportModpack(path.clone()));
+        }
+        for mod_id in &self.remove {
+            actions.push(HeadlessAction::RemoveMod(mod_id.clone()));
+        }
+        if let Some(profile) = &self.profile {
+            actions.push(HeadlessAction::ApplyProfile(profile.clone()));
+        }
+        for mod_id in &self.enable {
+            actions.push(HeadlessAction::SetEnabled(mod_id.clone(), true));
+        }
+        for mod_id in &self.disable {
+            actions.push(HeadlessAction::SetEnabled(mod_id.clone(), false));
+        }
+        for selection in &self.select_version {
+            actions.push(HeadlessAction::SelectVersion(selection.clone()));
+        }
+        for export in &self.export_modpack {
+            actions.push(HeadlessAction::ExportModpack(
+                export.clone(),
+                self.bundle_paks,
+            ));
+        }
+        if !self.no_integrate {
+            actions.push(HeadlessAction::Integrate);
+        }
+        if self.launch {
+            actions.push(HeadlessAction::LaunchGame);
+        }
+
+        actions
+    }
+
+    /// Messages for the background thread, the mod config is saved after all actions
+    fn messages(&self) -> Vec<BackgroundThreadMessage> {
+        self.actions()
+            .into_iter()
+            .map(BackgroundThreadMessage::from)
+            .chain([
+                BackgroundThreadMessage::WriteConfig,
+                BackgroundThreadMessage::Exit,
+            ])
+            .collect()
+    }
+}
+
+/// Installed mod
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModSummary {
+    pub mod_id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub selected_version: String,
+    pub versions: Vec<Version>,
+}
+
+/// Result of a headless run
+#[derive(Debug, Default)]
+pub struct HeadlessReport {
+    pub mods: Vec<ModSummary>,
+    pub warnings: Vec<ModLoaderWarning>,
+}
+
+/// Run the actions of `args` without a GUI
+///
+/// Returns an error if the mod manager couldn't start or integration failed,
+/// problems with single actions are reported as warnings.
+pub fn run_headless<'data, GC, IC, D: 'data, E: 'static + std::error::Error + Send>(
+    config: GC,
+    args: &HeadlessArgs,
+) -> Result<HeadlessReport, ModLoaderError>
+where
+    GC: 'static + GameConfig<'data, IC, D, E>,
+    IC: 'static + IntegratorConfig<'data, D, E>,
+{
+    let data = Arc::new(Mutex::new(ModLoaderAppData::new(&config)));
+
+    let (background_tx, background_rx) = mpsc::channel::<BackgroundThreadMessage>();
+    for message in args.messages() {
+        let _ = background_tx.send(message);
+    }
+
+    let background_thread_data = BackgroundThreadData {
+        data: data.clone(),
+        use_cpp_loader: false,
+        ready_exit: Arc::new(AtomicBool::new(false)),
+        last_integration_time: Arc::new(Mutex::new(Instant::now())),
+        working: Arc::new(AtomicBool::new(false)),
+        newer_update: Arc::new(Mutex::new(None)),
+        should_update: Arc::new(AtomicBool::new(false)),
+        update_progress: Arc::new(AtomicI32::new(0)),
+    };
+
+    debug!("Running headless");
+    background_work::background_work(config, background_thread_data, background_rx)?;
+
+    let mut data = data.lock();
+    if let Some(err) = data.error.take() {
+        return Err(err);
+    }
+    if data.failed {
+        if let Some(warning) = data.warnings.pop() {
+            return Err(warning.into());
+        }
+    }
+    if !args.no_integrate && (data.paks_path.is_none() || data.game_install_path.is_none()) {
+        data.warnings.push(ModLoaderWarning::other(
+            "Game install not found, mods were not integrated".to_owned(),
+        ));
+    }
+
+    let mods = data
+        .game_mods
+        .iter()
+        .map(|(mod_id, game_mod)| ModSummary {
+            mod_id: mod_id.clone(),
+            name: game_mod.name.clone(),
+            enabled: game_mod.enabled,
+            selected_version: game_mod.selected_version.to_string(),
+            versions: game_mod.versions.keys().cloned().collect(),
+        })
+        .collect();
+
+    Ok(HeadlessReport {
+        mods,
+        warnings: std::mem::take(&mut data.warnings),
+    })
+}
+
+/// Parse [`HeadlessArgs`] from the command line and run them without a GUI
+///
+/// Warnings are printed to stderr, the mod list is printed to stdout if `--list` was passed.
+pub fn run_cli<'data, GC, IC, D: 'data, E: 'static + std::error::Error + Send>(
+    config: GC,
+) -> ExitCode
+where
+    GC: 'static + GameConfig<'data, IC, D, E>,
+    IC: 'static + IntegratorConfig<'data, D, E>,
+{
+    let args = HeadlessArgs::parse();
+
+    match run_headless(config, &args) {
+        Ok(report) => {
+            for warning in &report.warnings {
+                eprintln!("warning: {warning}");
+            }
+
+            if args.list {
+                for game_mod in &report.mods {
+                    println!(
+                        "{}\t{}\t{}\t{}",
+                        game_mod.mod_id,
+                        game_mod.name,
+                        match game_mod.enabled {
+                            true => "enabled",
+                            false => "disabled",
+                        },
+                        game_mod.selected_version
+                    );
+                }
+            }
+
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
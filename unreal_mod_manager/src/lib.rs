@@ -23,12 +23,14 @@ mod app;
 mod background_work;
 pub mod config;
 pub mod error;
-pub(crate) mod game_mod;
+pub mod game_mod;
 pub mod game_path_helpers;
 pub mod game_platform_managers;
+pub mod headless;
 mod mod_config;
 mod mod_processing;
-pub(crate) mod profile;
+pub mod modpack;
+pub mod profile;
 pub mod update_info;
 pub mod version;
 
@@ -40,6 +42,7 @@ use mod_config::write_config;
 use mod_processing::dependencies::DependencyGraph;
 use version::GameBuild;
 
+pub use mod_processing::{conflicts, index_file, load_order, resolver};
 pub use unreal_asset;
 #[cfg(feature = "cpp_loader")]
 pub use unreal_cpp_bootstrapper;
@@ -88,6 +91,8 @@ pub(crate) struct ModLoaderAppData {
 
     pub game_build: Option<GameBuild>,
     pub refuse_mismatched_connections: bool,
+    /// compare the exports of conflicting assets instead of only reporting the overlapping files
+    pub compare_conflicting_assets: bool,
     pub files_to_process: Vec<FileToProcess>,
 
     pub game_mods: BTreeMap<String, GameMod>,
@@ -114,6 +119,20 @@ pub(crate) struct ModLoaderAppData {
 }
 
 impl ModLoaderAppData {
+    pub fn new<'data, GC, IC, D: 'data, E: 'static + std::error::Error + Send>(config: &GC) -> Self
+    where
+        GC: config::GameConfig<'data, IC, D, E>,
+        IC: IntegratorConfig<'data, D, E>,
+    {
+        ModLoaderAppData {
+            refuse_mismatched_connections: true,
+            install_managers: config.get_install_managers(),
+            #[cfg(feature = "cpp_loader")]
+            cpp_loader_config: GC::get_cpp_loader_config(),
+            ..Default::default()
+        }
+    }
+
     // TODO actually return an error?
     pub fn set_game_platform(&mut self, platform: &str) -> Result<(), ()> {
         let manager = self.install_managers.get(platform);
@@ -150,13 +169,7 @@ where
     GC: 'static + config::GameConfig<'data, IC, D, E>,
     IC: 'static + IntegratorConfig<'data, D, E>,
 {
-    let data = Arc::new(Mutex::new(ModLoaderAppData {
-        refuse_mismatched_connections: true,
-        install_managers: config.get_install_managers(),
-        #[cfg(feature = "cpp_loader")]
-        cpp_loader_config: GC::get_cpp_loader_config(),
-        ..Default::default()
-    }));
+    let data = Arc::new(Mutex::new(ModLoaderAppData::new(&config)));
 
     let icon_data = config.get_icon();
 
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::game_mod::SelectedVersion;
+use crate::game_platform_managers::WineSettings;
 use crate::profile::parse_profile_config;
 use crate::ModLoaderAppData;
 
@@ -14,10 +15,15 @@ use crate::ModLoaderAppData;
 struct ModConfig {
     selected_game_platform: Option<String>,
     refuse_mismatched_connections: bool,
+    #[serde(default)]
+    compare_conflicting_assets: bool,
     current: ModsConfigData,
     #[serde(default)]
     trusted_mods: Vec<String>,
     profiles: Value,
+    /// Wine settings overridden by the user, by platform
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    wine_settings: HashMap<String, WineSettings>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -62,6 +68,7 @@ pub(crate) fn load_config(data: &mut ModLoaderAppData) {
     };
 
     data.refuse_mismatched_connections = config.refuse_mismatched_connections;
+    data.compare_conflicting_assets = config.compare_conflicting_assets;
 
     for (mod_id, mod_config) in config.current.mods.iter() {
         let game_mod = data.game_mods.get_mut(mod_id);
@@ -105,6 +112,13 @@ pub(crate) fn load_config(data: &mut ModLoaderAppData) {
         }
     };
 
+    for (platform, settings) in config.wine_settings {
+        match data.install_managers.get(platform.as_str()) {
+            Some(manager) => manager.set_wine_settings(settings),
+            None => warn!("Unknown platform {} in modconfig.json", platform),
+        }
+    }
+
     if let Some(ref selected_game_platform) = config.selected_game_platform {
         let _ = data.set_game_platform(selected_game_platform);
     } else if data.set_game_platform("Steam").is_err() {
@@ -120,6 +134,7 @@ pub(crate) fn write_config(data: &ModLoaderAppData) {
     let mut config = ModConfig {
         selected_game_platform: data.selected_game_platform.clone(),
         refuse_mismatched_connections: data.refuse_mismatched_connections,
+        compare_conflicting_assets: data.compare_conflicting_assets,
         current: ModsConfigData {
             mods: HashMap::new(),
         },
@@ -129,6 +144,14 @@ pub(crate) fn write_config(data: &ModLoaderAppData) {
             .map(hex::encode)
             .collect::<Vec<_>>(),
         profiles: serde_json::to_value(data.profiles.clone()).unwrap(),
+        wine_settings: data
+            .install_managers
+            .iter()
+            .filter_map(|(platform, manager)| {
+                Some((platform.to_string(), manager.get_wine_settings()?))
+            })
+            .filter(|(_, settings)| *settings != WineSettings::default())
+            .collect(),
     };
 
     for (mod_id, game_mod) in data.game_mods.iter() {
@@ -0,0 +1,186 @@
+//! Detection of mods that override the same game files
+//!
+//! Only one version of a file can be loaded, so when multiple mods contain the same file
+//! all but one of them silently lose their changes. Files that are modified by integrator handlers
+//! are also reported, because the integrator reads them from the game and might discard the override.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use unreal_asset::engine_version::EngineVersion;
+use unreal_mod_integrator::helpers::read_asset;
+use unreal_pak::{error::PakError, PakReader};
+
+/// Files that every mod has
+const IGNORED_FILES: [&str; 1] = ["metadata.json"];
+/// Extensions of the files of a package, conflicts are reported once per package
+const PACKAGE_EXTENSIONS: [&str; 5] = ["uasset", "umap", "uexp", "ubulk", "uptnl"];
+
+/// File that is contained in multiple mods
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileConflict {
+    /// Pak entry name, the asset or map of a package if any of the mods contains it
+    pub file_name: String,
+    /// Mods that contain the file, in the order they were given
+    pub mod_ids: Vec<String>,
+    /// Object paths of the exports that differ between the versions of the mods,
+    /// `None` if the assets weren't compared
+    pub differing_exports: Option<Vec<String>>,
+}
+
+impl fmt::Display for FileConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} is overridden by multiple mods: {}",
+            self.file_name,
+            self.mod_ids.join(", ")
+        )?;
+        if let Some(ref exports) = self.differing_exports {
+            write!(f, ", differing exports: {}", exports.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the name of a file without its extension, to compare paths of assets and maps
+fn without_extension(file_name: &str) -> &str {
+    match file_name.rsplit_once('.') {
+        Some((name, extension)) if !extension.contains('/') => name,
+        _ => file_name,
+    }
+}
+
+/// Get the path of the package a file belongs to, `None` if it isn't part of a package
+fn package_path(file_name: &str) -> Option<&str> {
+    match file_name.rsplit_once('.') {
+        Some((name, extension)) if PACKAGE_EXTENSIONS.contains(&extension) => Some(name),
+        _ => None,
+    }
+}
+
+/// Enabled mod paks that can be checked for conflicts
+pub struct ConflictScanner {
+    paks: Vec<(String, PakReader<BufReader<File>>)>,
+}
+
+impl ConflictScanner {
+    /// Open the paks of mods, given as mod id and pak path
+    pub fn new<P: AsRef<Path>>(
+        mods: impl IntoIterator<Item = (String, P)>,
+    ) -> Result<Self, PakError> {
+        let mut paks = Vec::new();
+        for (mod_id, path) in mods {
+            let mut pak = PakReader::new(BufReader::new(File::open(path)?));
+            pak.load_index()?;
+            paks.push((mod_id, pak));
+        }
+        Ok(ConflictScanner { paks })
+    }
+
+    /// Find files that are contained in more than one mod
+    ///
+    /// The files of a package are compared by the package path, so mods that only override
+    /// the .uexp or .ubulk of an asset conflict with mods that override the whole asset.
+    pub fn file_conflicts(&self) -> Vec<FileConflict> {
+        let mut files: BTreeMap<&str, (&str, Vec<&str>)> = BTreeMap::new();
+        for (mod_id, pak) in &self.paks {
+            for file_name in pak.get_entry_names() {
+                if IGNORED_FILES.contains(&file_name.as_str()) {
+                    continue;
+                }
+
+                let key = package_path(file_name).unwrap_or(file_name);
+                let (name, mod_ids) = files.entry(key).or_insert((file_name, Vec::new()));
+                if is_asset(file_name) && !is_asset(name) {
+                    *name = file_name;
+                }
+                if !mod_ids.contains(&mod_id.as_str()) {
+                    mod_ids.push(mod_id);
+                }
+            }
+        }
+
+        files
+            .into_values()
+            .filter(|(_, mod_ids)| mod_ids.len() > 1)
+            .map(|(file_name, mod_ids)| FileConflict {
+                file_name: file_name.to_string(),
+                mod_ids: mod_ids.into_iter().map(str::to_string).collect(),
+                differing_exports: None,
+            })
+            .collect()
+    }
+
+    /// Find files that override files modified by integrator handlers
+    ///
+    /// `handled_files` are pak entry names, the extension is ignored so that
+    /// game paths without an extension match both assets and maps.
+    /// Returns the mod id and file name of each override.
+    pub fn handled_file_overrides(&self, handled_files: &[String]) -> Vec<(String, String)> {
+        let handled_files = handled_files
+            .iter()
+            .map(|e| without_extension(e))
+            .collect::<HashSet<_>>();
+
+        let mut overrides = Vec::new();
+        for (mod_id, pak) in &self.paks {
+            for file_name in pak.get_entry_names() {
+                if is_asset(file_name) && handled_files.contains(without_extension(file_name)) {
+                    overrides.push((mod_id.clone(), file_name.clone()));
+                }
+            }
+        }
+        overrides
+    }
+
+    /// Compare the versions of a conflicting asset and set [`FileConflict::differing_exports`]
+    ///
+    /// Every version is compared against the version of the first mod.
+    /// If all versions are equal the list is empty, so the conflict doesn't matter.
+    pub fn compare_assets(
+        &mut self,
+        conflict: &mut FileConflict,
+        engine_version: EngineVersion,
+    ) -> Result<(), unreal_mod_integrator::Error> {
+        let mut assets = Vec::new();
+        for mod_id in &conflict.mod_ids {
+            let Some((_, pak)) = self.paks.iter_mut().find(|(e, _)| e == mod_id) else {
+                continue;
+            };
+
+            let asset = read_asset(
+                |name| match pak.contains_entry(name) {
+                    true => Ok(Some(pak.read_entry(name)?)),
+                    false => Ok(None),
+                },
+                engine_version,
+                &conflict.file_name,
+            )?;
+            assets.push(asset);
+        }
+
+        let mut differing_exports = Vec::new();
+        if let Some((first, others)) = assets.split_first() {
+            for other in others {
+                for export in first.diff(other).exports {
+                    let path = export.get_path().to_string();
+                    if !differing_exports.contains(&path) {
+                        differing_exports.push(path);
+                    }
+                }
+            }
+        }
+
+        conflict.differing_exports = Some(differing_exports);
+        Ok(())
+    }
+}
+
+/// Check if a pak entry is an asset that can be compared with [`ConflictScanner::compare_assets`]
+pub fn is_asset(file_name: &str) -> bool {
+    file_name.ends_with(".uasset") || file_name.ends_with(".umap")
+}
@@ -4,8 +4,6 @@ use petgraph::{graph::NodeIndex, prelude::DiGraph, visit::IntoNodeReferences, Di
 use semver::{Version, VersionReq};
 use unreal_mod_metadata::{Dependency, DownloadInfo};
 
-use crate::error::ModLoaderWarning;
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct GraphMod {
     mod_id: String,
@@ -128,50 +126,6 @@ impl DependencyGraph {
         version_requirements
     }
 
-    pub fn validate_graph(&self) -> (HashMap<String, Version>, Vec<ModLoaderWarning>) {
-        let mut matching_versions = HashMap::new();
-        let mut warnings = Vec::new();
-
-        for node in self.graph.node_indices() {
-            let requirements = self
-                .graph
-                .edges_directed(node, Direction::Incoming)
-                .map(|e| e.weight().clone());
-            let requirement = intersect_requirements(requirements);
-
-            let weight = self.graph.node_weight(node).unwrap();
-            let matching_version = weight.versions.iter().find(|e| requirement.matches(e));
-
-            match matching_version {
-                Some(matching_version) => {
-                    matching_versions.insert(weight.mod_id.clone(), matching_version.clone());
-                }
-                None => {
-                    let warning = ModLoaderWarning::unresolved_dependency(
-                        weight.mod_id.clone(),
-                        self.graph
-                            .neighbors_directed(node, Direction::Incoming)
-                            .map(|e| {
-                                (
-                                    self.graph.node_weight(e).unwrap().mod_id.clone(),
-                                    self.graph
-                                        .edges_connecting(node, e)
-                                        .next()
-                                        .unwrap()
-                                        .weight(),
-                                )
-                            })
-                            .map(|(mod_id, version_req)| (mod_id, version_req.to_string()))
-                            .collect::<Vec<_>>(),
-                    );
-                    warnings.push(warning);
-                }
-            }
-        }
-
-        (matching_versions, warnings)
-    }
-
     pub fn find_mod_dependents(&self, mod_id: &str) -> Vec<String> {
         match self
             .graph
@@ -186,33 +140,4 @@ impl DependencyGraph {
             None => Vec::new(),
         }
     }
-
-    pub fn find_mod_dependents_with_version(&self, mod_id: &str) -> Vec<(String, String)> {
-        let node = self
-            .graph
-            .node_references()
-            .find(|(_, graph_mod)| graph_mod.mod_id == mod_id);
-
-        if let Some((node, _)) = node {
-            return self
-                .graph
-                .neighbors_directed(node, Direction::Incoming)
-                .map(|e| {
-                    (
-                        self.graph.node_weight(e).unwrap().mod_id.clone(),
-                        self.graph.find_edge_undirected(node, e),
-                    )
-                })
-                .filter(|(_, edge)| edge.is_some())
-                .map(|(mod_id, edge)| {
-                    (
-                        mod_id,
-                        self.graph.edge_weight(edge.unwrap().0).unwrap().to_string(),
-                    )
-                })
-                .collect::<Vec<_>>();
-        }
-
-        Vec::new()
-    }
 }
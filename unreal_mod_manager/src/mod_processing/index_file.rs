@@ -1,13 +1,27 @@
+//! Index files, which list the downloadable versions of mods
+//!
+//! An index file is a JSON file with a `mods` object, mapping mod ids to their latest version
+//! and their versions. Each version has a `download_url`, a `filename` and optionally
+//! a hex encoded `sha256` hash of the file, which is checked before the file is moved into the mods directory.
+//!
+//! If the [`DownloadInfo`] of a mod has a `public_key`, the index file must be signed.
+//! The hex encoded ed25519 signature of the index file is downloaded from the index file url with `.sig` appended.
+
 use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
 use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::thread;
 
+use ed25519_dalek::{Signature, VerifyingKey};
 use log::{debug, warn};
 use reqwest::blocking::Client;
 use semver::Version;
 use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer};
+use sha2::{Digest, Sha256};
 use unreal_mod_metadata::DownloadInfo;
 
 use crate::error::ModLoaderWarning;
@@ -17,8 +31,8 @@ use crate::ModLoaderAppData;
 use super::verify;
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub(crate) struct IndexFile {
-    mods: HashMap<String, IndexFileMod>,
+pub struct IndexFile {
+    pub mods: HashMap<String, IndexFileMod>,
 }
 
 fn string_to_version<'de, T, D>(deserializer: D) -> Result<T, D::Error>
@@ -60,7 +74,7 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub(crate) struct IndexFileMod {
+pub struct IndexFileMod {
     #[serde(deserialize_with = "string_to_version")]
     pub latest_version: Version,
     #[serde(deserialize_with = "deserialize_version_map")]
@@ -68,19 +82,102 @@ pub(crate) struct IndexFileMod {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Hash)]
-pub(crate) struct IndexFileModVersion {
+pub struct IndexFileModVersion {
     pub download_url: String,
     #[serde(rename = "filename")]
     pub file_name: String,
+    /// Hex encoded SHA-256 hash of the file
+    #[serde(default)]
+    pub sha256: Option<String>,
 }
 
 impl IndexFileModVersion {
-    pub fn new(download_url: String, file_name: String) -> Self {
+    pub fn new(download_url: String, file_name: String, sha256: Option<String>) -> Self {
         IndexFileModVersion {
             download_url,
             file_name,
+            sha256,
         }
     }
+
+    /// Download this version into `mods_path`
+    ///
+    /// The file is downloaded next to its destination first and only renamed
+    /// once its hash matches `sha256`, otherwise it is removed again.
+    /// The file name must already be validated.
+    pub fn download(&self, mods_path: &Path) -> Result<PathBuf, ModLoaderWarning> {
+        let mut response = reqwest::blocking::get(self.download_url.as_str())
+            .and_then(|e| e.error_for_status())
+            .map_err(|e| ModLoaderWarning::download_failed(self.file_name.clone(), e))?;
+
+        let file_path = mods_path.join(&self.file_name);
+        let download_path = mods_path.join(format!("{}.download", self.file_name));
+
+        let mut download = || -> Result<(), ModLoaderWarning> {
+            let mut hasher = Sha256::new();
+            let mut file = fs::File::create(&download_path)?;
+            io::copy(&mut response, &mut HashingWriter(&mut file, &mut hasher))?;
+            drop(file);
+
+            if let Some(ref expected) = self.sha256 {
+                let actual = hex::encode(hasher.finalize());
+                if !actual.eq_ignore_ascii_case(expected) {
+                    warn!(
+                        "Checksum mismatch for {:?}, expected {}, got {}",
+                        self.file_name, expected, actual
+                    );
+                    return Err(ModLoaderWarning::checksum_mismatch(
+                        self.file_name.clone(),
+                        expected.clone(),
+                        actual,
+                    ));
+                }
+            }
+
+            fs::rename(&download_path, &file_path)?;
+            Ok(())
+        };
+
+        download().inspect_err(|_| {
+            let _ = fs::remove_file(&download_path);
+        })?;
+
+        Ok(file_path)
+    }
+}
+
+/// Writer that hashes everything written to it
+struct HashingWriter<'a, W: Write>(&'a mut W, &'a mut Sha256);
+
+impl<W: Write> Write for HashingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.0.write(buf)?;
+        self.1.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// Check a hex encoded ed25519 signature of an index file
+pub fn verify_index_file_signature(index_file: &[u8], public_key: &str, signature: &str) -> bool {
+    let Some(public_key) = hex::decode(public_key.trim())
+        .ok()
+        .and_then(|e| <[u8; 32]>::try_from(e).ok())
+        .and_then(|e| VerifyingKey::from_bytes(&e).ok())
+    else {
+        return false;
+    };
+    let Some(signature) = hex::decode(signature.trim())
+        .ok()
+        .and_then(|e| Signature::from_slice(&e).ok())
+    else {
+        return false;
+    };
+
+    public_key.verify_strict(index_file, &signature).is_ok()
 }
 
 pub(crate) fn gather_index_files(
@@ -101,37 +198,63 @@ pub(crate) fn gather_index_files(
         .collect()
 }
 
-pub(crate) fn download_index_file(
-    mod_id: String,
-    download_info: &DownloadInfo,
-) -> Result<(String, IndexFileMod), ModLoaderWarning> {
-    let client = Client::new();
-    let response = client.get(download_info.url.as_str()).send();
+fn download_bytes(client: &Client, url: &str, mod_id: &str) -> Result<Vec<u8>, ModLoaderWarning> {
+    let response = client.get(url).send();
     if let Err(err) = response {
-        warn!("Failed to download index file for {:?}, {}", mod_id, err);
+        warn!("Failed to download {:?} for {:?}, {}", url, mod_id, err);
 
-        return Err(ModLoaderWarning::index_file_download_failed(mod_id, err));
+        return Err(ModLoaderWarning::index_file_download_failed(
+            mod_id.to_owned(),
+            err,
+        ));
     }
 
     let response = response.unwrap();
     if !response.status().is_success() {
         warn!(
-            "Failed to download index file for {:?}, {}",
+            "Failed to download {:?} for {:?}, {}",
+            url,
             mod_id,
             response.status()
         );
 
         return Err(ModLoaderWarning::index_file_download_failed_status(
-            mod_id,
+            mod_id.to_owned(),
             response.status(),
         ));
     }
 
-    let index_file =
-        serde_json::from_str::<IndexFile>(response.text().unwrap().as_str()).map_err(|err| {
-            warn!("Failed to parse index file for {}: {}", mod_id.clone(), err);
-            ModLoaderWarning::invalid_index_file(mod_id.clone())
-        })?;
+    response
+        .bytes()
+        .map(|e| e.to_vec())
+        .map_err(|err| ModLoaderWarning::index_file_download_failed(mod_id.to_owned(), err))
+}
+
+/// Download the index file of a mod and get the entry of that mod
+///
+/// If `download_info` has a public key, the signature of the index file is checked as well.
+pub fn download_index_file(
+    mod_id: String,
+    download_info: &DownloadInfo,
+) -> Result<(String, IndexFileMod), ModLoaderWarning> {
+    let client = Client::new();
+    let index_file = download_bytes(&client, download_info.url.as_str(), &mod_id)?;
+
+    if let Some(ref public_key) = download_info.public_key {
+        let signature_url = format!("{}.sig", download_info.url);
+        let signature = download_bytes(&client, &signature_url, &mod_id)?;
+        let signature = String::from_utf8_lossy(&signature);
+
+        if !verify_index_file_signature(&index_file, public_key, &signature) {
+            warn!("Invalid index file signature for {}", mod_id);
+            return Err(ModLoaderWarning::invalid_index_file_signature(mod_id));
+        }
+    }
+
+    let index_file = serde_json::from_slice::<IndexFile>(&index_file).map_err(|err| {
+        warn!("Failed to parse index file for {}: {}", mod_id.clone(), err);
+        ModLoaderWarning::invalid_index_file(mod_id.clone())
+    })?;
 
     match index_file.mods.get(&mod_id) {
         Some(index_file_mod) => Ok((mod_id, index_file_mod.clone())),
@@ -209,6 +332,7 @@ pub(crate) fn insert_index_file_data(
                 let existing_version_data = game_mod.versions.get_mut(version).unwrap();
 
                 existing_version_data.download_url = Some(version_info.download_url.clone());
+                existing_version_data.sha256 = version_info.sha256.clone();
             } else {
                 game_mod.versions.insert(
                     version.clone(),
@@ -217,6 +341,7 @@ pub(crate) fn insert_index_file_data(
                         file_name: version_info.file_name.clone(),
                         downloaded: false,
                         download_url: Some(version_info.download_url.clone()),
+                        sha256: version_info.sha256.clone(),
                         metadata: None,
                     },
                 );
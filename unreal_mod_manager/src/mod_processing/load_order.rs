@@ -0,0 +1,124 @@
+//! Pak priorities from the load order hints in mod metadata
+//!
+//! Paks with a higher priority are loaded later and override files of paks with a lower priority.
+//! [`assign_priorities`] raises the priority of mods that have to load after others,
+//! mods without hints keep the priority from their file name.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use unreal_mod_metadata::Metadata;
+
+/// Highest priority a mod can be raised to, the integrator pak with priority 900 has to load after all mods
+pub const MAX_PRIORITY: u32 = 899;
+
+/// Mod that is being installed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadOrderEntry {
+    pub mod_id: String,
+    /// Priority from the file name
+    pub priority: u32,
+    pub load_before: Vec<String>,
+    pub load_after: Vec<String>,
+}
+
+impl LoadOrderEntry {
+    pub fn new(mod_id: String, priority: u32, metadata: Option<&Metadata>) -> Self {
+        LoadOrderEntry {
+            mod_id,
+            priority,
+            load_before: metadata.map(|e| e.load_before.clone()).unwrap_or_default(),
+            load_after: metadata.map(|e| e.load_after.clone()).unwrap_or_default(),
+        }
+    }
+}
+
+/// Mods whose load order hints contradict each other or need a priority above [`MAX_PRIORITY`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadOrderCycle {
+    pub mod_ids: Vec<String>,
+}
+
+impl fmt::Display for LoadOrderCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Load order of {} can't be satisfied",
+            self.mod_ids.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for LoadOrderCycle {}
+
+/// Assign priorities so that every mod loads after the mods it has to load after
+///
+/// Hints that name mods which aren't being installed are ignored.
+/// Priorities from file names are kept even if they are above [`MAX_PRIORITY`],
+/// but hints never raise a mod past it.
+pub fn assign_priorities(
+    entries: &[LoadOrderEntry],
+) -> Result<BTreeMap<String, u32>, LoadOrderCycle> {
+    let priorities = entries
+        .iter()
+        .map(|e| (e.mod_id.as_str(), e.priority))
+        .collect::<BTreeMap<_, _>>();
+
+    // mod id -> mods that have to load before it
+    let mut before: BTreeMap<&str, BTreeSet<&str>> = priorities
+        .keys()
+        .map(|mod_id| (*mod_id, BTreeSet::new()))
+        .collect();
+    for entry in entries {
+        for other in entry.load_after.iter().map(String::as_str) {
+            if other != entry.mod_id && priorities.contains_key(other) {
+                before.get_mut(entry.mod_id.as_str()).unwrap().insert(other);
+            }
+        }
+        for other in entry.load_before.iter().map(String::as_str) {
+            if let Some(others_before) = before.get_mut(other).filter(|_| other != entry.mod_id) {
+                others_before.insert(entry.mod_id.as_str());
+            }
+        }
+    }
+
+    let mut assigned: BTreeMap<String, u32> = BTreeMap::new();
+    while assigned.len() < priorities.len() {
+        // lowest priority mod whose predecessors are all assigned
+        let next = before
+            .iter()
+            .filter(|(mod_id, _)| !assigned.contains_key(**mod_id))
+            .filter(|(_, others)| others.iter().all(|e| assigned.contains_key(*e)))
+            .min_by_key(|(mod_id, _)| (priorities[**mod_id], **mod_id));
+
+        let Some((mod_id, others)) = next else {
+            return Err(LoadOrderCycle {
+                mod_ids: before
+                    .keys()
+                    .filter(|e| !assigned.contains_key(**e))
+                    .map(|e| e.to_string())
+                    .collect(),
+            });
+        };
+
+        let priority = others
+            .iter()
+            .map(|e| assigned[*e] + 1)
+            .fold(priorities[mod_id], u32::max);
+        if priority > priorities[mod_id] && priority > MAX_PRIORITY {
+            return Err(LoadOrderCycle {
+                mod_ids: others
+                    .iter()
+                    .chain([mod_id])
+                    .copied()
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+            });
+        }
+        assigned.insert(mod_id.to_string(), priority);
+    }
+
+    Ok(assigned)
+}
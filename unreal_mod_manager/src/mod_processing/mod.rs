@@ -5,15 +5,18 @@ use parking_lot::Mutex;
 
 use crate::ModLoaderAppData;
 use crate::{error::ModLoaderWarning, FileToProcess};
+pub mod conflicts;
 pub(crate) mod dependencies;
-pub(crate) mod index_file;
+pub mod index_file;
 use index_file::{download_index_files, gather_index_files, insert_index_file_data};
 mod pakfile_reading;
 use pakfile_reading::{insert_mods_from_readdata, read_pak_files};
 mod version_handling;
 use version_handling::{auto_pick_versions, set_mod_data_from_version};
 
-mod verify;
+pub mod load_order;
+pub mod resolver;
+pub(crate) mod verify;
 
 // TODO this should at somepoint be changed to `-> Result<Vec<ModLoaderWarning>, ModLoaderError>`
 // to properly convey that some things might critically fail.
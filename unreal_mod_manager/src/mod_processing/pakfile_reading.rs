@@ -135,6 +135,7 @@ pub(crate) fn insert_mods_from_readdata(
                 file_name: read_data.0.clone(),
                 downloaded: true,
                 download_url: None,
+                sha256: None,
                 metadata: Some(read_data.1.clone()),
             };
             let key: Result<Version, _> =
@@ -0,0 +1,340 @@
+//! Dependency resolution
+//!
+//! [`resolve`] picks a version for every mod that is requested or depended on,
+//! so that every [`Dependency::version`] requirement is satisfied.
+//! Newer versions are preferred, when a choice leads to a conflict further down
+//! the resolver backtracks and tries the next older version.
+//! Optional dependencies don't add mods, they only restrict the versions of mods that are resolved anyway.
+//!
+//! When no combination works, the returned [`Conflict`] names the mod that couldn't be resolved
+//! and which mods required which versions of it.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use semver::{Version, VersionReq};
+use unreal_mod_metadata::{Dependency, DownloadInfo};
+
+/// Source of mod versions and their dependencies
+pub trait DependencyProvider {
+    /// Get all versions of a mod that can be installed
+    ///
+    /// `downloads` are the download infos of the dependencies on this mod,
+    /// which can be used to look up versions that aren't installed.
+    fn versions(&mut self, mod_id: &str, downloads: &[DownloadInfo]) -> Vec<Version>;
+
+    /// Get the dependencies of a mod version, this might need to download the mod
+    ///
+    /// Returns `None` if they can't be determined, the version is skipped in that case.
+    fn dependencies(
+        &mut self,
+        mod_id: &str,
+        version: &Version,
+    ) -> Option<HashMap<String, Dependency>>;
+
+    /// Get the optional dependencies of a mod version,
+    /// their requirements only apply if the dependency is resolved anyway
+    fn optional_dependencies(
+        &mut self,
+        _mod_id: &str,
+        _version: &Version,
+    ) -> HashMap<String, VersionReq> {
+        HashMap::new()
+    }
+
+    /// Whether a mod is always available, e.g. because it is part of the mod manager,
+    /// these satisfy every requirement and are not resolved
+    fn is_builtin(&self, _mod_id: &str) -> bool {
+        false
+    }
+}
+
+/// Version requirement on a mod
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Requirement {
+    /// Mod version that has this requirement, `None` if the mod was requested directly
+    pub required_by: Option<(String, Version)>,
+    pub version: VersionReq,
+}
+
+impl Requirement {
+    fn matches(&self, version: &Version) -> bool {
+        // `*` doesn't match pre-releases, but a directly requested mod can be a pre-release
+        self.version == VersionReq::STAR || self.version.matches(version)
+    }
+}
+
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.required_by {
+            Some((ref mod_id, ref version)) => {
+                write!(f, "{mod_id} {version} requires {}", self.version)
+            }
+            None => write!(f, "{} was requested", self.version),
+        }
+    }
+}
+
+/// A mod for which no version satisfies all requirements
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub mod_id: String,
+    pub requirements: Vec<Requirement>,
+    /// Versions that could be installed, newest first
+    pub available: Vec<Version>,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let requirements = self
+            .requirements
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if self.available.is_empty() {
+            return write!(
+                f,
+                "No version of {} is available, but {}",
+                self.mod_id, requirements
+            );
+        }
+
+        let available = self
+            .available
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "No version of {} satisfies all requirements: {}. Available versions: {}",
+            self.mod_id, requirements, available
+        )
+    }
+}
+
+impl std::error::Error for Conflict {}
+
+/// Partial solution
+#[derive(Debug, Clone, Default)]
+struct State {
+    versions: BTreeMap<String, Version>,
+    requirements: BTreeMap<String, Vec<Requirement>>,
+    /// requirements of optional dependencies, these don't cause a mod to be resolved
+    optional: BTreeMap<String, Vec<Requirement>>,
+    downloads: HashMap<String, Vec<DownloadInfo>>,
+}
+
+impl State {
+    fn require(&mut self, mod_id: &str, requirement: Requirement, download: Option<&DownloadInfo>) {
+        self.requirements
+            .entry(mod_id.to_owned())
+            .or_default()
+            .push(requirement);
+
+        if let Some(download) = download {
+            let downloads = self.downloads.entry(mod_id.to_owned()).or_default();
+            if !downloads.contains(download) {
+                downloads.push(download.clone());
+            }
+        }
+    }
+
+    fn all_requirements(&self, mod_id: &str) -> Vec<Requirement> {
+        self.requirements
+            .get(mod_id)
+            .into_iter()
+            .chain(self.optional.get(mod_id))
+            .flatten()
+            .cloned()
+            .collect()
+    }
+}
+
+struct Resolver<'a, P: DependencyProvider> {
+    provider: &'a mut P,
+    versions: HashMap<String, Vec<Version>>,
+    dependencies: HashMap<(String, Version), Option<HashMap<String, Dependency>>>,
+}
+
+impl<P: DependencyProvider> Resolver<'_, P> {
+    fn versions(&mut self, mod_id: &str, downloads: &[DownloadInfo]) -> Vec<Version> {
+        if let Some(versions) = self.versions.get(mod_id) {
+            return versions.clone();
+        }
+
+        let mut versions = self.provider.versions(mod_id, downloads);
+        versions.sort_by(|a, b| b.cmp(a));
+        versions.dedup();
+
+        self.versions.insert(mod_id.to_owned(), versions.clone());
+        versions
+    }
+
+    fn dependencies(
+        &mut self,
+        mod_id: &str,
+        version: &Version,
+    ) -> Option<HashMap<String, Dependency>> {
+        let key = (mod_id.to_owned(), version.clone());
+        if let Some(dependencies) = self.dependencies.get(&key) {
+            return dependencies.clone();
+        }
+
+        let dependencies = self.provider.dependencies(mod_id, version);
+        self.dependencies.insert(key, dependencies.clone());
+        dependencies
+    }
+
+    fn solve(&mut self, state: State) -> Result<State, Conflict> {
+        let next = state
+            .requirements
+            .keys()
+            .find(|mod_id| !state.versions.contains_key(*mod_id))
+            .cloned();
+        let Some(mod_id) = next else {
+            return Ok(state);
+        };
+
+        let requirements = state.all_requirements(&mod_id);
+        let downloads = state.downloads.get(&mod_id).cloned().unwrap_or_default();
+        let available = self.versions(&mod_id, &downloads);
+
+        let mut first_conflict = None;
+        for version in available
+            .iter()
+            .filter(|version| requirements.iter().all(|e| e.matches(version)))
+        {
+            let Some(dependencies) = self.dependencies(&mod_id, version) else {
+                continue;
+            };
+
+            let mut next_state = state.clone();
+            next_state.versions.insert(mod_id.clone(), version.clone());
+
+            let mut conflict = None;
+            let mut dependencies = dependencies.into_iter().collect::<Vec<_>>();
+            dependencies.sort_by(|a, b| a.0.cmp(&b.0));
+            for (dependency_id, dependency) in dependencies {
+                if self.provider.is_builtin(&dependency_id) {
+                    continue;
+                }
+
+                let requirement = Requirement {
+                    required_by: Some((mod_id.clone(), version.clone())),
+                    version: dependency.version.clone(),
+                };
+                let satisfied = next_state
+                    .versions
+                    .get(&dependency_id)
+                    .map(|e| requirement.matches(e));
+                next_state.require(&dependency_id, requirement, dependency.download.as_ref());
+
+                if satisfied == Some(false) {
+                    let downloads = next_state
+                        .downloads
+                        .get(&dependency_id)
+                        .cloned()
+                        .unwrap_or_default();
+                    conflict = Some(Conflict {
+                        requirements: next_state.all_requirements(&dependency_id),
+                        available: self.versions(&dependency_id, &downloads),
+                        mod_id: dependency_id,
+                    });
+                    break;
+                }
+            }
+
+            let mut optional_dependencies = self
+                .provider
+                .optional_dependencies(&mod_id, version)
+                .into_iter()
+                .collect::<Vec<_>>();
+            optional_dependencies.sort_by(|a, b| a.0.cmp(&b.0));
+            for (dependency_id, version_req) in optional_dependencies {
+                if conflict.is_some() {
+                    break;
+                }
+                if self.provider.is_builtin(&dependency_id) {
+                    continue;
+                }
+
+                let requirement = Requirement {
+                    required_by: Some((mod_id.clone(), version.clone())),
+                    version: version_req,
+                };
+                let satisfied = next_state
+                    .versions
+                    .get(&dependency_id)
+                    .map(|e| requirement.matches(e));
+                next_state
+                    .optional
+                    .entry(dependency_id.clone())
+                    .or_default()
+                    .push(requirement);
+
+                if satisfied == Some(false) {
+                    conflict = Some(Conflict {
+                        requirements: next_state.all_requirements(&dependency_id),
+                        available: self
+                            .versions
+                            .get(&dependency_id)
+                            .cloned()
+                            .unwrap_or_default(),
+                        mod_id: dependency_id,
+                    });
+                }
+            }
+
+            let result = match conflict {
+                Some(conflict) => Err(conflict),
+                None => self.solve(next_state),
+            };
+            match result {
+                Ok(solution) => return Ok(solution),
+                Err(conflict) => {
+                    first_conflict.get_or_insert(conflict);
+                }
+            }
+        }
+
+        Err(first_conflict.unwrap_or_else(|| Conflict {
+            mod_id: mod_id.clone(),
+            requirements,
+            available,
+        }))
+    }
+}
+
+/// Find a version for every mod in `requested` and their dependencies
+///
+/// Returns the chosen version of every mod that isn't builtin.
+pub fn resolve<P: DependencyProvider>(
+    requested: &[(String, VersionReq)],
+    provider: &mut P,
+) -> Result<BTreeMap<String, Version>, Conflict> {
+    let mut state = State::default();
+    for (mod_id, version) in requested {
+        if provider.is_builtin(mod_id) {
+            continue;
+        }
+
+        state.require(
+            mod_id,
+            Requirement {
+                required_by: None,
+                version: version.clone(),
+            },
+            None,
+        );
+    }
+
+    let mut resolver = Resolver {
+        provider,
+        versions: HashMap::new(),
+        dependencies: HashMap::new(),
+    };
+    resolver.solve(state).map(|e| e.versions)
+}
@@ -1,6 +1,3 @@
-use lazy_static::lazy_static;
-use regex::Regex;
-
 // migth need at some point
 // pub fn verify_mod_id(mod_id: &str) -> bool {
 //     lazy_static! {
@@ -9,25 +6,5 @@ use regex::Regex;
 
 //     RE.is_match(mod_id)
 // }
-lazy_static! {
-    /// Match 1: 000
-    /// Match 2: ModName69.author
-    /// Match 3: 1.1.1
-    // regex explanation                                           (  000 )-(Mod69<.author>)-( 1 . 1 . 1 )_P.pak
-    pub(crate) static ref MOD_FILENAME_REGEX: Regex = Regex::new(r"(^\d{3})-([a-zA-Z0-9\.]+)-(\d+.\d+.\d+)_P.pak$").unwrap();
-}
-
-pub fn verify_mod_file_name(mod_id: &str) -> bool {
-    if let Some(matches) = MOD_FILENAME_REGEX.captures(mod_id) {
-        if let Some(mod_name) = matches.get(2) {
-            return mod_name
-                .as_str()
-                .chars()
-                .next()
-                .map(|e| e.is_uppercase())
-                .unwrap_or(false);
-        }
-    }
 
-    false
-}
+pub(crate) use unreal_mod_metadata::verify::{verify_mod_file_name, MOD_FILENAME_REGEX};
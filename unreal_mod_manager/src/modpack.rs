@@ -0,0 +1,369 @@
+//! Modpacks, profiles that can be shared with other players
+//!
+//! A modpack is a zip file with a `modpack.json` manifest that lists the exact version of
+//! every mod of a profile together with where to download it and the SHA-256 hash of its file.
+//! Mod files can be bundled in the `paks/` folder of the modpack, mods that aren't bundled
+//! are downloaded through their index file or their download url when the modpack is installed.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use log::{debug, warn};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use unreal_mod_metadata::{DownloadInfo, DownloadMode};
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::error::ModLoaderWarning;
+use crate::game_mod::GameMod;
+use crate::mod_processing::index_file::{download_index_file, IndexFileModVersion};
+use crate::mod_processing::verify::verify_mod_file_name;
+use crate::profile::{Profile, ProfileMod};
+
+/// File extension of modpacks
+pub const MODPACK_EXTENSION: &str = "modpack";
+/// Latest manifest format version
+pub const FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_NAME: &str = "modpack.json";
+const PAKS_DIR: &str = "paks";
+
+/// Manifest of a modpack
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Modpack {
+    pub format_version: u32,
+    /// Name of the profile
+    pub name: String,
+    pub mods: BTreeMap<String, ModpackMod>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModpackMod {
+    pub version: String,
+    /// Use the newest version when importing instead of `version`
+    #[serde(default)]
+    pub force_latest: bool,
+    #[serde(default)]
+    pub priority: u16,
+    pub file_name: String,
+    /// Hex encoded SHA-256 hash of the mod file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// Index file that lists `version`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download: Option<DownloadInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download_url: Option<String>,
+    /// The mod file is included in the modpack
+    #[serde(default)]
+    pub bundled: bool,
+}
+
+impl ModpackMod {
+    /// Whether the mod can be installed without bundling it
+    pub fn is_downloadable(&self) -> bool {
+        self.download_url.is_some()
+            || self
+                .download
+                .as_ref()
+                .is_some_and(|e| e.download_mode == DownloadMode::IndexFile)
+    }
+}
+
+impl Modpack {
+    /// Create a modpack from a profile, mods of the profile that aren't installed are skipped
+    pub(crate) fn from_profile(
+        profile: &Profile,
+        game_mods: &BTreeMap<String, GameMod>,
+    ) -> (Self, Vec<ModLoaderWarning>) {
+        let mut warnings = Vec::new();
+        let mut mods = BTreeMap::new();
+
+        for (mod_id, profile_mod) in &profile.mods {
+            let mod_version = game_mods.get(mod_id).and_then(|game_mod| {
+                let version = Version::parse(&profile_mod.version).ok()?;
+                Some((game_mod, game_mod.versions.get(&version)?))
+            });
+            let Some((game_mod, mod_version)) = mod_version else {
+                warnings.push(
+                    ModLoaderWarning::other(format!(
+                        "Mod of profile {:?} is not installed, it is not part of the modpack",
+                        profile.name
+                    ))
+                    .with_mod_id(mod_id.clone()),
+                );
+                continue;
+            };
+
+            let download = mod_version
+                .metadata
+                .as_ref()
+                .and_then(|e| e.download.clone())
+                .or_else(|| game_mod.download.clone());
+
+            mods.insert(
+                mod_id.clone(),
+                ModpackMod {
+                    version: profile_mod.version.clone(),
+                    force_latest: profile_mod.force_latest,
+                    priority: profile_mod.priority,
+                    file_name: mod_version.file_name.clone(),
+                    sha256: mod_version.sha256.clone(),
+                    download,
+                    download_url: mod_version.download_url.clone(),
+                    bundled: false,
+                },
+            );
+        }
+
+        let modpack = Modpack {
+            format_version: FORMAT_VERSION,
+            name: profile.name.clone(),
+            mods,
+        };
+        (modpack, warnings)
+    }
+
+    pub(crate) fn to_profile(&self) -> Profile {
+        Profile {
+            name: self.name.clone(),
+            mods: self
+                .mods
+                .iter()
+                .map(|(mod_id, modpack_mod)| {
+                    (
+                        mod_id.clone(),
+                        ProfileMod {
+                            force_latest: modpack_mod.force_latest,
+                            priority: modpack_mod.priority,
+                            version: modpack_mod.version.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Write the modpack to `path`, mod files are read from `mods_path`
+    ///
+    /// With `bundle` every mod file is included, otherwise only the files of mods
+    /// that can't be downloaded. Hashes of bundled files are added to the manifest.
+    /// The modpack is written next to `path` first, so an existing file is only replaced
+    /// once the new one is complete.
+    pub fn write(
+        &self,
+        path: &Path,
+        mods_path: &Path,
+        bundle: bool,
+    ) -> Result<(), ModLoaderWarning> {
+        let mut manifest = self.clone();
+        let mut bundled_files = Vec::new();
+
+        for (mod_id, modpack_mod) in manifest.mods.iter_mut() {
+            modpack_mod.bundled = bundle || !modpack_mod.is_downloadable();
+            if !modpack_mod.bundled {
+                continue;
+            }
+
+            let file_path = mods_path.join(&modpack_mod.file_name);
+            if !file_path.is_file() {
+                return Err(ModLoaderWarning::other(format!(
+                    "Mod file {} can't be bundled, it is not downloaded",
+                    modpack_mod.file_name
+                ))
+                .with_mod_id(mod_id.clone()));
+            }
+            if modpack_mod.sha256.is_none() {
+                modpack_mod.sha256 = Some(file_sha256(&file_path)?);
+            }
+            bundled_files.push((modpack_mod.file_name.clone(), file_path));
+        }
+
+        let mut write_path = path.as_os_str().to_owned();
+        write_path.push(".write");
+        let write_path = PathBuf::from(write_path);
+
+        let write = || -> Result<(), ModLoaderWarning> {
+            let mut zip = ZipWriter::new(BufWriter::new(File::create(&write_path)?));
+
+            zip.start_file(MANIFEST_NAME, FileOptions::default())?;
+            serde_json::to_writer_pretty(&mut zip, &manifest)
+                .map_err(|e| ModLoaderWarning::invalid_modpack(e.to_string()))?;
+
+            // paks are already compressed
+            let options = FileOptions::default().compression_method(CompressionMethod::Stored);
+            for (file_name, file_path) in &bundled_files {
+                debug!("Bundling {:?}", file_path);
+                zip.start_file(format!("{PAKS_DIR}/{file_name}"), options)?;
+                io::copy(&mut File::open(file_path)?, &mut zip)?;
+            }
+
+            zip.finish()?.flush()?;
+            fs::rename(&write_path, path)?;
+            Ok(())
+        };
+
+        write().inspect_err(|_| {
+            let _ = fs::remove_file(&write_path);
+        })
+    }
+
+    /// Read the manifest of the modpack at `path`
+    pub fn read(path: &Path) -> Result<Self, ModLoaderWarning> {
+        let mut zip = ZipArchive::new(BufReader::new(File::open(path)?))?;
+
+        let mut manifest = Vec::new();
+        zip.by_name(MANIFEST_NAME)?.read_to_end(&mut manifest)?;
+        let modpack: Modpack = serde_json::from_slice(&manifest)
+            .map_err(|e| ModLoaderWarning::invalid_modpack(e.to_string()))?;
+
+        if modpack.format_version > FORMAT_VERSION {
+            return Err(ModLoaderWarning::invalid_modpack(format!(
+                "unsupported format version {}",
+                modpack.format_version
+            )));
+        }
+
+        Ok(modpack)
+    }
+
+    /// Install the mods of the modpack at `path` into `mods_path`
+    ///
+    /// Bundled mods are extracted, other mods are downloaded. Mods whose file already exists are skipped.
+    /// Returns the paths of the newly installed files.
+    pub fn install(&self, path: &Path, mods_path: &Path) -> (Vec<PathBuf>, Vec<ModLoaderWarning>) {
+        let mut installed = Vec::new();
+        let mut warnings = Vec::new();
+
+        let mut zip = match File::open(path)
+            .map_err(ModLoaderWarning::from)
+            .and_then(|e| Ok(ZipArchive::new(BufReader::new(e))?))
+        {
+            Ok(zip) => zip,
+            Err(err) => return (installed, vec![err]),
+        };
+
+        for (mod_id, modpack_mod) in &self.mods {
+            // the file name is used as a path, so it has to be checked first
+            if !verify_mod_file_name(&modpack_mod.file_name) {
+                warnings.push(ModLoaderWarning::invalid_mod_file_name(mod_id.clone()));
+                continue;
+            }
+            if mods_path.join(&modpack_mod.file_name).is_file() {
+                debug!("{} is already installed", modpack_mod.file_name);
+                continue;
+            }
+
+            let result = match modpack_mod.bundled {
+                true => extract_mod(&mut zip, modpack_mod, mods_path),
+                false => download_mod(mod_id, modpack_mod, mods_path),
+            };
+            match result {
+                Ok(file_path) => installed.push(file_path),
+                Err(err) => {
+                    warn!("Failed to install {}: {}", mod_id, err);
+                    warnings.push(err.with_mod_id(mod_id.clone()));
+                }
+            }
+        }
+
+        (installed, warnings)
+    }
+}
+
+fn extract_mod<R: io::Read + io::Seek>(
+    zip: &mut ZipArchive<R>,
+    modpack_mod: &ModpackMod,
+    mods_path: &Path,
+) -> Result<PathBuf, ModLoaderWarning> {
+    let mut entry = zip.by_name(&format!("{PAKS_DIR}/{}", modpack_mod.file_name))?;
+
+    let file_path = mods_path.join(&modpack_mod.file_name);
+    let extract_path = mods_path.join(format!("{}.extract", modpack_mod.file_name));
+
+    let mut extract = || -> Result<(), ModLoaderWarning> {
+        io::copy(&mut entry, &mut File::create(&extract_path)?)?;
+
+        if let Some(ref expected) = modpack_mod.sha256 {
+            let actual = file_sha256(&extract_path)?;
+            if !actual.eq_ignore_ascii_case(expected) {
+                return Err(ModLoaderWarning::checksum_mismatch(
+                    modpack_mod.file_name.clone(),
+                    expected.clone(),
+                    actual,
+                ));
+            }
+        }
+
+        fs::rename(&extract_path, &file_path)?;
+        Ok(())
+    };
+
+    extract().inspect_err(|_| {
+        let _ = fs::remove_file(&extract_path);
+    })?;
+
+    Ok(file_path)
+}
+
+fn download_mod(
+    mod_id: &str,
+    modpack_mod: &ModpackMod,
+    mods_path: &Path,
+) -> Result<PathBuf, ModLoaderWarning> {
+    let mut download_url = modpack_mod.download_url.clone();
+    let mut sha256 = modpack_mod.sha256.clone();
+
+    if let Some(download) = modpack_mod
+        .download
+        .as_ref()
+        .filter(|e| e.download_mode == DownloadMode::IndexFile)
+    {
+        let index_version = Version::parse(&modpack_mod.version)
+            .map_err(|_| ModLoaderWarning::invalid_version(mod_id.to_owned()))
+            .and_then(|version| {
+                let (_, index_mod) = download_index_file(mod_id.to_owned(), download)?;
+                index_mod.versions.get(&version).cloned().ok_or_else(|| {
+                    ModLoaderWarning::other(format!(
+                        "Version {} is not listed in the index file",
+                        modpack_mod.version
+                    ))
+                })
+            });
+
+        match index_version {
+            Ok(index_version) => {
+                download_url = Some(index_version.download_url);
+                sha256 = sha256.or(index_version.sha256);
+            }
+            // the index file is preferred, but a direct download url still works if it's unreachable
+            Err(err) if download_url.is_some() => {
+                warn!("Index file of {} can't be used: {}", mod_id, err);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    let download_url = download_url.ok_or_else(|| {
+        ModLoaderWarning::other(format!(
+            "Version {} is neither bundled nor downloadable",
+            modpack_mod.version
+        ))
+    })?;
+
+    debug!(
+        "Downloading {} from {}",
+        modpack_mod.file_name, download_url
+    );
+    IndexFileModVersion::new(download_url, modpack_mod.file_name.clone(), sha256)
+        .download(mods_path)
+}
+
+fn file_sha256(path: &Path) -> Result<String, ModLoaderWarning> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(hex::encode(hasher.finalize()))
+}
@@ -1,14 +1,58 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use semver::Version;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::error::ModLoaderWarning;
+use crate::game_mod::GameMod;
+
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 pub struct Profile {
     pub name: String,
     pub mods: HashMap<String, ProfileMod>,
 }
 
+impl Profile {
+    /// Enable the mods of this profile with their saved versions and disable all other mods
+    pub fn apply(&self, game_mods: &mut BTreeMap<String, GameMod>) -> Vec<ModLoaderWarning> {
+        let mut warnings = Vec::new();
+
+        for (mod_id, game_mod) in game_mods.iter_mut() {
+            let Some(profile_mod) = self.mods.get(mod_id) else {
+                game_mod.enabled = false;
+                continue;
+            };
+            game_mod.enabled = true;
+
+            if profile_mod.force_latest {
+                game_mod.select_latest_version();
+                continue;
+            }
+
+            let selected = Version::parse(&profile_mod.version)
+                .is_ok_and(|version| game_mod.select_version(&version));
+            if !selected {
+                warnings.push(ModLoaderWarning::invalid_version(mod_id.clone()));
+            }
+        }
+
+        for mod_id in self.mods.keys() {
+            if !game_mods.contains_key(mod_id) {
+                warnings.push(
+                    ModLoaderWarning::other(format!(
+                        "Mod of profile {:?} is not installed",
+                        self.name
+                    ))
+                    .with_mod_id(mod_id.clone()),
+                );
+            }
+        }
+
+        warnings
+    }
+}
+
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 pub struct ProfileMod {
     #[serde(default = "crate::default_true")]
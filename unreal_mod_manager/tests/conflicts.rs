@@ -0,0 +1,183 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use unreal_mod_manager::conflicts::{ConflictScanner, FileConflict};
+use unreal_mod_manager::unreal_asset::engine_version::EngineVersion;
+use unreal_mod_manager::unreal_pak::{pakversion::PakVersion, PakMemory};
+
+const CURVE: &[u8] = include_bytes!(
+    "../../unreal_asset/tests/assets/general/Astroneer_prebulk/ResourceProgressCurve.uasset"
+);
+const AUGMENT: &[u8] = include_bytes!(
+    "../../unreal_asset/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush.uasset"
+);
+const CANISTER: &[u8] = include_bytes!(
+    "../../unreal_asset/tests/assets/general/Astroneer_prebulk/LargeResourceCanister_IT.uasset"
+);
+
+fn write_pak(dir: &Path, file_name: &str, entries: &[(&str, &[u8])]) -> PathBuf {
+    let mut pak = PakMemory::new(PakVersion::FnameBasedCompressionMethod);
+    pak.set_entry("metadata.json".to_string(), b"{}".to_vec());
+    for (name, data) in entries {
+        pak.set_entry(name.to_string(), data.to_vec());
+    }
+
+    let path = dir.join(file_name);
+    pak.write(&mut File::create(&path).unwrap()).unwrap();
+    path
+}
+
+#[test]
+fn file_conflicts() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let mods = vec![
+        (
+            "ModA".to_string(),
+            write_pak(
+                dir,
+                "000-ModA-1.0.0_P.pak",
+                &[
+                    ("Game/Content/Config.ini", b"a"),
+                    ("Game/Content/Item.uasset", b"a"),
+                    ("Game/Content/Item.uexp", b"a"),
+                    ("Game/Content/Texture.ubulk", b"a"),
+                ],
+            ),
+        ),
+        (
+            "ModB".to_string(),
+            write_pak(
+                dir,
+                "001-ModB-1.0.0_P.pak",
+                &[
+                    ("Game/Content/Config.ini", b"b"),
+                    ("Game/Content/Item.uasset", b"b"),
+                    ("Game/Content/Item.uexp", b"b"),
+                    ("Game/Content/Other.uasset", b"b"),
+                    ("Game/Content/Texture.uasset", b"b"),
+                    ("Game/Content/Texture.uexp", b"b"),
+                    ("Game/Content/Texture.ubulk", b"b"),
+                ],
+            ),
+        ),
+    ];
+
+    let scanner = ConflictScanner::new(mods).unwrap();
+    // metadata.json is ignored and the files of a package are reported once,
+    // also if one of the mods only overrides the bulk data
+    assert_eq!(
+        scanner.file_conflicts(),
+        vec![
+            FileConflict {
+                file_name: "Game/Content/Config.ini".to_string(),
+                mod_ids: vec!["ModA".to_string(), "ModB".to_string()],
+                differing_exports: None,
+            },
+            FileConflict {
+                file_name: "Game/Content/Item.uasset".to_string(),
+                mod_ids: vec!["ModA".to_string(), "ModB".to_string()],
+                differing_exports: None,
+            },
+            FileConflict {
+                file_name: "Game/Content/Texture.uasset".to_string(),
+                mod_ids: vec!["ModA".to_string(), "ModB".to_string()],
+                differing_exports: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn handled_file_overrides() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let mods = vec![(
+        "ModA".to_string(),
+        write_pak(
+            dir,
+            "000-ModA-1.0.0_P.pak",
+            &[
+                ("Game/Content/Maps/Level.umap", b"a"),
+                ("Game/Content/Maps/Level.uexp", b"a"),
+                ("Game/Content/Maps/Other.umap", b"a"),
+            ],
+        ),
+    )];
+
+    let scanner = ConflictScanner::new(mods).unwrap();
+    assert_eq!(
+        scanner.handled_file_overrides(&["Game/Content/Maps/Level.umap".to_string()]),
+        vec![(
+            "ModA".to_string(),
+            "Game/Content/Maps/Level.umap".to_string()
+        )]
+    );
+    // handled files without an extension match assets and maps
+    assert_eq!(
+        scanner
+            .handled_file_overrides(&["Game/Content/Maps/Other".to_string()])
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn asset_conflicts() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let mods = vec![
+        (
+            "ModA".to_string(),
+            write_pak(
+                dir,
+                "000-ModA-1.0.0_P.pak",
+                &[
+                    ("Game/Content/Curve.uasset", CURVE),
+                    ("Game/Content/Item.uasset", AUGMENT),
+                    ("Game/Content/Broken.uasset", b"not an asset"),
+                ],
+            ),
+        ),
+        (
+            "ModB".to_string(),
+            write_pak(
+                dir,
+                "001-ModB-1.0.0_P.pak",
+                &[
+                    ("Game/Content/Curve.uasset", CURVE),
+                    ("Game/Content/Item.uasset", CANISTER),
+                    ("Game/Content/Broken.uasset", b"not an asset"),
+                ],
+            ),
+        ),
+    ];
+
+    let mut scanner = ConflictScanner::new(mods).unwrap();
+    let mut conflicts = scanner.file_conflicts();
+    assert_eq!(conflicts.len(), 3);
+
+    let (broken, rest) = conflicts.split_first_mut().unwrap();
+    assert!(scanner
+        .compare_assets(broken, EngineVersion::VER_UE4_23)
+        .is_err());
+    assert_eq!(broken.differing_exports, None);
+
+    let (curve, item) = rest.split_first_mut().unwrap();
+    scanner
+        .compare_assets(curve, EngineVersion::VER_UE4_23)
+        .unwrap();
+    // identical assets don't conflict
+    assert_eq!(curve.differing_exports, Some(Vec::new()));
+
+    let item = &mut item[0];
+    scanner
+        .compare_assets(item, EngineVersion::VER_UE4_23)
+        .unwrap();
+    assert!(!item.differing_exports.as_ref().unwrap().is_empty());
+    assert!(item
+        .to_string()
+        .starts_with("Game/Content/Item.uasset is overridden by multiple mods: ModA, ModB"));
+}
@@ -0,0 +1,210 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::Parser;
+use semver::Version;
+
+use unreal_mod_manager::game_mod::{GameMod, GameModVersion, SelectedVersion};
+use unreal_mod_manager::game_platform_managers::WineSettings;
+use unreal_mod_manager::headless::{HeadlessAction, HeadlessArgs, ModpackExport, VersionSelection};
+use unreal_mod_manager::profile::{Profile, ProfileMod};
+
+fn game_mod(mod_id: &str, versions: &[&str]) -> GameMod {
+    let versions = versions
+        .iter()
+        .map(|version| {
+            (
+                Version::parse(version).unwrap(),
+                GameModVersion {
+                    mod_id: mod_id.to_string(),
+                    file_name: format!("000-{mod_id}-{version}_P.pak"),
+                    downloaded: true,
+                    download_url: None,
+                    sha256: None,
+                    metadata: None,
+                },
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    GameMod {
+        latest_version: versions.keys().next_back().cloned(),
+        versions,
+        ..Default::default()
+    }
+}
+
+fn profile_mod(version: &str, force_latest: bool) -> ProfileMod {
+    ProfileMod {
+        force_latest,
+        priority: 0,
+        version: version.to_string(),
+    }
+}
+
+#[test]
+fn version_selection() {
+    assert_eq!(
+        VersionSelection::from_str("ModA=1.2.3"),
+        Ok(VersionSelection {
+            mod_id: "ModA".to_string(),
+            version: Some(Version::new(1, 2, 3)),
+        })
+    );
+    assert_eq!(
+        VersionSelection::from_str("ModA=latest"),
+        Ok(VersionSelection {
+            mod_id: "ModA".to_string(),
+            version: None,
+        })
+    );
+
+    // only the first `=` separates the mod id from the version
+    assert!(VersionSelection::from_str("ModA=1.0.0=2.0.0").is_err());
+    for invalid in ["ModA", "ModA=", "ModA=1.0", "ModA=Latest", ""] {
+        assert!(VersionSelection::from_str(invalid).is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn action_order() {
+    // actions run in a fixed order, no matter the order of the arguments
+    let args = HeadlessArgs::try_parse_from([
+        "game",
+        "--launch",
+        "--export-modpack",
+        "Server=server.modpack",
+        "--select-version",
+        "ModB=latest",
+        "--disable",
+        "ModC",
+        "--enable",
+        "ModB",
+        "--profile",
+        "Server",
+        "--remove",
+        "ModD",
+        "--import-modpack",
+        "friends.modpack",
+        "--install",
+        "ModA.pak",
+        "--wine-prefix",
+        "/prefix",
+        "--compare-assets",
+        "true",
+        "--platform",
+        "Steam",
+        "--select-version",
+        "ModA=1.0.0",
+        "--enable",
+        "ModA",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        args.actions(),
+        vec![
+            HeadlessAction::SetGamePlatform("Steam".to_string()),
+            HeadlessAction::SetWineSettings(
+                Some("Steam".to_string()),
+                WineSettings {
+                    prefix_path: Some(PathBuf::from("/prefix")),
+                    ..Default::default()
+                },
+                false
+            ),
+            HeadlessAction::SetCompareAssets(true),
+            HeadlessAction::Install(vec![PathBuf::from("ModA.pak")]),
+            HeadlessAction::ImportModpack(PathBuf::from("friends.modpack")),
+            HeadlessAction::RemoveMod("ModD".to_string()),
+            HeadlessAction::ApplyProfile("Server".to_string()),
+            HeadlessAction::SetEnabled("ModB".to_string(), true),
+            HeadlessAction::SetEnabled("ModA".to_string(), true),
+            HeadlessAction::SetEnabled("ModC".to_string(), false),
+            HeadlessAction::SelectVersion(VersionSelection {
+                mod_id: "ModB".to_string(),
+                version: None,
+            }),
+            HeadlessAction::SelectVersion(VersionSelection {
+                mod_id: "ModA".to_string(),
+                version: Some(Version::new(1, 0, 0)),
+            }),
+            HeadlessAction::ExportModpack(
+                ModpackExport {
+                    profile: "Server".to_string(),
+                    path: PathBuf::from("server.modpack"),
+                },
+                false
+            ),
+            HeadlessAction::Integrate,
+            HeadlessAction::LaunchGame,
+        ]
+    );
+
+    let args = HeadlessArgs::try_parse_from(["game", "--no-integrate", "--list"]).unwrap();
+    assert!(args.actions().is_empty());
+    // resetting works without new settings
+    let args =
+        HeadlessArgs::try_parse_from(["game", "--no-integrate", "--reset-wine-settings"]).unwrap();
+    assert_eq!(
+        args.actions(),
+        vec![HeadlessAction::SetWineSettings(
+            None,
+            WineSettings::default(),
+            true
+        )]
+    );
+    assert!(HeadlessArgs::try_parse_from(["game", "--select-version", "ModA"]).is_err());
+}
+
+#[test]
+fn apply_profile() {
+    let mut game_mods = BTreeMap::from([
+        ("ModA".to_string(), game_mod("ModA", &["1.0.0", "2.0.0"])),
+        ("ModB".to_string(), game_mod("ModB", &["1.0.0", "2.0.0"])),
+        ("ModC".to_string(), game_mod("ModC", &["1.0.0"])),
+        ("ModD".to_string(), game_mod("ModD", &["1.0.0"])),
+    ]);
+    game_mods.get_mut("ModD").unwrap().enabled = true;
+
+    let profile = Profile {
+        name: "Server".to_string(),
+        mods: HashMap::from([
+            ("ModA".to_string(), profile_mod("1.0.0", false)),
+            ("ModB".to_string(), profile_mod("1.0.0", true)),
+            ("ModC".to_string(), profile_mod("3.0.0", false)),
+            ("ModE".to_string(), profile_mod("1.0.0", false)),
+        ]),
+    };
+    let warnings = profile.apply(&mut game_mods);
+
+    assert_eq!(
+        game_mods["ModA"].selected_version,
+        SelectedVersion::Specific(Version::new(1, 0, 0))
+    );
+    assert_eq!(
+        game_mods["ModB"].selected_version,
+        SelectedVersion::LatestIndirect(Some(Version::new(2, 0, 0)))
+    );
+    // mods with a missing version are still enabled with their current version
+    assert_eq!(
+        game_mods["ModC"].selected_version,
+        SelectedVersion::default()
+    );
+    assert!(["ModA", "ModB", "ModC"]
+        .iter()
+        .all(|mod_id| game_mods[*mod_id].enabled));
+    assert!(!game_mods["ModD"].enabled);
+    assert!(!game_mods.contains_key("ModE"));
+
+    let mut warned = warnings
+        .iter()
+        .map(|e| e.mod_id.clone())
+        .collect::<Vec<_>>();
+    warned.sort();
+    assert_eq!(
+        warned,
+        vec![Some("ModC".to_string()), Some("ModE".to_string())]
+    );
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::fs;
+
+use ed25519_dalek::{Signer, SigningKey};
+use semver::Version;
+use sha2::{Digest, Sha256};
+use tempfile::TempDir;
+
+use unreal_mod_manager::error::ModLoaderWarningKind;
+use unreal_mod_manager::index_file::{
+    download_index_file, verify_index_file_signature, IndexFileModVersion,
+};
+use unreal_mod_manager::unreal_mod_metadata::{DownloadInfo, DownloadMode};
+
+mod common;
+
+use common::serve;
+
+fn index_file(base_url: &str, sha256: &str) -> Vec<u8> {
+    format!(
+        r#"{{
+    "mods": {{
+        "TestMod": {{
+            "latest_version": "1.0.0",
+            "versions": {{
+                "1.0.0": {{
+                    "download_url": "{base_url}/000-TestMod-1.0.0_P.pak",
+                    "filename": "000-TestMod-1.0.0_P.pak",
+                    "sha256": "{sha256}"
+                }}
+            }}
+        }}
+    }}
+}}"#
+    )
+    .into_bytes()
+}
+
+fn download_info(url: String, public_key: Option<String>) -> DownloadInfo {
+    DownloadInfo {
+        download_mode: DownloadMode::IndexFile,
+        url,
+        public_key,
+    }
+}
+
+const PAK: &[u8] = b"not really a pak";
+
+#[test]
+fn checksum() {
+    let sha256 = hex::encode(Sha256::digest(PAK));
+    let base_url = serve(HashMap::from([
+        ("/000-TestMod-1.0.0_P.pak", PAK.to_vec()),
+        ("/corrupted_P.pak", b"corrupted".to_vec()),
+    ]));
+    let dir = TempDir::new().unwrap();
+    let mods_path = dir.path();
+
+    let version = IndexFileModVersion::new(
+        format!("{base_url}/000-TestMod-1.0.0_P.pak"),
+        "000-TestMod-1.0.0_P.pak".to_string(),
+        Some(sha256.to_uppercase()),
+    );
+    let path = version.download(mods_path).unwrap();
+    assert_eq!(path, mods_path.join("000-TestMod-1.0.0_P.pak"));
+    assert_eq!(fs::read(&path).unwrap(), PAK);
+
+    let corrupted = IndexFileModVersion::new(
+        format!("{base_url}/corrupted_P.pak"),
+        "000-Corrupted-1.0.0_P.pak".to_string(),
+        Some(sha256.clone()),
+    );
+    let warning = corrupted.download(mods_path).unwrap_err();
+    match warning.kind {
+        ModLoaderWarningKind::ChecksumMismatch(expected, actual) => {
+            assert_eq!(expected, sha256);
+            assert_eq!(actual, hex::encode(Sha256::digest(b"corrupted")));
+        }
+        kind => panic!("unexpected warning {kind:?}"),
+    }
+    // the corrupted download was removed again
+    assert_eq!(fs::read_dir(mods_path).unwrap().count(), 1);
+
+    let missing = IndexFileModVersion::new(
+        format!("{base_url}/missing_P.pak"),
+        "000-Missing-1.0.0_P.pak".to_string(),
+        None,
+    );
+    assert!(matches!(
+        missing.download(mods_path).unwrap_err().kind,
+        ModLoaderWarningKind::DownloadFailed(_)
+    ));
+}
+
+#[test]
+fn signed_index_file() {
+    let signing_key = SigningKey::from_bytes(&[7; 32]);
+    let public_key = hex::encode(signing_key.verifying_key().as_bytes());
+    let other_key = hex::encode(SigningKey::from_bytes(&[8; 32]).verifying_key().as_bytes());
+
+    let index = index_file("http://127.0.0.1", &hex::encode(Sha256::digest(PAK)));
+    let signature = hex::encode(signing_key.sign(&index).to_bytes());
+
+    assert!(verify_index_file_signature(&index, &public_key, &signature));
+    assert!(!verify_index_file_signature(&index, &other_key, &signature));
+    assert!(!verify_index_file_signature(
+        b"tampered",
+        &public_key,
+        &signature
+    ));
+    assert!(!verify_index_file_signature(&index, "not hex", &signature));
+
+    let mut tampered = index.clone();
+    tampered.extend_from_slice(b"\n");
+    let base_url = serve(HashMap::from([
+        ("/index.json", index),
+        ("/index.json.sig", signature.clone().into_bytes()),
+        ("/tampered.json", tampered),
+        ("/tampered.json.sig", signature.into_bytes()),
+        ("/unsigned.json", b"{}".to_vec()),
+    ]));
+
+    let (mod_id, index_file_mod) = download_index_file(
+        "TestMod".to_string(),
+        &download_info(format!("{base_url}/index.json"), Some(public_key.clone())),
+    )
+    .unwrap();
+    assert_eq!(mod_id, "TestMod");
+    assert_eq!(index_file_mod.latest_version, Version::new(1, 0, 0));
+    let version = &index_file_mod.versions[&Version::new(1, 0, 0)];
+    assert_eq!(
+        version.sha256.as_deref(),
+        Some(hex::encode(Sha256::digest(PAK)).as_str())
+    );
+
+    // unsigned index files are still accepted without a public key
+    assert!(download_index_file(
+        "TestMod".to_string(),
+        &download_info(format!("{base_url}/index.json"), None),
+    )
+    .is_ok());
+
+    let warning = download_index_file(
+        "TestMod".to_string(),
+        &download_info(
+            format!("{base_url}/tampered.json"),
+            Some(public_key.clone()),
+        ),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        warning.kind,
+        ModLoaderWarningKind::InvalidIndexFileSignature
+    ));
+
+    let warning = download_index_file(
+        "TestMod".to_string(),
+        &download_info(format!("{base_url}/unsigned.json"), Some(public_key)),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        warning.kind,
+        ModLoaderWarningKind::IndexFileDownloadFailedStatus(_)
+    ));
+}
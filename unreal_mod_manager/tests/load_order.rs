@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use unreal_mod_manager::load_order::{
+    assign_priorities, LoadOrderCycle, LoadOrderEntry, MAX_PRIORITY,
+};
+use unreal_mod_manager::unreal_mod_metadata::Metadata;
+
+fn entry(mod_id: &str, priority: u32, load_before: &[&str], load_after: &[&str]) -> LoadOrderEntry {
+    let metadata = Metadata {
+        mod_id: mod_id.to_string(),
+        load_before: load_before.iter().map(|e| e.to_string()).collect(),
+        load_after: load_after.iter().map(|e| e.to_string()).collect(),
+        ..Default::default()
+    };
+    LoadOrderEntry::new(mod_id.to_string(), priority, Some(&metadata))
+}
+
+fn priorities(priorities: &[(&str, u32)]) -> BTreeMap<String, u32> {
+    priorities
+        .iter()
+        .map(|(mod_id, priority)| (mod_id.to_string(), *priority))
+        .collect()
+}
+
+#[test]
+fn file_name_priorities() {
+    let entries = [
+        entry("ModA", 0, &[], &[]),
+        entry("ModB", 5, &[], &[]),
+        LoadOrderEntry::new("ModC".to_string(), 1, None),
+    ];
+    assert_eq!(
+        assign_priorities(&entries).unwrap(),
+        priorities(&[("ModA", 0), ("ModB", 5), ("ModC", 1)])
+    );
+}
+
+#[test]
+fn hints() {
+    let entries = [
+        entry("ModA", 3, &[], &["ModB"]),
+        entry("ModB", 3, &[], &[]),
+        entry("ModC", 0, &[], &["ModA"]),
+        // ModD has to load before ModB, so ModB and everything after it is raised
+        entry("ModD", 4, &["ModB"], &[]),
+        // hints on mods that aren't installed are ignored
+        entry("ModE", 2, &["Missing"], &["Missing"]),
+    ];
+    assert_eq!(
+        assign_priorities(&entries).unwrap(),
+        priorities(&[
+            ("ModA", 6),
+            ("ModB", 5),
+            ("ModC", 7),
+            ("ModD", 4),
+            ("ModE", 2)
+        ])
+    );
+}
+
+#[test]
+fn cycle() {
+    let entries = [
+        entry("ModA", 0, &[], &["ModB"]),
+        entry("ModB", 0, &[], &["ModA"]),
+        entry("ModC", 0, &[], &[]),
+    ];
+    let cycle = assign_priorities(&entries).unwrap_err();
+    assert_eq!(
+        cycle,
+        LoadOrderCycle {
+            mod_ids: vec!["ModA".to_string(), "ModB".to_string()]
+        }
+    );
+    assert_eq!(
+        cycle.to_string(),
+        "Load order of ModA, ModB can't be satisfied"
+    );
+}
+
+#[test]
+fn priority_limit() {
+    // hints can raise mods up to the limit
+    let entries = [
+        entry("ModA", MAX_PRIORITY - 1, &[], &[]),
+        entry("ModB", 0, &[], &["ModA"]),
+        // mods named above the limit keep their priority
+        entry("ModC", 950, &[], &["ModA"]),
+    ];
+    assert_eq!(
+        assign_priorities(&entries).unwrap(),
+        priorities(&[
+            ("ModA", MAX_PRIORITY - 1),
+            ("ModB", MAX_PRIORITY),
+            ("ModC", 950)
+        ])
+    );
+
+    // but not past it, they would load after the integrator pak or get a 4 digit priority
+    // that sorts before lower ones
+    let entries = [
+        entry("ModA", MAX_PRIORITY, &[], &[]),
+        entry("ModB", 0, &["ModA"], &[]),
+        entry("ModC", 0, &[], &["ModA"]),
+    ];
+    let cycle = assign_priorities(&entries).unwrap_err();
+    assert_eq!(
+        cycle,
+        LoadOrderCycle {
+            mod_ids: vec!["ModA".to_string(), "ModC".to_string()]
+        }
+    );
+}
@@ -0,0 +1,264 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+
+use tempfile::TempDir;
+
+use unreal_mod_manager::error::ModLoaderWarningKind;
+use unreal_mod_manager::modpack::{Modpack, ModpackMod, FORMAT_VERSION};
+use unreal_mod_manager::unreal_mod_metadata::{DownloadInfo, DownloadMode};
+
+mod common;
+
+use common::serve;
+
+const BUNDLED_FILE: &str = "000-BundledMod-1.0.0_P.pak";
+const DOWNLOADED_FILE: &str = "000-DownloadedMod-2.1.0_P.pak";
+
+fn modpack_mod(version: &str, file_name: &str, download_url: Option<String>) -> ModpackMod {
+    ModpackMod {
+        version: version.to_string(),
+        force_latest: false,
+        priority: 0,
+        file_name: file_name.to_string(),
+        sha256: None,
+        download: None,
+        download_url,
+        bundled: false,
+    }
+}
+
+fn modpack(mods: Vec<(&str, ModpackMod)>) -> Modpack {
+    Modpack {
+        format_version: FORMAT_VERSION,
+        name: "Shared".to_string(),
+        mods: mods
+            .into_iter()
+            .map(|(mod_id, modpack_mod)| (mod_id.to_string(), modpack_mod))
+            .collect::<BTreeMap<_, _>>(),
+    }
+}
+
+#[test]
+fn export_and_install() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let mods_path = dir.join("Mods");
+    fs::create_dir_all(&mods_path).unwrap();
+    fs::write(mods_path.join(BUNDLED_FILE), b"bundled pak").unwrap();
+    fs::write(mods_path.join(DOWNLOADED_FILE), b"downloaded pak").unwrap();
+
+    let base_url = serve(HashMap::from([(
+        "/000-DownloadedMod-2.1.0_P.pak",
+        b"downloaded pak".to_vec(),
+    )]));
+
+    let path = dir.join("Shared.modpack");
+    modpack(vec![
+        ("BundledMod", modpack_mod("1.0.0", BUNDLED_FILE, None)),
+        (
+            "DownloadedMod",
+            modpack_mod(
+                "2.1.0",
+                DOWNLOADED_FILE,
+                Some(format!("{base_url}/{DOWNLOADED_FILE}")),
+            ),
+        ),
+    ])
+    .write(&path, &mods_path, false)
+    .unwrap();
+    assert!(!dir.join("Shared.modpack.write").exists());
+
+    // mods without a download source are always bundled
+    let read = Modpack::read(&path).unwrap();
+    assert_eq!(read.name, "Shared");
+    assert!(read.mods["BundledMod"].bundled);
+    assert!(read.mods["BundledMod"].sha256.is_some());
+    assert!(!read.mods["DownloadedMod"].bundled);
+
+    let other_mods_path = dir.join("OtherMods");
+    fs::create_dir_all(&other_mods_path).unwrap();
+    let (installed, warnings) = read.install(&path, &other_mods_path);
+    assert!(warnings.is_empty(), "{warnings:?}");
+    assert_eq!(installed.len(), 2);
+    assert_eq!(
+        fs::read(other_mods_path.join(BUNDLED_FILE)).unwrap(),
+        b"bundled pak"
+    );
+    assert_eq!(
+        fs::read(other_mods_path.join(DOWNLOADED_FILE)).unwrap(),
+        b"downloaded pak"
+    );
+
+    // installed mods are skipped
+    let (installed, warnings) = read.install(&path, &other_mods_path);
+    assert!(installed.is_empty());
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn index_file_install() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+
+    let pak_url = serve(HashMap::from([(
+        "/000-DownloadedMod-2.1.0_P.pak",
+        b"downloaded pak".to_vec(),
+    )]));
+    let index_file = format!(
+        r#"{{
+    "mods": {{
+        "DownloadedMod": {{
+            "latest_version": "2.1.0",
+            "versions": {{
+                "2.1.0": {{
+                    "download_url": "{pak_url}/{DOWNLOADED_FILE}",
+                    "filename": "{DOWNLOADED_FILE}"
+                }}
+            }}
+        }}
+    }}
+}}"#
+    );
+    let index_url = serve(HashMap::from([("/index.json", index_file.into_bytes())]));
+
+    let index_mod = |version: &str, url: String| {
+        let mut modpack_mod = modpack_mod(version, DOWNLOADED_FILE, None);
+        modpack_mod.download = Some(DownloadInfo {
+            download_mode: DownloadMode::IndexFile,
+            url,
+            public_key: None,
+        });
+        modpack_mod
+    };
+
+    let path = dir.join("Shared.modpack");
+    modpack(vec![(
+        "DownloadedMod",
+        index_mod("2.1.0", format!("{index_url}/index.json")),
+    )])
+    .write(&path, dir, false)
+    .unwrap();
+
+    let read = Modpack::read(&path).unwrap();
+    assert!(!read.mods["DownloadedMod"].bundled);
+
+    let mods_path = dir.join("Mods");
+    fs::create_dir_all(&mods_path).unwrap();
+    let (installed, warnings) = read.install(&path, &mods_path);
+    assert!(warnings.is_empty(), "{warnings:?}");
+    assert_eq!(installed, vec![mods_path.join(DOWNLOADED_FILE)]);
+    assert_eq!(
+        fs::read(mods_path.join(DOWNLOADED_FILE)).unwrap(),
+        b"downloaded pak"
+    );
+
+    // without a download url the errors of the index file are reported
+    fs::remove_file(mods_path.join(DOWNLOADED_FILE)).unwrap();
+    let install_error = |modpack_mod: ModpackMod| {
+        modpack(vec![("DownloadedMod", modpack_mod)])
+            .write(&path, dir, false)
+            .unwrap();
+        let (installed, mut warnings) = Modpack::read(&path).unwrap().install(&path, &mods_path);
+        assert!(installed.is_empty());
+        assert_eq!(warnings.len(), 1);
+        warnings.remove(0).kind
+    };
+    assert!(matches!(
+        install_error(index_mod("2.1.0", format!("{index_url}/missing.json"))),
+        ModLoaderWarningKind::IndexFileDownloadFailedStatus(_)
+    ));
+    assert!(matches!(
+        install_error(index_mod("2.2.0", format!("{index_url}/index.json"))),
+        ModLoaderWarningKind::Other(_)
+    ));
+}
+
+#[test]
+fn bundle_everything() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    fs::write(dir.join(DOWNLOADED_FILE), b"downloaded pak").unwrap();
+
+    let path = dir.join("Shared.modpack");
+    modpack(vec![(
+        "DownloadedMod",
+        modpack_mod(
+            "2.1.0",
+            DOWNLOADED_FILE,
+            Some("http://127.0.0.1:1/unreachable".to_string()),
+        ),
+    )])
+    .write(&path, dir, true)
+    .unwrap();
+
+    let read = Modpack::read(&path).unwrap();
+    assert!(read.mods["DownloadedMod"].bundled);
+
+    // the download url is never used
+    let install_path = dir.join("Install");
+    fs::create_dir_all(&install_path).unwrap();
+    let (installed, warnings) = read.install(&path, &install_path);
+    assert!(warnings.is_empty(), "{warnings:?}");
+    assert_eq!(installed, vec![install_path.join(DOWNLOADED_FILE)]);
+
+    // bundling needs the mod file
+    fs::remove_file(dir.join(DOWNLOADED_FILE)).unwrap();
+    assert!(read.write(&dir.join("Missing.modpack"), dir, true).is_err());
+}
+
+#[test]
+fn invalid_modpacks() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let base_url = serve(HashMap::from([(
+        "/000-DownloadedMod-2.1.0_P.pak",
+        b"tampered pak".to_vec(),
+    )]));
+
+    let mut tampered = modpack_mod(
+        "2.1.0",
+        DOWNLOADED_FILE,
+        Some(format!("{base_url}/{DOWNLOADED_FILE}")),
+    );
+    tampered.sha256 =
+        Some("0000000000000000000000000000000000000000000000000000000000000000".to_string());
+
+    let path = dir.join("Invalid.modpack");
+    modpack(vec![
+        ("DownloadedMod", tampered),
+        (
+            "EscapingMod",
+            modpack_mod(
+                "1.0.0",
+                "../000-EscapingMod-1.0.0_P.pak",
+                Some(format!("{base_url}/{DOWNLOADED_FILE}")),
+            ),
+        ),
+    ])
+    .write(&path, dir, false)
+    .unwrap();
+
+    let mods_path = dir.join("Mods");
+    fs::create_dir_all(&mods_path).unwrap();
+    let (installed, warnings) = Modpack::read(&path).unwrap().install(&path, &mods_path);
+    assert!(installed.is_empty());
+    assert_eq!(warnings.len(), 2);
+    assert!(matches!(
+        warnings[0].kind,
+        ModLoaderWarningKind::ChecksumMismatch(_, _)
+    ));
+    assert!(matches!(
+        warnings[1].kind,
+        ModLoaderWarningKind::InvalidModFileName
+    ));
+    assert_eq!(fs::read_dir(&mods_path).unwrap().count(), 0);
+    assert!(!dir.join("000-EscapingMod-1.0.0_P.pak").exists());
+
+    let mut newer = modpack(Vec::new());
+    newer.format_version = FORMAT_VERSION + 1;
+    newer.write(&path, dir, false).unwrap();
+    assert!(matches!(
+        Modpack::read(&path).unwrap_err().kind,
+        ModLoaderWarningKind::InvalidModpack(_)
+    ));
+}
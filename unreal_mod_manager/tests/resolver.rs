@@ -0,0 +1,280 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use semver::{Version, VersionReq};
+
+use unreal_mod_manager::resolver::{resolve, Conflict, DependencyProvider, Requirement};
+use unreal_mod_manager::unreal_mod_metadata::{Dependency, DownloadInfo, DownloadMode};
+
+/// Mods with their versions and the dependencies of each version
+#[derive(Default)]
+struct Registry {
+    mods: HashMap<String, BTreeMap<Version, HashMap<String, Dependency>>>,
+    optional: HashMap<(String, Version), HashMap<String, VersionReq>>,
+    /// mods that are only available when a dependency has a download
+    remote: HashSet<String>,
+    builtin: HashSet<String>,
+    dependency_queries: Vec<(String, Version)>,
+}
+
+impl Registry {
+    fn add(&mut self, mod_id: &str, version: &str, dependencies: &[(&str, &str)]) -> &mut Self {
+        self.mods.entry(mod_id.to_string()).or_default().insert(
+            Version::parse(version).unwrap(),
+            dependencies
+                .iter()
+                .map(|(mod_id, requirement)| {
+                    (
+                        mod_id.to_string(),
+                        Dependency::new(VersionReq::parse(requirement).unwrap(), None),
+                    )
+                })
+                .collect(),
+        );
+        self
+    }
+
+    fn add_optional(&mut self, mod_id: &str, version: &str, dependencies: &[(&str, &str)]) {
+        self.optional.insert(
+            (mod_id.to_string(), Version::parse(version).unwrap()),
+            dependencies
+                .iter()
+                .map(|(mod_id, requirement)| {
+                    (mod_id.to_string(), VersionReq::parse(requirement).unwrap())
+                })
+                .collect(),
+        );
+    }
+}
+
+impl DependencyProvider for Registry {
+    fn versions(&mut self, mod_id: &str, downloads: &[DownloadInfo]) -> Vec<Version> {
+        if self.remote.contains(mod_id) && downloads.is_empty() {
+            return Vec::new();
+        }
+
+        self.mods
+            .get(mod_id)
+            .map(|e| e.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn dependencies(
+        &mut self,
+        mod_id: &str,
+        version: &Version,
+    ) -> Option<HashMap<String, Dependency>> {
+        self.dependency_queries
+            .push((mod_id.to_string(), version.clone()));
+        self.mods.get(mod_id)?.get(version).cloned()
+    }
+
+    fn optional_dependencies(
+        &mut self,
+        mod_id: &str,
+        version: &Version,
+    ) -> HashMap<String, VersionReq> {
+        self.optional
+            .get(&(mod_id.to_string(), version.clone()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn is_builtin(&self, mod_id: &str) -> bool {
+        self.builtin.contains(mod_id)
+    }
+}
+
+fn request(mods: &[(&str, &str)]) -> Vec<(String, VersionReq)> {
+    mods.iter()
+        .map(|(mod_id, requirement)| (mod_id.to_string(), VersionReq::parse(requirement).unwrap()))
+        .collect()
+}
+
+fn versions(versions: &[(&str, &str)]) -> BTreeMap<String, Version> {
+    versions
+        .iter()
+        .map(|(mod_id, version)| (mod_id.to_string(), Version::parse(version).unwrap()))
+        .collect()
+}
+
+#[test]
+fn latest_compatible() {
+    let mut registry = Registry::default();
+    registry
+        .add("ModA", "1.0.0", &[("Lib", "^1.0")])
+        .add("Lib", "1.0.0", &[])
+        .add("Lib", "1.4.2", &[])
+        .add("Lib", "2.0.0", &[]);
+
+    let resolution = resolve(&request(&[("ModA", "*")]), &mut registry).unwrap();
+    assert_eq!(resolution, versions(&[("ModA", "1.0.0"), ("Lib", "1.4.2")]));
+}
+
+#[test]
+fn backtracking() {
+    // the newest ModA needs a Lib that ModB doesn't accept, so an older ModA is picked
+    let mut registry = Registry::default();
+    registry
+        .add("ModA", "1.0.0", &[("Lib", "^1.0")])
+        .add("ModA", "2.0.0", &[("Lib", "^2.0")])
+        .add("ModB", "1.0.0", &[("Lib", ">=1.2, <2.0")])
+        .add("Lib", "1.0.0", &[])
+        .add("Lib", "1.3.0", &[])
+        .add("Lib", "2.1.0", &[]);
+
+    let resolution = resolve(&request(&[("ModA", "*"), ("ModB", "*")]), &mut registry).unwrap();
+    assert_eq!(
+        resolution,
+        versions(&[("ModA", "1.0.0"), ("ModB", "1.0.0"), ("Lib", "1.3.0")])
+    );
+
+    // dependencies are only queried once per version
+    let mut queries = registry.dependency_queries.clone();
+    queries.sort();
+    queries.dedup();
+    assert_eq!(queries.len(), registry.dependency_queries.len());
+}
+
+#[test]
+fn transitive_dependencies() {
+    let mut registry = Registry::default();
+    registry
+        .add("ModA", "1.0.0", &[("ModB", "^1")])
+        .add("ModB", "1.0.0", &[("ModC", "~0.2")])
+        .add("ModB", "1.1.0", &[("ModC", "~0.3")])
+        .add("ModC", "0.2.5", &[])
+        .add("ModC", "0.3.1", &[])
+        .add("ModC", "0.4.0", &[]);
+
+    let resolution = resolve(&request(&[("ModA", "=1.0.0")]), &mut registry).unwrap();
+    assert_eq!(
+        resolution,
+        versions(&[("ModA", "1.0.0"), ("ModB", "1.1.0"), ("ModC", "0.3.1")])
+    );
+
+    // a pinned version restricts what the dependencies can use
+    let resolution = resolve(
+        &request(&[("ModA", "=1.0.0"), ("ModC", "=0.2.5")]),
+        &mut registry,
+    )
+    .unwrap();
+    assert_eq!(
+        resolution,
+        versions(&[("ModA", "1.0.0"), ("ModB", "1.0.0"), ("ModC", "0.2.5")])
+    );
+}
+
+#[test]
+fn builtin_mods() {
+    let mut registry = Registry::default();
+    registry.add("ModA", "1.0.0", &[("CoreMod", "^1")]);
+    registry.builtin.insert("CoreMod".to_string());
+
+    let resolution = resolve(&request(&[("ModA", "*")]), &mut registry).unwrap();
+    assert_eq!(resolution, versions(&[("ModA", "1.0.0")]));
+}
+
+#[test]
+fn downloads() {
+    let mut registry = Registry::default();
+    registry.add("ModA", "1.0.0", &[]).add("Lib", "1.0.0", &[]);
+    registry.remote.insert("Lib".to_string());
+
+    let download = DownloadInfo {
+        download_mode: DownloadMode::IndexFile,
+        url: "https://example.com/index.json".to_string(),
+        public_key: None,
+    };
+    registry.mods.get_mut("ModA").unwrap().insert(
+        Version::new(1, 0, 0),
+        HashMap::from([(
+            "Lib".to_string(),
+            Dependency::new(VersionReq::parse("^1").unwrap(), Some(download)),
+        )]),
+    );
+
+    let resolution = resolve(&request(&[("ModA", "*")]), &mut registry).unwrap();
+    assert_eq!(resolution, versions(&[("ModA", "1.0.0"), ("Lib", "1.0.0")]));
+}
+
+#[test]
+fn optional_dependencies() {
+    let mut registry = Registry::default();
+    registry
+        .add("ModA", "1.0.0", &[])
+        .add("Lib", "1.0.0", &[])
+        .add("Lib", "2.0.0", &[]);
+    registry.add_optional("ModA", "1.0.0", &[("Lib", "^1")]);
+
+    // optional dependencies are not installed on their own
+    let resolution = resolve(&request(&[("ModA", "*")]), &mut registry).unwrap();
+    assert_eq!(resolution, versions(&[("ModA", "1.0.0")]));
+
+    // but restrict the version if they are
+    let resolution = resolve(&request(&[("ModA", "*"), ("Lib", "*")]), &mut registry).unwrap();
+    assert_eq!(resolution, versions(&[("ModA", "1.0.0"), ("Lib", "1.0.0")]));
+
+    let conflict =
+        resolve(&request(&[("ModA", "*"), ("Lib", "=2.0.0")]), &mut registry).unwrap_err();
+    assert_eq!(conflict.mod_id, "Lib");
+}
+
+#[test]
+fn builtin_optional_dependencies() {
+    let mut registry = Registry::default();
+    registry
+        .add("ModA", "1.0.0", &[])
+        .add("Lib", "1.0.0", &[])
+        .add("Lib", "2.0.0", &[]);
+    // the builtin mod sorts first, the dependencies after it are still checked
+    registry.add_optional("ModA", "1.0.0", &[("CoreMod", "^1"), ("Lib", "^1")]);
+    registry.builtin.insert("CoreMod".to_string());
+
+    let resolution = resolve(&request(&[("ModA", "*"), ("Lib", "*")]), &mut registry).unwrap();
+    assert_eq!(resolution, versions(&[("ModA", "1.0.0"), ("Lib", "1.0.0")]));
+
+    let conflict =
+        resolve(&request(&[("ModA", "*"), ("Lib", "=2.0.0")]), &mut registry).unwrap_err();
+    assert_eq!(conflict.mod_id, "Lib");
+}
+
+#[test]
+fn conflicts() {
+    let mut registry = Registry::default();
+    registry
+        .add("ModA", "1.0.0", &[("Lib", ">=2.0")])
+        .add("ModB", "1.0.0", &[("Lib", "<2.0")])
+        .add("Lib", "1.0.0", &[])
+        .add("Lib", "2.0.0", &[]);
+
+    let conflict = resolve(&request(&[("ModA", "*"), ("ModB", "*")]), &mut registry).unwrap_err();
+    assert_eq!(
+        conflict,
+        Conflict {
+            mod_id: "Lib".to_string(),
+            requirements: vec![
+                Requirement {
+                    required_by: Some(("ModA".to_string(), Version::new(1, 0, 0))),
+                    version: VersionReq::parse(">=2.0").unwrap(),
+                },
+                Requirement {
+                    required_by: Some(("ModB".to_string(), Version::new(1, 0, 0))),
+                    version: VersionReq::parse("<2.0").unwrap(),
+                },
+            ],
+            available: vec![Version::new(2, 0, 0), Version::new(1, 0, 0)],
+        }
+    );
+    assert_eq!(
+        conflict.to_string(),
+        "No version of Lib satisfies all requirements: \
+         ModA 1.0.0 requires >=2.0, ModB 1.0.0 requires <2.0. \
+         Available versions: 2.0.0, 1.0.0"
+    );
+
+    let conflict = resolve(&request(&[("ModC", "^1")]), &mut registry).unwrap_err();
+    assert_eq!(
+        conflict.to_string(),
+        "No version of ModC is available, but ^1 was requested"
+    );
+}
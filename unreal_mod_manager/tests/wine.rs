@@ -0,0 +1,185 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::thread;
+#[cfg(unix)]
+use std::time::{Duration, Instant};
+
+use tempfile::TempDir;
+
+use unreal_mod_manager::config::InstallManager;
+use unreal_mod_manager::game_path_helpers::{
+    determine_install_path_wine, determine_installed_mods_path_wine, determine_user_path_wine,
+};
+use unreal_mod_manager::game_platform_managers::{
+    set_dll_override, GetGameBuildTrait, WineInstallManager, WineSettings,
+};
+use unreal_mod_manager::version::GameBuild;
+
+#[derive(Debug)]
+struct NoGameBuild;
+
+impl GetGameBuildTrait<WineInstallManager> for NoGameBuild {
+    fn get_game_build(&self, _manager: &WineInstallManager) -> Option<GameBuild> {
+        None
+    }
+}
+
+/// Prefix like the ones created by Lutris or Heroic, with the game installed inside of it
+fn create_prefix(prefix: &Path, user: &str) -> PathBuf {
+    let drive_c = prefix.join("drive_c");
+    fs::create_dir_all(drive_c.join("users").join("Public")).unwrap();
+    fs::create_dir_all(drive_c.join("users").join(user).join("AppData")).unwrap();
+    fs::create_dir_all(drive_c.join("windows").join("system32")).unwrap();
+
+    let game_path = drive_c.join("Program Files").join("Publisher").join("Game");
+    fs::create_dir_all(game_path.join("TestGame").join("Binaries").join("Win64")).unwrap();
+    game_path
+}
+
+#[test]
+fn prefix_paths() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+
+    let prefix = dir.join("prefix");
+    let game_path = create_prefix(&prefix, "steamuser");
+    // games in user folders are ignored
+    fs::create_dir_all(
+        prefix
+            .join("drive_c")
+            .join("users")
+            .join("steamuser")
+            .join("TestGame")
+            .join("Binaries"),
+    )
+    .unwrap();
+
+    let user_path = prefix.join("drive_c").join("users").join("steamuser");
+    assert_eq!(determine_user_path_wine(&prefix), Some(user_path.clone()));
+    assert_eq!(
+        determine_installed_mods_path_wine(&prefix, "TestGame"),
+        Some(user_path.join("AppData/Local/TestGame/Saved/Paks"))
+    );
+    assert_eq!(
+        determine_install_path_wine(&prefix, "TestGame"),
+        Some(game_path)
+    );
+    assert_eq!(determine_install_path_wine(&prefix, "OtherGame"), None);
+
+    // older Wine versions use a different folder for local app data
+    let legacy_prefix = dir.join("legacy");
+    let legacy_user_path = legacy_prefix.join("drive_c").join("users").join("player");
+    fs::create_dir_all(legacy_user_path.join("Local Settings/Application Data")).unwrap();
+    assert_eq!(
+        determine_installed_mods_path_wine(&legacy_prefix, "TestGame"),
+        Some(legacy_user_path.join("Local Settings/Application Data/TestGame/Saved/Paks"))
+    );
+
+    assert_eq!(determine_user_path_wine(&dir.join("missing")), None);
+}
+
+#[test]
+fn install_manager() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let prefix = dir.join("prefix");
+    let game_path = create_prefix(&prefix, "player");
+
+    let manager =
+        WineInstallManager::new("TestGame", Box::new(NoGameBuild)).with_prefix_path(prefix.clone());
+    assert_eq!(manager.get_prefix_path(), Some(prefix.clone()));
+    assert_eq!(manager.get_game_install_path(), Some(game_path));
+    assert_eq!(
+        manager.get_paks_path(),
+        Some(prefix.join("drive_c/users/player/AppData/Local/TestGame/Saved/Paks"))
+    );
+    assert_eq!(manager.get_wine_settings(), Some(WineSettings::default()));
+
+    // games installed outside of the prefix, like with Heroic
+    let external_game_path = dir.join("Games").join("Game");
+    manager.set_wine_settings(WineSettings {
+        game_path: Some(external_game_path.clone()),
+        launch_command: Some("heroic --no-gui".to_owned()),
+        ..Default::default()
+    });
+    assert_eq!(manager.get_game_install_path(), Some(external_game_path));
+    assert_eq!(manager.settings().prefix_path, Some(prefix));
+    assert_eq!(
+        manager.settings().launch_command.as_deref(),
+        Some("heroic --no-gui")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn launch_command() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let prefix = dir.join("prefix");
+    let game_path = create_prefix(&prefix, "player");
+    let output = dir.join("launch.txt");
+
+    // the command is run with the paths of the prefix and the game
+    let manager = WineInstallManager::new("TestGame", Box::new(NoGameBuild))
+        .with_prefix_path(prefix.clone())
+        .with_launch_command(format!(
+            "printf '%s\\n%s' \"$WINEPREFIX\" \"$GAME_PATH\" > '{0}.tmp' && mv '{0}.tmp' '{0}'",
+            output.display()
+        ));
+    manager.launch_game().unwrap();
+
+    // the game is started in the background
+    let start = Instant::now();
+    while !output.is_file() {
+        assert!(start.elapsed() < Duration::from_secs(10), "not launched");
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(
+        fs::read_to_string(&output).unwrap(),
+        format!("{}\n{}", prefix.display(), game_path.display())
+    );
+}
+
+#[test]
+fn dll_override() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let section =
+        "[Software\\\\Wine\\\\AppDefaults\\\\TestGame-Win64-Shipping.exe\\\\DllOverrides]";
+
+    set_dll_override(dir, "TestGame-Win64-Shipping.exe", "xinput1_3").unwrap();
+    set_dll_override(dir, "TestGame-Win64-Shipping.exe", "xinput1_3").unwrap();
+    let registry = fs::read_to_string(dir.join("user.reg")).unwrap();
+    assert!(registry.starts_with("WINE REGISTRY Version 2\n"));
+    assert_eq!(registry.matches(section).count(), 1);
+    assert_eq!(
+        registry.matches("\"xinput1_3\"=\"native,builtin\"").count(),
+        1
+    );
+
+    // existing sections are updated in place
+    fs::write(
+        dir.join("user.reg"),
+        "WINE REGISTRY Version 2\n\n\
+        [Software\\\\Wine\\\\AppDefaults\\\\testgame-win64-shipping.exe\\\\DllOverrides] 1700000000\n\
+        #time=1da1b2c3d4e5f60\n\
+        \"d3d11\"=\"native\"\n\
+        \"XInput1_3\"=\"builtin\"\n\n\
+        [Software\\\\Wine\\\\Drivers] 1700000000\n\
+        \"Audio\"=\"pulse\"\n",
+    )
+    .unwrap();
+    set_dll_override(dir, "TestGame-Win64-Shipping.exe", "xinput1_3").unwrap();
+    let registry = fs::read_to_string(dir.join("user.reg")).unwrap();
+    assert_eq!(
+        registry,
+        "WINE REGISTRY Version 2\n\n\
+        [Software\\\\Wine\\\\AppDefaults\\\\testgame-win64-shipping.exe\\\\DllOverrides] 1700000000\n\
+        #time=1da1b2c3d4e5f60\n\
+        \"d3d11\"=\"native\"\n\
+        \"xinput1_3\"=\"native,builtin\"\n\n\
+        [Software\\\\Wine\\\\Drivers] 1700000000\n\
+        \"Audio\"=\"pulse\"\n"
+    );
+}
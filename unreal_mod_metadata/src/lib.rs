@@ -8,7 +8,9 @@ use error::Error;
 pub mod error;
 pub(crate) mod v1;
 pub mod v2;
-pub use crate::v2::Metadata;
+pub mod v3;
+pub mod verify;
+pub use crate::v3::Metadata;
 
 #[macro_export]
 macro_rules! hash_value {
@@ -61,6 +63,9 @@ pub struct DownloadInfo {
     pub download_mode: DownloadMode,
     #[serde(default)]
     pub url: String,
+    /// Hex encoded ed25519 public key, when set the index file must be signed with the matching key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
 }
 
 fn semver_to_string<S>(version: &VersionReq, serializer: S) -> Result<S::Ok, S::Error>
@@ -115,8 +120,9 @@ pub fn from_slice(slice: &[u8]) -> Result<Metadata, Error> {
     let schema_version = value.schema_version.unwrap_or(1);
 
     match schema_version {
-        1 => Ok(v1::Metadata::to_v2(slice)?),
-        2 => Ok(serde_json::from_slice(slice)?),
+        1 => Ok(v1::Metadata::to_v2(slice)?.to_v3()),
+        2 => Ok(serde_json::from_slice::<v2::Metadata>(slice)?.to_v3()),
+        3 => Ok(serde_json::from_slice(slice)?),
         _ => Err(Error::unsupported_schema(schema_version)),
     }
 }
@@ -138,7 +144,7 @@ mod tests {
         let parsed = from_slice(src.as_bytes()).unwrap();
 
         let expected = Metadata {
-            schema_version: 2,
+            schema_version: 3,
             name: "Test".to_string(),
             mod_id: "TestModId".to_string(),
             mod_version: "1.0.0".to_string(),
@@ -162,7 +168,7 @@ mod tests {
         let parsed = from_slice(src.as_bytes()).unwrap();
 
         let expected = Metadata {
-            schema_version: 2,
+            schema_version: 3,
             name: "Test".to_string(),
             mod_id: "TestModId".to_string(),
             mod_version: "1.0.0".to_string(),
@@ -186,7 +192,7 @@ mod tests {
         let parsed = from_slice(src.as_bytes()).unwrap();
 
         let expected = Metadata {
-            schema_version: 2,
+            schema_version: 3,
             name: "Test".to_string(),
             mod_id: "TestModId".to_string(),
             mod_version: "1.0.0".to_string(),
@@ -200,7 +206,7 @@ mod tests {
     fn unsupported_test() {
         let src = r#"
             {
-                "schema_version": 3,
+                "schema_version": 4,
                 "name": "Test",
                 "mod_id": "TestModId",
                 "version": "1.0.0"
@@ -1,5 +1,5 @@
 /// The version 1 of the metadata is considered deprecated.
-/// It is recommended to use the version 2.
+/// It is recommended to use the latest version.
 /// Support for the version 1 will be removed in a future release.
 /// The current implementation that ensures backwards compatibility (for now) contains some Astroneer specific data.
 use std::{collections::HashMap, hash::Hash};
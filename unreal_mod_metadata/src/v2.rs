@@ -9,7 +9,7 @@ use serde::{
 };
 use serde_json::Value;
 
-use crate::{error, hash_value, Dependency, DownloadInfo, SyncMode};
+use crate::{error, hash_value, v3, Dependency, DownloadInfo, SyncMode};
 
 fn string_or_struct<'de, T, D>(deserializer: D) -> Result<T, D::Error>
 where
@@ -46,7 +46,7 @@ where
     deserializer.deserialize_any(StringOrStruct(PhantomData))
 }
 
-fn deserialize_dependency_map<'de, D>(
+pub(crate) fn deserialize_dependency_map<'de, D>(
     deserializer: D,
 ) -> Result<HashMap<String, Dependency>, D::Error>
 where
@@ -143,6 +143,27 @@ impl PartialEq for Metadata {
     }
 }
 
+impl Metadata {
+    pub fn to_v3(self) -> v3::Metadata {
+        v3::Metadata {
+            schema_version: 3,
+            name: self.name,
+            mod_id: self.mod_id,
+            author: self.author,
+            description: self.description,
+            mod_version: self.mod_version,
+            game_build: self.game_build,
+            sync: self.sync,
+            homepage: self.homepage,
+            download: self.download,
+            dependencies: self.dependencies,
+            integrator: self.integrator,
+            cpp_loader_dlls: self.cpp_loader_dlls,
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -259,6 +280,7 @@ mod tests {
                 Some(DownloadInfo {
                     download_mode: crate::DownloadMode::IndexFile,
                     url: "https://example.com".to_string(),
+                    public_key: None,
                 }),
             ),
         );
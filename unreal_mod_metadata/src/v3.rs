@@ -0,0 +1,219 @@
+use std::collections::{hash_map::DefaultHasher, BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+
+use semver::VersionReq;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::v2::deserialize_dependency_map;
+use crate::{hash_value, Dependency, DownloadInfo, SyncMode};
+
+#[derive(Debug, Default, Clone, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    pub schema_version: usize,
+    pub name: String,
+    pub mod_id: String,
+    pub author: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "version")]
+    pub mod_version: String,
+    /// Game build the mod was made for
+    pub game_build: Option<String>,
+    /// Game builds the mod works with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supported_game_builds: Option<VersionReq>,
+    pub sync: Option<SyncMode>,
+    pub homepage: Option<String>,
+    pub download: Option<DownloadInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    #[serde(default, deserialize_with = "deserialize_dependency_map")]
+    pub dependencies: HashMap<String, Dependency>,
+    /// Dependencies that are only checked if the mod is installed
+    #[serde(
+        default,
+        deserialize_with = "deserialize_dependency_map",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub optional_dependencies: HashMap<String, Dependency>,
+    /// Versions of other mods that can't be used together with this mod
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub incompatible_with: BTreeMap<String, VersionReq>,
+    /// Mods that should be loaded after this mod, so they can override its files
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub load_before: Vec<String>,
+    /// Mods that should be loaded before this mod, so this mod can override their files
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub load_after: Vec<String>,
+
+    #[serde(default)]
+    pub integrator: HashMap<String, Value>,
+
+    #[serde(default)]
+    pub cpp_loader_dlls: Vec<String>,
+}
+
+impl Metadata {
+    /// Check if a game build is supported, mods without [`Metadata::supported_game_builds`]
+    /// support every build
+    pub fn supports_game_build(&self, game_build: &semver::Version) -> bool {
+        self.supported_game_builds
+            .as_ref()
+            .is_none_or(|e| e.matches(game_build))
+    }
+}
+
+impl Hash for Metadata {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.schema_version.hash(state);
+        self.name.hash(state);
+        self.mod_id.hash(state);
+        self.author.hash(state);
+        self.description.hash(state);
+        self.mod_version.hash(state);
+        self.game_build.hash(state);
+        self.supported_game_builds.hash(state);
+        self.sync.hash(state);
+        self.homepage.hash(state);
+        self.download.hash(state);
+        self.license.hash(state);
+        self.tags.hash(state);
+
+        for dependencies in [&self.dependencies, &self.optional_dependencies] {
+            dependencies.len().hash(state);
+            for (element_name, element) in dependencies {
+                element_name.hash(state);
+                element.hash(state);
+            }
+        }
+
+        self.incompatible_with.hash(state);
+        self.load_before.hash(state);
+        self.load_after.hash(state);
+
+        self.integrator.len().hash(state);
+        for (element_name, element) in &self.integrator {
+            element_name.hash(state);
+            hash_value!(element, state);
+        }
+
+        self.cpp_loader_dlls.hash(state);
+    }
+}
+
+impl PartialEq for Metadata {
+    fn eq(&self, other: &Self) -> bool {
+        let cmp = self.schema_version == other.schema_version
+            && self.name == other.name
+            && self.mod_id == other.mod_id
+            && self.author == other.author
+            && self.description == other.description
+            && self.mod_version == other.mod_version
+            && self.game_build == other.game_build
+            && self.supported_game_builds == other.supported_game_builds
+            && self.sync == other.sync
+            && self.homepage == other.homepage
+            && self.download == other.download
+            && self.license == other.license
+            && self.tags == other.tags
+            && self.dependencies == other.dependencies
+            && self.optional_dependencies == other.optional_dependencies
+            && self.incompatible_with == other.incompatible_with
+            && self.load_before == other.load_before
+            && self.load_after == other.load_after
+            && self.cpp_loader_dlls == other.cpp_loader_dlls
+            && self.integrator.len() == other.integrator.len();
+
+        let mut hasher = DefaultHasher::new();
+        for (element_name, element) in &self.integrator {
+            element_name.hash(&mut hasher);
+            hash_value!(element, &mut hasher);
+        }
+
+        let mut other_hasher = DefaultHasher::new();
+        for (element_name, element) in &other.integrator {
+            element_name.hash(&mut other_hasher);
+            hash_value!(element, &mut other_hasher);
+        }
+
+        cmp && (hasher.finish() == other_hasher.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use semver::{Version, VersionReq};
+
+    use crate::{v3::Metadata, Dependency};
+
+    #[test]
+    fn v3_test() {
+        let src = r#"
+            {
+                "schema_version": 3,
+                "name": "Test",
+                "mod_id": "TestModId",
+                "version": "1.0.0",
+                "supported_game_builds": ">=1.2, <1.4",
+                "license": "MIT",
+                "tags": ["qol", "ui"],
+
+                "dependencies": {
+                    "FirstMod": "^1"
+                },
+                "optional_dependencies": {
+                    "SecondMod": ">=2.0.0"
+                },
+                "incompatible_with": {
+                    "ThirdMod": "<3"
+                },
+                "load_before": ["FourthMod"],
+                "load_after": ["FirstMod", "SecondMod"]
+            }
+        "#;
+
+        let parsed: Metadata = serde_json::from_str(src).unwrap();
+
+        let expected = Metadata {
+            schema_version: 3,
+            name: "Test".to_string(),
+            mod_id: "TestModId".to_string(),
+            mod_version: "1.0.0".to_string(),
+            supported_game_builds: Some(VersionReq::parse(">=1.2, <1.4").unwrap()),
+            license: Some("MIT".to_string()),
+            tags: Vec::from(["qol".to_string(), "ui".to_string()]),
+            dependencies: HashMap::from([(
+                "FirstMod".to_string(),
+                Dependency::new(VersionReq::parse("^1").unwrap(), None),
+            )]),
+            optional_dependencies: HashMap::from([(
+                "SecondMod".to_string(),
+                Dependency::new(VersionReq::parse(">=2.0.0").unwrap(), None),
+            )]),
+            incompatible_with: BTreeMap::from([(
+                "ThirdMod".to_string(),
+                VersionReq::parse("<3").unwrap(),
+            )]),
+            load_before: Vec::from(["FourthMod".to_string()]),
+            load_after: Vec::from(["FirstMod".to_string(), "SecondMod".to_string()]),
+            ..Default::default()
+        };
+
+        assert_eq!(parsed, expected);
+
+        let serialized = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Metadata>(&serialized).unwrap(),
+            expected
+        );
+
+        assert!(parsed.supports_game_build(&Version::new(1, 3, 0)));
+        assert!(!parsed.supports_game_build(&Version::new(1, 4, 0)));
+        assert!(Metadata::default().supports_game_build(&Version::new(1, 4, 0)));
+    }
+}
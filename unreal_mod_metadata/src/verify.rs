@@ -0,0 +1,32 @@
+//! Naming rules for mod pak files
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// Match 1: 000
+    /// Match 2: ModName69.author
+    /// Match 3: 1.1.1
+    // regex explanation                                           (  000 )-(Mod69<.author>)-( 1 . 1 . 1 )_P.pak
+    pub static ref MOD_FILENAME_REGEX: Regex = Regex::new(r"(^\d{3})-([a-zA-Z0-9\.]+)-(\d+.\d+.\d+)_P.pak$").unwrap();
+}
+
+pub fn verify_mod_file_name(mod_id: &str) -> bool {
+    if let Some(matches) = MOD_FILENAME_REGEX.captures(mod_id) {
+        if let Some(mod_name) = matches.get(2) {
+            return mod_name
+                .as_str()
+                .chars()
+                .next()
+                .map(|e| e.is_uppercase())
+                .unwrap_or(false);
+        }
+    }
+
+    false
+}
+
+/// Build the pak file name of a mod, the result still has to be checked with [`verify_mod_file_name`]
+pub fn mod_file_name(priority: u32, mod_id: &str, mod_version: &str) -> String {
+    format!("{priority:03}-{mod_id}-{mod_version}_P.pak")
+}
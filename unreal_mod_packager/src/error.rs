@@ -0,0 +1,139 @@
+//! Error type for unreal_mod_packager
+
+use std::error;
+use std::fmt;
+use std::io;
+
+use unreal_pak::PakError;
+
+/// Error type used by unreal_mod_packager
+#[derive(Debug)]
+pub struct PackageError {
+    /// Type of the error
+    pub kind: PackageErrorKind,
+}
+
+impl PackageError {
+    /// construct MissingMetadata error
+    pub fn missing_metadata() -> Self {
+        PackageError {
+            kind: PackageErrorKind::MissingMetadata,
+        }
+    }
+    /// construct InvalidMetadata error
+    pub fn invalid_metadata(err: unreal_mod_metadata::error::Error) -> Self {
+        PackageError {
+            kind: PackageErrorKind::InvalidMetadata(err),
+        }
+    }
+    /// construct InvalidVersion error
+    pub fn invalid_version(version: String) -> Self {
+        PackageError {
+            kind: PackageErrorKind::InvalidVersion(version),
+        }
+    }
+    /// construct InvalidFileName error
+    pub fn invalid_file_name(file_name: String) -> Self {
+        PackageError {
+            kind: PackageErrorKind::InvalidFileName(file_name),
+        }
+    }
+    /// construct UnknownIntegratorKey error
+    pub fn unknown_integrator_key(key: String) -> Self {
+        PackageError {
+            kind: PackageErrorKind::UnknownIntegratorKey(key),
+        }
+    }
+    /// construct InvalidIntegratorData error
+    pub fn invalid_integrator_data(key: String, message: String) -> Self {
+        PackageError {
+            kind: PackageErrorKind::InvalidIntegratorData(key, message),
+        }
+    }
+    /// construct MissingFile error
+    pub fn missing_file(file_name: String) -> Self {
+        PackageError {
+            kind: PackageErrorKind::MissingFile(file_name),
+        }
+    }
+    /// construct UnknownGameName error
+    pub fn unknown_game_name() -> Self {
+        PackageError {
+            kind: PackageErrorKind::UnknownGameName,
+        }
+    }
+}
+
+impl fmt::Display for PackageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            PackageErrorKind::MissingMetadata => write!(f, "metadata.json not found"),
+            PackageErrorKind::InvalidMetadata(ref err) => write!(f, "Invalid metadata: {err}"),
+            PackageErrorKind::InvalidVersion(ref version) => write!(
+                f,
+                "Invalid mod version {version:?}, expected MAJOR.MINOR.PATCH"
+            ),
+            PackageErrorKind::InvalidFileName(ref file_name) => {
+                write!(f, "Invalid mod file name {file_name:?}, mod ids have to start with an uppercase letter and only contain letters, numbers and dots")
+            }
+            PackageErrorKind::UnknownIntegratorKey(ref key) => {
+                write!(f, "Unknown integrator key {key:?}")
+            }
+            PackageErrorKind::InvalidIntegratorData(ref key, ref message) => {
+                write!(f, "Invalid integrator data for {key:?}: {message}")
+            }
+            PackageErrorKind::MissingFile(ref file_name) => {
+                write!(f, "{file_name} is referenced but not part of the mod")
+            }
+            PackageErrorKind::UnknownGameName => write!(
+                f,
+                "Game name couldn't be determined from the project folder, specify it explicitly"
+            ),
+            PackageErrorKind::Io(ref err) => write!(f, "IO error: {err}"),
+            PackageErrorKind::Pak(ref err) => write!(f, "Pak error: {err}"),
+        }
+    }
+}
+
+/// Type of error
+#[derive(Debug)]
+pub enum PackageErrorKind {
+    /// The project folder doesn't contain a metadata.json
+    MissingMetadata,
+    /// The metadata.json couldn't be parsed
+    InvalidMetadata(unreal_mod_metadata::error::Error),
+    /// The mod version isn't a plain semver version
+    InvalidVersion(String),
+    /// The mod id doesn't result in a valid file name
+    InvalidFileName(String),
+    /// Integrator data for a key that no handler uses
+    UnknownIntegratorKey(String),
+    /// Integrator data that the integrator can't use
+    InvalidIntegratorData(String, String),
+    /// A file that is referenced by the metadata is missing
+    MissingFile(String),
+    /// The game name is needed but couldn't be determined
+    UnknownGameName,
+    /// io::Error
+    Io(io::Error),
+    /// unreal_pak::PakError
+    Pak(PakError),
+}
+
+impl error::Error for PackageError {}
+
+impl From<io::Error> for PackageError {
+    fn from(error: io::Error) -> Self {
+        PackageError {
+            kind: PackageErrorKind::Io(error),
+        }
+    }
+}
+
+impl From<PakError> for PackageError {
+    fn from(error: PakError) -> Self {
+        PackageError {
+            kind: PackageErrorKind::Pak(error),
+        }
+    }
+}
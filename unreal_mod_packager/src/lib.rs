@@ -0,0 +1,282 @@
+#![deny(missing_docs)]
+
+//! Build release paks of mods from a project folder
+//!
+//! A project folder contains the `metadata.json` of the mod next to the cooked files
+//! in the same layout as in the game, e.g. `GameName/Content/Mods/MyMod/MyActor.uasset`.
+//! Only the `metadata.json` and the `Content` folders are packed, other files in the project folder
+//! like version control data are left out.
+//! [`Project::validate`] checks everything that the mod manager and integrator rely on,
+//! [`Project::write`] then writes a pak with a file name that the mod manager accepts.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use semver::Version;
+use serde_json::Value;
+use unreal_helpers::game_to_absolute;
+use unreal_mod_metadata::{
+    verify::{mod_file_name, verify_mod_file_name},
+    Metadata,
+};
+use unreal_pak::{pakversion::PakVersion, PakWriter};
+use walkdir::WalkDir;
+
+pub mod error;
+use error::PackageError;
+
+/// Options for validating and writing a mod
+#[derive(Debug, Clone)]
+pub struct PackageOptions {
+    /// Priority in the file name, mods with a higher priority override mods with a lower one
+    pub priority: u32,
+    /// Name of the game folder, determined from the project folder if `None`
+    pub game_name: Option<String>,
+    /// Integrator keys that the game has handlers for, if set other keys are rejected.
+    /// The keys handled by the integrator itself are always accepted.
+    pub integrator_keys: Option<Vec<String>>,
+    /// Compress the pak entries
+    pub compress: bool,
+}
+
+impl Default for PackageOptions {
+    fn default() -> Self {
+        PackageOptions {
+            priority: 0,
+            game_name: None,
+            integrator_keys: None,
+            compress: true,
+        }
+    }
+}
+
+/// Mod project folder
+#[derive(Debug, Clone)]
+pub struct Project {
+    /// Parsed metadata, upgraded to the latest schema
+    pub metadata: Metadata,
+    /// Schema version of the metadata.json in the project
+    pub schema_version: u64,
+    /// Pak entry names with the paths of the files on disk, sorted by entry name
+    pub files: BTreeMap<String, PathBuf>,
+}
+
+impl Project {
+    /// Read the metadata and the file list of a project folder
+    ///
+    /// Hidden files and `.pak` files are skipped, so previously written paks aren't packed again.
+    pub fn load(path: &Path) -> Result<Self, PackageError> {
+        let metadata_path = path.join("metadata.json");
+        if !metadata_path.is_file() {
+            return Err(PackageError::missing_metadata());
+        }
+
+        let metadata = fs::read(&metadata_path)?;
+        let schema_version = serde_json::from_slice::<Value>(&metadata)
+            .ok()
+            .and_then(|e| e.get("schema_version").and_then(Value::as_u64))
+            .unwrap_or(1);
+        let metadata =
+            unreal_mod_metadata::from_slice(&metadata).map_err(PackageError::invalid_metadata)?;
+
+        let mut files = BTreeMap::from([("metadata.json".to_string(), metadata_path)]);
+        for game_dir in fs::read_dir(path)? {
+            let content_path = game_dir?.path().join("Content");
+            if !content_path.is_dir() {
+                continue;
+            }
+
+            let entries = WalkDir::new(&content_path)
+                .into_iter()
+                .filter_entry(|e| !e.file_name().to_string_lossy().starts_with('.'));
+            for entry in entries {
+                let entry = entry.map_err(std::io::Error::from)?;
+                let is_pak = entry.path().extension().is_some_and(|e| e == "pak");
+                if !entry.file_type().is_file() || is_pak {
+                    continue;
+                }
+
+                let relative_path = entry.path().strip_prefix(path).unwrap();
+                let name = relative_path
+                    .components()
+                    .map(|e| e.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                files.insert(name, entry.path().to_path_buf());
+            }
+        }
+
+        Ok(Project {
+            metadata,
+            schema_version,
+            files,
+        })
+    }
+
+    /// Name of the game folder, e.g. `GameName` for `GameName/Content/...`
+    ///
+    /// Only works if exactly one top level folder has a `Content` folder.
+    pub fn game_name(&self) -> Option<String> {
+        let mut game_names = self
+            .files
+            .keys()
+            .filter_map(|e| {
+                let mut parts = e.split('/');
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some(game_name), Some("Content"), Some(_)) => Some(game_name),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        game_names.dedup();
+
+        match game_names[..] {
+            [game_name] => Some(game_name.to_string()),
+            _ => None,
+        }
+    }
+
+    /// File name of the pak, checked against the rules of the mod manager
+    pub fn file_name(&self, priority: u32) -> Result<String, PackageError> {
+        let version = &self.metadata.mod_version;
+        match Version::parse(version) {
+            Ok(parsed) if parsed.pre.is_empty() && parsed.build.is_empty() => {}
+            _ => return Err(PackageError::invalid_version(version.clone())),
+        }
+
+        let file_name = mod_file_name(priority, &self.metadata.mod_id, version);
+        match verify_mod_file_name(&file_name) {
+            true => Ok(file_name),
+            false => Err(PackageError::invalid_file_name(file_name)),
+        }
+    }
+
+    /// Check the project, returns warnings about things that might be intended
+    ///
+    /// Persistent actor maps are only checked for the `.umap` extension,
+    /// they are usually maps of the game and not part of the mod.
+    pub fn validate(&self, options: &PackageOptions) -> Result<Vec<String>, PackageError> {
+        let mut warnings = Vec::new();
+        self.file_name(options.priority)?;
+
+        if self.schema_version < 3 {
+            warnings.push(format!(
+                "metadata.json uses schema version {}, the latest version is 3",
+                self.schema_version
+            ));
+        }
+
+        let game_name = || {
+            options
+                .game_name
+                .clone()
+                .or_else(|| self.game_name())
+                .ok_or_else(PackageError::unknown_game_name)
+        };
+
+        for (key, value) in &self.metadata.integrator {
+            match key.as_str() {
+                "persistent_actor_maps" => {
+                    for map in string_array(key, value)? {
+                        if !map.ends_with(".umap") {
+                            return Err(PackageError::invalid_integrator_data(
+                                key.clone(),
+                                format!("{map:?} is not a .umap file"),
+                            ));
+                        }
+                    }
+                }
+                "persistent_actors" => {
+                    let game_name = game_name()?;
+                    for actor in string_array(key, value)? {
+                        let file_name = game_to_absolute(&game_name, package_path(actor))
+                            .ok_or_else(|| {
+                                PackageError::invalid_integrator_data(
+                                    key.clone(),
+                                    format!("{actor:?} is not a /Game/ path"),
+                                )
+                            })?;
+                        if !self.files.contains_key(&file_name) {
+                            warnings.push(format!(
+                                "Persistent actor {actor} is not part of the mod, it has to be in the game"
+                            ));
+                        }
+                    }
+                }
+                _ => {
+                    if let Some(ref integrator_keys) = options.integrator_keys {
+                        if !integrator_keys.contains(key) {
+                            return Err(PackageError::unknown_integrator_key(key.clone()));
+                        }
+                    }
+                }
+            }
+        }
+
+        if !self.metadata.cpp_loader_dlls.is_empty() {
+            let game_name = game_name()?;
+            for dll in &self.metadata.cpp_loader_dlls {
+                let file_name = game_to_absolute(&game_name, dll).unwrap_or_else(|| dll.clone());
+                if !self.files.contains_key(&file_name) {
+                    return Err(PackageError::missing_file(file_name));
+                }
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    /// Write the pak into `out_dir`, returns the path of the pak
+    ///
+    /// The project should be validated first.
+    pub fn write(&self, out_dir: &Path, options: &PackageOptions) -> Result<PathBuf, PackageError> {
+        let path = out_dir.join(self.file_name(options.priority)?);
+
+        let file = File::create(&path)?;
+        let mut pak = PakWriter::new(
+            BufWriter::new(file),
+            PakVersion::FnameBasedCompressionMethod,
+        );
+        for (name, file_path) in &self.files {
+            pak.write_entry(name, &fs::read(file_path)?, options.compress)?;
+        }
+        pak.finish_write()?;
+
+        Ok(path)
+    }
+}
+
+/// Validate and write a mod project, returns the path of the pak and warnings
+pub fn package(
+    project_path: &Path,
+    out_dir: &Path,
+    options: &PackageOptions,
+) -> Result<(PathBuf, Vec<String>), PackageError> {
+    let project = Project::load(project_path)?;
+    let warnings = project.validate(options)?;
+    let path = project.write(out_dir, options)?;
+    Ok((path, warnings))
+}
+
+fn string_array<'a>(key: &str, value: &'a Value) -> Result<Vec<&'a str>, PackageError> {
+    value
+        .as_array()
+        .and_then(|e| e.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+        .ok_or_else(|| {
+            PackageError::invalid_integrator_data(
+                key.to_string(),
+                "expected an array of strings".to_string(),
+            )
+        })
+}
+
+/// Strip the object name from an object path, `/Game/A/B.B_C` -> `/Game/A/B`
+fn package_path(object_path: &str) -> &str {
+    let name_start = object_path.rfind('/').map(|e| e + 1).unwrap_or(0);
+    match object_path[name_start..].find('.') {
+        Some(dot) => &object_path[..name_start + dot],
+        None => object_path,
+    }
+}
@@ -0,0 +1,83 @@
+use std::path::PathBuf;
+use std::process::exit;
+
+use clap::Parser;
+use unreal_mod_packager::{PackageOptions, Project};
+
+/// Build a release .pak file of a mod from a project folder.
+/// The project folder has to contain a metadata.json next to the game folder with the cooked files.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    /// The project folder
+    project: PathBuf,
+    /// The directory to write the .pak file to, defaults to the current directory
+    #[clap(short, long)]
+    out_dir: Option<PathBuf>,
+    /// Priority of the mod, mods with a higher priority override mods with a lower one
+    #[clap(short, long, default_value_t = 0)]
+    priority: u32,
+    /// Name of the game folder, only needed if it can't be determined from the project folder
+    #[clap(long)]
+    game_name: Option<String>,
+    /// Integrator key that the game has a handler for, when given unknown keys are rejected
+    #[clap(long = "integrator-key", value_name = "KEY")]
+    integrator_keys: Vec<String>,
+    /// Do not use compression when writing the file
+    #[clap(short, long)]
+    no_compression: bool,
+    /// Only validate the project without writing the .pak file
+    #[clap(long)]
+    check: bool,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let options = PackageOptions {
+        priority: args.priority,
+        game_name: args.game_name,
+        integrator_keys: match args.integrator_keys.is_empty() {
+            true => None,
+            false => Some(args.integrator_keys),
+        },
+        compress: !args.no_compression,
+    };
+
+    let project = match Project::load(&args.project) {
+        Ok(project) => project,
+        Err(err) => {
+            eprintln!("Error loading project {:?}! Error: {err}", args.project);
+            exit(1);
+        }
+    };
+
+    match project.validate(&options) {
+        Ok(warnings) => {
+            for warning in warnings {
+                eprintln!("Warning: {warning}");
+            }
+        }
+        Err(err) => {
+            eprintln!("Invalid project! Error: {err}");
+            exit(1);
+        }
+    }
+
+    if args.check {
+        println!(
+            "{} {} is valid",
+            project.metadata.mod_id, project.metadata.mod_version
+        );
+        return;
+    }
+
+    let out_dir = args.out_dir.unwrap_or_else(|| PathBuf::from("."));
+    match project.write(&out_dir, &options) {
+        Ok(path) => println!("Wrote {path:?}"),
+        Err(err) => {
+            eprintln!("Error writing pak! Error: {err}");
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use unreal_mod_packager::error::PackageErrorKind;
+use unreal_mod_packager::{package, PackageOptions, Project};
+use unreal_pak::PakReader;
+
+fn write_project(path: &Path, metadata: &str) {
+    fs::write(path.join("metadata.json"), metadata).unwrap();
+    let content = path
+        .join("Game")
+        .join("Content")
+        .join("Mods")
+        .join("TestMod");
+    fs::create_dir_all(&content).unwrap();
+    fs::write(content.join("Actor.uasset"), b"uasset").unwrap();
+    fs::write(content.join("Actor.uexp"), b"uexp").unwrap();
+}
+
+fn metadata(mod_id: &str, version: &str, integrator: &str) -> String {
+    format!(
+        r#"{{
+            "schema_version": 3,
+            "name": "Test Mod",
+            "mod_id": "{mod_id}",
+            "version": "{version}",
+            "integrator": {integrator}
+        }}"#
+    )
+}
+
+#[test]
+fn package_project() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let project = dir.join("project");
+    fs::create_dir_all(&project).unwrap();
+    write_project(
+        &project,
+        &metadata(
+            "TestMod",
+            "1.2.3",
+            r#"{
+                "persistent_actor_maps": ["Game/Content/Maps/Level.umap"],
+                "persistent_actors": ["/Game/Mods/TestMod/Actor", "/Game/Items/Other.Other_C"],
+                "item_list_entries": {}
+            }"#,
+        ),
+    );
+
+    // files outside of the content folder and paks from previous builds are not packed
+    fs::create_dir_all(project.join(".git")).unwrap();
+    fs::write(project.join(".git").join("HEAD"), b"ref").unwrap();
+    fs::write(project.join("notes.txt"), b"notes").unwrap();
+    fs::write(
+        project.join("Game/Content/Mods/TestMod/.Actor.uasset.swp"),
+        b"swap",
+    )
+    .unwrap();
+    fs::write(project.join("Game/Content/000-TestMod-1.2.2_P.pak"), b"pak").unwrap();
+
+    let options = PackageOptions {
+        priority: 5,
+        ..Default::default()
+    };
+    // the pak is written into the project, it isn't packed when packaging again
+    package(&project, &project, &options).unwrap();
+    let (path, warnings) = package(&project, &project, &options).unwrap();
+    assert_eq!(path, project.join("005-TestMod-1.2.3_P.pak"));
+    // actors that aren't part of the mod might be part of the game
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("/Game/Items/Other.Other_C"));
+
+    let mut pak = PakReader::new(BufReader::new(File::open(&path).unwrap()));
+    pak.load_index().unwrap();
+    assert_eq!(
+        pak.get_entry_names(),
+        vec![
+            "Game/Content/Mods/TestMod/Actor.uasset",
+            "Game/Content/Mods/TestMod/Actor.uexp",
+            "metadata.json",
+        ]
+    );
+    assert_eq!(
+        pak.read_entry(&"Game/Content/Mods/TestMod/Actor.uexp".to_string())
+            .unwrap(),
+        b"uexp"
+    );
+}
+
+#[test]
+fn invalid_projects() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let options = PackageOptions::default();
+
+    let check = |metadata: &str, options: &PackageOptions| {
+        write_project(dir, metadata);
+        Project::load(dir)
+            .unwrap()
+            .validate(options)
+            .unwrap_err()
+            .kind
+    };
+
+    assert!(matches!(
+        check(&metadata("TestMod", "1.0", "{}"), &options),
+        PackageErrorKind::InvalidVersion(_)
+    ));
+    assert!(matches!(
+        check(&metadata("TestMod", "1.0.0-beta", "{}"), &options),
+        PackageErrorKind::InvalidVersion(_)
+    ));
+    assert!(matches!(
+        check(&metadata("testMod", "1.0.0", "{}"), &options),
+        PackageErrorKind::InvalidFileName(_)
+    ));
+    assert!(matches!(
+        check(&metadata("Test_Mod", "1.0.0", "{}"), &options),
+        PackageErrorKind::InvalidFileName(_)
+    ));
+    assert!(matches!(
+        check(
+            &metadata("TestMod", "1.0.0", "{}"),
+            &PackageOptions {
+                priority: 1000,
+                ..Default::default()
+            }
+        ),
+        PackageErrorKind::InvalidFileName(_)
+    ));
+    assert!(matches!(
+        check(
+            &metadata(
+                "TestMod",
+                "1.0.0",
+                r#"{ "persistent_actor_maps": ["Game/Content/Maps/Level.uasset"] }"#
+            ),
+            &options
+        ),
+        PackageErrorKind::InvalidIntegratorData(_, _)
+    ));
+    assert!(matches!(
+        check(
+            &metadata(
+                "TestMod",
+                "1.0.0",
+                r#"{ "persistent_actors": "/Game/Mods/TestMod/Actor" }"#
+            ),
+            &options
+        ),
+        PackageErrorKind::InvalidIntegratorData(_, _)
+    ));
+
+    let known_keys = PackageOptions {
+        integrator_keys: Some(vec!["item_list_entries".to_string()]),
+        ..Default::default()
+    };
+    let unknown_key = metadata("TestMod", "1.0.0", r#"{ "biome_placement": [] }"#);
+    assert!(matches!(
+        check(&unknown_key, &known_keys),
+        PackageErrorKind::UnknownIntegratorKey(key) if key == "biome_placement"
+    ));
+    // without a list of keys every key is accepted
+    assert!(Project::load(dir).unwrap().validate(&options).is_ok());
+
+    fs::remove_file(dir.join("metadata.json")).unwrap();
+    assert!(matches!(
+        Project::load(dir).unwrap_err().kind,
+        PackageErrorKind::MissingMetadata
+    ));
+}
+
+#[test]
+fn old_schema() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    write_project(
+        dir,
+        r#"{
+            "schema_version": 2,
+            "name": "Test Mod",
+            "mod_id": "TestMod",
+            "version": "1.0.0",
+            "cpp_loader_dlls": ["/Game/Mods/TestMod/loader.dll"]
+        }"#,
+    );
+
+    let project = Project::load(dir).unwrap();
+    assert_eq!(project.schema_version, 2);
+    assert_eq!(project.game_name().as_deref(), Some("Game"));
+    // dlls have to be part of the mod
+    assert!(matches!(
+        project
+            .validate(&PackageOptions::default())
+            .unwrap_err()
+            .kind,
+        PackageErrorKind::MissingFile(_)
+    ));
+
+    fs::write(dir.join("Game/Content/Mods/TestMod/loader.dll"), b"dll").unwrap();
+    let warnings = Project::load(dir)
+        .unwrap()
+        .validate(&PackageOptions::default())
+        .unwrap();
+    assert_eq!(warnings.len(), 1);
+}
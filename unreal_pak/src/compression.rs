@@ -102,33 +102,25 @@ impl Compression {
         }
     }
 
-    // These are panics becasue they should hard fail during developement.
-
-    pub(crate) fn decompress(&self, buf: &mut Vec<u8>, data: &[u8]) -> io::Result<()> {
+    pub(crate) fn decompress(&self, buf: &mut Vec<u8>, data: &[u8]) -> Result<(), PakError> {
         match self {
-            Self::Known(method) => match *method {
-                "Zlib" => {
-                    let mut decoder = ZlibDecoder::new(data);
-                    decoder.read_to_end(buf)?;
-                    Ok(())
-                }
-                _ => panic!("Found Compression::Known with unknown compression."),
-            },
-            _ => panic!("Attempted to decompress with Compression type that can't decompress."),
+            Self::Known("Zlib") => {
+                let mut decoder = ZlibDecoder::new(data);
+                decoder.read_to_end(buf)?;
+                Ok(())
+            }
+            _ => Err(PakError::compression_unsupported(*self)),
         }
     }
 
-    pub(crate) fn compress(&self, data: &[u8]) -> io::Result<Vec<u8>> {
+    pub(crate) fn compress(&self, data: &[u8]) -> Result<Vec<u8>, PakError> {
         match self {
-            Self::Known(method) => match *method {
-                "Zlib" => {
-                    let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
-                    encoder.write_all(data)?;
-                    Ok(encoder.finish()?)
-                }
-                _ => panic!("Found Compression::Known with unknown compression."),
-            },
-            _ => panic!("Attempted to compress with Compression type that can't compress."),
+            Self::Known("Zlib") => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(data)?;
+                Ok(encoder.finish()?)
+            }
+            _ => Err(PakError::compression_unsupported(*self)),
         }
     }
 }
@@ -1,5 +1,7 @@
 use std::io::{Read, Seek, SeekFrom, Write};
 
+use unreal_helpers::{read_ext::capped_capacity, UnrealReadExt};
+
 use crate::compression::CompressionMethods;
 use crate::error::PakError;
 use crate::hash;
@@ -28,13 +30,9 @@ where
     let header = Header::read(reader, pak_version, compression)?;
 
     match header.compression_method {
-        Compression::None => {
-            let mut data = vec![0u8; header.decompressed_size as usize];
-            reader.read_exact(data.as_mut_slice())?;
-            Ok(data)
-        }
+        Compression::None => Ok(reader.read_vec(header.decompressed_size as usize)?),
         Compression::Known(_) => {
-            let mut data = Vec::with_capacity(header.decompressed_size as usize);
+            let mut data = Vec::with_capacity(capped_capacity(header.decompressed_size));
 
             let compression_blocks = header
                 .compression_blocks
@@ -42,8 +40,7 @@ where
                 .ok_or_else(PakError::entry_invalid)?;
             for block in compression_blocks {
                 // we do not need to seek here because the reader is at the end of the header and compression blocks are continuous
-                let mut compressed_data = vec![0u8; block.size as usize];
-                reader.read_exact(&mut compressed_data)?;
+                let compressed_data = reader.read_vec(block.size as usize)?;
                 header
                     .compression_method
                     .decompress(&mut data, compressed_data.as_slice())?;
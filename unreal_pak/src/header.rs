@@ -18,6 +18,7 @@ use std::io::{self, Read, Seek, Write};
 
 use bitvec::prelude::*;
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+use unreal_helpers::read_ext::capped_capacity;
 
 use crate::compression::{Compression, CompressionMethods};
 use crate::error::PakError;
@@ -75,20 +76,24 @@ impl Header {
         if pak_version >= PakVersion::CompressionEncryption {
             if !matches!(compression_method, Compression::None) {
                 let block_count = reader.read_u32::<LE>()? as usize;
-                let mut compression_blocks_inner = Vec::with_capacity(block_count);
+                let mut compression_blocks_inner = Vec::with_capacity(capped_capacity(block_count));
 
                 for _ in 0..block_count {
                     // convert old absolute to relative offsets
-                    let start_offset = reader.read_u64::<LE>()?
-                        - if pak_version < PakVersion::RelativeChunkOffsets {
+                    let start_offset = reader
+                        .read_u64::<LE>()?
+                        .checked_sub(if pak_version < PakVersion::RelativeChunkOffsets {
                             offset
                         } else {
                             0
-                        };
+                        })
+                        .ok_or_else(PakError::entry_invalid)?;
                     let end_offset = reader.read_u64::<LE>()?;
                     compression_blocks_inner.push(Block {
                         start: start_offset,
-                        size: end_offset - start_offset,
+                        size: end_offset
+                            .checked_sub(start_offset)
+                            .ok_or_else(PakError::entry_invalid)?,
                     });
                 }
                 compression_blocks = Some(compression_blocks_inner);
@@ -2,7 +2,7 @@ use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
 use byteorder::{ReadBytesExt, WriteBytesExt, BE, LE};
 
-use unreal_helpers::{UnrealReadExt, UnrealWriteExt};
+use unreal_helpers::{read_ext::capped_capacity, UnrealReadExt, UnrealWriteExt};
 
 use crate::compression::CompressionMethods;
 use crate::error::PakError;
@@ -28,7 +28,7 @@ impl Index {
         let mut path_hash_seed = None;
 
         let entry_count = reader.read_u32::<LE>()?;
-        let mut entries = Vec::with_capacity(entry_count as usize);
+        let mut entries = Vec::with_capacity(capped_capacity(entry_count));
 
         if footer.pak_version < PakVersion::PathHashIndex {
             for _ in 0..entry_count {
@@ -0,0 +1,103 @@
+use std::io::Cursor;
+
+use proptest::prelude::*;
+
+use unreal_pak::PakReader;
+
+macro_rules! test_pak {
+    ($name:literal) => {
+        include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/testfiles/", $name))
+    };
+}
+
+const PAKS: [&[u8]; 4] = [
+    test_pak!("000-TestPak-cus-C_P.pak"),
+    test_pak!("000-TestPak-cus-NoC_P.pak"),
+    test_pak!("000-TestPak-off-C_P.pak"),
+    test_pak!("000-TestPak-off-NoC_P.pak"),
+];
+
+/// Change to apply to a valid pak
+///
+/// Positions are counted from the end of the pak, where the footer and the index are.
+#[derive(Debug, Clone)]
+enum Mutation {
+    /// Overwrite a byte
+    Byte(usize, u8),
+    /// Overwrite eight bytes with a `u64`, sizes and offsets are usually `u64`s
+    Int(usize, u64),
+    /// Cut off the pak
+    Truncate(usize),
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        (1..4096usize, any::<u8>()).prop_map(|(position, value)| Mutation::Byte(position, value)),
+        (
+            8..4096usize,
+            prop_oneof![Just(0), Just(u64::MAX), Just(u32::MAX as u64), any::<u64>()]
+        )
+            .prop_map(|(position, value)| Mutation::Int(position, value)),
+        (1..4096usize).prop_map(Mutation::Truncate),
+    ]
+}
+
+fn mutate(data: &[u8], mutations: &[Mutation]) -> Vec<u8> {
+    let mut data = data.to_vec();
+    for mutation in mutations {
+        let position = |from_end: usize| data.len().saturating_sub(from_end);
+        match *mutation {
+            Mutation::Byte(from_end, value) => {
+                let position = position(from_end);
+                if let Some(byte) = data.get_mut(position) {
+                    *byte = value;
+                }
+            }
+            Mutation::Int(from_end, value) => {
+                let position = position(from_end);
+                let end = (position + 8).min(data.len());
+                data[position..end].copy_from_slice(&value.to_le_bytes()[..end - position]);
+            }
+            Mutation::Truncate(from_end) => data.truncate(position(from_end)),
+        }
+    }
+    data
+}
+
+/// Read the index and all entries of a pak, stopping at the first error
+fn read_pak(data: Vec<u8>) -> Result<(), unreal_pak::PakError> {
+    let mut pak = PakReader::new(Cursor::new(data));
+    pak.load_index()?;
+
+    let names = pak
+        .get_entry_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    for name in &names {
+        pak.read_entry(name)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn valid_paks() {
+    for pak in PAKS {
+        read_pak(pak.to_vec()).unwrap();
+    }
+}
+
+proptest! {
+    #[test]
+    fn mutated_paks(
+        pak in 0..PAKS.len(),
+        mutations in prop::collection::vec(mutation(), 1..4),
+    ) {
+        let _ = read_pak(mutate(PAKS[pak], &mutations));
+    }
+
+    #[test]
+    fn random_bytes(data in prop::collection::vec(any::<u8>(), 0..512)) {
+        let _ = read_pak(data);
+    }
+}